@@ -25,6 +25,16 @@ export const A320Failure = Object.freeze({
   OutflowValve: 21017,
   SafetyValve: 21018,
   RapidDecompression: 21019,
+  DoorSealLeak1: 21020,
+  DoorSealLeak2: 21021,
+  DoorSealLeak3: 21022,
+  DoorSealLeak4: 21023,
+  DoorSealLeak5: 21024,
+  DoorSealLeak6: 21025,
+  DoorSealLeak7: 21026,
+  CockpitHullBreach: 21027,
+  FwdCabinHullBreach: 21028,
+  AftCabinHullBreach: 21029,
 
   Fac1Failure: 22000,
   Fac2Failure: 22001,
@@ -62,6 +72,25 @@ export const A320Failure = Object.freeze({
   Sec3Failure: 27004,
   Fcdc1Failure: 27005,
   Fcdc2Failure: 27006,
+  LeftAileronBlueActuatorLeak: 27100,
+  LeftAileronGreenActuatorLeak: 27101,
+  RightAileronBlueActuatorLeak: 27102,
+  RightAileronGreenActuatorLeak: 27103,
+  LeftElevatorBlueActuatorLeak: 27104,
+  LeftElevatorGreenActuatorLeak: 27105,
+  RightElevatorBlueActuatorLeak: 27106,
+  RightElevatorYellowActuatorLeak: 27107,
+  RudderGreenActuatorLeak: 27108,
+  RudderBlueActuatorLeak: 27109,
+  RudderYellowActuatorLeak: 27110,
+
+  CenterTankLeak: 28000,
+  LeftInnerTankLeak: 28001,
+  LeftOuterTankLeak: 28002,
+  RightInnerTankLeak: 28003,
+  RightOuterTankLeak: 28004,
+  Engine1FeedLineLeak: 28010,
+  Engine2FeedLineLeak: 28011,
 
   GreenReservoirLeak: 29000,
   BlueReservoirLeak: 29001,
@@ -76,6 +105,12 @@ export const A320Failure = Object.freeze({
   BlueEpumpOverheat: 29010,
   YellowEdpOverheat: 29011,
   YellowEpumpOverheat: 29012,
+  GreenEdpSectionLeak: 29013,
+  BlueEpumpSectionLeak: 29014,
+  YellowEdpSectionLeak: 29015,
+  GreenSystemSectionLeak: 29016,
+  BlueSystemSectionLeak: 29017,
+  YellowSystemSectionLeak: 29018,
 
   LeftPfdDisplay: 31000,
   RightPfdDisplay: 31001,
@@ -106,6 +141,13 @@ export const A320Failure = Object.freeze({
   GearActuatorJammedGearDoorLeft: 32024,
   GearActuatorJammedGearDoorRight: 32025,
 
+  GearActuatorLeakGearNose: 32030,
+  GearActuatorLeakGearLeft: 32031,
+  GearActuatorLeakGearRight: 32032,
+  GearActuatorLeakGearDoorNose: 32033,
+  GearActuatorLeakGearDoorLeft: 32034,
+  GearActuatorLeakGearDoorRight: 32035,
+
   GreenBrakeHydraulicLeak: 32100,
   YellowBrakeHydraulicLeak: 32101,
   YellowBrakeAccumulatorGasLeak: 32150,
@@ -116,6 +158,50 @@ export const A320Failure = Object.freeze({
   RadioAntennaInterrupted2: 34011,
   RadioAntennaDirectCoupling1: 34020,
   RadioAntennaDirectCoupling2: 34021,
+  AirDataReference1: 34100,
+  AirDataReference2: 34101,
+  AirDataReference3: 34102,
+  AirDataReferenceFault1: 34110,
+  AirDataReferenceFault2: 34111,
+  AirDataReferenceFault3: 34112,
+  InertialReference1: 34120,
+  InertialReference2: 34121,
+  InertialReference3: 34122,
+  InertialReferenceAttitudeOnly1: 34130,
+  InertialReferenceAttitudeOnly2: 34131,
+  InertialReferenceAttitudeOnly3: 34132,
+  InertialReferenceAlignmentFault1: 34140,
+  InertialReferenceAlignmentFault2: 34141,
+  InertialReferenceAlignmentFault3: 34142,
+
+  BleedAirDuctLeakPylon1: 36000,
+  BleedAirDuctLeakPylon2: 36001,
+  BleedAirDuctLeakWing1: 36010,
+  BleedAirDuctLeakWing2: 36011,
+  BleedAirDuctLeakApu: 36020,
+  BleedAirDuctLeakPackBay: 36030,
+  FanAirValveStuckClosed1: 36100,
+  FanAirValveStuckClosed2: 36101,
+  FanAirValveStuckOpen1: 36110,
+  FanAirValveStuckOpen2: 36111,
+  PrecoolerFouling1: 36120,
+  PrecoolerFouling2: 36121,
+
+  ApuOilLeak: 49000,
+  ApuIgnitionFault: 49001,
+  ApuEgtMarginDegradation: 49002,
+
+  Engine1FanBladeDamage: 72000,
+  Engine2FanBladeDamage: 72001,
+  Engine1BirdStrike: 72010,
+  Engine2BirdStrike: 72011,
+
+  Engine1OilLowQuantity: 79000,
+  Engine2OilLowQuantity: 79001,
+  Engine1OilFilterClog: 79010,
+  Engine2OilFilterClog: 79011,
+  Engine1OilPressureTransmitter: 79020,
+  Engine2OilPressureTransmitter: 79021,
 });
 
 export const A320FailureDefinitions: FailureDefinition[] = [
@@ -139,6 +225,16 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [21, A320Failure.OutflowValve, 'Outflow Valve'],
   [21, A320Failure.SafetyValve, 'Safety Valve'],
   [21, A320Failure.RapidDecompression, 'Rapid Decompression'],
+  [21, A320Failure.DoorSealLeak1, 'Door Seal Leak - Door 1'],
+  [21, A320Failure.DoorSealLeak2, 'Door Seal Leak - Door 2'],
+  [21, A320Failure.DoorSealLeak3, 'Door Seal Leak - Door 3'],
+  [21, A320Failure.DoorSealLeak4, 'Door Seal Leak - Door 4'],
+  [21, A320Failure.DoorSealLeak5, 'Door Seal Leak - Door 5'],
+  [21, A320Failure.DoorSealLeak6, 'Door Seal Leak - Door 6'],
+  [21, A320Failure.DoorSealLeak7, 'Door Seal Leak - Door 7'],
+  [21, A320Failure.CockpitHullBreach, 'Progressive Hull Breach - Cockpit'],
+  [21, A320Failure.FwdCabinHullBreach, 'Progressive Hull Breach - Forward Cabin'],
+  [21, A320Failure.AftCabinHullBreach, 'Progressive Hull Breach - Aft Cabin'],
 
   [22, A320Failure.Fac1Failure, 'FAC 1'],
   [22, A320Failure.Fac2Failure, 'FAC 2'],
@@ -176,6 +272,25 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [27, A320Failure.Sec3Failure, 'SEC 3'],
   [27, A320Failure.Fcdc1Failure, 'FCDC 1'],
   [27, A320Failure.Fcdc2Failure, 'FCDC 2'],
+  [27, A320Failure.LeftAileronBlueActuatorLeak, 'Left aileron blue actuator leak'],
+  [27, A320Failure.LeftAileronGreenActuatorLeak, 'Left aileron green actuator leak'],
+  [27, A320Failure.RightAileronBlueActuatorLeak, 'Right aileron blue actuator leak'],
+  [27, A320Failure.RightAileronGreenActuatorLeak, 'Right aileron green actuator leak'],
+  [27, A320Failure.LeftElevatorBlueActuatorLeak, 'Left elevator blue actuator leak'],
+  [27, A320Failure.LeftElevatorGreenActuatorLeak, 'Left elevator green actuator leak'],
+  [27, A320Failure.RightElevatorBlueActuatorLeak, 'Right elevator blue actuator leak'],
+  [27, A320Failure.RightElevatorYellowActuatorLeak, 'Right elevator yellow actuator leak'],
+  [27, A320Failure.RudderGreenActuatorLeak, 'Rudder green actuator leak'],
+  [27, A320Failure.RudderBlueActuatorLeak, 'Rudder blue actuator leak'],
+  [27, A320Failure.RudderYellowActuatorLeak, 'Rudder yellow actuator leak'],
+
+  [28, A320Failure.CenterTankLeak, 'Center tank leak'],
+  [28, A320Failure.LeftInnerTankLeak, 'Left inner tank leak'],
+  [28, A320Failure.LeftOuterTankLeak, 'Left outer tank leak'],
+  [28, A320Failure.RightInnerTankLeak, 'Right inner tank leak'],
+  [28, A320Failure.RightOuterTankLeak, 'Right outer tank leak'],
+  [28, A320Failure.Engine1FeedLineLeak, 'Engine 1 feed line leak'],
+  [28, A320Failure.Engine2FeedLineLeak, 'Engine 2 feed line leak'],
 
   [29, A320Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A320Failure.BlueReservoirLeak, 'Blue reservoir leak'],
@@ -190,6 +305,12 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [29, A320Failure.BlueEpumpOverheat, 'Blue electric pump overheat'],
   [29, A320Failure.YellowEdpOverheat, 'Yellow engine pump overheat'],
   [29, A320Failure.YellowEpumpOverheat, 'Yellow electric pump overheat'],
+  [29, A320Failure.GreenEdpSectionLeak, 'Green engine pump section leak'],
+  [29, A320Failure.BlueEpumpSectionLeak, 'Blue electric pump section leak'],
+  [29, A320Failure.YellowEdpSectionLeak, 'Yellow engine pump section leak'],
+  [29, A320Failure.GreenSystemSectionLeak, 'Green system section leak'],
+  [29, A320Failure.BlueSystemSectionLeak, 'Blue system section leak'],
+  [29, A320Failure.YellowSystemSectionLeak, 'Yellow system section leak'],
 
   [31, A320Failure.LeftPfdDisplay, 'Captain PFD display'],
   [31, A320Failure.RightPfdDisplay, 'F/O PFD display'],
@@ -219,6 +340,13 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [32, A320Failure.GearActuatorJammedGearDoorLeft, 'Main left gear door jammed actuator'],
   [32, A320Failure.GearActuatorJammedGearDoorRight, 'Main right gear door jammed actuator'],
 
+  [32, A320Failure.GearActuatorLeakGearNose, 'Nose gear actuator leak'],
+  [32, A320Failure.GearActuatorLeakGearLeft, 'Main left gear actuator leak'],
+  [32, A320Failure.GearActuatorLeakGearRight, 'Main right gear actuator leak'],
+  [32, A320Failure.GearActuatorLeakGearDoorNose, 'Nose gear door actuator leak'],
+  [32, A320Failure.GearActuatorLeakGearDoorLeft, 'Main left gear door actuator leak'],
+  [32, A320Failure.GearActuatorLeakGearDoorRight, 'Main right gear door actuator leak'],
+
   [32, A320Failure.GreenBrakeHydraulicLeak, 'Green brakes circuit leak'],
   [32, A320Failure.YellowBrakeHydraulicLeak, 'Yellow brakes circuit leak'],
   [32, A320Failure.YellowBrakeAccumulatorGasLeak, 'Yellow brake accumulator gas leak'],
@@ -229,4 +357,45 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [34, A320Failure.RadioAntennaInterrupted2, 'RA 2 Interrupted'],
   [34, A320Failure.RadioAntennaDirectCoupling1, 'RA 1 Direct Coupling'],
   [34, A320Failure.RadioAntennaDirectCoupling2, 'RA 2 Direct Coupling'],
+  [34, A320Failure.AirDataReference1, 'ADR 1'],
+  [34, A320Failure.AirDataReference2, 'ADR 2'],
+  [34, A320Failure.AirDataReference3, 'ADR 3'],
+  [34, A320Failure.AirDataReferenceFault1, 'ADR 1 Fault'],
+  [34, A320Failure.AirDataReferenceFault2, 'ADR 2 Fault'],
+  [34, A320Failure.AirDataReferenceFault3, 'ADR 3 Fault'],
+  [34, A320Failure.InertialReference1, 'IR 1'],
+  [34, A320Failure.InertialReference2, 'IR 2'],
+  [34, A320Failure.InertialReference3, 'IR 3'],
+  [34, A320Failure.InertialReferenceAttitudeOnly1, 'IR 1 Attitude Only'],
+  [34, A320Failure.InertialReferenceAttitudeOnly2, 'IR 2 Attitude Only'],
+  [34, A320Failure.InertialReferenceAttitudeOnly3, 'IR 3 Attitude Only'],
+  [34, A320Failure.InertialReferenceAlignmentFault1, 'IR 1 Alignment Fault'],
+  [34, A320Failure.InertialReferenceAlignmentFault2, 'IR 2 Alignment Fault'],
+  [34, A320Failure.InertialReferenceAlignmentFault3, 'IR 3 Alignment Fault'],
+
+  [36, A320Failure.BleedAirDuctLeakPylon1, 'Engine 1 pylon bleed duct leak'],
+  [36, A320Failure.BleedAirDuctLeakPylon2, 'Engine 2 pylon bleed duct leak'],
+  [36, A320Failure.BleedAirDuctLeakWing1, 'Left wing bleed duct leak'],
+  [36, A320Failure.BleedAirDuctLeakWing2, 'Right wing bleed duct leak'],
+  [36, A320Failure.BleedAirDuctLeakApu, 'APU bleed duct leak'],
+  [36, A320Failure.BleedAirDuctLeakPackBay, 'Pack bay crossbleed duct leak'],
+  [36, A320Failure.FanAirValveStuckClosed1, 'Engine 1 fan air valve stuck closed'],
+  [36, A320Failure.FanAirValveStuckClosed2, 'Engine 2 fan air valve stuck closed'],
+  [36, A320Failure.FanAirValveStuckOpen1, 'Engine 1 fan air valve stuck open'],
+  [36, A320Failure.FanAirValveStuckOpen2, 'Engine 2 fan air valve stuck open'],
+  [36, A320Failure.PrecoolerFouling1, 'Engine 1 precooler fouling'],
+  [36, A320Failure.PrecoolerFouling2, 'Engine 2 precooler fouling'],
+  [49, A320Failure.ApuOilLeak, 'APU oil leak'],
+  [49, A320Failure.ApuIgnitionFault, 'APU ignition fault'],
+  [49, A320Failure.ApuEgtMarginDegradation, 'APU EGT margin degradation'],
+  [72, A320Failure.Engine1FanBladeDamage, 'Engine 1 fan blade damage'],
+  [72, A320Failure.Engine2FanBladeDamage, 'Engine 2 fan blade damage'],
+  [72, A320Failure.Engine1BirdStrike, 'Engine 1 bird strike'],
+  [72, A320Failure.Engine2BirdStrike, 'Engine 2 bird strike'],
+  [79, A320Failure.Engine1OilLowQuantity, 'Engine 1 oil low quantity'],
+  [79, A320Failure.Engine2OilLowQuantity, 'Engine 2 oil low quantity'],
+  [79, A320Failure.Engine1OilFilterClog, 'Engine 1 oil filter clog'],
+  [79, A320Failure.Engine2OilFilterClog, 'Engine 2 oil filter clog'],
+  [79, A320Failure.Engine1OilPressureTransmitter, 'Engine 1 oil pressure transmitter'],
+  [79, A320Failure.Engine2OilPressureTransmitter, 'Engine 2 oil pressure transmitter'],
 ];
@@ -44,6 +44,13 @@ export class A32NX_Refuel {
   update(_deltaTime) {
     const refuelStartedByUser = SimVar.GetSimVarValue('L:A32NX_REFUEL_STARTED_BY_USR', 'Bool');
     const gsxFuelHose = SimVar.GetSimVarValue('L:FSDT_GSX_FUELHOSE_CONNECTED', 'Number');
+    // refuelling is refused while the load once refuelled stays outside the weight and balance envelopes
+    if (SimVar.GetSimVarValue('L:A32NX_AIRFRAME_CG_OUT_OF_LIMITS_DESIRED', 'Bool')) {
+      if (refuelStartedByUser) {
+        SimVar.SetSimVarValue('L:A32NX_REFUEL_STARTED_BY_USR', 'Bool', false);
+      }
+      return;
+    }
     if (!refuelStartedByUser && gsxFuelHose == 0) {
       return;
     }
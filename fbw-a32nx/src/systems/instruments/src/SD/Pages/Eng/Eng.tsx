@@ -92,7 +92,7 @@ interface ComponentPositionProps {
 }
 
 const PressureGauge = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps) => {
-  const [engineOilPressure] = useSimVar(`ENG OIL PRESSURE:${engineNumber}`, 'psi', 100);
+  const [engineOilPressure] = useSimVar(`L:A32NX_ENGINE_OIL_PRESSURE:${engineNumber}`, 'psi', 100);
   const displayedEngineOilPressure = Math.round(engineOilPressure / 2) * 2; // Engine oil pressure has a step of 2
   const OIL_PSI_MAX = 130;
   const OIL_PSI_HIGH_LIMIT = 130;
@@ -190,11 +190,11 @@ const PressureGauge = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps)
 };
 
 const QuantityGauge = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps) => {
-  const [engineOilQuantity] = useSimVar(`ENG OIL QUANTITY:${engineNumber}`, 'percent', 100);
+  const [engineOilQuantity] = useSimVar(`L:A32NX_ENGINE_OIL_QTY:${engineNumber}`, 'number', 100);
   const OIL_QTY_MAX = 24.25;
   const OIL_QTY_LOW_ADVISORY = 1.35;
   const displayedEngineOilQuantity =
-    engineOilQuantity === 100 ? OIL_QTY_MAX : Math.round(((engineOilQuantity / 100) * OIL_QTY_MAX) / 0.5) * 0.5; // Engine oil quantity has a step of 0.2
+    Math.round(Math.min(engineOilQuantity, OIL_QTY_MAX) / 0.5) * 0.5; // Engine oil quantity has a step of 0.5
   const [quantityAtOrBelowLow, setQuantityAtOrBelowLow] = useState(false);
   const [shouldQuantityPulse, setShouldQuantityPulse] = useState(false);
 
@@ -324,7 +324,7 @@ const EngineColumn = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps) =
   const displayedFuelUsed =
     parseInt(weightUnit) === 1 ? Math.round(fuelUsed / 10) * 10 : Math.round(fuelUsed / 0.4535934 / 20) * 20;
 
-  const [engineOilTemperature] = useSimVar(`GENERAL ENG OIL TEMPERATURE:${engineNumber}`, 'celsius', 250);
+  const [engineOilTemperature] = useSimVar(`L:A32NX_ENGINE_OIL_TEMPERATURE:${engineNumber}`, 'celsius', 250);
   const OIL_TEMP_LOW_TAKEOFF = 38;
   const OIL_TEMP_HIGH_ADVISORY = 140;
   const OIL_TEMP_VHIGH_LIMIT = 155;
@@ -1,6 +1,7 @@
 // Copyright (c) 2023-2024 FlyByWire Simulations
 // SPDX-License-Identifier: GPL-3.0
 
+#include "arinc429.hpp"
 #include "logging.h"
 #include "lvar_encoder.hpp"
 #include "simple_assert.h"
@@ -173,14 +174,8 @@ void EngineControl_A32NX::initializeEngineControlData() {
   // Initialize Engine Imbalance
   const double imbalance = generateEngineImbalance();
   simData.engineImbalance->set(imbalance);
-  const double engineImbalanced = imbalanceExtractor(imbalance, 1);
-  const double oilQtyImbalance  = imbalanceExtractor(imbalance, 5) / 10;
 
-  // Setting initial Oil with some randomness and imbalance
-  const double idleOilL = (rand() % (MAX_OIL - MIN_OIL + 1) + MIN_OIL) / 10;
-  simData.engineOilTotal[L]->set(idleOilL - ((engineImbalanced == 1) ? oilQtyImbalance : 0));
-  const double idleOilR = (rand() % (MAX_OIL - MIN_OIL + 1) + MIN_OIL) / 10;
-  simData.engineOilTotal[R]->set(idleOilR - ((engineImbalanced == 2) ? oilQtyImbalance : 0));
+  // Oil quantities (A32NX_ENGINE_OIL_QTY/TOTAL) are owned by the systems oil model
 
   const bool engine1Combustion = static_cast<bool>(simData.engineCombustion[L]->updateFromSim(timeStamp, tickCounter));
   const bool engine2Combustion = static_cast<bool>(simData.engineCombustion[R]->updateFromSim(timeStamp, tickCounter));
@@ -885,8 +880,9 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
       fuelRightPre = 0;
     }
 
-    /// apu fuel consumption for this frame in pounds
-    double apuFuelConsumption = simData.simVarsDataPtr->data().apuFuelConsumption * weightLbsPerGallon * deltaTimeHours;
+    /// apu fuel consumption for this frame in pounds, as metered by the APU ECB so that the fuel burnt matches its fuel used
+    const Arinc429NumericWord apuFuelFlow(simData.apuFuelFlow->get());
+    double apuFuelConsumption = apuFuelFlow.valueOr(0.0) * Fadec::KGS_TO_LBS * deltaTimeHours;
 
     // check if APU is actually running instead of just the ASU which doesn't consume fuel
     if (apuNpercent <= 0.0) {
@@ -947,9 +943,22 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
       xfrCenterToRight = fuelCenterPre - centerQuantity;
 
     //--------------------------------------------
-    // Final Fuel levels for left and right inner tanks
-    const double fuelLeft  = (fuelLeftPre - (fuelBurn1 * Fadec::KGS_TO_LBS)) + xfrAuxLeft + xfrCenterToLeft - apuBurn1;     // Pounds
-    const double fuelRight = (fuelRightPre - (fuelBurn2 * Fadec::KGS_TO_LBS)) + xfrAuxRight + xfrCenterToRight - apuBurn2;  // Pounds
+    // Leaks, drained at the flows published by the fuel system (kg/s)
+    const double leakFactor   = Fadec::KGS_TO_LBS * deltaTimeSeconds;
+    const double leakCenter   = (std::min)(centerQuantity, simData.fuelTankLeakFlow[0]->get() * leakFactor);    // Pounds
+    const double leakLeft     = (std::min)(fuelLeftPre, simData.fuelTankLeakFlow[1]->get() * leakFactor);       // Pounds
+    const double leakAuxLeft  = (std::min)(leftAuxQuantity, simData.fuelTankLeakFlow[2]->get() * leakFactor);   // Pounds
+    const double leakRight    = (std::min)(fuelRightPre, simData.fuelTankLeakFlow[3]->get() * leakFactor);      // Pounds
+    const double leakAuxRight = (std::min)(rightAuxQuantity, simData.fuelTankLeakFlow[4]->get() * leakFactor);  // Pounds
+
+    const double fuelCenter   = centerQuantity - leakCenter;      // Pounds
+    const double fuelAuxLeft  = leftAuxQuantity - leakAuxLeft;    // Pounds
+    const double fuelAuxRight = rightAuxQuantity - leakAuxRight;  // Pounds
+
+    //--------------------------------------------
+    // Final Fuel levels for left and right inner tanks (Pounds)
+    const double fuelLeft  = (fuelLeftPre - (fuelBurn1 * Fadec::KGS_TO_LBS)) + xfrAuxLeft + xfrCenterToLeft - apuBurn1 - leakLeft;
+    const double fuelRight = (fuelRightPre - (fuelBurn2 * Fadec::KGS_TO_LBS)) + xfrAuxRight + xfrCenterToRight - apuBurn2 - leakRight;
 
     //--------------------------------------------
     // Setting new pre-cycle conditions
@@ -959,9 +968,9 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
     simData.engineFuelUsed[L]->set(fuelUsedLeft);
     simData.engineFuelUsed[R]->set(fuelUsedRight);
 
-    simData.fuelAuxLeftPre->set(leftAuxQuantity);
-    simData.fuelAuxRightPre->set(rightAuxQuantity);
-    simData.fuelCenterPre->set(centerQuantity);
+    simData.fuelAuxLeftPre->set(fuelAuxLeft);
+    simData.fuelAuxRightPre->set(fuelAuxRight);
+    simData.fuelCenterPre->set(fuelCenter);
 
     simData.fuelLeftPre->set(fuelLeft);    // in Pounds
     simData.fuelRightPre->set(fuelRight);  // in Pounds
@@ -969,6 +978,13 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
     simData.fuelFeedTankDataPtr->data().fuelLeftMain  = (fuelLeft / weightLbsPerGallon);
     simData.fuelFeedTankDataPtr->data().fuelRightMain = (fuelRight / weightLbsPerGallon);
     simData.fuelFeedTankDataPtr->writeDataToSim();
+
+    if (leakCenter > 0 || leakAuxLeft > 0 || leakAuxRight > 0) {
+      simData.fuelCandAuxDataPtr->data().fuelCenter   = (fuelCenter / weightLbsPerGallon);
+      simData.fuelCandAuxDataPtr->data().fuelLeftAux  = (fuelAuxLeft / weightLbsPerGallon);
+      simData.fuelCandAuxDataPtr->data().fuelRightAux = (fuelAuxRight / weightLbsPerGallon);
+      simData.fuelCandAuxDataPtr->writeDataToSim();
+    }
   }
 
   //--------------------------------------------
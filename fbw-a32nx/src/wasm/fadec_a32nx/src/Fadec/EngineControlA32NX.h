@@ -63,8 +63,6 @@ class EngineControl_A32NX {
   bool   wasFlexActive = false;
 
   // additional constants
-  static constexpr int    MAX_OIL             = 200;
-  static constexpr int    MIN_OIL             = 140;
   static constexpr double FUEL_RATE_THRESHOLD = 661;  // lbs/sec for determining fuel ui tampering
 
   /**
@@ -103,7 +103,6 @@ class FadecSimData_A32NX {
     FLOAT64 ambientPressure;           // Millibars
     FLOAT64 ambientTemperature;        // Celsius
     FLOAT64 animationDeltaTime;        // Seconds
-    FLOAT64 apuFuelConsumption;        // Gallons per hour
     FLOAT64 engineAntiIce[2];          // Bool
     FLOAT64 engineFuelValveOpen[2];    // Number
     FLOAT64 engineIgniter[2];          // Number
@@ -132,7 +131,6 @@ class FadecSimData_A32NX {
       {"AMBIENT PRESSURE",             0,  UNITS.Millibars}, // ambientPressure
       {"AMBIENT TEMPERATURE",          0,  UNITS.Celsius  }, // ambientTemperature
       {"ANIMATION DELTA TIME",         0,  UNITS.Seconds  }, // animationDeltaTime
-      {"FUELSYSTEM LINE FUEL FLOW",    18, UNITS.Gph      }, // apuFuelConsumption
       {"ENG ANTI ICE",                 1,  UNITS.Bool     }, // engineAntiIce[0]
       {"ENG ANTI ICE",                 2,  UNITS.Bool     }, // engineAntiIce[1]
       {"FUELSYSTEM VALVE OPEN",        1,  UNITS.Number   }, // engineFuelValveOpen[0]
@@ -186,6 +184,7 @@ class FadecSimData_A32NX {
 
   // LVars
   NamedVariablePtr airlinerToFlexTemp;  // Celsius
+  NamedVariablePtr apuFuelFlow;         // Arinc429, Kg/hr
   NamedVariablePtr apuRpmPercent;       // Percent
   NamedVariablePtr engineEgt[2];
   NamedVariablePtr engineFF[2];
@@ -197,8 +196,6 @@ class FadecSimData_A32NX {
   NamedVariablePtr engineImbalance;
   NamedVariablePtr engineN1[2];
   NamedVariablePtr engineN2[2];
-  NamedVariablePtr engineOilTotal[2];
-  NamedVariablePtr engineOil[2];
   NamedVariablePtr enginePreFF[2];
   NamedVariablePtr engineStarterPressurized[2];
   NamedVariablePtr engineState[2];
@@ -209,6 +206,7 @@ class FadecSimData_A32NX {
   NamedVariablePtr fuelLeftPre;      // Pounds
   NamedVariablePtr fuelPumpState[2];
   NamedVariablePtr fuelRightPre;
+  NamedVariablePtr fuelTankLeakFlow[5];  // Kg/s, center, left inner, left outer, right inner, right outer
   NamedVariablePtr packsState[2];
   NamedVariablePtr refuelRate;
   NamedVariablePtr refuelStartedByUser;
@@ -314,12 +312,6 @@ class FadecSimData_A32NX {
     engineN2[L] = dm->make_named_var("A32NX_ENGINE_N2:1", UNITS.Number, AUTO_READ_WRITE);
     engineN2[R] = dm->make_named_var("A32NX_ENGINE_N2:2", UNITS.Number, AUTO_READ_WRITE);
 
-    engineOil[L] = dm->make_named_var("A32NX_ENGINE_OIL_QTY:1", UNITS.Number, AUTO_READ_WRITE);
-    engineOil[R] = dm->make_named_var("A32NX_ENGINE_OIL_QTY:2", UNITS.Number, AUTO_READ_WRITE);
-
-    engineOilTotal[L] = dm->make_named_var("A32NX_ENGINE_OIL_TOTAL:1", UNITS.Number, AUTO_READ_WRITE);
-    engineOilTotal[R] = dm->make_named_var("A32NX_ENGINE_OIL_TOTAL:2", UNITS.Number, AUTO_READ_WRITE);
-
     enginePreFF[L] = dm->make_named_var("A32NX_ENGINE_PRE_FF:1", UNITS.Number, AUTO_READ_WRITE);
     enginePreFF[R] = dm->make_named_var("A32NX_ENGINE_PRE_FF:2", UNITS.Number, AUTO_READ_WRITE);
 
@@ -340,6 +332,12 @@ class FadecSimData_A32NX {
     fuelPumpState[R] = dm->make_named_var("A32NX_PUMP_STATE:2", UNITS.Number, AUTO_READ_WRITE);
     fuelRightPre     = dm->make_named_var("A32NX_FUEL_RIGHT_PRE", UNITS.Number, AUTO_READ_WRITE);
 
+    fuelTankLeakFlow[0] = dm->make_named_var("A32NX_FUEL_TANK_LEAK_FLOW_1", UNITS.Number, AUTO_READ);
+    fuelTankLeakFlow[1] = dm->make_named_var("A32NX_FUEL_TANK_LEAK_FLOW_2", UNITS.Number, AUTO_READ);
+    fuelTankLeakFlow[2] = dm->make_named_var("A32NX_FUEL_TANK_LEAK_FLOW_3", UNITS.Number, AUTO_READ);
+    fuelTankLeakFlow[3] = dm->make_named_var("A32NX_FUEL_TANK_LEAK_FLOW_4", UNITS.Number, AUTO_READ);
+    fuelTankLeakFlow[4] = dm->make_named_var("A32NX_FUEL_TANK_LEAK_FLOW_5", UNITS.Number, AUTO_READ);
+
     thrustLimitType  = dm->make_named_var("A32NX_AUTOTHRUST_THRUST_LIMIT_TYPE", UNITS.Number, AUTO_READ);
     thrustLimitIdle  = dm->make_named_var("A32NX_AUTOTHRUST_THRUST_LIMIT_IDLE", UNITS.Number, AUTO_WRITE);
     thrustLimitClimb = dm->make_named_var("A32NX_AUTOTHRUST_THRUST_LIMIT_CLB", UNITS.Number, AUTO_WRITE);
@@ -353,6 +351,7 @@ class FadecSimData_A32NX {
     refuelRate          = dm->make_named_var("A32NX_EFB_REFUEL_RATE_SETTING", UNITS.Number, AUTO_READ);
     refuelStartedByUser = dm->make_named_var("A32NX_REFUEL_STARTED_BY_USR", UNITS.Number, AUTO_READ);
     airlinerToFlexTemp  = dm->make_named_var("A32NX_AIRLINER_TO_FLEX_TEMP", UNITS.Number, AUTO_READ);
+    apuFuelFlow         = dm->make_named_var("A32NX_APU_FUEL_FLOW", UNITS.Number, AUTO_READ);
     apuRpmPercent       = dm->make_named_var("A32NX_APU_N_RAW", UNITS.Number, AUTO_READ);
 
     aircraftPresetQuickMode = dm->make_named_var("A32NX_AIRCRAFT_PRESET_QUICK_MODE", UNITS.Number, AUTO_READ);
@@ -374,10 +373,6 @@ class FadecSimData_A32NX {
     engineN1[R]->setAndWriteToSim(0);
     engineN2[L]->setAndWriteToSim(0);
     engineN2[R]->setAndWriteToSim(0);
-    engineOilTotal[L]->setAndWriteToSim(0);
-    engineOilTotal[R]->setAndWriteToSim(0);
-    engineOil[L]->setAndWriteToSim(0);
-    engineOil[R]->setAndWriteToSim(0);
     enginePreFF[L]->setAndWriteToSim(0);
     enginePreFF[R]->setAndWriteToSim(0);
     engineState[L]->setAndWriteToSim(0);
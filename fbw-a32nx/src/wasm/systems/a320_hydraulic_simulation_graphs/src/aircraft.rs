@@ -0,0 +1,765 @@
+use std::time::Duration;
+
+use systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    hydraulic::{
+        linear_actuator::Actuator, pumps::PumpCharacteristics, ElectricPump, EngineDrivenPump,
+        HeatingElement, HeatingPressureSource, HydraulicCircuit, HydraulicCircuitController,
+        PowerTransferUnit, PowerTransferUnitController, PressureSource, PressureSwitchState,
+        PumpController, RamAirTurbine, Reservoir,
+    },
+    shared::{
+        update_iterator::FixedStepLoop, AirbusElectricPumpId, AirbusEngineDrivenPumpId,
+        ElectricalBusType, PotentialOrigin, RamAirTurbineController, SectionPressure,
+    },
+    simulation::{
+        Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
+    },
+};
+use uom::si::{
+    angular_velocity::revolution_per_minute,
+    electric_current::ampere,
+    electric_potential::volt,
+    f64::*,
+    pressure::psi,
+    volume::{cubic_inch, gallon},
+    volume_rate::gallon_per_minute,
+};
+
+use a320_systems::hydraulic::{A320HydraulicCircuitFactory, A320PowerTransferUnitCharacteristics};
+use a380_systems::hydraulic::A380HydraulicCircuitFactory;
+
+use crate::scenario::{
+    Scenario, ScenarioAction, ScenarioAircraftType, ScenarioCircuitColor, ScenarioPumpType,
+};
+
+struct ScenarioCircuitController;
+impl HydraulicCircuitController for ScenarioCircuitController {
+    fn should_open_fire_shutoff_valve(&self, _: usize) -> bool {
+        true
+    }
+
+    fn should_open_leak_measurement_valve(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Default)]
+struct ScenarioPumpController {
+    should_pressurise: bool,
+}
+impl PumpController for ScenarioPumpController {
+    fn should_pressurise(&self) -> bool {
+        self.should_pressurise
+    }
+}
+impl RamAirTurbineController for ScenarioPumpController {
+    fn should_deploy(&self) -> bool {
+        self.should_pressurise
+    }
+}
+
+#[derive(Default)]
+struct ScenarioPtuController {
+    should_enable: bool,
+}
+impl PowerTransferUnitController for ScenarioPtuController {
+    fn should_enable(&self) -> bool {
+        self.should_enable
+    }
+}
+
+/// Hands out a distinct identifier to every pump so that pumps of the scenario don't share
+/// their simulation variables and failures
+struct ScenarioPumpIds {
+    engine_driven: std::vec::IntoIter<AirbusEngineDrivenPumpId>,
+    electric: std::vec::IntoIter<AirbusElectricPumpId>,
+}
+impl ScenarioPumpIds {
+    fn new(aircraft: ScenarioAircraftType) -> Self {
+        use AirbusElectricPumpId as Elec;
+        use AirbusEngineDrivenPumpId as Edp;
+
+        let (engine_driven, electric) = match aircraft {
+            ScenarioAircraftType::A320 => (
+                vec![
+                    Edp::Green,
+                    Edp::Yellow,
+                    Edp::Edp1a,
+                    Edp::Edp1b,
+                    Edp::Edp2a,
+                    Edp::Edp2b,
+                    Edp::Edp3a,
+                    Edp::Edp3b,
+                    Edp::Edp4a,
+                    Edp::Edp4b,
+                ],
+                vec![
+                    Elec::Blue,
+                    Elec::Yellow,
+                    Elec::Green,
+                    Elec::GreenA,
+                    Elec::GreenB,
+                    Elec::YellowA,
+                    Elec::YellowB,
+                    Elec::GreenAux,
+                ],
+            ),
+            ScenarioAircraftType::A380 => (
+                vec![
+                    Edp::Edp1a,
+                    Edp::Edp1b,
+                    Edp::Edp2a,
+                    Edp::Edp2b,
+                    Edp::Edp3a,
+                    Edp::Edp3b,
+                    Edp::Edp4a,
+                    Edp::Edp4b,
+                    Edp::Green,
+                    Edp::Yellow,
+                ],
+                vec![
+                    Elec::GreenA,
+                    Elec::GreenB,
+                    Elec::YellowA,
+                    Elec::YellowB,
+                    Elec::GreenAux,
+                    Elec::Green,
+                    Elec::Blue,
+                    Elec::Yellow,
+                ],
+            ),
+        };
+
+        Self {
+            engine_driven: engine_driven.into_iter(),
+            electric: electric.into_iter(),
+        }
+    }
+}
+
+enum PumpKind {
+    Electric(ElectricPump),
+    EngineDriven(EngineDrivenPump, AngularVelocity),
+    RamAirTurbine(RamAirTurbine),
+}
+
+/// A pump of the scenario. Pump sections without any pump in the scenario get an idle pump
+/// which is never spun nor powered so that every pump section can be handed to the circuit.
+struct ScenarioPump {
+    name: Option<String>,
+    kind: PumpKind,
+    controller: ScenarioPumpController,
+}
+impl ScenarioPump {
+    const PUMP_COUNT_VALIDATED: &'static str = "scenario validation limits the number of pumps";
+
+    fn new(
+        context: &mut InitContext,
+        name: Option<String>,
+        pump_type: ScenarioPumpType,
+        aircraft: ScenarioAircraftType,
+        ids: &mut ScenarioPumpIds,
+    ) -> Self {
+        let kind = match (pump_type, aircraft) {
+            (ScenarioPumpType::Electric, ScenarioAircraftType::A320) => {
+                PumpKind::Electric(Self::electric_pump(
+                    context,
+                    ids.electric.next().expect(Self::PUMP_COUNT_VALIDATED),
+                    PumpCharacteristics::a320_electric_pump(),
+                ))
+            }
+            (ScenarioPumpType::Electric, ScenarioAircraftType::A380) => {
+                PumpKind::Electric(Self::electric_pump(
+                    context,
+                    ids.electric.next().expect(Self::PUMP_COUNT_VALIDATED),
+                    PumpCharacteristics::a380_electric_pump(),
+                ))
+            }
+            (ScenarioPumpType::EngineDriven, ScenarioAircraftType::A320) => PumpKind::EngineDriven(
+                EngineDrivenPump::new(
+                    context,
+                    ids.engine_driven.next().expect(Self::PUMP_COUNT_VALIDATED),
+                    PumpCharacteristics::a320_edp(),
+                ),
+                AngularVelocity::default(),
+            ),
+            (ScenarioPumpType::EngineDriven, ScenarioAircraftType::A380) => PumpKind::EngineDriven(
+                EngineDrivenPump::new(
+                    context,
+                    ids.engine_driven.next().expect(Self::PUMP_COUNT_VALIDATED),
+                    PumpCharacteristics::a380_edp(),
+                ),
+                AngularVelocity::default(),
+            ),
+            (ScenarioPumpType::RamAirTurbine, _) => PumpKind::RamAirTurbine(RamAirTurbine::new(
+                context,
+                PumpCharacteristics::a320_rat(),
+            )),
+        };
+
+        Self {
+            name,
+            kind,
+            controller: ScenarioPumpController::default(),
+        }
+    }
+
+    /// Builds the pump of a section without any pump in the scenario, using whichever
+    /// identifier is still free once all pumps of the scenario got theirs
+    fn new_idle(
+        context: &mut InitContext,
+        aircraft: ScenarioAircraftType,
+        ids: &mut ScenarioPumpIds,
+    ) -> Self {
+        let pump_type = if ids.engine_driven.len() > 0 {
+            ScenarioPumpType::EngineDriven
+        } else {
+            ScenarioPumpType::Electric
+        };
+
+        Self::new(context, None, pump_type, aircraft, ids)
+    }
+
+    fn electric_pump(
+        context: &mut InitContext,
+        id: AirbusElectricPumpId,
+        characteristics: PumpCharacteristics,
+    ) -> ElectricPump {
+        ElectricPump::new(
+            context,
+            id,
+            ScenarioAircraft::PUMPS_POWER_BUS,
+            ElectricCurrent::new::<ampere>(45.),
+            characteristics,
+        )
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        section: &impl SectionPressure,
+        reservoir: &Reservoir,
+    ) {
+        match &mut self.kind {
+            PumpKind::Electric(pump) => pump.update(context, section, reservoir, &self.controller),
+            PumpKind::EngineDriven(pump, speed) => {
+                pump.update(context, section, reservoir, *speed, &self.controller)
+            }
+            PumpKind::RamAirTurbine(rat) => {
+                rat.update_position(&context.delta());
+                rat.update_physics(context, section);
+                rat.update(context, section, reservoir, &self.controller);
+            }
+        }
+    }
+
+    fn pump(&self) -> &dyn HeatingPressureSource {
+        match &self.kind {
+            PumpKind::Electric(pump) => pump,
+            PumpKind::EngineDriven(pump, _) => pump,
+            PumpKind::RamAirTurbine(rat) => rat,
+        }
+    }
+
+    fn pump_mut(&mut self) -> &mut dyn HeatingPressureSource {
+        match &mut self.kind {
+            PumpKind::Electric(pump) => pump,
+            PumpKind::EngineDriven(pump, _) => pump,
+            PumpKind::RamAirTurbine(rat) => rat,
+        }
+    }
+
+    fn speed(&self) -> AngularVelocity {
+        match &self.kind {
+            PumpKind::Electric(pump) => pump.speed(),
+            PumpKind::EngineDriven(_, speed) => *speed,
+            PumpKind::RamAirTurbine(_) => AngularVelocity::default(),
+        }
+    }
+}
+impl PressureSource for ScenarioPump {
+    fn delta_vol_max(&self) -> Volume {
+        self.pump().delta_vol_max()
+    }
+
+    fn update_after_pressure_regulation(
+        &mut self,
+        context: &UpdateContext,
+        volume_required: Volume,
+        reservoir: &mut Reservoir,
+        is_pump_connected_to_reservoir: bool,
+    ) {
+        self.pump_mut().update_after_pressure_regulation(
+            context,
+            volume_required,
+            reservoir,
+            is_pump_connected_to_reservoir,
+        );
+    }
+
+    fn flow(&self) -> VolumeRate {
+        self.pump().flow()
+    }
+
+    fn displacement(&self) -> Volume {
+        self.pump().displacement()
+    }
+}
+impl HeatingElement for ScenarioPump {
+    fn is_overheating(&self) -> bool {
+        self.pump().is_overheating()
+    }
+
+    fn is_damaged(&self) -> bool {
+        self.pump().is_damaged()
+    }
+}
+impl HeatingPressureSource for ScenarioPump {}
+impl SimulationElement for ScenarioPump {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        match &mut self.kind {
+            PumpKind::Electric(pump) => pump.accept(visitor),
+            PumpKind::EngineDriven(pump, _) => pump.accept(visitor),
+            PumpKind::RamAirTurbine(rat) => rat.accept(visitor),
+        }
+
+        visitor.visit(self);
+    }
+}
+
+/// Consumer drawing a flow from system section proportional to circuit pressure, like an
+/// orifice would. All consumed fluid is returned to reservoir.
+struct FlowConsumer {
+    name: String,
+    nominal_flow: VolumeRate,
+    flow: VolumeRate,
+    volume: Volume,
+}
+impl FlowConsumer {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            nominal_flow: VolumeRate::default(),
+            flow: VolumeRate::default(),
+            volume: Volume::default(),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, pressure: Pressure, nominal_pressure: Pressure) {
+        let pressure_ratio = (pressure / nominal_pressure).value.clamp(0., 1.);
+        self.flow = self.nominal_flow * pressure_ratio;
+        self.volume += self.flow * context.delta_as_time();
+    }
+}
+impl Actuator for FlowConsumer {
+    fn used_volume(&self) -> Volume {
+        self.volume
+    }
+
+    fn reservoir_return(&self) -> Volume {
+        self.volume
+    }
+
+    fn reset_volumes(&mut self) {
+        self.volume = Volume::default();
+    }
+}
+
+struct ScenarioCircuit {
+    color: ScenarioCircuitColor,
+    circuit: HydraulicCircuit,
+    section_pumps: Vec<ScenarioPump>,
+    system_pump: Option<ScenarioPump>,
+    consumers: Vec<FlowConsumer>,
+}
+impl ScenarioCircuit {
+    fn new(
+        context: &mut InitContext,
+        scenario: &Scenario,
+        index: usize,
+        pumps: Vec<ScenarioPump>,
+        ids: &mut ScenarioPumpIds,
+    ) -> Self {
+        let definition = &scenario.circuits[index];
+        let circuit = match (scenario.aircraft, definition.color) {
+            (ScenarioAircraftType::A320, ScenarioCircuitColor::Green) => {
+                A320HydraulicCircuitFactory::new_green_circuit(context)
+            }
+            (ScenarioAircraftType::A320, ScenarioCircuitColor::Blue) => {
+                A320HydraulicCircuitFactory::new_blue_circuit(context)
+            }
+            (ScenarioAircraftType::A320, ScenarioCircuitColor::Yellow) => {
+                A320HydraulicCircuitFactory::new_yellow_circuit(context, Volume::default())
+            }
+            (ScenarioAircraftType::A380, ScenarioCircuitColor::Green) => {
+                A380HydraulicCircuitFactory::new_green_circuit(context)
+            }
+            (ScenarioAircraftType::A380, ScenarioCircuitColor::Yellow) => {
+                A380HydraulicCircuitFactory::new_yellow_circuit(context)
+            }
+            (ScenarioAircraftType::A380, ScenarioCircuitColor::Blue) => {
+                panic!("A380 has no blue circuit")
+            }
+        };
+
+        let mut section_pumps: Vec<Option<ScenarioPump>> =
+            (0..circuit.pump_sections_count()).map(|_| None).collect();
+        let mut system_pump = None;
+
+        for (pump, scenario_pump) in definition.pumps.iter().zip(pumps) {
+            match pump.section {
+                Some(section) => section_pumps[section] = Some(scenario_pump),
+                None => system_pump = Some(scenario_pump),
+            }
+        }
+
+        Self {
+            color: definition.color,
+            circuit,
+            section_pumps: section_pumps
+                .into_iter()
+                .map(|pump| {
+                    pump.unwrap_or_else(|| ScenarioPump::new_idle(context, scenario.aircraft, ids))
+                })
+                .collect(),
+            system_pump,
+            consumers: definition
+                .consumers
+                .iter()
+                .map(|c| FlowConsumer::new(c.name.clone()))
+                .collect(),
+        }
+    }
+
+    fn pumps(&self) -> impl Iterator<Item = &ScenarioPump> {
+        self.section_pumps.iter().chain(self.system_pump.iter())
+    }
+
+    fn pumps_mut(&mut self) -> impl Iterator<Item = &mut ScenarioPump> {
+        self.section_pumps
+            .iter_mut()
+            .chain(self.system_pump.iter_mut())
+    }
+
+    fn update_pumps(&mut self, context: &UpdateContext) {
+        for (pump_index, pump) in self.section_pumps.iter_mut().enumerate() {
+            pump.update(
+                context,
+                self.circuit.pump_section(pump_index),
+                self.circuit.reservoir(),
+            );
+        }
+
+        if let Some(pump) = &mut self.system_pump {
+            pump.update(
+                context,
+                self.circuit.system_section(),
+                self.circuit.reservoir(),
+            );
+        }
+    }
+
+    fn update_consumers(&mut self, context: &UpdateContext, nominal_pressure: Pressure) {
+        for consumer in &mut self.consumers {
+            consumer.update(
+                context,
+                self.circuit.system_section_pressure(),
+                nominal_pressure,
+            );
+            self.circuit.update_system_actuator_volumes(consumer);
+        }
+    }
+
+    fn update_circuit(
+        &mut self,
+        context: &UpdateContext,
+        ptu: Option<&PowerTransferUnit>,
+        reservoir_air_pressure: Pressure,
+    ) {
+        let mut section_pumps: Vec<&mut dyn HeatingPressureSource> = self
+            .section_pumps
+            .iter_mut()
+            .map(|pump| pump as &mut dyn HeatingPressureSource)
+            .collect();
+
+        self.circuit.update(
+            context,
+            &mut section_pumps,
+            self.system_pump.as_mut(),
+            None::<&mut ElectricPump>,
+            ptu,
+            &ScenarioCircuitController,
+            reservoir_air_pressure,
+        );
+    }
+
+    fn series_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = (0..self.circuit.pump_sections_count())
+            .map(|idx| format!("{}.pump_section_{}.pressure", self.color, idx))
+            .collect();
+
+        names.extend(
+            [
+                "system_section.pressure",
+                "system_section.pressure_switch",
+                "accumulator.fluid_volume",
+                "reservoir.level",
+            ]
+            .iter()
+            .map(|name| format!("{}.{}", self.color, name)),
+        );
+
+        names
+    }
+
+    fn series_values(&self) -> Vec<f64> {
+        let mut values: Vec<f64> = (0..self.circuit.pump_sections_count())
+            .map(|idx| self.circuit.pump_pressure(idx).get::<psi>())
+            .collect();
+
+        values.extend([
+            self.circuit.system_section_pressure().get::<psi>(),
+            (self.circuit.system_section_pressure_switch() == PressureSwitchState::Pressurised)
+                as u8 as f64,
+            self.circuit
+                .system_accumulator_fluid_volume()
+                .get::<gallon>(),
+            self.circuit.reservoir_level().get::<gallon>(),
+        ]);
+
+        values
+    }
+}
+impl SimulationElement for ScenarioCircuit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.circuit.accept(visitor);
+
+        for pump in self.pumps_mut() {
+            pump.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
+
+fn find_circuit(
+    circuits: &[ScenarioCircuit],
+    color: ScenarioCircuitColor,
+) -> Option<&ScenarioCircuit> {
+    circuits.iter().find(|c| c.color == color)
+}
+
+/// Aircraft made of the hydraulic circuits, pumps, PTU and consumers described by a scenario
+pub struct ScenarioAircraft {
+    updater: FixedStepLoop,
+
+    nominal_pressure: Pressure,
+    reservoir_air_pressure: Pressure,
+
+    circuits: Vec<ScenarioCircuit>,
+    ptu: Option<PowerTransferUnit>,
+    ptu_controller: ScenarioPtuController,
+
+    powered_source_ac: TestElectricitySource,
+    pumps_bus: ElectricalBus,
+}
+impl ScenarioAircraft {
+    const PUMPS_POWER_BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrentGndFltService;
+
+    /// Builds the aircraft of a scenario which has been validated when parsed
+    pub fn new(context: &mut InitContext, scenario: &Scenario) -> Self {
+        // Pumps of the scenario get their identifiers before idle pumps of empty sections
+        let mut ids = ScenarioPumpIds::new(scenario.aircraft);
+        let pumps: Vec<Vec<ScenarioPump>> = scenario
+            .circuits
+            .iter()
+            .map(|circuit| {
+                circuit
+                    .pumps
+                    .iter()
+                    .map(|pump| {
+                        ScenarioPump::new(
+                            context,
+                            Some(pump.name.clone()),
+                            pump.pump_type,
+                            scenario.aircraft,
+                            &mut ids,
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let circuits = pumps
+            .into_iter()
+            .enumerate()
+            .map(|(index, pumps)| ScenarioCircuit::new(context, scenario, index, pumps, &mut ids))
+            .collect();
+
+        Self {
+            updater: FixedStepLoop::new(Duration::from_secs_f64(scenario.time_step)),
+            nominal_pressure: Pressure::new::<psi>(match scenario.aircraft {
+                ScenarioAircraftType::A320 => 3000.,
+                ScenarioAircraftType::A380 => 5000.,
+            }),
+            reservoir_air_pressure: Pressure::new::<psi>(scenario.reservoir_air_pressure),
+            circuits,
+            ptu: scenario.ptu.then(|| {
+                PowerTransferUnit::new(
+                    context,
+                    &A320PowerTransferUnitCharacteristics::new_randomized(),
+                )
+            }),
+            ptu_controller: ScenarioPtuController::default(),
+            powered_source_ac: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            pumps_bus: ElectricalBus::new(context, Self::PUMPS_POWER_BUS),
+        }
+    }
+
+    pub fn apply(&mut self, action: &ScenarioAction) {
+        match action {
+            ScenarioAction::Pump { pump, pressurise } => {
+                if let Some(pump) = self.pump_mut(pump) {
+                    pump.controller.should_pressurise = *pressurise;
+                }
+            }
+            ScenarioAction::EngineSpeed { pump, rpm } => {
+                if let Some(PumpKind::EngineDriven(_, speed)) =
+                    self.pump_mut(pump).map(|p| &mut p.kind)
+                {
+                    *speed = AngularVelocity::new::<revolution_per_minute>(*rpm);
+                }
+            }
+            ScenarioAction::Consumer { consumer, flow_gpm } => {
+                for c in self
+                    .circuits
+                    .iter_mut()
+                    .flat_map(|c| c.consumers.iter_mut())
+                {
+                    if &c.name == consumer {
+                        c.nominal_flow = VolumeRate::new::<gallon_per_minute>(*flow_gpm);
+                    }
+                }
+            }
+            ScenarioAction::Ptu { enabled } => self.ptu_controller.should_enable = *enabled,
+            // Airspeed is an input of the simulation context, handled by the runner
+            ScenarioAction::TrueAirspeed { .. } => {}
+        }
+    }
+
+    fn pump_mut(&mut self, name: &str) -> Option<&mut ScenarioPump> {
+        self.circuits
+            .iter_mut()
+            .flat_map(|c| c.pumps_mut())
+            .find(|p| p.name.as_deref() == Some(name))
+    }
+
+    /// Names of every recorded series, matching order of `series_values`
+    pub fn series_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .circuits
+            .iter()
+            .flat_map(|c| c.series_names())
+            .collect();
+
+        for pump in self.circuits.iter().flat_map(|c| c.pumps()) {
+            if let Some(name) = &pump.name {
+                names.push(format!("{}.speed", name));
+                names.push(format!("{}.displacement", name));
+                names.push(format!("{}.flow", name));
+            }
+        }
+
+        for consumer in self.circuits.iter().flat_map(|c| c.consumers.iter()) {
+            names.push(format!("{}.flow", consumer.name));
+        }
+
+        if self.ptu.is_some() {
+            names.push("ptu.flow".to_owned());
+        }
+
+        names
+    }
+
+    pub fn series_values(&self) -> Vec<f64> {
+        let mut values: Vec<f64> = self
+            .circuits
+            .iter()
+            .flat_map(|c| c.series_values())
+            .collect();
+
+        for pump in self.circuits.iter().flat_map(|c| c.pumps()) {
+            if pump.name.is_some() {
+                values.push(pump.speed().get::<revolution_per_minute>());
+                values.push(pump.displacement().get::<cubic_inch>());
+                values.push(pump.flow().get::<gallon_per_minute>());
+            }
+        }
+
+        for consumer in self.circuits.iter().flat_map(|c| c.consumers.iter()) {
+            values.push(consumer.flow.get::<gallon_per_minute>());
+        }
+
+        if let Some(ptu) = &self.ptu {
+            values.push(ptu.flow().get::<gallon_per_minute>());
+        }
+
+        values
+    }
+}
+impl Aircraft for ScenarioAircraft {
+    fn update_before_power_distribution(
+        &mut self,
+        _: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        self.powered_source_ac
+            .power_with_potential(ElectricPotential::new::<volt>(115.));
+        electricity.supplied_by(&self.powered_source_ac);
+        electricity.flow(&self.powered_source_ac, &self.pumps_bus);
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        self.updater.update(context);
+
+        for cur_time_step in self.updater {
+            let context = &context.with_delta(cur_time_step);
+
+            if let Some(ptu) = &mut self.ptu {
+                // Scenario validation ensures both circuits exist when there's a PTU
+                let green = find_circuit(&self.circuits, ScenarioCircuitColor::Green).unwrap();
+                let yellow = find_circuit(&self.circuits, ScenarioCircuitColor::Yellow).unwrap();
+
+                ptu.update(
+                    context,
+                    green.circuit.system_section(),
+                    yellow.circuit.system_section(),
+                    &self.ptu_controller,
+                );
+            }
+
+            for circuit in &mut self.circuits {
+                circuit.update_consumers(context, self.nominal_pressure);
+                circuit.update_pumps(context);
+                circuit.update_circuit(context, self.ptu.as_ref(), self.reservoir_air_pressure);
+            }
+        }
+    }
+}
+impl SimulationElement for ScenarioAircraft {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for circuit in &mut self.circuits {
+            circuit.accept(visitor);
+        }
+
+        if let Some(ptu) = &mut self.ptu {
+            ptu.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
@@ -0,0 +1,69 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+/// Records named series of values over simulation time
+pub struct History {
+    /// Simulation time starting from 0
+    time_vector: Vec<f64>,
+    /// Name of each var saved
+    name_vector: Vec<String>,
+    /// Vector data for each var saved
+    data_vector: Vec<Vec<f64>>,
+}
+impl History {
+    pub fn new(names: Vec<String>) -> Self {
+        Self {
+            time_vector: Vec::new(),
+            data_vector: vec![Vec::new(); names.len()],
+            name_vector: names,
+        }
+    }
+
+    /// Records one sample of each var at given time
+    pub fn record(&mut self, time: f64, values: Vec<f64>) {
+        assert_eq!(values.len(), self.name_vector.len());
+
+        self.time_vector.push(time);
+        for (data, v) in self.data_vector.iter_mut().zip(values) {
+            data.push(v);
+        }
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.name_vector
+    }
+
+    pub fn time(&self) -> &[f64] {
+        &self.time_vector
+    }
+
+    pub fn series(&self, name: &str) -> Option<&[f64]> {
+        self.name_vector
+            .iter()
+            .position(|n| n == name)
+            .map(|idx| self.data_vector[idx].as_slice())
+    }
+
+    pub fn write_csv(&self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        write!(writer, "time")?;
+        for name in &self.name_vector {
+            write!(writer, ",{}", name)?;
+        }
+        writeln!(writer)?;
+
+        for (idx, time) in self.time_vector.iter().enumerate() {
+            write!(writer, "{}", time)?;
+            for data in &self.data_vector {
+                write!(writer, ",{}", data[idx])?;
+            }
+            writeln!(writer)?;
+        }
+
+        writer.flush()
+    }
+}
@@ -0,0 +1,127 @@
+use crate::history::History;
+use plotters::{coord::Shift, prelude::*, style::register_font};
+use std::{error::Error, path::Path, sync::Once};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Svg,
+    Png,
+}
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Svg => "svg",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+// Font shipped with the aircraft, so no system font is needed to render text in images
+static FONT: &[u8] = include_bytes!(
+    "../../../../base/flybywire-aircraft-a320-neo/html_ui/Fonts/fbw-a32nx/Inter-Regular.ttf"
+);
+static REGISTER_FONT: Once = Once::new();
+
+const SUBPLOT_WIDTH_PX: u32 = 1280;
+const SUBPLOT_HEIGHT_PX: u32 = 240;
+
+/// Renders one figure with a subplot per series, sharing the time axis
+pub fn render(
+    history: &History,
+    title: &str,
+    series: &[String],
+    format: ImageFormat,
+    path: &Path,
+) -> Result<(), Box<dyn Error>> {
+    REGISTER_FONT.call_once(|| {
+        if register_font("sans-serif", FontStyle::Normal, FONT).is_err() {
+            panic!("Embedded plot font is invalid");
+        }
+    });
+
+    let size = (
+        SUBPLOT_WIDTH_PX,
+        SUBPLOT_HEIGHT_PX * series.len().max(1) as u32,
+    );
+
+    match format {
+        ImageFormat::Svg => draw(
+            SVGBackend::new(path, size).into_drawing_area(),
+            history,
+            title,
+            series,
+        ),
+        ImageFormat::Png => draw(
+            BitMapBackend::new(path, size).into_drawing_area(),
+            history,
+            title,
+            series,
+        ),
+    }
+}
+
+fn draw<DB: DrawingBackend>(
+    root: DrawingArea<DB, Shift>,
+    history: &History,
+    title: &str,
+    series: &[String],
+) -> Result<(), Box<dyn Error>>
+where
+    DB::ErrorType: 'static,
+{
+    root.fill(&WHITE)?;
+    let root = root.titled(title, ("sans-serif", 24))?;
+
+    let time = history.time();
+    let time_range = time.first().copied().unwrap_or(0.)..time.last().copied().unwrap_or(1.);
+
+    for (area, name) in root.split_evenly((series.len(), 1)).iter().zip(series) {
+        let values = history
+            .series(name)
+            .ok_or_else(|| format!("unknown series {}", name))?;
+
+        let mut chart = ChartBuilder::on(area)
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(70)
+            .build_cartesian_2d(time_range.clone(), value_range(values))?;
+
+        chart
+            .configure_mesh()
+            .x_desc("Time [s]")
+            .y_desc(name.as_str())
+            .draw()?;
+
+        chart
+            .draw_series(LineSeries::new(
+                time.iter().copied().zip(values.iter().copied()),
+                &BLUE,
+            ))?
+            .label(name.as_str())
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+        chart
+            .configure_series_labels()
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .draw()?;
+    }
+
+    root.present()?;
+
+    Ok(())
+}
+
+/// Value range of a series with a small margin so constant values are still visible
+fn value_range(values: &[f64]) -> std::ops::Range<f64> {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    if !min.is_finite() || !max.is_finite() {
+        return 0.0..1.0;
+    }
+
+    let margin = ((max - min) * 0.05).max(max.abs() * 0.01).max(0.1);
+
+    (min - margin)..(max + margin)
+}
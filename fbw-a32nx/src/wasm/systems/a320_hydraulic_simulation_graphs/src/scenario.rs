@@ -0,0 +1,422 @@
+use serde::Deserialize;
+use std::{collections::HashSet, fmt, fs, path::Path};
+
+/// Aircraft providing the hydraulic circuits of a scenario
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub enum ScenarioAircraftType {
+    A320,
+    A380,
+}
+impl ScenarioAircraftType {
+    /// Number of pump sections in each circuit built by the aircraft circuit factory
+    pub fn pump_sections_count(&self) -> usize {
+        match self {
+            ScenarioAircraftType::A320 => 1,
+            ScenarioAircraftType::A380 => 6,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash)]
+pub enum ScenarioCircuitColor {
+    Green,
+    Blue,
+    Yellow,
+}
+impl fmt::Display for ScenarioCircuitColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioCircuitColor::Green => write!(f, "green"),
+            ScenarioCircuitColor::Blue => write!(f, "blue"),
+            ScenarioCircuitColor::Yellow => write!(f, "yellow"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScenarioPumpType {
+    Electric,
+    EngineDriven,
+    RamAirTurbine,
+}
+impl ScenarioPumpType {
+    /// Maximum number of pumps of this type in a scenario: each pump needs its own identifier
+    /// so that pumps don't share their simulation variables
+    pub fn max_count(&self) -> usize {
+        match self {
+            ScenarioPumpType::Electric => 8,
+            ScenarioPumpType::EngineDriven => 10,
+            ScenarioPumpType::RamAirTurbine => 1,
+        }
+    }
+}
+
+/// A pump connected to a circuit. When no pump section is given, pump is connected to the
+/// system section of the circuit (like yellow electric pump or RAT on A320).
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioPump {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub pump_type: ScenarioPumpType,
+    pub section: Option<usize>,
+}
+
+/// A generic consumer drawing fluid from the system section and returning it to reservoir
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioConsumer {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioCircuit {
+    pub color: ScenarioCircuitColor,
+    #[serde(default)]
+    pub pumps: Vec<ScenarioPump>,
+    #[serde(default)]
+    pub consumers: Vec<ScenarioConsumer>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "action", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ScenarioAction {
+    /// Commands a pump to pressurise or not. For a RAT, pressurising means deploying it.
+    Pump { pump: String, pressurise: bool },
+    /// Sets the speed of the engine gearbox driving an engine driven pump
+    EngineSpeed { pump: String, rpm: f64 },
+    /// Sets the flow demand of a consumer when its circuit is at nominal pressure
+    Consumer { consumer: String, flow_gpm: f64 },
+    /// Enables or disables the PTU
+    Ptu { enabled: bool },
+    /// Sets the aircraft true airspeed, used by the RAT
+    TrueAirspeed { knots: f64 },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ScenarioEvent {
+    pub time: f64,
+    #[serde(flatten)]
+    pub action: ScenarioAction,
+}
+
+/// A figure rendered at the end of the simulation, one subplot per series
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioPlot {
+    pub name: String,
+    pub series: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    pub aircraft: ScenarioAircraftType,
+    /// Simulated duration in seconds
+    pub duration: f64,
+    /// Simulation step in seconds
+    #[serde(default = "Scenario::default_time_step")]
+    pub time_step: f64,
+    /// Air pressure in reservoirs in psi
+    #[serde(default = "Scenario::default_reservoir_air_pressure")]
+    pub reservoir_air_pressure: f64,
+    #[serde(default)]
+    pub ptu: bool,
+    pub circuits: Vec<ScenarioCircuit>,
+    #[serde(default)]
+    pub events: Vec<ScenarioEvent>,
+    #[serde(default)]
+    pub plots: Vec<ScenarioPlot>,
+}
+impl Scenario {
+    fn default_time_step() -> f64 {
+        0.033
+    }
+
+    fn default_reservoir_air_pressure() -> f64 {
+        50.
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, ScenarioError> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| ScenarioError(format!("cannot read {}: {}", path.display(), e)))?;
+
+        Self::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<Self, ScenarioError> {
+        let mut scenario: Scenario =
+            toml::from_str(content).map_err(|e| ScenarioError(e.to_string()))?;
+
+        scenario.validate()?;
+        scenario.events.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+        Ok(scenario)
+    }
+
+    pub fn pumps(&self) -> impl Iterator<Item = (ScenarioCircuitColor, &ScenarioPump)> {
+        self.circuits
+            .iter()
+            .flat_map(|c| c.pumps.iter().map(move |p| (c.color, p)))
+    }
+
+    pub fn consumers(&self) -> impl Iterator<Item = &ScenarioConsumer> {
+        self.circuits.iter().flat_map(|c| c.consumers.iter())
+    }
+
+    fn validate(&self) -> Result<(), ScenarioError> {
+        if !(self.duration.is_finite()
+            && self.duration > 0.
+            && self.time_step.is_finite()
+            && self.time_step > 0.)
+        {
+            return Err(ScenarioError(
+                "duration and time_step must be positive numbers".to_owned(),
+            ));
+        }
+
+        let mut colors = HashSet::new();
+        for circuit in &self.circuits {
+            if !colors.insert(circuit.color) {
+                return Err(ScenarioError(format!(
+                    "circuit {} is defined twice",
+                    circuit.color
+                )));
+            }
+
+            if self.aircraft == ScenarioAircraftType::A380
+                && circuit.color == ScenarioCircuitColor::Blue
+            {
+                return Err(ScenarioError("A380 has no blue circuit".to_owned()));
+            }
+
+            let system_section_pumps = circuit.pumps.iter().filter(|p| p.section.is_none());
+            if system_section_pumps.count() > 1 {
+                return Err(ScenarioError(format!(
+                    "circuit {} has more than one pump on its system section",
+                    circuit.color
+                )));
+            }
+
+            let mut sections = HashSet::new();
+            for section in circuit.pumps.iter().filter_map(|p| p.section) {
+                if section >= self.aircraft.pump_sections_count() || !sections.insert(section) {
+                    return Err(ScenarioError(format!(
+                        "circuit {}: pump section {} doesn't exist or has more than one pump",
+                        circuit.color, section
+                    )));
+                }
+            }
+
+            for pump in &circuit.pumps {
+                if pump.pump_type == ScenarioPumpType::RamAirTurbine
+                    && (self.aircraft != ScenarioAircraftType::A320 || pump.section.is_some())
+                {
+                    return Err(ScenarioError(format!(
+                        "pump {}: RAT is only available on A320 system section",
+                        pump.name
+                    )));
+                }
+            }
+        }
+
+        for pump_type in [
+            ScenarioPumpType::Electric,
+            ScenarioPumpType::EngineDriven,
+            ScenarioPumpType::RamAirTurbine,
+        ] {
+            if self
+                .pumps()
+                .filter(|(_, p)| p.pump_type == pump_type)
+                .count()
+                > pump_type.max_count()
+            {
+                return Err(ScenarioError(format!(
+                    "more than {} pumps of type {:?}",
+                    pump_type.max_count(),
+                    pump_type
+                )));
+            }
+        }
+
+        if self.ptu
+            && (self.aircraft != ScenarioAircraftType::A320
+                || !colors.contains(&ScenarioCircuitColor::Green)
+                || !colors.contains(&ScenarioCircuitColor::Yellow))
+        {
+            return Err(ScenarioError(
+                "PTU requires A320 green and yellow circuits".to_owned(),
+            ));
+        }
+
+        let mut names = HashSet::new();
+        for name in self
+            .pumps()
+            .map(|(_, p)| &p.name)
+            .chain(self.consumers().map(|c| &c.name))
+        {
+            if !names.insert(name) {
+                return Err(ScenarioError(format!("name {} is used twice", name)));
+            }
+        }
+
+        for event in &self.events {
+            self.validate_event(event)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate_event(&self, event: &ScenarioEvent) -> Result<(), ScenarioError> {
+        let pump_type = |name: &str| {
+            self.pumps()
+                .find(|(_, p)| p.name == name)
+                .map(|(_, p)| p.pump_type)
+        };
+
+        if !event.time.is_finite() || event.time < 0. {
+            return Err(ScenarioError(format!(
+                "event time {} must be a positive number",
+                event.time
+            )));
+        }
+
+        match &event.action {
+            ScenarioAction::Pump { pump, .. } if pump_type(pump).is_none() => Err(ScenarioError(
+                format!("event references unknown pump {}", pump),
+            )),
+            ScenarioAction::EngineSpeed { pump, .. }
+                if pump_type(pump) != Some(ScenarioPumpType::EngineDriven) =>
+            {
+                Err(ScenarioError(format!(
+                    "engine speed event references {} which is not an engine driven pump",
+                    pump
+                )))
+            }
+            ScenarioAction::Consumer { consumer, .. }
+                if !self.consumers().any(|c| &c.name == consumer) =>
+            {
+                Err(ScenarioError(format!(
+                    "event references unknown consumer {}",
+                    consumer
+                )))
+            }
+            ScenarioAction::Ptu { .. } if !self.ptu => Err(ScenarioError(
+                "ptu event but scenario has no PTU".to_owned(),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ScenarioError(pub String);
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid scenario: {}", self.0)
+    }
+}
+impl std::error::Error for ScenarioError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLUE_EPUMP: &str = r#"
+        aircraft = "A320"
+        duration = 10.0
+
+        [[circuits]]
+        color = "Blue"
+        pumps = [{ name = "blue_epump", type = "electric", section = 0 }]
+        consumers = [{ name = "flight_controls" }]
+
+        [[events]]
+        time = 5.0
+        action = "pump"
+        pump = "blue_epump"
+        pressurise = false
+
+        [[events]]
+        time = 0.0
+        action = "pump"
+        pump = "blue_epump"
+        pressurise = true
+    "#;
+
+    #[test]
+    fn parses_scenario_and_sorts_events() {
+        let scenario = Scenario::parse(BLUE_EPUMP).unwrap();
+
+        assert_eq!(scenario.aircraft, ScenarioAircraftType::A320);
+        assert_eq!(scenario.circuits.len(), 1);
+        assert_eq!(scenario.time_step, Scenario::default_time_step());
+        assert_eq!(scenario.events[0].time, 0.);
+        assert_eq!(
+            scenario.events[1].action,
+            ScenarioAction::Pump {
+                pump: "blue_epump".to_owned(),
+                pressurise: false
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_event_on_unknown_pump() {
+        let scenario = BLUE_EPUMP.replace("pump = \"blue_epump\"\n", "pump = \"green_edp\"\n");
+
+        assert!(Scenario::parse(&scenario).is_err());
+    }
+
+    #[test]
+    fn rejects_ptu_without_green_and_yellow_circuits() {
+        let scenario = BLUE_EPUMP.replace("duration = 10.0", "duration = 10.0\nptu = true");
+
+        assert!(Scenario::parse(&scenario).is_err());
+    }
+
+    #[test]
+    fn rejects_pump_on_missing_section() {
+        let scenario = BLUE_EPUMP.replace("section = 0", "section = 1");
+
+        assert!(Scenario::parse(&scenario).is_err());
+    }
+
+    #[test]
+    fn rejects_nan_event_time() {
+        let scenario = BLUE_EPUMP.replace("time = 5.0", "time = nan");
+
+        assert!(Scenario::parse(&scenario).is_err());
+    }
+
+    #[test]
+    fn rejects_infinite_duration() {
+        let scenario = BLUE_EPUMP.replace("duration = 10.0", "duration = inf");
+
+        assert!(Scenario::parse(&scenario).is_err());
+    }
+
+    #[test]
+    fn rejects_more_than_one_rat() {
+        let scenario = BLUE_EPUMP.replace(
+            "pumps = [{ name = \"blue_epump\", type = \"electric\", section = 0 }]",
+            "pumps = [{ name = \"blue_epump\", type = \"electric\", section = 0 }, { name = \"rat\", type = \"ram_air_turbine\" }]\n\n        [[circuits]]\n        color = \"Green\"\n        pumps = [{ name = \"rat_2\", type = \"ram_air_turbine\" }]",
+        );
+
+        assert!(Scenario::parse(&scenario).is_err());
+        assert!(Scenario::parse(&scenario.replace(
+            "{ name = \"rat_2\", type = \"ram_air_turbine\" }",
+            "{ name = \"green_edp\", type = \"engine_driven\", section = 0 }"
+        ))
+        .is_ok());
+    }
+
+    #[test]
+    fn rejects_a380_blue_circuit() {
+        let scenario = BLUE_EPUMP.replace("\"A320\"", "\"A380\"");
+
+        assert!(Scenario::parse(&scenario).is_err());
+    }
+}
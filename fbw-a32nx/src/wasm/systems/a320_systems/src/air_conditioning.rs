@@ -6,9 +6,9 @@ use systems::{
         cabin_pressure_controller::{CabinPressureController, CpcId},
         pressure_valve::{OutflowValve, SafetyValve, SafetyValveSignal},
         AdirsToAirCondInterface, Air, AirConditioningOverheadShared, AirConditioningPack, CabinFan,
-        Channel, DuctTemperature, MixerUnit, OutflowValveSignal, OutletAir, OverheadFlowSelector,
-        PackFlowControllers, PressurizationConstants, PressurizationOverheadShared, TrimAirSystem,
-        VcmShared, ZoneType,
+        CabinRecirculation, Channel, DuctTemperature, MixerUnit, OutflowValveSignal, OutletAir,
+        OverheadFlowSelector, PackFlow, PackFlowControllers, PressurizationConstants,
+        PressurizationOverheadShared, TrimAirSystem, VcmShared, ZoneType,
     },
     overhead::{
         AutoManFaultPushButton, NormalOnPushButton, OnOffFaultPushButton, OnOffPushButton,
@@ -180,7 +180,11 @@ impl A320Cabin {
     fn update(
         &mut self,
         context: &UpdateContext,
-        air_conditioning_system: &(impl OutletAir + DuctTemperature + VcmShared),
+        air_conditioning_system: &(impl OutletAir
+              + DuctTemperature
+              + PackFlow
+              + CabinRecirculation
+              + VcmShared),
         lgciu: [&impl LgciuWeightOnWheels; 2],
         number_of_passengers: &impl NumberOfPassengers,
         pressurization: &A320PressurizationSystem,
@@ -225,6 +229,14 @@ impl CabinSimulation for A320Cabin {
     fn cabin_pressure(&self) -> Pressure {
         self.cabin_air_simulation.cabin_pressure()
     }
+
+    fn cabin_co2_concentration(&self) -> Vec<Ratio> {
+        self.cabin_air_simulation.cabin_co2_concentration()
+    }
+
+    fn cabin_relative_humidity(&self) -> Vec<Ratio> {
+        self.cabin_air_simulation.cabin_relative_humidity()
+    }
 }
 
 impl SimulationElement for A320Cabin {
@@ -490,6 +502,24 @@ impl DuctTemperature for A320AirConditioningSystem {
     }
 }
 
+impl PackFlow for A320AirConditioningSystem {
+    fn pack_flow(&self) -> MassRate {
+        self.packs
+            .iter()
+            .map(|pack| pack.outlet_air().flow_rate())
+            .sum()
+    }
+}
+
+impl CabinRecirculation for A320AirConditioningSystem {
+    fn recirculation_flow(&self) -> MassRate {
+        self.cabin_fans
+            .iter()
+            .map(|fan| fan.outlet_air().flow_rate())
+            .sum()
+    }
+}
+
 impl OutletAir for A320AirConditioningSystem {
     fn outlet_air(&self) -> Air {
         let mut outlet_air = Air::new();
@@ -955,6 +985,7 @@ impl PressurizationConstants for A320PressurizationConstants {
     const SAFETY_VALVE_SIZE: f64 = 0.02; // m2
     const DOOR_OPENING_AREA: f64 = 1.5; // m2
     const HULL_BREACH_AREA: f64 = 0.02; // m2
+    const NUMBER_OF_DOORS: usize = 7; // 4 passenger doors and 3 cargo doors
 
     const MAX_CLIMB_RATE: f64 = 750.; // fpm
     const MAX_CLIMB_RATE_IN_DESCENT: f64 = 500.; // fpm
@@ -1872,6 +1903,23 @@ mod tests {
             self.write_by_name("DESTINATION_QNH", value);
         }
 
+        fn command_cruise_altitude(mut self, altitude: Length) -> Self {
+            self.write_by_name("AIRLINER_CRUISE_ALTITUDE", altitude.get::<foot>());
+            self
+        }
+
+        fn fly_with_vertical_speed(mut self, vertical_speed: Velocity, duration: Duration) -> Self {
+            self.command_on_ground(false);
+            self.indicated_airspeed(Velocity::new::<knot>(250.));
+            self.set_vertical_speed(vertical_speed);
+            for _ in 0..duration.as_secs() {
+                self.run_with_vertical_speed(Duration::from_secs(1));
+                let ambient_pressure = self.query(|a| a.adirs.ambient_pressure);
+                self.set_ambient_pressure(ambient_pressure);
+            }
+            self
+        }
+
         fn command_ditching_pb_on(mut self) -> Self {
             self.write_by_name("OVHD_PRESS_DITCHING_PB_IS_ON", true);
             self
@@ -2039,6 +2087,14 @@ mod tests {
         fn reference_pressure(&self) -> Pressure {
             self.query(|a| a.a320_cabin_air.a320_pressurization_system.cpc[0].reference_pressure())
         }
+
+        fn planned_cruise_cabin_altitude(&self) -> Length {
+            self.query(|a| {
+                a.a320_cabin_air.a320_pressurization_system.cpc[0]
+                    .planned_cruise_cabin_altitude()
+                    .unwrap_or_default()
+            })
+        }
     }
     impl TestBed for CabinAirTestBed {
         type Aircraft = TestAircraft;
@@ -2811,6 +2867,134 @@ mod tests {
             assert!(test_bed.cabin_vs().abs() > Velocity::new::<foot_per_minute>(100.));
         }
 
+        #[test]
+        fn outflow_valve_closes_to_compensate_door_seal_leak() {
+            let mut test_bed = test_bed_in_cruise()
+                .iterate(400)
+                .memorize_outflow_valve_open_amount();
+
+            test_bed.fail(FailureType::DoorSealLeak(1));
+
+            test_bed = test_bed.iterate_with_delta(400, Duration::from_secs(1));
+
+            assert!(
+                test_bed.outflow_valve_open_amount() < test_bed.initial_outflow_valve_open_amount()
+            );
+            assert!(test_bed.cabin_vs().abs() < Velocity::new::<foot_per_minute>(10.));
+        }
+
+        #[test]
+        fn cabin_slowly_climbs_with_progressive_hull_breach() {
+            let mut test_bed = test_bed_in_cruise().iterate(400);
+
+            test_bed.command_ambient_pressure(Pressure::new::<hectopascal>(472.));
+            test_bed.write_by_name("PRESS_FWD_HULL_BREACH_AREA", 400.);
+            test_bed.fail(FailureType::ProgressiveHullBreach(ZoneType::Cabin(1)));
+
+            test_bed = test_bed.iterate_with_delta(10, Duration::from_secs(1));
+            assert!(test_bed.cabin_vs().abs() < Velocity::new::<foot_per_minute>(100.));
+
+            // The outflow valve closes before the breach overcomes the packs
+            test_bed = test_bed.iterate_with_delta(60, Duration::from_secs(1));
+            assert!(test_bed.outflow_valve_open_amount() < Ratio::new::<percent>(1.));
+
+            test_bed = test_bed.iterate_with_delta(120, Duration::from_secs(1));
+            assert!(test_bed.cabin_vs() > Velocity::new::<foot_per_minute>(100.));
+            assert!(test_bed.cabin_vs() < Velocity::new::<foot_per_minute>(6000.));
+        }
+
+        #[test]
+        fn cabin_reaches_planned_cabin_altitude_at_top_of_climb() {
+            let mut test_bed = test_bed()
+                .set_on_ground()
+                .iterate(10)
+                .command_cruise_altitude(Length::new::<foot>(35000.));
+
+            for _ in 0..35 {
+                test_bed = test_bed.fly_with_vertical_speed(
+                    Velocity::new::<foot_per_minute>(2000.),
+                    Duration::from_secs(30),
+                );
+                assert!(test_bed.cabin_vs() <= Velocity::new::<foot_per_minute>(750.));
+            }
+
+            assert!(
+                (test_bed.cabin_altitude() - test_bed.planned_cruise_cabin_altitude()).abs()
+                    < Length::new::<foot>(100.)
+            );
+
+            test_bed =
+                test_bed.fly_with_vertical_speed(Velocity::default(), Duration::from_secs(300));
+
+            assert!(test_bed.cabin_vs().abs() < Velocity::new::<foot_per_minute>(10.));
+            assert!(
+                (test_bed.cabin_altitude() - test_bed.planned_cruise_cabin_altitude()).abs()
+                    < Length::new::<foot>(50.)
+            );
+        }
+
+        #[test]
+        fn cabin_descends_to_landing_elevation_on_early_descent() {
+            let mut test_bed = test_bed()
+                .set_on_ground()
+                .iterate(10)
+                .command_cruise_altitude(Length::new::<foot>(35000.))
+                .fly_with_vertical_speed(
+                    Velocity::new::<foot_per_minute>(2000.),
+                    Duration::from_secs(600),
+                );
+
+            assert!(test_bed.cabin_altitude() < test_bed.planned_cruise_cabin_altitude());
+
+            test_bed = test_bed.fly_with_vertical_speed(
+                Velocity::new::<foot_per_minute>(-2000.),
+                Duration::from_secs(120),
+            );
+
+            assert!(test_bed.cabin_vs() < Velocity::default());
+            assert!(test_bed.cabin_vs() > Velocity::new::<foot_per_minute>(-750.));
+
+            test_bed = test_bed.fly_with_vertical_speed(
+                Velocity::new::<foot_per_minute>(-2000.),
+                Duration::from_secs(480),
+            );
+
+            // The cabin lands 0.1 PSI above the landing elevation pressure
+            assert!(
+                (test_bed.cabin_altitude() - Length::new::<foot>(-187.8)).abs()
+                    < Length::new::<foot>(100.)
+            );
+        }
+
+        #[test]
+        fn cabin_returns_to_departure_elevation_on_aborted_climb() {
+            let mut test_bed = test_bed()
+                .set_on_ground()
+                .iterate(10)
+                .command_cruise_altitude(Length::new::<foot>(35000.))
+                .fly_with_vertical_speed(
+                    Velocity::new::<foot_per_minute>(2000.),
+                    Duration::from_secs(180),
+                )
+                .fly_with_vertical_speed(
+                    Velocity::new::<foot_per_minute>(-1000.),
+                    Duration::from_secs(60),
+                );
+
+            assert!(
+                (test_bed.cabin_vs() - Velocity::new::<foot_per_minute>(-500.)).abs()
+                    < Velocity::new::<foot_per_minute>(20.)
+            );
+
+            test_bed = test_bed.fly_with_vertical_speed(
+                Velocity::new::<foot_per_minute>(-1000.),
+                Duration::from_secs(180),
+            );
+
+            assert!(test_bed.cabin_altitude() < Length::default());
+            assert!(test_bed.cabin_altitude() > Length::new::<foot>(-300.));
+        }
+
         mod cabin_pressure_controller_tests {
             use super::*;
 
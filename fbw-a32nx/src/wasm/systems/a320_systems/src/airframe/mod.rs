@@ -1,5 +1,9 @@
 use systems::{
-    airframe::{CenterOfGravityData, WeightData},
+    airframe::{
+        CenterOfGravityData, CenterOfGravityEnvelope, CenterOfGravityEnvelopes,
+        CenterOfGravityLimits, Loadsheet, LoadsheetData, LoadsheetLayout, TakeOffTrimTable,
+        WeightData,
+    },
     fuel::FuelPayload,
     payload::{CargoPayload, LoadsheetInfo, PassengerPayload},
     simulation::{InitContext, SimulationElement, SimulationElementVisitor},
@@ -12,7 +16,8 @@ mod test;
 pub struct A320Airframe {
     center_of_gravity: CenterOfGravityData,
     weight: WeightData,
-    // trim_horizontal_stabilizer: f64,
+    loadsheet: LoadsheetData,
+    limits: CenterOfGravityLimits,
 }
 impl A320Airframe {
     const LOADSHEET: LoadsheetInfo = LoadsheetInfo {
@@ -21,13 +26,56 @@ impl A320Airframe {
         per_pax_weight_kg: 84.,
         mean_aerodynamic_chord_size: 13.464,
         leading_edge_mean_aerodynamic_chord: -5.383,
+        index_constant: 1000.,
+        index_offset: 50.,
+    };
+
+    const LOADSHEET_LAYOUT: LoadsheetLayout<6> = LoadsheetLayout {
+        info: &Self::LOADSHEET,
+        passenger_sections: &[("A", &[0]), ("B", &[1]), ("C", &[2]), ("D", &[3])],
+        cargo_holds: &["FWD BAGGAGE", "AFT CONTAINER", "AFT BAGGAGE", "AFT BULK"],
+        fuel_tanks: &[
+            "CENTER",
+            "LEFT INNER",
+            "LEFT OUTER",
+            "RIGHT INNER",
+            "RIGHT OUTER",
+        ],
+        take_off_trim: TakeOffTrimTable::new(
+            [17., 20., 25., 30., 35., 40.],
+            [3.8, 3., 1.7, 0.4, -0.9, -2.2],
+        ),
+    };
+
+    const ENVELOPES: CenterOfGravityEnvelopes = CenterOfGravityEnvelopes {
+        zero_fuel: CenterOfGravityEnvelope::new(
+            &[37230., 50000., 64300.],
+            &[17., 18., 21.],
+            &[39., 40., 39.],
+        ),
+        take_off: CenterOfGravityEnvelope::new(
+            &[37230., 53000., 63000., 73500., 78000.],
+            &[15., 17., 20., 22.5, 25.],
+            &[38.5, 40., 40., 38., 36.],
+        ),
+        landing: CenterOfGravityEnvelope::new(
+            &[37230., 50000., 67400.],
+            &[15., 16., 20.],
+            &[39., 41., 41.],
+        ),
+        in_flight: CenterOfGravityEnvelope::new(
+            &[37230., 53000., 78000.],
+            &[15., 16.5, 24.],
+            &[40., 41., 37.5],
+        ),
     };
 
     pub fn new(context: &mut InitContext) -> Self {
         A320Airframe {
             center_of_gravity: CenterOfGravityData::new(context),
             weight: WeightData::new(context),
-            // trim_horizontal_stabilizer: 0.0,
+            loadsheet: LoadsheetData::new(context),
+            limits: CenterOfGravityLimits::new(context),
         }
     }
 
@@ -63,9 +111,16 @@ impl A320Airframe {
         self.center_of_gravity.target_take_off_center_of_gravity()
     }
 
+    pub(crate) fn loadsheet(&self) -> &Loadsheet {
+        self.loadsheet.loadsheet()
+    }
+
+    pub(crate) fn is_target_within_limits(&self) -> bool {
+        self.limits.is_target_within_limits()
+    }
+
     fn convert_cg(cg: f64) -> f64 {
-        -100. * (cg - Self::LOADSHEET.leading_edge_mean_aerodynamic_chord)
-            / Self::LOADSHEET.mean_aerodynamic_chord_size
+        Self::LOADSHEET.percent_mac(cg)
     }
 
     fn set_zero_fuel_weight_center_of_gravity(&mut self, zero_fuel_weight_cg: f64) {
@@ -189,25 +244,49 @@ impl A320Airframe {
         self.set_target_gross_weight(target_gross_weight);
         self.set_target_gross_weight_center_of_gravity(target_gross_weight_cg);
 
-        // TODO: Implement Taxi Fuel Input/Calculation
+        let take_off_fuel = (fuel - self.loadsheet.taxi_fuel()).max(Mass::default());
+        let take_off_fuel_moment = take_off_fuel * fuel_payload.fore_aft_center_of_gravity();
 
-        let tow = gross_weight;
-        let to_cg = gross_weight_cg;
+        let tow = zero_fuel_weight + take_off_fuel;
+        let to_cg = (zero_fuel_weight_moment + take_off_fuel_moment).get::<kilogram>()
+            / tow.get::<kilogram>();
 
         self.set_take_off_weight(tow);
         self.set_take_off_center_of_gravity(to_cg);
 
-        let target_tow = target_gross_weight;
-        let target_to_cg = target_gross_weight_cg;
+        let target_tow = target_zero_fuel_weight + take_off_fuel;
+        let target_to_cg = (target_zero_fuel_weight_moment + take_off_fuel_moment)
+            .get::<kilogram>()
+            / target_tow.get::<kilogram>();
 
         self.set_target_take_off_weight(target_tow);
         self.set_target_take_off_center_of_gravity(target_to_cg);
+
+        self.loadsheet.update(
+            &Self::LOADSHEET_LAYOUT,
+            &self.weight,
+            &self.center_of_gravity,
+            fuel_payload,
+            pax_payload,
+            cargo_payload,
+        );
+
+        self.limits.update(
+            &Self::ENVELOPES,
+            &Self::LOADSHEET,
+            &self.weight,
+            &self.center_of_gravity,
+            &self.loadsheet,
+            fuel_payload,
+        );
     }
 }
 impl SimulationElement for A320Airframe {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.center_of_gravity.accept(visitor);
         self.weight.accept(visitor);
+        self.loadsheet.accept(visitor);
+        self.limits.accept(visitor);
 
         visitor.visit(self);
     }
@@ -1,12 +1,27 @@
-// Note: Fuel system for now is still handled in MSFS. This is used for calculating fuel-related factors.
+// Note: The FADEC still burns fuel and moves it between the tanks in MSFS. The fuel logic below only
+// owns the tank quantities when asked to, which allows testing it independently of the simulator.
+
+use std::time::Duration;
 
 use nalgebra::Vector3;
 use systems::{
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem},
-    shared::ElectricalBusType,
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+    accept_iterable,
+    engine::Engine,
+    failures::{Failure, FailureType},
+    fuel::{
+        FuelCG, FuelInfo, FuelLeakMonitor, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem,
+        FUEL_GALLONS_TO_KG,
+    },
+    shared::{ElectricalBusType, ElectricalBuses, EngineFuelFlow},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    angle::degree, f64::*, mass::kilogram, mass_rate::kilogram_per_second, power::watt,
+    thermodynamic_temperature::kelvin,
 };
-use uom::si::f64::*;
 
 #[cfg(test)]
 mod test;
@@ -20,6 +35,7 @@ pub trait FuelLevel {
     fn center_tank_has_fuel(&self) -> bool;
 }
 
+#[derive(Clone, Copy)]
 pub enum A320FuelTankType {
     Center,
     LeftInner,
@@ -46,8 +62,427 @@ impl From<usize> for A320FuelTankType {
     }
 }
 
+/// A fuel pump push button. The cockpit drives the native `FUELSYSTEM` switch behind it, while
+/// the fault light is computed here.
+struct A320FuelPumpPushButton {
+    is_on_id: VariableIdentifier,
+    has_fault_id: VariableIdentifier,
+    is_on: bool,
+    has_fault: bool,
+}
+impl A320FuelPumpPushButton {
+    fn new(context: &mut InitContext, switch_name: &str, name: &str) -> Self {
+        Self {
+            is_on_id: context.get_identifier(switch_name.to_owned()),
+            has_fault_id: context.get_identifier(format!("OVHD_FUEL_{}_PB_HAS_FAULT", name)),
+            is_on: true,
+            has_fault: false,
+        }
+    }
+
+    fn is_on(&self) -> bool {
+        self.is_on
+    }
+
+    fn set_fault(&mut self, has_fault: bool) {
+        self.has_fault = has_fault;
+    }
+}
+impl SimulationElement for A320FuelPumpPushButton {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_on = reader.read(&self.is_on_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.has_fault_id, self.has_fault);
+    }
+}
+
+pub struct A320FuelOverheadPanel {
+    left_tank_pumps: [A320FuelPumpPushButton; 2],
+    center_tank_pumps: [A320FuelPumpPushButton; 2],
+    right_tank_pumps: [A320FuelPumpPushButton; 2],
+
+    mode_sel_is_manual_id: VariableIdentifier,
+    mode_sel_has_fault_id: VariableIdentifier,
+    mode_sel_is_manual: bool,
+    mode_sel_has_fault: bool,
+
+    crossfeed_id: VariableIdentifier,
+    crossfeed_is_on: bool,
+}
+impl A320FuelOverheadPanel {
+    // Indexes of the native fuel system switches the cockpit binds the push buttons to.
+    const LEFT_TANK_PUMP_SWITCHES: [usize; 2] = [2, 5];
+    const RIGHT_TANK_PUMP_SWITCHES: [usize; 2] = [3, 6];
+    const CENTER_TANK_PUMP_VALVE_SWITCHES: [usize; 2] = [9, 10];
+    const CROSSFEED_VALVE_SWITCH: usize = 3;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            left_tank_pumps: [1, 2].map(|id| {
+                A320FuelPumpPushButton::new(
+                    context,
+                    &format!(
+                        "FUELSYSTEM PUMP SWITCH:{}",
+                        Self::LEFT_TANK_PUMP_SWITCHES[id - 1]
+                    ),
+                    &format!("L_TK_{}", id),
+                )
+            }),
+            center_tank_pumps: [1, 2].map(|id| {
+                A320FuelPumpPushButton::new(
+                    context,
+                    &format!(
+                        "FUELSYSTEM VALVE SWITCH:{}",
+                        Self::CENTER_TANK_PUMP_VALVE_SWITCHES[id - 1]
+                    ),
+                    &format!("CTR_TK_{}", id),
+                )
+            }),
+            right_tank_pumps: [1, 2].map(|id| {
+                A320FuelPumpPushButton::new(
+                    context,
+                    &format!(
+                        "FUELSYSTEM PUMP SWITCH:{}",
+                        Self::RIGHT_TANK_PUMP_SWITCHES[id - 1]
+                    ),
+                    &format!("R_TK_{}", id),
+                )
+            }),
+
+            mode_sel_is_manual_id: context.get_identifier("OVHD_FUEL_MODESEL_MANUAL".to_owned()),
+            mode_sel_has_fault_id: context
+                .get_identifier("OVHD_FUEL_MODE_SEL_PB_HAS_FAULT".to_owned()),
+            mode_sel_is_manual: false,
+            mode_sel_has_fault: false,
+
+            crossfeed_id: context.get_identifier(format!(
+                "FUELSYSTEM VALVE SWITCH:{}",
+                Self::CROSSFEED_VALVE_SWITCH
+            )),
+            crossfeed_is_on: false,
+        }
+    }
+
+    fn wing_tank_pump_is_on(&self, side: A320FuelSide, number: usize) -> bool {
+        match side {
+            A320FuelSide::Left => self.left_tank_pumps[number - 1].is_on(),
+            A320FuelSide::Right => self.right_tank_pumps[number - 1].is_on(),
+        }
+    }
+
+    fn center_tank_pump_is_on(&self, side: A320FuelSide) -> bool {
+        self.center_tank_pumps[side as usize].is_on()
+    }
+
+    fn mode_sel_is_auto(&self) -> bool {
+        !self.mode_sel_is_manual
+    }
+
+    fn crossfeed_is_on(&self) -> bool {
+        self.crossfeed_is_on
+    }
+
+    pub fn update_after_fuel(&mut self, fuel: &A320Fuel) {
+        for side in [A320FuelSide::Left, A320FuelSide::Right] {
+            let wing_tank_pumps = match side {
+                A320FuelSide::Left => &mut self.left_tank_pumps,
+                A320FuelSide::Right => &mut self.right_tank_pumps,
+            };
+            for (push_button, pump) in wing_tank_pumps
+                .iter_mut()
+                .zip(&fuel.wing_tank_pumps[side as usize])
+            {
+                push_button.set_fault(pump.has_fault());
+            }
+
+            self.center_tank_pumps[side as usize]
+                .set_fault(fuel.center_tank_pumps[side as usize].has_fault());
+        }
+
+        self.mode_sel_has_fault = fuel.center_tank_transfer_is_abnormal();
+    }
+}
+impl SimulationElement for A320FuelOverheadPanel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.left_tank_pumps, visitor);
+        accept_iterable!(self.center_tank_pumps, visitor);
+        accept_iterable!(self.right_tank_pumps, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.mode_sel_is_manual = reader.read(&self.mode_sel_is_manual_id);
+        self.crossfeed_is_on = reader.read(&self.crossfeed_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.mode_sel_has_fault_id, self.mode_sel_has_fault);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum A320FuelSide {
+    Left,
+    Right,
+}
+impl A320FuelSide {
+    fn inner_tank(&self) -> A320FuelTankType {
+        match self {
+            A320FuelSide::Left => A320FuelTankType::LeftInner,
+            A320FuelSide::Right => A320FuelTankType::RightInner,
+        }
+    }
+
+    fn outer_tank(&self) -> A320FuelTankType {
+        match self {
+            A320FuelSide::Left => A320FuelTankType::LeftOuter,
+            A320FuelSide::Right => A320FuelTankType::RightOuter,
+        }
+    }
+
+    fn opposite(&self) -> Self {
+        match self {
+            A320FuelSide::Left => A320FuelSide::Right,
+            A320FuelSide::Right => A320FuelSide::Left,
+        }
+    }
+}
+
+/// A booster pump immersed in a fuel tank. Its pressure switch reports low pressure
+/// whenever the pump isn't delivering fuel.
+struct A320FuelPump {
+    low_pressure_id: VariableIdentifier,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    is_commanded_on: bool,
+    is_running: bool,
+}
+impl A320FuelPump {
+    fn new(context: &mut InitContext, name: &str, powered_by: ElectricalBusType) -> Self {
+        Self {
+            low_pressure_id: context.get_identifier(format!("FUEL_{}_PUMP_LOW_PRESSURE", name)),
+            powered_by,
+            is_powered: false,
+            is_commanded_on: false,
+            is_running: false,
+        }
+    }
+
+    fn update(&mut self, is_commanded_on: bool, tank_has_fuel: bool) {
+        self.is_commanded_on = is_commanded_on;
+        self.is_running = is_commanded_on && self.is_powered && tank_has_fuel;
+    }
+
+    fn is_running(&self) -> bool {
+        self.is_running
+    }
+
+    fn has_low_pressure(&self) -> bool {
+        !self.is_running
+    }
+
+    fn has_fault(&self) -> bool {
+        self.is_commanded_on && self.has_low_pressure()
+    }
+}
+impl SimulationElement for A320FuelPump {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.low_pressure_id, self.has_low_pressure());
+    }
+}
+
+/// The two transfer valves between the outer and inner tank of a wing. They open when the inner
+/// tank quantity falls below the low level and remain latched open until the next refuel.
+struct OuterTankTransferValves {
+    is_open_id: VariableIdentifier,
+    is_open: bool,
+    previous_outer_tank_quantity: Mass,
+}
+impl OuterTankTransferValves {
+    const INNER_TANK_LOW_LEVEL_KG: f64 = 750.;
+
+    fn new(context: &mut InitContext, side: A320FuelSide) -> Self {
+        Self {
+            is_open_id: context.get_identifier(format!(
+                "FUEL_{}_TRANSFER_VALVES_OPEN",
+                match side {
+                    A320FuelSide::Left => "LEFT",
+                    A320FuelSide::Right => "RIGHT",
+                }
+            )),
+            is_open: false,
+            previous_outer_tank_quantity: Mass::default(),
+        }
+    }
+
+    fn update(&mut self, inner_tank_quantity: Mass, outer_tank_quantity: Mass) {
+        let is_refuelling = outer_tank_quantity > self.previous_outer_tank_quantity;
+        if is_refuelling {
+            self.is_open = false;
+        } else if inner_tank_quantity < Mass::new::<kilogram>(Self::INNER_TANK_LOW_LEVEL_KG) {
+            self.is_open = true;
+        }
+
+        self.previous_outer_tank_quantity = outer_tank_quantity;
+    }
+
+    fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+impl SimulationElement for OuterTankTransferValves {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_open_id, self.is_open());
+    }
+}
+
+/// The crossfeed valve interconnects the left and right engine feed lines. Its motor needs power
+/// to move, otherwise the valve remains in its last position.
+struct CrossfeedValve {
+    is_open_id: VariableIdentifier,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    is_open: bool,
+}
+impl CrossfeedValve {
+    fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
+        Self {
+            is_open_id: context.get_identifier("FUEL_XFEED_VALVE_OPEN".to_owned()),
+            powered_by,
+            is_powered: false,
+            is_open: false,
+        }
+    }
+
+    fn update(&mut self, is_commanded_open: bool) {
+        if self.is_powered {
+            self.is_open = is_commanded_open;
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+impl SimulationElement for CrossfeedValve {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_open_id, self.is_open());
+    }
+}
+
+/// Automatic control of the center tank pumps with the MODE SEL push button in AUTO.
+/// The pumps run for a short while after an engine start, then only with the slats retracted.
+/// Each pump stops when the high level sensor of its inner tank is wet and restarts once
+/// some fuel has been used from that tank. Both pumps stop once the center tank low level
+/// is reached, so they don't run dry.
+struct CenterTankPumpsAutoControl {
+    engine_start_run_time_remaining: Duration,
+    engines_were_running: [bool; 2],
+    inner_tanks_are_full: [bool; 2],
+    center_tank_is_at_low_level: bool,
+}
+impl CenterTankPumpsAutoControl {
+    const RUN_TIME_AFTER_ENGINE_START: Duration = Duration::from_secs(120);
+    const INNER_TANK_HIGH_LEVEL_MARGIN_KG: f64 = 50.;
+    const INNER_TANK_RESTART_QUANTITY_USED_KG: f64 = 500.;
+    const CENTER_TANK_LOW_LEVEL_KG: f64 = 20.;
+
+    fn new() -> Self {
+        Self {
+            engine_start_run_time_remaining: Duration::ZERO,
+            engines_were_running: [false; 2],
+            inner_tanks_are_full: [false; 2],
+            center_tank_is_at_low_level: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        engines_are_running: [bool; 2],
+        inner_tank_quantities: [Mass; 2],
+        inner_tank_capacity: Mass,
+        center_tank_quantity: Mass,
+    ) {
+        self.engine_start_run_time_remaining = self
+            .engine_start_run_time_remaining
+            .saturating_sub(context.delta());
+        if engines_are_running
+            .iter()
+            .zip(self.engines_were_running)
+            .any(|(&is_running, was_running)| is_running && !was_running)
+        {
+            self.engine_start_run_time_remaining = Self::RUN_TIME_AFTER_ENGINE_START;
+        }
+        self.engines_were_running = engines_are_running;
+
+        let high_level =
+            inner_tank_capacity - Mass::new::<kilogram>(Self::INNER_TANK_HIGH_LEVEL_MARGIN_KG);
+        let restart_level =
+            high_level - Mass::new::<kilogram>(Self::INNER_TANK_RESTART_QUANTITY_USED_KG);
+        for (is_full, &quantity) in self
+            .inner_tanks_are_full
+            .iter_mut()
+            .zip(inner_tank_quantities.iter())
+        {
+            if quantity >= high_level {
+                *is_full = true;
+            } else if quantity < restart_level {
+                *is_full = false;
+            }
+        }
+
+        self.center_tank_is_at_low_level =
+            center_tank_quantity < Mass::new::<kilogram>(Self::CENTER_TANK_LOW_LEVEL_KG);
+    }
+
+    fn pump_should_run(
+        &self,
+        side: A320FuelSide,
+        any_engine_is_running: bool,
+        slats_are_extended: bool,
+    ) -> bool {
+        any_engine_is_running
+            && (!slats_are_extended || self.engine_start_run_time_remaining > Duration::ZERO)
+            && !self.inner_tanks_are_full[side as usize]
+            && !self.center_tank_is_at_low_level
+    }
+}
+
 pub struct A320Fuel {
+    slats_angle_id: VariableIdentifier,
+    engine_gravity_feed_ids: [VariableIdentifier; 2],
+    tank_leak_flow_ids: [VariableIdentifier; 5],
+    target_tank_quantity_ids: [VariableIdentifier; 5],
+
     fuel_system: FuelSystem<5, 5>,
+    simulate_fuel_quantities: bool,
+
+    wing_tank_pumps: [[A320FuelPump; 2]; 2],
+    center_tank_pumps: [A320FuelPump; 2],
+    center_tank_pumps_auto_control: CenterTankPumpsAutoControl,
+    transfer_valves: [OuterTankTransferValves; 2],
+    crossfeed_valve: CrossfeedValve,
+    leak_monitor: FuelLeakMonitor,
+
+    tank_leak_failures: [Failure; 5],
+    engine_feed_leak_failures: [Failure; 2],
+    tank_leak_flows: [MassRate; 5],
+    target_tank_quantities: [Mass; 5],
+
+    slats_angle: Angle,
+    engines_are_gravity_fed: [bool; 2],
 }
 impl A320Fuel {
     pub const A320_FUEL: [FuelInfo<'static>; 5] = [
@@ -122,15 +557,305 @@ impl A320Fuel {
         ),
     ];
 
-    pub fn new(context: &mut InitContext) -> Self {
-        let fuel_tanks = Self::A320_FUEL.map(|f| f.into_fuel_tank(context, false));
+    const SLATS_EXTENDED_ANGLE_DEGREES: f64 = 1.;
+    // Kilograms per second
+    const OUTER_TANK_TRANSFER_FLOW: f64 = 0.8;
+    const CENTER_TANK_TRANSFER_ABNORMAL_CENTER_QUANTITY_KG: f64 = 250.;
+    const CENTER_TANK_TRANSFER_ABNORMAL_INNER_QUANTITY_KG: f64 = 5000.;
+    // Kilograms per second
+    const TANK_LEAK_FLOW: f64 = 0.5;
+    const ENGINE_FEED_LEAK_FLOW: f64 = 1.;
+    // Heat picked up in the IDG cooler per kilogram of fuel burnt, i.e. about 5 kW at cruise.
+    const IDG_RECIRCULATION_HEAT_JOULE_PER_KG: f64 = 15000.;
+    const LEAK_SUSPECTED_DISCREPANCY_KG: f64 = 500.;
+
+    /// When `simulate_fuel_quantities` is false the tank quantities remain owned by the simulator
+    /// and only the state of the pumps and valves is computed. The per-engine feed, outer tank
+    /// transfer and gravity feed are then not reflected in the tank quantities, and the leak
+    /// flows are only published for the FADEC to drain the tanks with.
+    pub fn new(context: &mut InitContext, simulate_fuel_quantities: bool) -> Self {
+        let fuel_tanks =
+            Self::A320_FUEL.map(|f| f.into_fuel_tank(context, simulate_fuel_quantities));
         let fuel_pumps =
             Self::FUEL_PUMPS.map(|(id, properties)| FuelPump::new(context, id, properties));
         A320Fuel {
+            slats_angle_id: context.get_identifier("SLATS_FPPU_ANGLE".to_owned()),
+            engine_gravity_feed_ids: [1, 2].map(|number| {
+                context.get_identifier(format!("FUEL_ENGINE_{}_GRAVITY_FEED", number))
+            }),
+            tank_leak_flow_ids: [1, 2, 3, 4, 5]
+                .map(|number| context.get_identifier(format!("FUEL_TANK_LEAK_FLOW_{}", number))),
+            // The tank quantities the refuel aims for, set in gallons by the refuel panel.
+            target_tank_quantity_ids: [
+                "CENTER",
+                "LEFT_MAIN",
+                "LEFT_AUX",
+                "RIGHT_MAIN",
+                "RIGHT_AUX",
+            ]
+            .map(|tank| context.get_identifier(format!("FUEL_{}_DESIRED", tank))),
+
             fuel_system: FuelSystem::new(context, fuel_tanks, fuel_pumps),
+            simulate_fuel_quantities,
+
+            wing_tank_pumps: ["L", "R"].map(|name| {
+                [
+                    A320FuelPump::new(
+                        context,
+                        &format!("{}_TK_1", name),
+                        ElectricalBusType::Virtual("FUEL_PUMP_1_SUPPLY"),
+                    ),
+                    A320FuelPump::new(
+                        context,
+                        &format!("{}_TK_2", name),
+                        ElectricalBusType::Virtual("FUEL_PUMP_2_SUPPLY"),
+                    ),
+                ]
+            }),
+            center_tank_pumps: [
+                A320FuelPump::new(
+                    context,
+                    "CTR_TK_1",
+                    ElectricalBusType::Virtual("FUEL_PUMP_1_SUPPLY"),
+                ),
+                A320FuelPump::new(
+                    context,
+                    "CTR_TK_2",
+                    ElectricalBusType::Virtual("FUEL_PUMP_2_SUPPLY"),
+                ),
+            ],
+            center_tank_pumps_auto_control: CenterTankPumpsAutoControl::new(),
+            transfer_valves: [A320FuelSide::Left, A320FuelSide::Right]
+                .map(|side| OuterTankTransferValves::new(context, side)),
+            crossfeed_valve: CrossfeedValve::new(
+                context,
+                ElectricalBusType::DirectCurrentEssential,
+            ),
+            leak_monitor: FuelLeakMonitor::new(
+                context,
+                Mass::new::<kilogram>(Self::LEAK_SUSPECTED_DISCREPANCY_KG),
+            ),
+
+            tank_leak_failures: [0, 1, 2, 3, 4]
+                .map(|tank| Failure::new(FailureType::FuelTankLeak(tank))),
+            engine_feed_leak_failures: [1, 2]
+                .map(|number| Failure::new(FailureType::FuelEngineFeedLeak(number))),
+            tank_leak_flows: [MassRate::default(); 5],
+            target_tank_quantities: [Mass::default(); 5],
+
+            slats_angle: Angle::default(),
+            engines_are_gravity_fed: [false; 2],
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &A320FuelOverheadPanel,
+        engines: [&(impl Engine + EngineFuelFlow); 2],
+    ) {
+        let sides = [A320FuelSide::Left, A320FuelSide::Right];
+        let engines_are_running = engines.map(|engine| engine.is_above_minimum_idle());
+        let any_engine_is_running = engines_are_running.iter().any(|&is_running| is_running);
+        let slats_are_extended =
+            self.slats_angle.get::<degree>() > Self::SLATS_EXTENDED_ANGLE_DEGREES;
+
+        self.center_tank_pumps_auto_control.update(
+            context,
+            engines_are_running,
+            sides.map(|side| self.tank_quantity(side.inner_tank())),
+            Self::tank_capacity(A320FuelTankType::LeftInner),
+            self.tank_quantity(A320FuelTankType::Center),
+        );
+
+        let center_tank_has_fuel = self.center_tank_has_fuel();
+        for side in sides {
+            let inner_tank_has_fuel = self.fuel_system.tank_has_fuel(side.inner_tank().into());
+            for (number, pump) in self.wing_tank_pumps[side as usize].iter_mut().enumerate() {
+                pump.update(
+                    overhead.wing_tank_pump_is_on(side, number + 1),
+                    inner_tank_has_fuel,
+                );
+            }
+
+            let center_pump_is_commanded_on = overhead.center_tank_pump_is_on(side)
+                && (!overhead.mode_sel_is_auto()
+                    || self.center_tank_pumps_auto_control.pump_should_run(
+                        side,
+                        any_engine_is_running,
+                        slats_are_extended,
+                    ));
+            self.center_tank_pumps[side as usize]
+                .update(center_pump_is_commanded_on, center_tank_has_fuel);
+
+            let inner_tank_quantity = self.tank_quantity(side.inner_tank());
+            let outer_tank_quantity = self.tank_quantity(side.outer_tank());
+            self.transfer_valves[side as usize].update(inner_tank_quantity, outer_tank_quantity);
+        }
+
+        self.crossfeed_valve.update(overhead.crossfeed_is_on());
+
+        let pressurised_engine_feed_tanks =
+            sides.map(|side| self.pressurised_engine_feed_tank(side));
+        self.engines_are_gravity_fed = pressurised_engine_feed_tanks.map(|tank| tank.is_none());
+
+        if self.simulate_fuel_quantities {
+            for side in sides {
+                if self.transfer_valves[side as usize].is_open() {
+                    let inner_tank_space = Self::tank_capacity(side.inner_tank())
+                        - self.tank_quantity(side.inner_tank());
+                    let transferred = (Mass::new::<kilogram>(
+                        Self::OUTER_TANK_TRANSFER_FLOW * context.delta_as_secs_f64(),
+                    ))
+                    .min(self.tank_quantity(side.outer_tank()))
+                    .min(inner_tank_space);
+                    self.move_fuel(side.outer_tank(), side.inner_tank(), transferred);
+                }
+            }
+
+            for ((engine, side), feed_tank) in
+                engines.iter().zip(sides).zip(pressurised_engine_feed_tanks)
+            {
+                // Without any pump pressure the engine sucks fuel from its inner tank.
+                let feed_tank = feed_tank.unwrap_or_else(|| side.inner_tank());
+                let demand: Mass = engine.fuel_flow() * context.delta_as_time();
+                let quantity = self.tank_quantity(feed_tank);
+                self.set_tank_quantity(feed_tank, (quantity - demand).max(Mass::default()));
+            }
+        }
+
+        self.update_leaks(context, engines_are_running, pressurised_engine_feed_tanks);
+
+        self.fuel_system.update_temperatures(
+            context,
+            Self::skin_temperature(context),
+            Self::recirculation_heat(engines),
+        );
+
+        self.leak_monitor
+            .update(context, self.fuel_system.fuel_on_board(), engines);
+    }
+
+    /// A leaking engine feed line loses fuel upstream of the fuel flow meter for as long as it is
+    /// pressurised or the engine is running. When the simulator owns the tank quantities, the
+    /// FADEC drains the tanks by the published leak flows.
+    fn update_leaks(
+        &mut self,
+        context: &UpdateContext,
+        engines_are_running: [bool; 2],
+        pressurised_engine_feed_tanks: [Option<A320FuelTankType>; 2],
+    ) {
+        self.tank_leak_flows = self.tank_leak_failures.each_ref().map(|failure| {
+            if failure.is_active() {
+                MassRate::new::<kilogram_per_second>(Self::TANK_LEAK_FLOW)
+            } else {
+                MassRate::default()
+            }
+        });
+
+        for (((side, failure), is_running), feed_tank) in [A320FuelSide::Left, A320FuelSide::Right]
+            .into_iter()
+            .zip(&self.engine_feed_leak_failures)
+            .zip(engines_are_running)
+            .zip(pressurised_engine_feed_tanks)
+        {
+            if failure.is_active() && (is_running || feed_tank.is_some()) {
+                let feed_tank = feed_tank.unwrap_or_else(|| side.inner_tank());
+                self.tank_leak_flows[usize::from(feed_tank)] +=
+                    MassRate::new::<kilogram_per_second>(Self::ENGINE_FEED_LEAK_FLOW);
+            }
+        }
+
+        if self.simulate_fuel_quantities {
+            for (tank, flow) in self.tank_leak_flows.iter().enumerate() {
+                self.fuel_system
+                    .leak_from_tank(tank, *flow * context.delta_as_time());
+            }
         }
     }
 
+    fn pressurised_feed_tank(&self, side: A320FuelSide) -> Option<A320FuelTankType> {
+        // The center tank pumps deliver a higher pressure than the wing tank pumps,
+        // which are isolated by their sequence valves while the center tank feeds the engines.
+        if self.center_tank_pumps[side as usize].is_running() {
+            Some(A320FuelTankType::Center)
+        } else if self.wing_tank_pumps[side as usize]
+            .iter()
+            .any(|pump| pump.is_running())
+        {
+            Some(side.inner_tank())
+        } else {
+            None
+        }
+    }
+
+    fn pressurised_engine_feed_tank(&self, side: A320FuelSide) -> Option<A320FuelTankType> {
+        self.pressurised_feed_tank(side).or_else(|| {
+            if self.crossfeed_valve.is_open() {
+                self.pressurised_feed_tank(side.opposite())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The fuel cooling the IDG of each engine returns to the outer tank on its side. The heat it
+    /// carries grows with the engine power, hence with the fuel flow.
+    fn recirculation_heat(engines: [&(impl Engine + EngineFuelFlow); 2]) -> [Power; 5] {
+        let mut heat_input = [Power::default(); 5];
+        for (engine, side) in engines
+            .iter()
+            .zip([A320FuelSide::Left, A320FuelSide::Right])
+        {
+            heat_input[usize::from(side.outer_tank())] = Power::new::<watt>(
+                engine.fuel_flow().get::<kilogram_per_second>().max(0.)
+                    * Self::IDG_RECIRCULATION_HEAT_JOULE_PER_KG,
+            );
+        }
+        heat_input
+    }
+
+    /// The skin is at the total air temperature, derived from the static air temperature and the
+    /// Mach number.
+    fn skin_temperature(context: &UpdateContext) -> ThermodynamicTemperature {
+        let mach = f64::from(context.mach_number());
+        ThermodynamicTemperature::new::<kelvin>(
+            context.ambient_temperature().get::<kelvin>() * (1. + 0.2 * mach.powi(2)),
+        )
+    }
+
+    fn tank_capacity(tank: A320FuelTankType) -> Mass {
+        Mass::new::<kilogram>(
+            Self::A320_FUEL[tank as usize].total_capacity_gallons * FUEL_GALLONS_TO_KG,
+        )
+    }
+
+    fn tank_quantity(&self, tank: A320FuelTankType) -> Mass {
+        self.fuel_system.tank_mass(tank.into())
+    }
+
+    fn set_tank_quantity(&mut self, tank: A320FuelTankType, quantity: Mass) {
+        self.fuel_system.set_tank_quantity(tank.into(), quantity);
+    }
+
+    fn move_fuel(&mut self, from: A320FuelTankType, to: A320FuelTankType, quantity: Mass) {
+        self.set_tank_quantity(from, self.tank_quantity(from) - quantity);
+        self.set_tank_quantity(to, self.tank_quantity(to) + quantity);
+    }
+
+    fn center_tank_transfer_is_abnormal(&self) -> bool {
+        self.tank_quantity(A320FuelTankType::Center)
+            > Mass::new::<kilogram>(Self::CENTER_TANK_TRANSFER_ABNORMAL_CENTER_QUANTITY_KG)
+            && [A320FuelTankType::LeftInner, A320FuelTankType::RightInner]
+                .iter()
+                .any(|&tank| {
+                    self.tank_quantity(tank)
+                        < Mass::new::<kilogram>(
+                            Self::CENTER_TANK_TRANSFER_ABNORMAL_INNER_QUANTITY_KG,
+                        )
+                })
+    }
+
     pub fn left_inner_tank_has_fuel_remaining(&self) -> bool {
         self.fuel_system
             .tank_has_fuel(A320FuelTankType::LeftInner.into())
@@ -197,6 +922,17 @@ impl FuelPayload for A320Fuel {
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.fore_aft_center_of_gravity()
     }
+    fn total_target_load(&self) -> Mass {
+        self.target_tank_quantities.iter().copied().sum()
+    }
+    fn target_fore_aft_center_of_gravity(&self) -> f64 {
+        self.fuel_system
+            .center_of_gravity_with(&self.target_tank_quantities)
+            .x
+    }
+    fn tank_mass(&self, t: usize) -> Mass {
+        self.fuel_system.tank_mass(t)
+    }
 }
 impl FuelCG for A320Fuel {
     fn center_of_gravity(&self) -> Vector3<f64> {
@@ -206,6 +942,42 @@ impl FuelCG for A320Fuel {
 impl SimulationElement for A320Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
+        for pumps in self.wing_tank_pumps.iter_mut() {
+            accept_iterable!(pumps, visitor);
+        }
+        accept_iterable!(self.center_tank_pumps, visitor);
+        accept_iterable!(self.transfer_valves, visitor);
+        self.crossfeed_valve.accept(visitor);
+        self.leak_monitor.accept(visitor);
+        accept_iterable!(self.tank_leak_failures, visitor);
+        accept_iterable!(self.engine_feed_leak_failures, visitor);
+
         visitor.visit(self);
     }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.slats_angle = reader.read(&self.slats_angle_id);
+        for (id, quantity) in self
+            .target_tank_quantity_ids
+            .iter()
+            .zip(self.target_tank_quantities.iter_mut())
+        {
+            let gallons: f64 = reader.read(id);
+            *quantity = Mass::new::<kilogram>(gallons.max(0.) * FUEL_GALLONS_TO_KG);
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (id, is_gravity_fed) in self
+            .engine_gravity_feed_ids
+            .iter()
+            .zip(self.engines_are_gravity_fed)
+        {
+            writer.write(id, is_gravity_fed);
+        }
+
+        for (id, flow) in self.tank_leak_flow_ids.iter().zip(self.tank_leak_flows) {
+            writer.write(id, flow);
+        }
+    }
 }
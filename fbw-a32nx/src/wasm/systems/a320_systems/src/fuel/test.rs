@@ -1,31 +1,106 @@
 use std::time::Duration;
 
 use super::*;
-use crate::systems::simulation::{
-    test::{SimulationTestBed, TestBed, WriteByName},
-    Aircraft, SimulationElement, SimulationElementVisitor,
+use crate::systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    engine::leap_engine::LeapEngine,
+    shared::PotentialOrigin,
+    simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElement, SimulationElementVisitor,
+    },
 };
+use ntest::assert_about_eq;
+use uom::si::{length::foot, thermodynamic_temperature::degree_celsius};
 
 struct FuelTestAircraft {
     fuel: A320Fuel,
+    fuel_overhead: A320FuelOverheadPanel,
+    engine_1: LeapEngine,
+    engine_2: LeapEngine,
+
+    powered_source: TestElectricitySource,
+    fuel_pump_1_bus: ElectricalBus,
+    fuel_pump_2_bus: ElectricalBus,
+    dc_ess_bus: ElectricalBus,
+    fuel_pump_buses_are_powered: [bool; 2],
+    dc_ess_bus_is_powered: bool,
 }
 
 impl FuelTestAircraft {
-    fn new(context: &mut InitContext) -> Self {
+    fn new(context: &mut InitContext, simulate_fuel_quantities: bool) -> Self {
         Self {
-            fuel: A320Fuel::new(context),
+            fuel: A320Fuel::new(context, simulate_fuel_quantities),
+            fuel_overhead: A320FuelOverheadPanel::new(context),
+            engine_1: LeapEngine::new(context, 1),
+            engine_2: LeapEngine::new(context, 2),
+
+            powered_source: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            fuel_pump_1_bus: ElectricalBus::new(
+                context,
+                ElectricalBusType::Virtual("FUEL_PUMP_1_SUPPLY"),
+            ),
+            fuel_pump_2_bus: ElectricalBus::new(
+                context,
+                ElectricalBusType::Virtual("FUEL_PUMP_2_SUPPLY"),
+            ),
+            dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+            fuel_pump_buses_are_powered: [true; 2],
+            dc_ess_bus_is_powered: true,
         }
     }
 
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.fuel.fore_aft_center_of_gravity()
     }
+
+    fn set_fuel_pump_bus_power(&mut self, number: usize, is_powered: bool) {
+        self.fuel_pump_buses_are_powered[number - 1] = is_powered;
+    }
 }
 
-impl Aircraft for FuelTestAircraft {}
+impl Aircraft for FuelTestAircraft {
+    fn update_before_power_distribution(
+        &mut self,
+        _context: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        electricity.supplied_by(&self.powered_source);
+
+        if self.fuel_pump_buses_are_powered[0] {
+            electricity.flow(&self.powered_source, &self.fuel_pump_1_bus);
+        }
+
+        if self.fuel_pump_buses_are_powered[1] {
+            electricity.flow(&self.powered_source, &self.fuel_pump_2_bus);
+        }
+
+        if self.dc_ess_bus_is_powered {
+            electricity.flow(&self.powered_source, &self.dc_ess_bus);
+        }
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            [&self.engine_1, &self.engine_2],
+        );
+        self.fuel_overhead.update_after_fuel(&self.fuel);
+    }
+}
 impl SimulationElement for FuelTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
+        self.engine_1.accept(visitor);
+        self.engine_2.accept(visitor);
+        self.fuel_pump_1_bus.accept(visitor);
+        self.fuel_pump_2_bus.accept(visitor);
+        self.dc_ess_bus.accept(visitor);
 
         visitor.visit(self);
     }
@@ -35,14 +110,36 @@ const MINUTES_TO_SECONDS: u64 = 60;
 const FUEL_GALLONS_TO_KG: f64 = 3.039075693483925;
 const LBS_TO_KG: f64 = 0.4535934;
 
+fn pump_switch_name(name: &str) -> String {
+    match name {
+        "L_TK_1" => "FUELSYSTEM PUMP SWITCH:2",
+        "L_TK_2" => "FUELSYSTEM PUMP SWITCH:5",
+        "R_TK_1" => "FUELSYSTEM PUMP SWITCH:3",
+        "R_TK_2" => "FUELSYSTEM PUMP SWITCH:6",
+        "CTR_TK_1" => "FUELSYSTEM VALVE SWITCH:9",
+        "CTR_TK_2" => "FUELSYSTEM VALVE SWITCH:10",
+        _ => panic!("Unknown fuel pump {}.", name),
+    }
+    .to_owned()
+}
+
 struct FuelTestBed {
     test_bed: SimulationTestBed<FuelTestAircraft>,
 }
 impl FuelTestBed {
-    fn new() -> Self {
-        FuelTestBed {
-            test_bed: SimulationTestBed::new(FuelTestAircraft::new),
+    fn new(simulate_fuel_quantities: bool) -> Self {
+        let mut test_bed = FuelTestBed {
+            test_bed: SimulationTestBed::new(|context| {
+                FuelTestAircraft::new(context, simulate_fuel_quantities)
+            }),
+        };
+        for name in [
+            "L_TK_1", "L_TK_2", "CTR_TK_1", "CTR_TK_2", "R_TK_1", "R_TK_2",
+        ] {
+            test_bed.write_by_name(&pump_switch_name(name), true);
         }
+
+        test_bed
     }
 
     fn and_run(mut self) -> Self {
@@ -82,6 +179,148 @@ impl FuelTestBed {
         self
     }
 
+    fn and_run_for(mut self, duration: Duration) -> Self {
+        self.test_bed.run_multiple_frames(duration);
+
+        self
+    }
+
+    fn with_tank_quantities(
+        mut self,
+        center: f64,
+        left_inner: f64,
+        left_outer: f64,
+        right_inner: f64,
+        right_outer: f64,
+    ) -> Self {
+        self.write_by_name("FUEL TANK CENTER QUANTITY", center / FUEL_GALLONS_TO_KG);
+        self.write_by_name(
+            "FUEL TANK LEFT MAIN QUANTITY",
+            left_inner / FUEL_GALLONS_TO_KG,
+        );
+        self.write_by_name(
+            "FUEL TANK LEFT AUX QUANTITY",
+            left_outer / FUEL_GALLONS_TO_KG,
+        );
+        self.write_by_name(
+            "FUEL TANK RIGHT MAIN QUANTITY",
+            right_inner / FUEL_GALLONS_TO_KG,
+        );
+        self.write_by_name(
+            "FUEL TANK RIGHT AUX QUANTITY",
+            right_outer / FUEL_GALLONS_TO_KG,
+        );
+
+        self
+    }
+
+    fn engines_running_with_fuel_flow(mut self, kilograms_per_hour: f64) -> Self {
+        for number in 1..=2 {
+            self.write_by_name(&format!("ENGINE_N2:{}", number), 70.);
+            self.write_by_name(&format!("ENGINE_FF:{}", number), kilograms_per_hour);
+        }
+
+        self
+    }
+
+    fn engines_shut_down(mut self) -> Self {
+        for number in 1..=2 {
+            self.write_by_name(&format!("ENGINE_N2:{}", number), 0.);
+            self.write_by_name(&format!("ENGINE_FF:{}", number), 0.);
+        }
+
+        self
+    }
+
+    fn slats_extended(mut self) -> Self {
+        self.write_by_name("SLATS_FPPU_ANGLE", 18.);
+
+        self
+    }
+
+    fn pump_off(mut self, name: &str) -> Self {
+        self.write_by_name(&pump_switch_name(name), false);
+
+        self
+    }
+
+    fn mode_sel_man(mut self) -> Self {
+        self.write_by_name("OVHD_FUEL_MODESEL_MANUAL", true);
+
+        self
+    }
+
+    fn crossfeed_on(mut self) -> Self {
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:3", true);
+
+        self
+    }
+
+    fn fuel_pump_bus_unpowered(mut self, number: usize) -> Self {
+        self.command(|a| a.set_fuel_pump_bus_power(number, false));
+
+        self
+    }
+
+    fn crossfeed_off(mut self) -> Self {
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:3", false);
+
+        self
+    }
+
+    fn dc_ess_bus_unpowered(mut self) -> Self {
+        self.command(|a| a.dc_ess_bus_is_powered = false);
+
+        self
+    }
+
+    fn tank_quantity(&self, tank: A320FuelTankType) -> f64 {
+        self.query(|a: &FuelTestAircraft| a.fuel.tank_quantity(tank).get::<kilogram>())
+    }
+
+    fn pump_has_low_pressure(&mut self, name: &str) -> bool {
+        self.read_by_name(&format!("FUEL_{}_PUMP_LOW_PRESSURE", name))
+    }
+
+    fn pump_has_fault(&mut self, name: &str) -> bool {
+        self.read_by_name(&format!("OVHD_FUEL_{}_PB_HAS_FAULT", name))
+    }
+
+    fn mode_sel_has_fault(&mut self) -> bool {
+        self.read_by_name("OVHD_FUEL_MODE_SEL_PB_HAS_FAULT")
+    }
+
+    fn transfer_valves_are_open(&mut self, side: &str) -> bool {
+        self.read_by_name(&format!("FUEL_{}_TRANSFER_VALVES_OPEN", side))
+    }
+
+    fn crossfeed_valve_is_open(&mut self) -> bool {
+        self.read_by_name("FUEL_XFEED_VALVE_OPEN")
+    }
+
+    fn engine_is_gravity_fed(&mut self, number: usize) -> bool {
+        self.read_by_name(&format!("FUEL_ENGINE_{}_GRAVITY_FEED", number))
+    }
+
+    fn tank_temperature(&mut self, number: usize) -> f64 {
+        let temperature: ThermodynamicTemperature =
+            self.read_by_name(&format!("FUEL_TANK_TEMPERATURE_{}", number));
+        temperature.get::<degree_celsius>()
+    }
+
+    fn tank_leak_flow(&mut self, number: usize) -> f64 {
+        let flow: MassRate = self.read_by_name(&format!("FUEL_TANK_LEAK_FLOW_{}", number));
+        flow.get::<kilogram_per_second>()
+    }
+
+    fn leak_is_suspected(&mut self) -> bool {
+        self.read_by_name("FUEL_LEAK_SUSPECTED")
+    }
+
+    fn fob_fu_discrepancy(&mut self) -> f64 {
+        self.read_by_name("FUEL_FOB_FU_DISCREPANCY")
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.query(|a: &FuelTestAircraft| a.fore_aft_center_of_gravity())
     }
@@ -100,13 +339,17 @@ impl TestBed for FuelTestBed {
 }
 
 fn test_bed() -> FuelTestBed {
-    FuelTestBed::new()
+    FuelTestBed::new(false)
 }
 
 fn test_bed_with() -> FuelTestBed {
     test_bed()
 }
 
+fn test_bed_with_simulated_fuel_quantities() -> FuelTestBed {
+    FuelTestBed::new(true)
+}
+
 #[test]
 fn init() {
     let test_bed = test_bed_with().fuel_low();
@@ -144,3 +387,445 @@ fn high_fuel() {
         (test_bed.fore_aft_center_of_gravity() * 100.).round() / 100.,
     );
 }
+
+#[test]
+fn wing_tank_pumps_deliver_pressure_when_powered_with_fuel() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .and_run();
+
+    for name in ["L_TK_1", "L_TK_2", "R_TK_1", "R_TK_2"] {
+        assert!(!test_bed.pump_has_low_pressure(name));
+        assert!(!test_bed.pump_has_fault(name));
+    }
+}
+
+#[test]
+fn unpowered_wing_tank_pump_has_low_pressure_fault() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .fuel_pump_bus_unpowered(1)
+        .and_run();
+
+    assert!(test_bed.pump_has_low_pressure("L_TK_1"));
+    assert!(test_bed.pump_has_fault("L_TK_1"));
+    assert!(!test_bed.pump_has_low_pressure("L_TK_2"));
+}
+
+#[test]
+fn wing_tank_pump_switched_off_has_low_pressure_without_fault() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .pump_off("R_TK_2")
+        .and_run();
+
+    assert!(test_bed.pump_has_low_pressure("R_TK_2"));
+    assert!(!test_bed.pump_has_fault("R_TK_2"));
+}
+
+#[test]
+fn engines_burn_fuel_from_their_inner_tanks_without_center_fuel() {
+    let test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .engines_running_with_fuel_flow(1200.)
+        .and_run_for(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::LeftInner),
+        4800.,
+        1.
+    );
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::RightInner),
+        4800.,
+        1.
+    );
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::LeftOuter),
+        690.,
+        1.
+    );
+}
+
+#[test]
+fn center_tank_feeds_the_engines_with_slats_retracted() {
+    let test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(3000., 4500., 690., 4500., 690.)
+        .engines_running_with_fuel_flow(1200.)
+        .and_run_for(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    assert_about_eq!(test_bed.tank_quantity(A320FuelTankType::Center), 2600., 1.);
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::LeftInner),
+        4500.,
+        1.
+    );
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::RightInner),
+        4500.,
+        1.
+    );
+}
+
+#[test]
+fn center_tank_pumps_stop_in_auto_two_minutes_after_engine_start_with_slats_extended() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(3000., 4500., 690., 4500., 690.)
+        .slats_extended()
+        .engines_running_with_fuel_flow(1200.)
+        .and_run_for(Duration::from_secs(110));
+
+    assert!(!test_bed.pump_has_low_pressure("CTR_TK_1"));
+    assert!(!test_bed.pump_has_low_pressure("CTR_TK_2"));
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(20));
+
+    assert!(test_bed.pump_has_low_pressure("CTR_TK_1"));
+    assert!(test_bed.pump_has_low_pressure("CTR_TK_2"));
+    assert!(!test_bed.pump_has_fault("CTR_TK_1"));
+}
+
+#[test]
+fn center_tank_pumps_run_in_man_with_slats_extended() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(3000., 4500., 690., 4500., 690.)
+        .mode_sel_man()
+        .slats_extended()
+        .engines_running_with_fuel_flow(1200.)
+        .and_run_for(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    assert!(!test_bed.pump_has_low_pressure("CTR_TK_1"));
+    assert!(!test_bed.pump_has_low_pressure("CTR_TK_2"));
+}
+
+#[test]
+fn center_tank_pumps_do_not_run_in_auto_with_engines_shut_down() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(3000., 4500., 690., 4500., 690.)
+        .engines_shut_down()
+        .and_run();
+
+    assert!(test_bed.pump_has_low_pressure("CTR_TK_1"));
+    assert!(test_bed.pump_has_low_pressure("CTR_TK_2"));
+}
+
+#[test]
+fn center_tank_pumps_stop_when_inner_tanks_are_full_and_restart_after_fuel_is_used() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(3000., 5519., 690., 5519., 690.)
+        .engines_running_with_fuel_flow(3000.)
+        .and_run();
+
+    assert!(test_bed.pump_has_low_pressure("CTR_TK_1"));
+    assert!(test_bed.pump_has_low_pressure("CTR_TK_2"));
+
+    // Using 550 kg at 3000 kg/h takes 11 minutes.
+    test_bed = test_bed.and_run_for(Duration::from_secs(12 * MINUTES_TO_SECONDS));
+
+    assert!(!test_bed.pump_has_low_pressure("CTR_TK_1"));
+    assert!(!test_bed.pump_has_low_pressure("CTR_TK_2"));
+    assert!(test_bed.tank_quantity(A320FuelTankType::Center) < 3000.);
+}
+
+#[test]
+fn center_tank_pumps_stop_without_fault_at_center_tank_low_level() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(100., 4500., 690., 4500., 690.)
+        .engines_running_with_fuel_flow(1200.)
+        .and_run_for(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.pump_has_low_pressure("CTR_TK_1"));
+    assert!(test_bed.pump_has_low_pressure("CTR_TK_2"));
+    assert!(!test_bed.pump_has_fault("CTR_TK_1"));
+    assert!(!test_bed.pump_has_fault("CTR_TK_2"));
+    assert!(test_bed.tank_quantity(A320FuelTankType::Center) > 0.);
+    assert!(test_bed.tank_quantity(A320FuelTankType::Center) < 20.);
+}
+
+#[test]
+fn mode_sel_has_fault_with_center_tank_fuel_and_low_wing_tank_quantity() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(3000., 5500., 690., 5500., 690.)
+        .and_run();
+
+    assert!(!test_bed.mode_sel_has_fault());
+
+    test_bed = test_bed
+        .with_tank_quantities(3000., 4900., 690., 5500., 690.)
+        .and_run();
+
+    assert!(test_bed.mode_sel_has_fault());
+}
+
+#[test]
+fn outer_tank_transfer_valves_open_below_inner_tank_low_level() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 800., 690., 800., 690.)
+        .engines_running_with_fuel_flow(1200.)
+        .and_run();
+
+    assert!(!test_bed.transfer_valves_are_open("LEFT"));
+    assert!(!test_bed.transfer_valves_are_open("RIGHT"));
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(3 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.transfer_valves_are_open("LEFT"));
+    assert!(test_bed.transfer_valves_are_open("RIGHT"));
+    assert!(test_bed.tank_quantity(A320FuelTankType::LeftOuter) < 690.);
+}
+
+#[test]
+fn outer_tank_transfer_valves_remain_open_until_refuel() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 700., 690., 700., 690.)
+        .and_run_for(Duration::from_secs(20 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.transfer_valves_are_open("LEFT"));
+    assert_about_eq!(test_bed.tank_quantity(A320FuelTankType::LeftOuter), 0., 1.);
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::LeftInner),
+        1390.,
+        1.
+    );
+
+    test_bed = test_bed
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .and_run();
+
+    assert!(!test_bed.transfer_valves_are_open("LEFT"));
+    assert!(!test_bed.transfer_valves_are_open("RIGHT"));
+}
+
+#[test]
+fn engine_without_pump_pressure_is_gravity_fed_from_its_inner_tank() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .pump_off("L_TK_1")
+        .pump_off("L_TK_2")
+        .engines_running_with_fuel_flow(1200.)
+        .and_run_for(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.engine_is_gravity_fed(1));
+    assert!(!test_bed.engine_is_gravity_fed(2));
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::LeftInner),
+        4800.,
+        1.
+    );
+}
+
+#[test]
+fn crossfeed_valve_lets_one_side_feed_both_engines() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .pump_off("L_TK_1")
+        .pump_off("L_TK_2")
+        .crossfeed_on()
+        .engines_running_with_fuel_flow(1200.)
+        .and_run_for(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.crossfeed_valve_is_open());
+    assert!(!test_bed.engine_is_gravity_fed(1));
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::LeftInner),
+        5000.,
+        1.
+    );
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::RightInner),
+        4600.,
+        1.
+    );
+}
+
+#[test]
+fn crossfeed_valve_remains_in_position_without_power() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .crossfeed_on()
+        .and_run()
+        .dc_ess_bus_unpowered()
+        .crossfeed_off()
+        .and_run();
+
+    assert!(test_bed.crossfeed_valve_is_open());
+}
+
+#[test]
+fn tank_quantities_are_not_changed_when_owned_by_the_simulator() {
+    let test_bed = test_bed_with()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .engines_running_with_fuel_flow(1200.)
+        .and_run_for(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::LeftInner),
+        5000.,
+        1.
+    );
+}
+
+#[test]
+fn tank_leak_drains_the_tank() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.);
+    test_bed.fail(FailureType::FuelTankLeak(
+        A320FuelTankType::LeftOuter.into(),
+    ));
+    test_bed = test_bed.and_run_for(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::LeftOuter),
+        390.,
+        1.
+    );
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::RightOuter),
+        690.,
+        1.
+    );
+}
+
+#[test]
+fn tank_leak_flow_is_left_to_the_fadec_when_owned_by_the_simulator() {
+    let mut test_bed = test_bed_with().with_tank_quantities(0., 5000., 690., 5000., 690.);
+    test_bed.fail(FailureType::FuelTankLeak(
+        A320FuelTankType::RightInner.into(),
+    ));
+    test_bed = test_bed.and_run_for(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::RightInner),
+        5000.,
+        1.
+    );
+    assert_about_eq!(test_bed.tank_leak_flow(4), 0.5);
+    assert_about_eq!(test_bed.tank_leak_flow(2), 0.);
+}
+
+#[test]
+fn engine_feed_leak_drains_the_feeding_tank() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .engines_running_with_fuel_flow(1200.);
+    test_bed.fail(FailureType::FuelEngineFeedLeak(1));
+    test_bed = test_bed.and_run_for(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::LeftInner),
+        4200.,
+        1.
+    );
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::RightInner),
+        4800.,
+        1.
+    );
+    assert_about_eq!(test_bed.tank_leak_flow(2), 1.);
+}
+
+#[test]
+fn engine_feed_leak_stops_when_the_line_is_not_pressurised() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .pump_off("L_TK_1")
+        .pump_off("L_TK_2");
+    test_bed.fail(FailureType::FuelEngineFeedLeak(1));
+    test_bed = test_bed.and_run_for(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    assert_about_eq!(
+        test_bed.tank_quantity(A320FuelTankType::LeftInner),
+        5000.,
+        1.
+    );
+}
+
+#[test]
+fn no_leak_is_suspected_during_normal_fuel_burn() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .engines_running_with_fuel_flow(1200.)
+        .and_run_for(Duration::from_secs(30 * MINUTES_TO_SECONDS));
+
+    assert!(!test_bed.leak_is_suspected());
+    assert_about_eq!(test_bed.fob_fu_discrepancy(), 0., 1.);
+}
+
+#[test]
+fn leak_is_suspected_when_fob_and_fuel_used_fall_short_of_fob_at_engine_start() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .engines_running_with_fuel_flow(1200.);
+    test_bed.fail(FailureType::FuelEngineFeedLeak(2));
+    test_bed = test_bed.and_run_for(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    assert!(!test_bed.leak_is_suspected());
+    assert_about_eq!(test_bed.fob_fu_discrepancy(), 300., 1.);
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.leak_is_suspected());
+    assert_about_eq!(test_bed.fob_fu_discrepancy(), 600., 1.);
+}
+
+#[test]
+fn fob_fu_check_is_reset_after_engine_shutdown_on_ground() {
+    let mut test_bed = test_bed_with_simulated_fuel_quantities()
+        .with_tank_quantities(0., 5000., 690., 5000., 690.)
+        .engines_running_with_fuel_flow(1200.);
+    test_bed.fail(FailureType::FuelEngineFeedLeak(1));
+    test_bed = test_bed.and_run_for(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+    assert!(test_bed.leak_is_suspected());
+
+    test_bed.set_on_ground(true);
+    test_bed = test_bed.engines_shut_down().and_run();
+
+    assert!(!test_bed.leak_is_suspected());
+    assert_about_eq!(test_bed.fob_fu_discrepancy(), 0., 1.);
+}
+
+#[test]
+fn fuel_is_cold_soaked_and_follows_the_total_air_temperature() {
+    let mut test_bed = test_bed_with().with_tank_quantities(0., 5000., 100., 5000., 100.);
+    test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-30.));
+    test_bed = test_bed.and_run();
+
+    for number in 1..=5 {
+        assert_about_eq!(test_bed.tank_temperature(number), -30., 0.1);
+    }
+
+    test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(10.));
+    test_bed = test_bed.and_run_for(Duration::from_secs(60 * MINUTES_TO_SECONDS));
+
+    let inner_tank_temperature = test_bed.tank_temperature(2);
+    let outer_tank_temperature = test_bed.tank_temperature(3);
+    assert!(inner_tank_temperature > -30.);
+    // A tank holding little fuel follows the skin temperature much faster.
+    assert!(outer_tank_temperature > inner_tank_temperature);
+    assert!(outer_tank_temperature < 10.);
+}
+
+#[test]
+fn fuel_starts_at_preflight_temperature_when_spawning_in_cruise() {
+    let mut test_bed = test_bed_with().with_tank_quantities(0., 5000., 600., 5000., 600.);
+    test_bed.set_pressure_altitude(Length::new::<foot>(35000.));
+    test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-54.));
+    test_bed = test_bed.and_run();
+
+    for number in 2..=5 {
+        assert_about_eq!(test_bed.tank_temperature(number), 15., 1.);
+    }
+}
+
+#[test]
+fn outer_tanks_are_warmed_by_idg_fuel_recirculation() {
+    let mut test_bed = test_bed_with()
+        .with_tank_quantities(0., 5000., 600., 5000., 600.)
+        .engines_running_with_fuel_flow(1200.);
+    test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+    test_bed = test_bed.and_run_for(Duration::from_secs(60 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.tank_temperature(3) > 20.);
+    assert!(test_bed.tank_temperature(5) > 20.);
+    assert_about_eq!(test_bed.tank_temperature(2), 15., 0.1);
+}
@@ -21,6 +21,7 @@ use uom::si::{
 use systems::{
     accept_iterable,
     engine::Engine,
+    failures::FailureType,
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
         brake::{BrakeAssembly, BrakeFanPanel, BrakeProperties},
@@ -66,9 +67,10 @@ use systems::{
         AirbusEngineDrivenPumpId, ControllerSignal, DelayedFalseLogicGate,
         DelayedPulseTrueLogicGate, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
         EmergencyElectricalRatPushButton, EmergencyElectricalState, EmergencyGeneratorControlUnit,
-        EmergencyGeneratorPower, EngineFirePushButtons, GearWheel, HydraulicColor,
-        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, RamAirTurbineController,
-        ReservoirAirPressure, ReverserPosition, SectionPressure, TrimmableHorizontalStabilizer,
+        EmergencyGeneratorPower, EngineFirePushButtons, FlightControlSurface, GearWheel,
+        HydraulicColor, LandingGearHandle, LgciuInterface, LgciuWeightOnWheels,
+        RamAirTurbineController, ReservoirAirPressure, ReverserPosition, SectionPressure,
+        TrimmableHorizontalStabilizer,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -441,7 +443,21 @@ impl A320AileronFactory {
 
     fn new_aileron(context: &mut InitContext, id: ActuatorSide) -> AileronAssembly {
         let init_drooped_down = !context.is_in_flight();
-        let assembly = Self::a320_aileron_assembly(context, init_drooped_down);
+        let mut assembly = Self::a320_aileron_assembly(context, init_drooped_down);
+
+        let surface = match id {
+            ActuatorSide::Left => FlightControlSurface::LeftAileron,
+            ActuatorSide::Right => FlightControlSurface::RightAileron,
+        };
+        assembly.set_actuator_external_leak_failure(
+            AileronActuatorPosition::Blue as usize,
+            FailureType::FlightControlActuatorLeak(surface, HydraulicColor::Blue),
+        );
+        assembly.set_actuator_external_leak_failure(
+            AileronActuatorPosition::Green as usize,
+            FailureType::FlightControlActuatorLeak(surface, HydraulicColor::Green),
+        );
+
         AileronAssembly::new(context, id, assembly, Self::new_a320_aileron_aero_model())
     }
 
@@ -692,7 +708,43 @@ impl A320ElevatorFactory {
 
     fn new_elevator(context: &mut InitContext, id: ActuatorSide) -> ElevatorAssembly {
         let init_drooped_down = !context.is_in_flight();
-        let assembly = Self::a320_elevator_assembly(context, init_drooped_down);
+        let mut assembly = Self::a320_elevator_assembly(context, init_drooped_down);
+
+        match id {
+            ActuatorSide::Left => {
+                assembly.set_actuator_external_leak_failure(
+                    LeftElevatorActuatorCircuit::Blue as usize,
+                    FailureType::FlightControlActuatorLeak(
+                        FlightControlSurface::LeftElevator,
+                        HydraulicColor::Blue,
+                    ),
+                );
+                assembly.set_actuator_external_leak_failure(
+                    LeftElevatorActuatorCircuit::Green as usize,
+                    FailureType::FlightControlActuatorLeak(
+                        FlightControlSurface::LeftElevator,
+                        HydraulicColor::Green,
+                    ),
+                );
+            }
+            ActuatorSide::Right => {
+                assembly.set_actuator_external_leak_failure(
+                    RightElevatorActuatorCircuit::Blue as usize,
+                    FailureType::FlightControlActuatorLeak(
+                        FlightControlSurface::RightElevator,
+                        HydraulicColor::Blue,
+                    ),
+                );
+                assembly.set_actuator_external_leak_failure(
+                    RightElevatorActuatorCircuit::Yellow as usize,
+                    FailureType::FlightControlActuatorLeak(
+                        FlightControlSurface::RightElevator,
+                        HydraulicColor::Yellow,
+                    ),
+                );
+            }
+        }
+
         ElevatorAssembly::new(context, id, assembly, Self::new_a320_elevator_aero_model())
     }
 
@@ -812,7 +864,18 @@ impl A320RudderFactory {
             || context.start_state() == StartState::Runway
             || context.is_in_flight();
 
-        let assembly = Self::a320_rudder_assembly(context, init_at_center);
+        let mut assembly = Self::a320_rudder_assembly(context, init_at_center);
+        for (position, color) in [
+            (RudderActuatorPosition::Green, HydraulicColor::Green),
+            (RudderActuatorPosition::Blue, HydraulicColor::Blue),
+            (RudderActuatorPosition::Yellow, HydraulicColor::Yellow),
+        ] {
+            assembly.set_actuator_external_leak_failure(
+                position as usize,
+                FailureType::FlightControlActuatorLeak(FlightControlSurface::Rudder, color),
+            );
+        }
+
         RudderAssembly::new(context, assembly, Self::new_a320_rudder_aero_model())
     }
 
@@ -1304,7 +1367,7 @@ impl A320GearSystemFactory {
         )
     }
 }
-struct A320PowerTransferUnitCharacteristics {
+pub struct A320PowerTransferUnitCharacteristics {
     efficiency: Ratio,
 
     deactivation_delta_pressure: Pressure,
@@ -1342,7 +1405,7 @@ impl A320PowerTransferUnitCharacteristics {
 
     const SHOT_TO_SHOT_VARIABILITY_PERCENT_RATIO: f64 = 0.05;
 
-    fn new_randomized() -> Self {
+    pub fn new_randomized() -> Self {
         let randomized_is_ptu_worn_out = Self::randomized_is_ptu_worn_out();
 
         Self {
@@ -5395,6 +5458,12 @@ impl AileronAssembly {
     }
 }
 impl SimulationElement for AileronAssembly {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.hydraulic_assembly.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.position_id, self.position.get::<ratio>());
     }
@@ -5460,6 +5529,12 @@ impl ElevatorAssembly {
     }
 }
 impl SimulationElement for ElevatorAssembly {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.hydraulic_assembly.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.position_id, self.position.get::<ratio>());
     }
@@ -5521,6 +5596,12 @@ impl RudderAssembly {
     }
 }
 impl SimulationElement for RudderAssembly {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.hydraulic_assembly.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.name_id, self.position.get::<ratio>());
     }
@@ -6132,7 +6213,8 @@ mod tests {
             },
             landing_gear::{GearSystemState, LandingGear, LandingGearControlInterfaceUnitSet},
             shared::{
-                EmergencyElectricalState, EmergencyGeneratorControlUnit, LgciuId, PotentialOrigin,
+                EmergencyElectricalState, EmergencyGeneratorControlUnit, GearActuatorId,
+                HydraulicSectionId, LgciuId, PotentialOrigin,
             },
             simulation::{
                 test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -6565,6 +6647,9 @@ mod tests {
             }
 
             fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+                self.engine_1.update(context);
+                self.engine_2.update(context);
+
                 self.electrical.update(&self.hydraulics.gcu, context);
 
                 self.adirus.update(context);
@@ -11604,6 +11689,173 @@ mod tests {
             assert!(test_bed.yellow_reservoir_has_overheat_fault());
         }
 
+        #[test]
+        fn green_edp_section_leak_empties_green_reservoir() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_ptu_state(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            let green_level_before_leak = test_bed.get_green_reservoir_volume();
+
+            test_bed.fail(FailureType::HydraulicSectionLeak(
+                HydraulicColor::Green,
+                HydraulicSectionId::Pump(1),
+            ));
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(20.));
+
+            assert!(
+                test_bed.get_green_reservoir_volume()
+                    < green_level_before_leak - Volume::new::<gallon>(1.)
+            );
+            assert!(test_bed.is_green_pressure_switch_pressurised());
+        }
+
+        #[test]
+        fn green_edp_section_leak_stops_when_fire_valve_closed() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_ptu_state(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed.fail(FailureType::HydraulicSectionLeak(
+                HydraulicColor::Green,
+                HydraulicSectionId::Pump(1),
+            ));
+
+            test_bed = test_bed
+                .run_waiting_for(Duration::from_secs_f64(5.))
+                .set_eng1_fire_button(true)
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(test_bed.is_fire_valve_eng1_closed());
+
+            let green_level_after_isolation = test_bed.get_green_reservoir_volume();
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(60.));
+
+            assert!(
+                (test_bed.get_green_reservoir_volume() - green_level_after_isolation).abs()
+                    < Volume::new::<gallon>(0.05)
+            );
+        }
+
+        #[test]
+        fn yellow_system_section_leak_stops_when_all_yellow_pumps_off() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_ptu_state(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            let yellow_level_before_leak = test_bed.get_yellow_reservoir_volume();
+
+            test_bed.fail(FailureType::HydraulicSectionLeak(
+                HydraulicColor::Yellow,
+                HydraulicSectionId::System,
+            ));
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(
+                test_bed.get_yellow_reservoir_volume()
+                    < yellow_level_before_leak - Volume::new::<gallon>(0.5)
+            );
+
+            test_bed = test_bed
+                .set_yellow_ed_pump(false)
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+
+            let yellow_level_after_isolation = test_bed.get_yellow_reservoir_volume();
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(60.));
+
+            assert!(
+                (test_bed.get_yellow_reservoir_volume() - yellow_level_after_isolation).abs()
+                    < Volume::new::<gallon>(0.05)
+            );
+        }
+
+        #[test]
+        fn nose_gear_actuator_leak_loses_green_fluid_only_when_gear_system_pressurised() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_ptu_state(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            let mut reference_test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_ptu_state(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed.fail(FailureType::GearActuatorLeak(GearActuatorId::GearNose));
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(20.));
+            reference_test_bed = reference_test_bed.run_waiting_for(Duration::from_secs_f64(20.));
+
+            // Gear hydraulic supply is isolated when gear is up and locked
+            assert!(
+                (test_bed.get_green_reservoir_volume()
+                    - reference_test_bed.get_green_reservoir_volume())
+                .abs()
+                    < Volume::new::<gallon>(0.05)
+            );
+
+            test_bed = test_bed
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(30.));
+            reference_test_bed = reference_test_bed
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(test_bed.is_all_gears_really_down());
+            assert!(
+                test_bed.get_green_reservoir_volume()
+                    < reference_test_bed.get_green_reservoir_volume() - Volume::new::<gallon>(0.2)
+            );
+        }
+
+        #[test]
+        fn rudder_blue_actuator_leak_empties_blue_reservoir() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_ptu_state(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            let mut reference_test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_ptu_state(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed.fail(FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Blue,
+            ));
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(20.));
+            reference_test_bed = reference_test_bed.run_waiting_for(Duration::from_secs_f64(20.));
+
+            assert!(
+                test_bed.get_blue_reservoir_volume()
+                    < reference_test_bed.get_blue_reservoir_volume() - Volume::new::<gallon>(0.5)
+            );
+            assert!(
+                (test_bed.get_green_reservoir_volume()
+                    - reference_test_bed.get_green_reservoir_volume())
+                .abs()
+                    < Volume::new::<gallon>(0.05)
+            );
+        }
+
         #[test]
         fn green_edp_off_do_not_causes_ptu_overheat_if_ptu_on_and_cycling_gear() {
             let mut test_bed = test_bed_in_flight_with()
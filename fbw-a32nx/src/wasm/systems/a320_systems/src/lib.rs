@@ -12,7 +12,7 @@ mod power_consumption;
 
 use self::{
     air_conditioning::A320AirConditioning,
-    fuel::A320Fuel,
+    fuel::{A320Fuel, A320FuelOverheadPanel},
     payload::A320Payload,
     pneumatic::{A320Pneumatic, A320PneumaticOverheadPanel},
 };
@@ -26,17 +26,27 @@ use navigation::{A320AirDataInertialReferenceSystemBuilder, A320RadioAltimeters}
 use power_consumption::A320PowerConsumption;
 use systems::enhanced_gpwc::EnhancedGroundProximityWarningComputer;
 use systems::{hydraulic::brake::BrakeFanPanel, simulation::InitContext};
-use uom::si::{f64::Length, length::nautical_mile};
+use uom::si::{
+    f64::{Length, Ratio},
+    length::nautical_mile,
+};
 
 use systems::{
     air_starter_unit::AirStarterUnit,
+    airframe::Loadsheet,
     apu::{
         Aps3200ApuGenerator, Aps3200Constants, Aps3200StartMotor, AuxiliaryPowerUnit,
         AuxiliaryPowerUnitFactory, AuxiliaryPowerUnitFireOverheadPanel,
         AuxiliaryPowerUnitOverheadPanel,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
-    engine::{leap_engine::LeapEngine, reverser_thrust::ReverserForce, EngineFireOverheadPanel},
+    engine::{
+        leap_engine::LeapEngine,
+        reverser_thrust::ReverserForce,
+        vibration_monitoring::{EngineVibrationCharacteristics, EngineVibrationMonitoringUnit},
+        EngineFireOverheadPanel,
+    },
+    ground_services::GroundServicesProvider,
     hydraulic::brake_circuit::AutobrakePanel,
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
@@ -52,6 +62,7 @@ pub struct A320 {
     air_conditioning: A320AirConditioning,
     apu: AuxiliaryPowerUnit<Aps3200ApuGenerator, Aps3200StartMotor, Aps3200Constants, 1>,
     asu: AirStarterUnit,
+    ground_services: GroundServicesProvider,
     apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
     apu_overhead: AuxiliaryPowerUnitOverheadPanel,
     pneumatic_overhead: A320PneumaticOverheadPanel,
@@ -60,9 +71,11 @@ pub struct A320 {
     payload: A320Payload,
     airframe: A320Airframe,
     fuel: A320Fuel,
+    fuel_overhead: A320FuelOverheadPanel,
     engine_1: LeapEngine,
     engine_2: LeapEngine,
     engine_fire_overhead: EngineFireOverheadPanel<2>,
+    engine_vibration_monitoring: EngineVibrationMonitoringUnit<2>,
     electrical: A320Electrical,
     power_consumption: A320PowerConsumption,
     ext_pwr: ExternalPowerSource,
@@ -91,6 +104,7 @@ impl A320 {
                 ElectricalBusType::DirectCurrentBattery,
             ),
             asu: AirStarterUnit::new(context),
+            ground_services: GroundServicesProvider::new(context),
             apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel::new(context),
             apu_overhead: AuxiliaryPowerUnitOverheadPanel::new(context),
             pneumatic_overhead: A320PneumaticOverheadPanel::new(context),
@@ -98,10 +112,18 @@ impl A320 {
             emergency_electrical_overhead: A320EmergencyElectricalOverheadPanel::new(context),
             payload: A320Payload::new(context),
             airframe: A320Airframe::new(context),
-            fuel: A320Fuel::new(context),
+            // Tank quantities remain owned by the FADEC for now, which burns the engine fuel from
+            // its own tank selection. The per-engine feed simulated here only drives the pump and
+            // valve indications until the FADEC stops writing the tank quantities.
+            fuel: A320Fuel::new(context, false),
+            fuel_overhead: A320FuelOverheadPanel::new(context),
             engine_1: LeapEngine::new(context, 1),
             engine_2: LeapEngine::new(context, 2),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
+            engine_vibration_monitoring: EngineVibrationMonitoringUnit::new(
+                context,
+                EngineVibrationCharacteristics::leap_1a26(),
+            ),
             electrical: A320Electrical::new(context),
             power_consumption: A320PowerConsumption::new(context),
             ext_pwr: ExternalPowerSource::new(context, 1),
@@ -133,6 +155,11 @@ impl A320 {
             reverse_thrust: ReverserForce::new(context),
         }
     }
+
+    /// The final loadsheet, built from the current payload and fuel.
+    pub fn loadsheet(&self) -> &Loadsheet {
+        self.airframe.loadsheet()
+    }
 }
 impl Aircraft for A320 {
     fn update_before_power_distribution(
@@ -140,12 +167,21 @@ impl Aircraft for A320 {
         context: &UpdateContext,
         electricity: &mut Electricity,
     ) {
+        self.engine_1.update(context);
+        self.engine_2.update(context);
+        // No structural flex model on the A320, engine case movement is not simulated
+        self.engine_vibration_monitoring.update(
+            context,
+            [&self.engine_1, &self.engine_2],
+            [Ratio::default(); 2],
+        );
+
         self.apu.update_before_electrical(
             context,
             &self.apu_overhead,
             false, // Todo: fire detection system
             &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
+            self.pneumatic_overhead.apu_bleed_is_on() && !self.pneumatic.apu_bleed_leak_isolation(),
             // This will be replaced when integrating the whole electrical system.
             // For now we use the same logic as found in the JavaScript code; ignoring whether or not
             // the engine generators are supplying electricity.
@@ -175,7 +211,10 @@ impl Aircraft for A320 {
             .update_after_electrical(&self.electrical, electricity);
         self.emergency_electrical_overhead
             .update_after_electrical(context, &self.electrical);
-        self.payload.update(context);
+        self.ground_services.update(context);
+        self.payload
+            .set_target_load_within_limits(self.airframe.is_target_within_limits());
+        self.payload.update(context, &self.ground_services);
         self.airframe
             .update(&self.fuel, &self.payload, &self.payload);
     }
@@ -187,7 +226,14 @@ impl Aircraft for A320 {
         );
         self.apu_overhead.update_after_apu(&self.apu);
 
-        self.asu.update();
+        self.asu.update(&self.ground_services);
+
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            [&self.engine_1, &self.engine_2],
+        );
+        self.fuel_overhead.update_after_fuel(&self.fuel);
 
         self.lgcius.update(
             context,
@@ -243,6 +289,8 @@ impl Aircraft for A320 {
             &self.air_conditioning,
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
+        self.pneumatic_overhead
+            .update_after_pneumatic(&self.pneumatic);
         self.air_conditioning
             .mix_packs_air_update(self.pneumatic.packs());
         self.air_conditioning.update(
@@ -265,6 +313,7 @@ impl SimulationElement for A320 {
         self.air_conditioning.accept(visitor);
         self.apu.accept(visitor);
         self.asu.accept(visitor);
+        self.ground_services.accept(visitor);
         self.apu_fire_overhead.accept(visitor);
         self.apu_overhead.accept(visitor);
         self.payload.accept(visitor);
@@ -272,10 +321,12 @@ impl SimulationElement for A320 {
         self.electrical_overhead.accept(visitor);
         self.emergency_electrical_overhead.accept(visitor);
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
         self.engine_1.accept(visitor);
         self.engine_2.accept(visitor);
         self.engine_fire_overhead.accept(visitor);
+        self.engine_vibration_monitoring.accept(visitor);
         self.electrical.accept(visitor);
         self.power_consumption.accept(visitor);
         self.ext_pwr.accept(visitor);
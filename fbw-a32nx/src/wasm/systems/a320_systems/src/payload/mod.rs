@@ -5,8 +5,9 @@ use std::{cell::Cell, rc::Rc};
 use uom::si::{f64::Mass, mass::kilogram};
 
 use systems::{
+    ground_services::GroundServices,
     payload::{
-        BoardingAgent, BoardingSounds, Cargo, CargoDeck, CargoInfo, CargoPayload,
+        parse_seat_map, BoardingAgent, BoardingSounds, Cargo, CargoDeck, CargoInfo, CargoPayload,
         NumberOfPassengers, PassengerDeck, PassengerPayload, Pax, PaxInfo, PayloadManager,
     },
     simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
@@ -15,6 +16,8 @@ use systems::{
 #[cfg(test)]
 mod test;
 
+const SEAT_MAP: &str = include_str!("./seat_map.txt");
+
 pub enum A320Pax {
     A,
     B,
@@ -130,7 +133,12 @@ impl A320Payload {
         )));
         let developer_state = Rc::new(Cell::new(0));
         let boarding_sounds = BoardingSounds::new(context);
+        let seats = parse_seat_map(SEAT_MAP).expect("Could not parse the A320 seat map");
+        let mut first_seat = 0;
         let pax = Self::A320_PAX.map(|p| {
+            let station_seats = &seats[first_seat..first_seat + p.max_pax as usize];
+            first_seat += p.max_pax as usize;
+
             Pax::new(
                 context.get_identifier(p.pax_id.to_owned()),
                 context.get_identifier(format!("{}_DESIRED", p.pax_id)),
@@ -140,6 +148,7 @@ impl A320Payload {
                 Vector3::new(p.position.0, p.position.1, p.position.2),
                 p.max_pax,
             )
+            .with_seats(station_seats)
         });
 
         let cargo = Self::A320_CARGO.map(|c| {
@@ -186,8 +195,18 @@ impl A320Payload {
         }
     }
 
-    pub(crate) fn update(&mut self, context: &UpdateContext) {
-        self.payload_manager.update(context.delta());
+    pub(crate) fn update(
+        &mut self,
+        context: &UpdateContext,
+        ground_services: &impl GroundServices,
+    ) {
+        self.payload_manager
+            .update(context.delta(), ground_services);
+    }
+
+    pub(crate) fn set_target_load_within_limits(&mut self, target_load_within_limits: bool) {
+        self.payload_manager
+            .set_target_load_within_limits(target_load_within_limits);
     }
 
     fn pax_num(&self, ps: usize) -> i8 {
@@ -339,6 +358,14 @@ impl PassengerPayload for A320Payload {
     fn target_fore_aft_center_of_gravity(&self) -> f64 {
         self.target_passenger_center_of_gravity().x
     }
+
+    fn station_passengers(&self, ps: usize) -> i8 {
+        self.payload_manager.pax_num(ps)
+    }
+
+    fn station_passenger_load(&self, ps: usize) -> Mass {
+        self.payload_manager.pax_payload(ps)
+    }
 }
 impl CargoPayload for A320Payload {
     fn total_cargo_load(&self) -> Mass {
@@ -364,6 +391,10 @@ impl CargoPayload for A320Payload {
     fn target_fore_aft_center_of_gravity(&self) -> f64 {
         self.target_cargo_center_of_gravity().x
     }
+
+    fn station_cargo_load(&self, cs: usize) -> Mass {
+        self.payload_manager.cargo(cs)
+    }
 }
 impl SimulationElement for A320Payload {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -6,7 +6,8 @@ use std::time::Duration;
 use rand::seq::IteratorRandom;
 use rand::SeedableRng;
 use systems::electrical::Electricity;
-use systems::payload::{BoardingRate, GsxState};
+use systems::ground_services::{GroundServiceState, GroundServicesProvider};
+use systems::payload::{BoardingRate, BoardingStrategy};
 use uom::si::f64::Ratio;
 use uom::si::mass::pound;
 use uom::si::ratio::percent;
@@ -19,12 +20,14 @@ use crate::systems::simulation::{
 };
 struct BoardingTestAircraft {
     payload: A320Payload,
+    ground_services: GroundServicesProvider,
 }
 
 impl BoardingTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
             payload: A320Payload::new(context),
+            ground_services: GroundServicesProvider::new(context),
         }
     }
 
@@ -107,12 +110,14 @@ impl Aircraft for BoardingTestAircraft {
         context: &UpdateContext,
         _electricity: &mut Electricity,
     ) {
-        self.payload.update(context);
+        self.ground_services.update(context);
+        self.payload.update(context, &self.ground_services);
     }
 }
 impl SimulationElement for BoardingTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.payload.accept(visitor);
+        self.ground_services.accept(visitor);
 
         visitor.visit(self);
     }
@@ -155,12 +160,36 @@ impl BoardingTestBed {
         self
     }
 
+    fn init_vars_timeline(mut self) -> Self {
+        self.write_by_name("GROUND_SERVICES_TIMELINE_ENABLED", true);
+
+        self
+    }
+
+    fn request_ground_service(mut self, service: &str) -> Self {
+        self.write_by_name(&format!("GROUND_SERVICES_{}_REQUESTED", service), true);
+
+        self
+    }
+
     fn instant_board_rate(mut self) -> Self {
         self.write_by_name("BOARDING_RATE", BoardingRate::Instant);
 
         self
     }
 
+    fn boarding_strategy(mut self, boarding_strategy: BoardingStrategy) -> Self {
+        self.write_by_name("BOARDING_STRATEGY", boarding_strategy);
+
+        self
+    }
+
+    fn and_run_for(mut self, duration: Duration) -> Self {
+        self.test_bed.run_multiple_frames(duration);
+
+        self
+    }
+
     fn fast_board_rate(mut self) -> Self {
         self.write_by_name("BOARDING_RATE", BoardingRate::Fast);
 
@@ -180,33 +209,33 @@ impl BoardingTestBed {
     }
 
     fn gsx_requested_board_state(mut self) -> Self {
-        self.write_by_name("FSDT_GSX_BOARDING_STATE", GsxState::Requested);
+        self.write_by_name("FSDT_GSX_BOARDING_STATE", GroundServiceState::Requested);
         self
     }
 
     fn gsx_performing_board_state(mut self) -> Self {
-        self.write_by_name("FSDT_GSX_BOARDING_STATE", GsxState::Performing);
+        self.write_by_name("FSDT_GSX_BOARDING_STATE", GroundServiceState::Performing);
         self
     }
 
     fn gsx_performing_deboard_state(mut self) -> Self {
-        self.write_by_name("FSDT_GSX_DEBOARDING_STATE", GsxState::Performing);
+        self.write_by_name("FSDT_GSX_DEBOARDING_STATE", GroundServiceState::Performing);
         self.write_by_name("FSDT_GSX_DEBOARDING_CARGO_PERCENT", 0.);
         self
     }
 
     fn gsx_requested_deboard_state(mut self) -> Self {
-        self.write_by_name("FSDT_GSX_DEBOARDING_STATE", GsxState::Requested);
+        self.write_by_name("FSDT_GSX_DEBOARDING_STATE", GroundServiceState::Requested);
         self
     }
 
     fn gsx_complete_board_state(mut self) -> Self {
-        self.write_by_name("FSDT_GSX_BOARDING_STATE", GsxState::Completed);
+        self.write_by_name("FSDT_GSX_BOARDING_STATE", GroundServiceState::Completed);
         self
     }
 
     fn gsx_complete_deboard_state(mut self) -> Self {
-        self.write_by_name("FSDT_GSX_DEBOARDING_STATE", GsxState::Completed);
+        self.write_by_name("FSDT_GSX_DEBOARDING_STATE", GroundServiceState::Completed);
         self
     }
 
@@ -1021,8 +1050,7 @@ fn start_half_cargo_target_full_cargo_real_board() {
         .target_full_cargo()
         .real_board_rate()
         .start_boarding()
-        .and_run()
-        .and_stabilize();
+        .and_run_for(Duration::from_secs(6));
 
     test_bed.boarding_started();
 
@@ -1081,8 +1109,7 @@ fn start_half_cargo_target_full_cargo_real_board_usr_interferes_with_payload_man
         .target_full_cargo()
         .real_board_rate()
         .start_boarding()
-        .and_run()
-        .and_stabilize();
+        .and_run_for(Duration::from_secs(6));
 
     test_bed.boarding_started();
 
@@ -1355,8 +1382,7 @@ fn detailed_test_with_multiple_stops() {
         .with_cargo(A320Cargo::AftContainer.into(), Mass::new::<kilogram>(100.0))
         .real_board_rate()
         .start_boarding()
-        .and_run()
-        .and_stabilize();
+        .and_run_for(Duration::from_secs(6 * MINUTES_TO_SECONDS));
 
     test_bed.boarding_started();
     test_bed = test_bed.stop_boarding().and_run();
@@ -1365,10 +1391,11 @@ fn detailed_test_with_multiple_stops() {
 
     test_bed = test_bed.start_boarding();
 
+    // How long the passengers take to board depends on the seats they get
     assert_eq!(test_bed.pax_num(A320Pax::A.into()), 15);
     assert_eq!(test_bed.pax_num(A320Pax::B.into()), 14);
     assert_eq!(test_bed.pax_num(A320Pax::C.into()), 32);
-    assert_eq!(test_bed.pax_num(A320Pax::D.into()), 34);
+    assert!((13..42).contains(&test_bed.pax_num(A320Pax::D.into())));
 
     let five_minutes_in_seconds = 5 * MINUTES_TO_SECONDS;
 
@@ -1403,6 +1430,55 @@ fn detailed_test_with_multiple_stops() {
     test_bed.sound_boarding_complete_reset();
 }
 
+#[test]
+fn timeline_boarding_full_pax() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .init_vars_timeline()
+        .target_full_pax()
+        .target_full_cargo()
+        .request_ground_service("BOARDING")
+        .request_ground_service("CARGO")
+        .and_run()
+        .and_run_for(Duration::from_secs(510));
+
+    test_bed.has_sound_pax_boarding();
+    let pax_num = test_bed.total_pax_num();
+    assert!(pax_num > 0);
+    test_bed.has_no_sound_pax_deboarding();
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(600)).and_run();
+
+    assert!(test_bed.total_pax_num() > pax_num);
+    test_bed.has_full_pax();
+    test_bed.has_full_cargo();
+    test_bed.has_no_sound_pax_boarding();
+    test_bed.has_sound_pax_complete();
+}
+
+#[test]
+fn timeline_deboarding_full_pax() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .init_vars_timeline()
+        .with_full_pax()
+        .with_full_cargo()
+        .target_no_pax()
+        .target_no_cargo()
+        .request_ground_service("DEBOARDING")
+        .and_run()
+        .and_run_for(Duration::from_secs(360));
+
+    test_bed.has_sound_pax_deboarding();
+    test_bed.has_no_sound_pax_boarding();
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(360)).and_run();
+
+    test_bed.has_no_pax();
+    test_bed.has_no_cargo();
+    test_bed.has_no_sound_pax_deboarding();
+}
+
 #[test]
 fn disable_if_gsx_enabled() {
     let mut test_bed = test_bed_with()
@@ -1641,3 +1717,114 @@ fn gsx_deboarding_full_pax_partial() {
     test_bed.has_no_sound_pax_boarding();
     test_bed.sound_boarding_complete_reset();
 }
+
+#[test]
+fn back_to_front_boarding_fills_the_aft_rows_first() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .target_full_pax()
+        .fast_board_rate()
+        .boarding_strategy(BoardingStrategy::BackToFront)
+        .start_boarding()
+        .and_run_for(Duration::from_secs(20));
+
+    let pax_d: f64 = test_bed.read_by_name("PAX_D");
+    let last_row = 0b111111 << 42;
+
+    assert!(test_bed.query(|a| a.pax_num(A320Pax::D as usize)) > 6);
+    assert_eq!(pax_d as u64 & last_row, last_row);
+    for ps in [A320Pax::A, A320Pax::B, A320Pax::C] {
+        assert_eq!(test_bed.query(|a| a.pax_num(ps as usize)), 0);
+    }
+}
+
+#[test]
+fn window_middle_aisle_boarding_fills_the_window_seats_first() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .target_full_pax()
+        .fast_board_rate()
+        .boarding_strategy(BoardingStrategy::WindowMiddleAisle)
+        .start_boarding()
+        .and_run_for(Duration::from_secs(20));
+
+    assert!(test_bed.query(|a| a.total_pax_num()) > 0);
+    for station in ["PAX_A", "PAX_B", "PAX_C", "PAX_D"] {
+        let pax: f64 = test_bed.read_by_name(station);
+        let pax = pax as u64;
+        assert!((0..53)
+            .filter(|seat| pax & (1 << seat) > 0)
+            .all(|seat| seat % 6 == 0 || seat % 6 == 5));
+    }
+}
+
+#[test]
+fn random_boarding_boards_all_pax() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .target_full_pax()
+        .fast_board_rate()
+        .boarding_strategy(BoardingStrategy::Random)
+        .start_boarding()
+        .and_run()
+        .and_stabilize();
+
+    test_bed.has_full_pax();
+    test_bed.boarding_stopped();
+}
+
+#[test]
+fn seat_map_places_pax_at_their_row() {
+    let test_bed = test_bed_with()
+        .init_vars()
+        .with_pax_target(A320Pax::A as usize, 1)
+        .instant_board_rate()
+        .start_boarding()
+        .and_run();
+
+    let fore_aft_center_of_gravity = test_bed.pax_fore_aft_center_of_gravity();
+
+    assert_eq!(test_bed.query(|a| a.total_pax_num()), 1);
+    assert!(fore_aft_center_of_gravity != A320Payload::A320_PAX[0].position.0);
+    assert!((14.1..=27.).contains(&fore_aft_center_of_gravity));
+}
+
+#[test]
+fn seat_map_keeps_the_station_positions() {
+    let test_bed = test_bed_with()
+        .init_vars()
+        .target_full_pax()
+        .instant_board_rate()
+        .start_boarding()
+        .and_run();
+
+    let (moment, pax) = A320Payload::A320_PAX
+        .iter()
+        .fold((0., 0.), |(moment, pax), info| {
+            (
+                moment + info.max_pax as f64 * info.position.0,
+                pax + info.max_pax as f64,
+            )
+        });
+
+    assert!((test_bed.pax_fore_aft_center_of_gravity() - moment / pax).abs() < 1e-6);
+}
+
+#[test]
+fn window_middle_aisle_boarding_is_faster_than_back_to_front() {
+    let pax_boarded_in_five_minutes = |boarding_strategy| {
+        test_bed_with()
+            .init_vars()
+            .target_full_pax()
+            .real_board_rate()
+            .boarding_strategy(boarding_strategy)
+            .start_boarding()
+            .and_run_for(Duration::from_secs(300))
+            .query(|a| a.total_pax_num())
+    };
+
+    assert!(
+        pax_boarded_in_five_minutes(BoardingStrategy::WindowMiddleAisle)
+            > pax_boarded_in_five_minutes(BoardingStrategy::BackToFront)
+    );
+}
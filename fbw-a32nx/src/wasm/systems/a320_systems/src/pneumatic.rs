@@ -13,6 +13,7 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    failures::{Failure, FailureType},
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
         valve::*, BleedMonitoringComputerChannelOperationMode,
@@ -25,10 +26,10 @@ use systems::{
         WingAntiIcePushButton, WingAntiIceSelected,
     },
     shared::{
-        pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, DelayedTrueLogicGate,
-        ElectricalBusType, ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1,
-        EngineCorrectedN2, EngineFirePushButtons, EngineStartState, HydraulicColor,
-        LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
+        pid::PidController, update_iterator::MaxStepLoop, BleedAirDuctZone, ControllerSignal,
+        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EngineBleedPushbutton,
+        EngineCorrectedN1, EngineCorrectedN2, EngineFirePushButtons, EngineStartState,
+        HydraulicColor, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
         ReservoirAirPressure,
     },
     simulation::{
@@ -38,7 +39,9 @@ use systems::{
     valve_signal_implementation,
 };
 
+mod leak_detection;
 mod wing_anti_ice;
+use leak_detection::*;
 use wing_anti_ice::*;
 
 struct PressureRegulatingValveSignal {
@@ -127,6 +130,11 @@ pub struct A320Pneumatic {
         PressurisedReservoirWithExhaustValve<VariableVolumeContainer>,
 
     packs: [PackComplex; 2],
+
+    wing_ducts: [BleedAirDuct; 2],
+    pack_bay_duct: BleedAirDuct,
+    apu_bleed_duct: BleedAirDuct,
+    leak_detection: LeakDetectionSystem,
 }
 impl A320Pneumatic {
     const PNEUMATIC_SIM_MAX_TIME_STEP: Duration = Duration::from_millis(10);
@@ -219,6 +227,19 @@ impl A320Pneumatic {
                 PackComplex::new(context, 1, ElectricalBusType::DirectCurrent(1)),
                 PackComplex::new(context, 2, ElectricalBusType::DirectCurrent(2)),
             ],
+            wing_ducts: [
+                BleedAirDuct::new(BleedAirDuctZone::Wing(1)),
+                BleedAirDuct::new(BleedAirDuctZone::Wing(2)),
+            ],
+            pack_bay_duct: BleedAirDuct::new(BleedAirDuctZone::PackBay),
+            apu_bleed_duct: BleedAirDuct::new(BleedAirDuctZone::ApuDuct),
+            leak_detection: LeakDetectionSystem::new(
+                context,
+                [
+                    ElectricalBusType::DirectCurrentEssentialShed,
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+            ),
         }
     }
 
@@ -263,6 +284,19 @@ impl A320Pneumatic {
         self.apu_compression_chamber.update(apu);
         self.air_starter_unit_compression_chamber.update(asu);
 
+        self.leak_detection.update(
+            context,
+            &[
+                &self.engine_systems[0].pylon_duct,
+                &self.engine_systems[1].pylon_duct,
+                &self.wing_ducts[0],
+                &self.wing_ducts[1],
+                &self.pack_bay_duct,
+                &self.apu_bleed_duct,
+            ],
+            overhead_panel,
+        );
+
         for bleed_monitoring_computer in self.bleed_monitoring_computers.iter_mut() {
             bleed_monitoring_computer.update(
                 context,
@@ -273,6 +307,7 @@ impl A320Pneumatic {
                 &self.cross_bleed_valve,
                 &self.fadec,
                 &self.wing_anti_ice,
+                &self.leak_detection,
             );
 
             // I am not exactly sure if both BMCs should actually control this valve all the time.
@@ -325,7 +360,22 @@ impl A320Pneumatic {
             overhead_panel.wing_anti_ice.mode(),
             lgciu,
         );
+
+        for (wing_duct, engine_system) in self.wing_ducts.iter_mut().zip(&mut self.engine_systems) {
+            wing_duct.update_move_fluid(context, engine_system);
+        }
+
         let [left_system, right_system] = &mut self.engine_systems;
+
+        // The crossbleed duct in the pack bay and the APU duct both join the left bleed duct.
+        // A check valve isolates the APU duct when the APU bleed valve is closed.
+        self.pack_bay_duct.update_move_fluid(context, left_system);
+        if self.apu_bleed_air_valve.is_open() {
+            self.apu_bleed_duct.update_move_fluid(context, left_system);
+        } else {
+            self.apu_bleed_duct.update_isolated();
+        }
+
         self.apu_bleed_air_valve.update_move_fluid(
             context,
             &mut self.apu_compression_chamber,
@@ -381,6 +431,11 @@ impl A320Pneumatic {
     pub fn packs(&mut self) -> &mut [PackComplex; 2] {
         &mut self.packs
     }
+
+    /// Request from the bleed monitoring computers to the APU ECB to close the APU bleed valve
+    pub fn apu_bleed_leak_isolation(&self) -> bool {
+        self.leak_detection.apu_bleed_is_isolated()
+    }
 }
 impl PneumaticBleed for A320Pneumatic {
     fn apu_bleed_is_on(&self) -> bool {
@@ -419,6 +474,10 @@ impl SimulationElement for A320Pneumatic {
         accept_iterable!(self.bleed_monitoring_computers, visitor);
         accept_iterable!(self.engine_systems, visitor);
         accept_iterable!(self.packs, visitor);
+        accept_iterable!(self.wing_ducts, visitor);
+        self.pack_bay_duct.accept(visitor);
+        self.apu_bleed_duct.accept(visitor);
+        self.leak_detection.accept(visitor);
 
         self.blue_hydraulic_reservoir_with_valve.accept(visitor);
         self.yellow_hydraulic_reservoir_with_valve.accept(visitor);
@@ -534,6 +593,7 @@ impl BleedMonitoringComputer {
         cross_bleed_valve: &impl PneumaticValve,
         fadec: &FullAuthorityDigitalEngineControl,
         wing_anti_ice: &impl WingAntiIceSelected,
+        leak_detection: &LeakDetectionSystem,
     ) {
         self.main_channel.update(
             context,
@@ -544,6 +604,7 @@ impl BleedMonitoringComputer {
             overhead_panel,
             fadec,
             wing_anti_ice,
+            leak_detection,
         );
 
         self.backup_channel.update(
@@ -555,6 +616,7 @@ impl BleedMonitoringComputer {
             overhead_panel,
             fadec,
             wing_anti_ice,
+            leak_detection,
         );
     }
 
@@ -648,6 +710,8 @@ struct BleedMonitoringComputerChannel {
     flight_phase_loop: FlightPhaseLoop,
     low_temperature_regulation_active: DelayedTrueLogicGate,
     should_command_onside_prv_closed: bool,
+    is_engine_bleed_isolated_by_leak: bool,
+    is_cross_bleed_isolated_by_leak: bool,
 
     low_temperature_id: VariableIdentifier,
     overheat_id: VariableIdentifier,
@@ -691,7 +755,9 @@ impl BleedMonitoringComputerChannel {
                 Self::PRESSURE_REGULATING_VALVE_DUAL_BLEED_CONFIG_TARGET_PSI,
                 1.,
             ),
-            fan_air_valve_pid: PidController::new(-0.02, 0., 0., 0., 1., 200., 1.),
+            // The integral term lets the fan air valve reach full travel when the precooler can no
+            // longer hold the setpoint, e.g. on a hot day at takeoff thrust.
+            fan_air_valve_pid: PidController::new(-0.02, -0.005, 0., 0., 1., 200., 1.),
             cross_bleed_valve_selector: CrossBleedValveSelectorMode::Auto,
             should_use_ip_vs_hp_valve: false,
             overheat_monitor: BleedOverheatMonitor::new(),
@@ -700,6 +766,8 @@ impl BleedMonitoringComputerChannel {
             flight_phase_loop: FlightPhaseLoop::new(),
             low_temperature_regulation_active: DelayedTrueLogicGate::new(Duration::from_secs(20)),
             should_command_onside_prv_closed: false,
+            is_engine_bleed_isolated_by_leak: false,
+            is_cross_bleed_isolated_by_leak: false,
             is_in_dual_bleed_config: false,
             low_temperature_id: context
                 .get_identifier(format!("PNEU_ENG_{}_LOW_TEMPERATURE", engine_number)),
@@ -719,6 +787,7 @@ impl BleedMonitoringComputerChannel {
         overhead_panel: &A320PneumaticOverheadPanel,
         fadec: &FullAuthorityDigitalEngineControl,
         wing_anti_ice: &impl WingAntiIceSelected,
+        leak_detection: &LeakDetectionSystem,
     ) {
         // READ IN SENSORS
 
@@ -732,6 +801,10 @@ impl BleedMonitoringComputerChannel {
 
         self.pressure_regulating_valve_is_closed = !sensors.pressure_regulating_valve_is_open();
 
+        self.is_engine_bleed_isolated_by_leak =
+            leak_detection.engine_bleed_is_isolated(self.engine_number);
+        self.is_cross_bleed_isolated_by_leak = leak_detection.cross_bleed_is_isolated();
+
         // UPDATE STATE
 
         self.flight_phase_loop.update(context);
@@ -772,15 +845,18 @@ impl BleedMonitoringComputerChannel {
         let mut force_ip_bleed = false;
 
         if let Some(precooler_outlet_temperature) = sensors.bleed_temperature_sensor_temperature() {
+            // The measurement isn't clamped to the setpoint so that the integral term unwinds
+            // and the fan air valve closes again once the bleed air cools below the setpoint.
             self.fan_air_valve_pid.next_control_output(
-                precooler_outlet_temperature
-                    .get::<degree_celsius>()
-                    .max(self.fan_air_valve_pid.setpoint()),
+                precooler_outlet_temperature.get::<degree_celsius>(),
                 Some(context.delta()),
             );
 
-            self.overheat_monitor
-                .update(context, precooler_outlet_temperature);
+            self.overheat_monitor.update(
+                context,
+                precooler_outlet_temperature,
+                !overhead_panel.engine_bleed_pb_is_auto(self.engine_number),
+            );
 
             force_hp_bleed = (wing_anti_ice.is_wai_selected()
                 && precooler_outlet_temperature.get::<degree_celsius>()
@@ -914,7 +990,8 @@ impl BleedMonitoringComputerChannel {
             cross_bleed_valve,
         ) || sensors.engine_starter_valve_is_open()
             || self.overpressure_monitor.has_overpressure()
-            || self.overheat_monitor.has_overheat();
+            || self.overheat_monitor.has_overheat()
+            || self.is_engine_bleed_isolated_by_leak;
 
         let should_command_offside_prv_closed = self.should_command_prv_closed(
             self.engine_number % 2 + 1,
@@ -996,7 +1073,7 @@ impl ControllerSignal<CrossBleedValveSignal> for BleedMonitoringComputerChannel
                 CrossBleedValveSignalType::Manual,
             )),
             CrossBleedValveSelectorMode::Auto => {
-                if self.is_apu_bleed_valve_open {
+                if self.is_apu_bleed_valve_open && !self.is_cross_bleed_isolated_by_leak {
                     Some(CrossBleedValveSignal::new_open(
                         CrossBleedValveSignalType::Automatic,
                     ))
@@ -1069,11 +1146,20 @@ struct EngineBleedAirSystem {
     regulated_pressure_transducer: PressureTransducer,
     differential_pressure_transducer: DifferentialPressureTransducer,
     bleed_temperature_sensor: BleedTemperatureSensor,
+
+    pylon_duct: BleedAirDuct,
+
+    fan_air_valve_stuck_closed_failure: Failure,
+    fan_air_valve_stuck_open_failure: Failure,
+    precooler_fouling_failure: Failure,
 }
 impl EngineBleedAirSystem {
     const MIN_ENGINE_START_CONTAINER_PRESSURE_PSIG_HIGH: f64 = 10.;
     const MIN_ENGINE_START_CONTAINER_PRESSURE_PSIG_LOW: f64 = 5.;
 
+    // Share of the precooler heat exchange lost when its fins are clogged
+    const FOULED_PRECOOLER_FOULING_PERCENT: f64 = 80.;
+
     fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
         Self {
             number,
@@ -1114,7 +1200,9 @@ impl EngineBleedAirSystem {
             )),
             engine_starter_pressurized_id: context
                 .get_identifier(format!("PNEU_ENG_{}_STARTER_PRESSURIZED", number)),
-            fan_compression_chamber_controller: EngineCompressionChamberController::new(2., 0.),
+            // Fan pressure ratio should be about 1.6 at takeoff thrust. A higher ratio supplies fan
+            // air warmer than the bleed temperature setpoint, which the precooler can't cool with.
+            fan_compression_chamber_controller: EngineCompressionChamberController::new(0.54, 0.),
             // Maximum IP bleed pressure output should be about 150 psig
             intermediate_pressure_compression_chamber_controller:
                 EngineCompressionChamberController::new(2.51457, 0.116127),
@@ -1189,6 +1277,14 @@ impl EngineBleedAirSystem {
             regulated_pressure_transducer: PressureTransducer::new(powered_by),
             differential_pressure_transducer: DifferentialPressureTransducer::new(powered_by),
             bleed_temperature_sensor: BleedTemperatureSensor::new(powered_by),
+            pylon_duct: BleedAirDuct::new(BleedAirDuctZone::Pylon(number)),
+            fan_air_valve_stuck_closed_failure: Failure::new(FailureType::FanAirValveStuckClosed(
+                number,
+            )),
+            fan_air_valve_stuck_open_failure: Failure::new(FailureType::FanAirValveStuckOpen(
+                number,
+            )),
+            precooler_fouling_failure: Failure::new(FailureType::PrecoolerFouling(number)),
         }
     }
 
@@ -1222,8 +1318,23 @@ impl EngineBleedAirSystem {
             .update_open_amount(pressure_regulating_valve_controller);
         self.engine_starter_valve
             .update_open_amount(engine_starter_valve_controller);
+        self.fan_air_valve
+            .set_stuck(if self.fan_air_valve_stuck_closed_failure.is_active() {
+                Some(Ratio::new::<percent>(0.))
+            } else if self.fan_air_valve_stuck_open_failure.is_active() {
+                Some(Ratio::new::<percent>(100.))
+            } else {
+                None
+            });
         self.fan_air_valve
             .update_open_amount(fan_air_valve_controller);
+        self.precooler.set_fouling(Ratio::new::<percent>(
+            if self.precooler_fouling_failure.is_active() {
+                Self::FOULED_PRECOOLER_FOULING_PERCENT
+            } else {
+                0.
+            },
+        ));
 
         self.intermediate_pressure_valve.update_move_fluid(
             context,
@@ -1256,6 +1367,8 @@ impl EngineBleedAirSystem {
             &mut self.precooler_supply_pipe,
             &mut self.precooler_outlet_pipe,
         );
+        self.pylon_duct
+            .update_move_fluid(context, &mut self.precooler_inlet_pipe);
         self.engine_starter_valve
             .update_move_fluid_with_transfer_speed(
                 context,
@@ -1378,6 +1491,12 @@ impl SimulationElement for EngineBleedAirSystem {
         self.differential_pressure_transducer.accept(visitor);
         self.bleed_temperature_sensor.accept(visitor);
 
+        self.pylon_duct.accept(visitor);
+
+        self.fan_air_valve_stuck_closed_failure.accept(visitor);
+        self.fan_air_valve_stuck_open_failure.accept(visitor);
+        self.precooler_fouling_failure.accept(visitor);
+
         visitor.visit(self);
     }
 
@@ -1479,10 +1598,13 @@ impl PneumaticContainer for EngineBleedAirSystem {
     }
 }
 
+/// Detects a bleed overheat from the precooler outlet temperature. Once detected, the overheat
+/// stays latched until the crew selects the engine bleed push button off.
 struct BleedOverheatMonitor {
     temperature_over_257_for_55s: DelayedTrueLogicGate,
     temperature_over_270_for_15s: DelayedTrueLogicGate,
     temperature_over_290_for_5s: DelayedTrueLogicGate,
+    has_latched_overheat: bool,
 }
 impl BleedOverheatMonitor {
     fn new() -> Self {
@@ -1490,10 +1612,16 @@ impl BleedOverheatMonitor {
             temperature_over_257_for_55s: DelayedTrueLogicGate::new(Duration::from_secs(55)),
             temperature_over_270_for_15s: DelayedTrueLogicGate::new(Duration::from_secs(15)),
             temperature_over_290_for_5s: DelayedTrueLogicGate::new(Duration::from_secs(5)),
+            has_latched_overheat: false,
         }
     }
 
-    fn update(&mut self, context: &UpdateContext, temperature: ThermodynamicTemperature) {
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        temperature: ThermodynamicTemperature,
+        reset: bool,
+    ) {
         self.temperature_over_257_for_55s.update(
             context,
             temperature > ThermodynamicTemperature::new::<degree_celsius>(257.),
@@ -1506,12 +1634,16 @@ impl BleedOverheatMonitor {
             context,
             temperature > ThermodynamicTemperature::new::<degree_celsius>(290.),
         );
+
+        let detects_overheat = self.temperature_over_257_for_55s.output()
+            || self.temperature_over_270_for_15s.output()
+            || self.temperature_over_290_for_5s.output();
+
+        self.has_latched_overheat = !reset && (self.has_latched_overheat || detects_overheat);
     }
 
     fn has_overheat(&self) -> bool {
-        self.temperature_over_257_for_55s.output()
-            || self.temperature_over_270_for_15s.output()
-            || self.temperature_over_290_for_5s.output()
+        self.has_latched_overheat
     }
 }
 
@@ -1568,6 +1700,16 @@ impl A320PneumaticOverheadPanel {
             _ => panic!("Invalid engine number"),
         }
     }
+
+    pub fn update_after_pneumatic(&mut self, pneumatic: &A320Pneumatic) {
+        let leak_detection = &pneumatic.leak_detection;
+
+        self.engine_1_bleed
+            .set_fault(leak_detection.engine_bleed_is_isolated(1));
+        self.engine_2_bleed
+            .set_fault(leak_detection.engine_bleed_is_isolated(2));
+        self.apu_bleed.set_fault(leak_detection.apu_bleed_leak());
+    }
 }
 impl EngineBleedPushbutton<2> for A320PneumaticOverheadPanel {
     fn engine_bleed_pushbuttons_are_auto(&self) -> [bool; 2] {
@@ -1908,6 +2050,7 @@ pub mod tests {
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
         engine::leap_engine::LeapEngine,
         failures::FailureType,
+        ground_services::GroundServicesProvider,
         pneumatic::{
             BleedMonitoringComputerChannelOperationMode, ControllablePneumaticValve,
             CrossBleedValveSelectorMode, EngineState, PneumaticContainer, PneumaticValveSignal,
@@ -1915,11 +2058,11 @@ pub mod tests {
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
-            interpolation, ApuBleedAirValveSignal, CabinAltitude, CabinSimulation,
-            ControllerSignal, ElectricalBusType, ElectricalBuses, EmergencyElectricalState,
-            EngineCorrectedN1, EngineFirePushButtons, EngineStartState, HydraulicColor,
-            InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber, PackFlowValveState,
-            PneumaticBleed, PneumaticValve, PotentialOrigin,
+            interpolation, ApuBleedAirValveSignal, BleedAirDuctZone, CabinAltitude,
+            CabinSimulation, ControllerSignal, ElectricalBusType, ElectricalBuses,
+            EmergencyElectricalState, EngineCorrectedN1, EngineFirePushButtons, EngineStartState,
+            HydraulicColor, InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber,
+            PackFlowValveState, PneumaticBleed, PneumaticValve, PotentialOrigin,
         },
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -1935,6 +2078,7 @@ pub mod tests {
         mass_rate::kilogram_per_second,
         pressure::psi,
         ratio::ratio,
+        temperature_interval,
         thermodynamic_temperature::degree_celsius,
         velocity::{foot_per_minute, knot},
     };
@@ -2189,6 +2333,7 @@ pub mod tests {
         lgciu: TestLgciu,
         apu: TestApu,
         asu: AirStarterUnit,
+        ground_services: GroundServicesProvider,
         engine_1: LeapEngine,
         engine_2: LeapEngine,
         pneumatic_overhead_panel: A320PneumaticOverheadPanel,
@@ -2216,6 +2361,7 @@ pub mod tests {
                 lgciu: TestLgciu::new(true),
                 apu: TestApu::new(),
                 asu: AirStarterUnit::new(context),
+                ground_services: GroundServicesProvider::new(context),
                 engine_1: LeapEngine::new(context, 1),
                 engine_2: LeapEngine::new(context, 2),
                 pneumatic_overhead_panel: A320PneumaticOverheadPanel::new(context),
@@ -2285,7 +2431,8 @@ pub mod tests {
             self.electrical.update(context);
 
             self.apu.update(self.pneumatic.apu_bleed_air_valve());
-            self.asu.update();
+            self.ground_services.update(context);
+            self.asu.update(&self.ground_services);
             self.pneumatic.update(
                 context,
                 [&self.engine_1, &self.engine_2],
@@ -2296,6 +2443,8 @@ pub mod tests {
                 &self.air_conditioning,
                 [&self.lgciu; 2],
             );
+            self.pneumatic_overhead_panel
+                .update_after_pneumatic(&self.pneumatic);
             self.air_conditioning.update(
                 context,
                 [&self.engine_1, &self.engine_2],
@@ -2309,6 +2458,7 @@ pub mod tests {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
             self.electrical.accept(visitor);
             self.asu.accept(visitor);
+            self.ground_services.accept(visitor);
             self.pneumatic.accept(visitor);
             self.engine_1.accept(visitor);
             self.engine_2.accept(visitor);
@@ -2381,6 +2531,12 @@ pub mod tests {
             self
         }
 
+        fn ambient_temperature_of(mut self, temperature: ThermodynamicTemperature) -> Self {
+            self.set_ambient_temperature(temperature);
+
+            self
+        }
+
         fn idle_eng1(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.7));
@@ -2561,6 +2717,18 @@ pub mod tests {
             })
         }
 
+        fn fan_air_valve_open_amount(&self, number: usize) -> Ratio {
+            self.query(|a| {
+                a.pneumatic.engine_systems[number - 1]
+                    .fan_air_valve
+                    .open_amount()
+            })
+        }
+
+        fn engine_bleed_has_overheat(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_ENG_{}_OVERHEAT", number))
+        }
+
         fn bleed_temperature_sensor_temperature(
             &self,
             number: usize,
@@ -2740,6 +2908,26 @@ pub mod tests {
             self.query(|a| a.pneumatic.fadec.engine_state(number))
         }
 
+        fn engine_bleed_leak(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_ENG_{}_BLEED_LEAK", number))
+        }
+
+        fn wing_leak(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_WING_{}_LEAK", number))
+        }
+
+        fn apu_bleed_leak(&mut self) -> bool {
+            self.read_by_name("PNEU_APU_BLEED_LEAK")
+        }
+
+        fn engine_bleed_pb_has_fault(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("OVHD_PNEU_ENG_{}_BLEED_PB_HAS_FAULT", number))
+        }
+
+        fn apu_bleed_leak_isolation(&self) -> bool {
+            self.query(|a| a.pneumatic.apu_bleed_leak_isolation())
+        }
+
         fn cross_bleed_valve_is_open(&self) -> bool {
             self.query(|a| a.pneumatic.cross_bleed_valve.is_open())
         }
@@ -3684,6 +3872,11 @@ pub mod tests {
         assert!(test_bed.contains_variable_with_name("PNEU_WING_ANTI_ICE_SYSTEM_ON"));
         assert!(test_bed.contains_variable_with_name("PNEU_WING_ANTI_ICE_SYSTEM_SELECTED"));
         assert!(test_bed.contains_variable_with_name("PNEU_WING_ANTI_ICE_HAS_FAULT"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_1_BLEED_LEAK"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_2_BLEED_LEAK"));
+        assert!(test_bed.contains_variable_with_name("PNEU_WING_1_LEAK"));
+        assert!(test_bed.contains_variable_with_name("PNEU_WING_2_LEAK"));
+        assert!(test_bed.contains_variable_with_name("PNEU_APU_BLEED_LEAK"));
         assert!(test_bed.contains_variable_with_name("PNEU_WING_ANTI_ICE_GROUND_TIMER"));
         assert!(test_bed.contains_variable_with_name("PNEU_WING_ANTI_ICE_1_CONSUMER_PRESSURE"));
         assert!(test_bed.contains_variable_with_name("PNEU_WING_ANTI_ICE_2_CONSUMER_PRESSURE"));
@@ -5214,4 +5407,229 @@ pub mod tests {
             assert!(test_bed.pack_flow_valve_flow(2) < flow_rate_tolerance());
         }
     }
+
+    #[test]
+    fn no_bleed_leak_detected_without_failure() {
+        let mut test_bed = test_bed_with()
+            .toga_eng1()
+            .toga_eng2()
+            .both_packs_auto()
+            .and_stabilize();
+
+        test_bed.run_with_delta(Duration::from_secs(30));
+
+        assert!(!test_bed.engine_bleed_leak(1));
+        assert!(!test_bed.engine_bleed_leak(2));
+        assert!(!test_bed.wing_leak(1));
+        assert!(!test_bed.wing_leak(2));
+        assert!(!test_bed.apu_bleed_leak());
+        assert!(test_bed.pr_valve_is_open(1));
+        assert!(test_bed.pr_valve_is_open(2));
+    }
+
+    #[test]
+    fn pylon_leak_is_detected_and_isolates_engine_bleed() {
+        let mut test_bed = test_bed_with()
+            .toga_eng1()
+            .toga_eng2()
+            .both_packs_auto()
+            .and_stabilize();
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirDuctZone::Pylon(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(test_bed.engine_bleed_leak(1));
+        assert!(!test_bed.engine_bleed_leak(2));
+        assert!(!test_bed.pr_valve_is_open(1));
+        assert!(!test_bed.hp_valve_is_open(1));
+        assert!(test_bed.pr_valve_is_open(2));
+        assert!(!test_bed.cross_bleed_valve_is_open());
+        assert!(test_bed.engine_bleed_pb_has_fault(1));
+        assert!(!test_bed.engine_bleed_pb_has_fault(2));
+    }
+
+    #[test]
+    fn wing_leak_is_detected_and_isolates_engine_and_cross_bleed() {
+        let mut test_bed = test_bed_with()
+            .toga_eng1()
+            .toga_eng2()
+            .both_packs_auto()
+            .and_stabilize();
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirDuctZone::Wing(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(test_bed.wing_leak(1));
+        assert!(!test_bed.wing_leak(2));
+        assert!(!test_bed.pr_valve_is_open(1));
+        assert!(test_bed.pr_valve_is_open(2));
+        assert!(!test_bed.cross_bleed_valve_is_open());
+        assert!(test_bed.engine_bleed_pb_has_fault(1));
+    }
+
+    #[test]
+    fn apu_duct_leak_is_detected_and_isolates_apu_bleed() {
+        let mut test_bed = test_bed_with()
+            .stop_eng1()
+            .stop_eng2()
+            .set_bleed_air_running()
+            .both_packs_auto()
+            .and_stabilize();
+
+        assert!(test_bed.cross_bleed_valve_is_open());
+        assert!(!test_bed.apu_bleed_leak_isolation());
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirDuctZone::ApuDuct));
+        test_bed.run_multiple_frames(Duration::from_secs(20));
+
+        assert!(test_bed.apu_bleed_leak());
+        assert!(test_bed.apu_bleed_leak_isolation());
+        assert!(!test_bed.cross_bleed_valve_is_open());
+    }
+
+    #[test]
+    fn leak_isolation_is_reset_by_engine_bleed_pb_off() {
+        let mut test_bed = test_bed_with()
+            .toga_eng1()
+            .toga_eng2()
+            .both_packs_auto()
+            .and_stabilize();
+
+        test_bed.fail(FailureType::BleedAirDuctLeak(BleedAirDuctZone::Pylon(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+        test_bed.unfail(FailureType::BleedAirDuctLeak(BleedAirDuctZone::Pylon(1)));
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        assert!(!test_bed.engine_bleed_leak(1));
+        assert!(!test_bed.pr_valve_is_open(1));
+
+        test_bed = test_bed
+            .set_engine_bleed_push_button_off(1)
+            .and_run()
+            .set_engine_bleed_push_button_auto(1)
+            .and_stabilize();
+
+        assert!(test_bed.pr_valve_is_open(1));
+        assert!(!test_bed.engine_bleed_pb_has_fault(1));
+    }
+
+    fn hot_day_takeoff_test_bed(ambient_temperature_celsius: f64) -> PneumaticTestBed {
+        test_bed_with()
+            .in_isa_atmosphere(Length::new::<foot>(0.))
+            .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(
+                ambient_temperature_celsius,
+            ))
+            .toga_eng1()
+            .toga_eng2()
+            .both_packs_auto()
+            .and_stabilize()
+    }
+
+    #[test]
+    fn bleed_temperature_rises_on_hot_day_at_takeoff_thrust() {
+        let mut test_bed = hot_day_takeoff_test_bed(15.);
+        test_bed.run_with_delta(Duration::from_secs(60));
+        let standard_day_temperature = test_bed.bleed_temperature_sensor_temperature(1).unwrap();
+
+        let mut test_bed = hot_day_takeoff_test_bed(45.);
+        test_bed.run_with_delta(Duration::from_secs(60));
+        let hot_day_temperature = test_bed.bleed_temperature_sensor_temperature(1).unwrap();
+
+        assert!(hot_day_temperature > standard_day_temperature);
+        assert_about_eq!(test_bed.fan_air_valve_open_amount(1).get::<ratio>(), 1.);
+    }
+
+    #[test]
+    fn no_bleed_overheat_on_hot_day_at_takeoff_thrust() {
+        let mut test_bed = hot_day_takeoff_test_bed(45.);
+
+        for _ in 0..1200 {
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            assert!(!test_bed.engine_bleed_has_overheat(1));
+            assert!(!test_bed.engine_bleed_has_overheat(2));
+        }
+    }
+
+    #[test]
+    fn fan_air_valve_stuck_closed_causes_bleed_overheat_on_hot_day_at_takeoff_thrust() {
+        let mut test_bed = hot_day_takeoff_test_bed(45.);
+
+        test_bed.fail(FailureType::FanAirValveStuckClosed(1));
+
+        let mut has_overheated = false;
+        for _ in 0..1200 {
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            assert_eq!(test_bed.fan_air_valve_open_amount(1), Ratio::default());
+            assert!(!test_bed.engine_bleed_has_overheat(2));
+
+            if test_bed.engine_bleed_has_overheat(1) {
+                has_overheated = true;
+
+                assert!(!test_bed.pr_valve_is_open(1));
+            }
+        }
+
+        assert!(has_overheated);
+    }
+
+    #[test]
+    fn bleed_overheat_stays_latched_until_engine_bleed_pb_is_selected_off() {
+        let mut test_bed = hot_day_takeoff_test_bed(45.);
+        test_bed.fail(FailureType::FanAirValveStuckClosed(1));
+        test_bed.run_with_delta(Duration::from_secs(120));
+
+        assert!(test_bed.engine_bleed_has_overheat(1));
+
+        test_bed.unfail(FailureType::FanAirValveStuckClosed(1));
+        test_bed.run_with_delta(Duration::from_secs(120));
+
+        assert!(test_bed.engine_bleed_has_overheat(1));
+        assert!(!test_bed.pr_valve_is_open(1));
+
+        test_bed = test_bed
+            .set_engine_bleed_push_button_off(1)
+            .and_run()
+            .set_engine_bleed_push_button_auto(1)
+            .and_run();
+
+        assert!(!test_bed.engine_bleed_has_overheat(1));
+    }
+
+    #[test]
+    fn fouled_precooler_increases_bleed_temperature() {
+        let mut test_bed = hot_day_takeoff_test_bed(45.);
+        test_bed.fail(FailureType::PrecoolerFouling(1));
+        test_bed.run_with_delta(Duration::from_secs(60));
+
+        assert!(
+            test_bed.bleed_temperature_sensor_temperature(1).unwrap()
+                > test_bed.bleed_temperature_sensor_temperature(2).unwrap()
+                    + TemperatureInterval::new::<temperature_interval::degree_celsius>(20.)
+        );
+    }
+
+    #[test]
+    fn fan_air_valve_stuck_open_overcools_bleed_air() {
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .idle_eng2()
+            .both_packs_auto()
+            .and_stabilize();
+
+        assert!(test_bed.fan_air_valve_open_amount(1) < Ratio::new::<ratio>(0.5));
+
+        test_bed.fail(FailureType::FanAirValveStuckOpen(1));
+        test_bed.run_with_delta(Duration::from_secs(60));
+
+        assert_eq!(
+            test_bed.fan_air_valve_open_amount(1),
+            Ratio::new::<ratio>(1.)
+        );
+        assert!(
+            test_bed.bleed_temperature_sensor_temperature(1).unwrap()
+                < test_bed.bleed_temperature_sensor_temperature(2).unwrap()
+        );
+    }
 }
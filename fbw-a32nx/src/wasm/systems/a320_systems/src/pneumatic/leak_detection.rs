@@ -0,0 +1,285 @@
+use std::time::Duration;
+
+use uom::si::{
+    f64::*, mass_rate::kilogram_per_second, pressure::psi,
+    thermodynamic_temperature::degree_celsius,
+};
+
+use systems::{
+    accept_iterable,
+    failures::{Failure, FailureType},
+    pneumatic::{valve::PneumaticExhaust, PneumaticContainer},
+    shared::{
+        low_pass_filter::LowPassFilter, BleedAirDuctZone, ElectricalBusType, ElectricalBuses,
+        LatchedTrueLogicGate,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use super::A320PneumaticOverheadPanel;
+
+/// A hot air duct running through one of the zones monitored by the leak detection loops.
+/// When the duct fails, bleed air escapes into the surrounding zone.
+pub struct BleedAirDuct {
+    zone: BleedAirDuctZone,
+    leak_failure: Failure,
+    leak: PneumaticExhaust,
+    leak_flow: MassRate,
+    air_temperature: ThermodynamicTemperature,
+}
+impl BleedAirDuct {
+    const LEAK_EXHAUST_SPEED: f64 = 0.05;
+
+    pub fn new(zone: BleedAirDuctZone) -> Self {
+        Self {
+            zone,
+            leak_failure: Failure::new(FailureType::BleedAirDuctLeak(zone)),
+            leak: PneumaticExhaust::new(
+                Self::LEAK_EXHAUST_SPEED,
+                Self::LEAK_EXHAUST_SPEED,
+                Pressure::new::<psi>(0.),
+            ),
+            leak_flow: MassRate::default(),
+            air_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+        }
+    }
+
+    pub fn update_move_fluid(
+        &mut self,
+        context: &UpdateContext,
+        duct: &mut impl PneumaticContainer,
+    ) {
+        self.air_temperature = duct.temperature();
+
+        if self.leak_failure.is_active() {
+            self.leak.update_move_fluid(context, duct);
+            self.leak_flow = self.leak.fluid_flow();
+        } else {
+            self.leak_flow = MassRate::default();
+        }
+    }
+
+    /// Called instead of `update_move_fluid` when the duct is cut from its supply, like the
+    /// APU duct downstream of a closed APU bleed valve.
+    pub fn update_isolated(&mut self) {
+        self.leak_flow = MassRate::default();
+    }
+
+    pub fn zone(&self) -> BleedAirDuctZone {
+        self.zone
+    }
+
+    pub fn leak_flow(&self) -> MassRate {
+        self.leak_flow
+    }
+
+    fn air_temperature(&self) -> ThermodynamicTemperature {
+        self.air_temperature
+    }
+}
+impl SimulationElement for BleedAirDuct {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.leak_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Sensing element routed along the ducts of one or more zones. The loop reacts to the
+/// temperature of the air surrounding the ducts, which rises when hot air leaks into the zone.
+struct LeakDetectionLoop {
+    zones: Vec<BleedAirDuctZone>,
+    zone_temperature_rise: LowPassFilter<f64>,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    leak_detected: bool,
+}
+impl LeakDetectionLoop {
+    const ZONE_TEMPERATURE_TIME_CONSTANT: Duration = Duration::from_secs(5);
+
+    // Leak flow above which the zone is considered saturated with bleed air
+    const ZONE_SATURATION_LEAK_FLOW_KG_PER_S: f64 = 0.05;
+
+    // The aircraft loops alarm at 124°C (wing, APU) and 204°C (pylon). As the simulated bleed air
+    // stays colder than the real one, notably at idle, a single lower threshold is used.
+    const ALARM_TEMPERATURE_CELSIUS: f64 = 100.;
+
+    fn new(zones: Vec<BleedAirDuctZone>, powered_by: ElectricalBusType) -> Self {
+        Self {
+            zones,
+            zone_temperature_rise: LowPassFilter::new(Self::ZONE_TEMPERATURE_TIME_CONSTANT),
+            powered_by,
+            is_powered: false,
+            leak_detected: false,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, ducts: &[&BleedAirDuct]) {
+        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
+
+        let target_temperature_rise = ducts
+            .iter()
+            .filter(|duct| self.zones.contains(&duct.zone()))
+            .map(|duct| {
+                let saturation = (duct.leak_flow().get::<kilogram_per_second>()
+                    / Self::ZONE_SATURATION_LEAK_FLOW_KG_PER_S)
+                    .clamp(0., 1.);
+
+                (duct.air_temperature().get::<degree_celsius>() - ambient_temperature).max(0.)
+                    * saturation
+            })
+            .fold(0., f64::max);
+
+        let zone_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            ambient_temperature
+                + self
+                    .zone_temperature_rise
+                    .update(context.delta(), target_temperature_rise),
+        );
+
+        self.leak_detected = self.is_powered
+            && zone_temperature
+                > ThermodynamicTemperature::new::<degree_celsius>(Self::ALARM_TEMPERATURE_CELSIUS);
+    }
+
+    fn leak_detected(&self) -> bool {
+        self.leak_detected
+    }
+}
+impl SimulationElement for LeakDetectionLoop {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// Leak detection system (LDS) made of the loops along the hot air ducts. The loops are
+/// connected to the bleed monitoring computers, which isolate the leaking duct:
+/// - pylon or wing leak: the onside HP and PR valves and the crossbleed valve close,
+/// - left wing (including the pack bay crossbleed duct) or APU duct leak: the APU bleed valve
+///   and the crossbleed valve close.
+///
+/// The isolation stays latched until the related BLEED pushbutton is selected OFF.
+pub struct LeakDetectionSystem {
+    engine_bleed_leak_id: [VariableIdentifier; 2],
+    wing_leak_id: [VariableIdentifier; 2],
+    apu_bleed_leak_id: VariableIdentifier,
+
+    pylon_loops: [LeakDetectionLoop; 2],
+    wing_loops: [LeakDetectionLoop; 2],
+    apu_loop: LeakDetectionLoop,
+
+    engine_bleed_isolation: [LatchedTrueLogicGate; 2],
+    apu_bleed_isolation: LatchedTrueLogicGate,
+}
+impl LeakDetectionSystem {
+    pub fn new(
+        context: &mut InitContext,
+        bleed_monitoring_computer_buses: [ElectricalBusType; 2],
+    ) -> Self {
+        let [bmc_1_bus, bmc_2_bus] = bleed_monitoring_computer_buses;
+
+        Self {
+            engine_bleed_leak_id: [1, 2]
+                .map(|number| context.get_identifier(format!("PNEU_ENG_{}_BLEED_LEAK", number))),
+            wing_leak_id: [1, 2]
+                .map(|number| context.get_identifier(format!("PNEU_WING_{}_LEAK", number))),
+            apu_bleed_leak_id: context.get_identifier("PNEU_APU_BLEED_LEAK".to_owned()),
+
+            pylon_loops: [
+                LeakDetectionLoop::new(vec![BleedAirDuctZone::Pylon(1)], bmc_1_bus),
+                LeakDetectionLoop::new(vec![BleedAirDuctZone::Pylon(2)], bmc_2_bus),
+            ],
+            wing_loops: [
+                LeakDetectionLoop::new(
+                    vec![BleedAirDuctZone::Wing(1), BleedAirDuctZone::PackBay],
+                    bmc_1_bus,
+                ),
+                LeakDetectionLoop::new(vec![BleedAirDuctZone::Wing(2)], bmc_2_bus),
+            ],
+            apu_loop: LeakDetectionLoop::new(vec![BleedAirDuctZone::ApuDuct], bmc_1_bus),
+
+            engine_bleed_isolation: Default::default(),
+            apu_bleed_isolation: LatchedTrueLogicGate::default(),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        ducts: &[&BleedAirDuct],
+        overhead_panel: &A320PneumaticOverheadPanel,
+    ) {
+        self.pylon_loops
+            .iter_mut()
+            .chain(self.wing_loops.iter_mut())
+            .chain(std::iter::once(&mut self.apu_loop))
+            .for_each(|detection_loop| detection_loop.update(context, ducts));
+
+        for (index, isolation) in self.engine_bleed_isolation.iter_mut().enumerate() {
+            if overhead_panel.engine_bleed_pb_is_auto(index + 1) {
+                isolation.update(
+                    self.pylon_loops[index].leak_detected()
+                        || self.wing_loops[index].leak_detected(),
+                );
+            } else {
+                isolation.reset();
+            }
+        }
+
+        if overhead_panel.apu_bleed_is_on() {
+            self.apu_bleed_isolation
+                .update(self.apu_loop.leak_detected() || self.wing_loops[0].leak_detected());
+        } else {
+            self.apu_bleed_isolation.reset();
+        }
+    }
+
+    pub fn engine_bleed_leak(&self, engine_number: usize) -> bool {
+        self.pylon_loops[engine_number - 1].leak_detected()
+    }
+
+    pub fn wing_leak(&self, wing_number: usize) -> bool {
+        self.wing_loops[wing_number - 1].leak_detected()
+    }
+
+    pub fn apu_bleed_leak(&self) -> bool {
+        self.apu_loop.leak_detected()
+    }
+
+    /// The onside HP and PR valves are commanded closed
+    pub fn engine_bleed_is_isolated(&self, engine_number: usize) -> bool {
+        self.engine_bleed_isolation[engine_number - 1].output()
+    }
+
+    /// The ECB is requested to close the APU bleed valve
+    pub fn apu_bleed_is_isolated(&self) -> bool {
+        self.apu_bleed_isolation.output()
+    }
+
+    /// The crossbleed valve is commanded closed when in AUTO mode
+    pub fn cross_bleed_is_isolated(&self) -> bool {
+        self.engine_bleed_isolation.iter().any(|i| i.output()) || self.apu_bleed_is_isolated()
+    }
+}
+impl SimulationElement for LeakDetectionSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.pylon_loops, visitor);
+        accept_iterable!(self.wing_loops, visitor);
+        self.apu_loop.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (number, id) in [1, 2].iter().zip(&self.engine_bleed_leak_id) {
+            writer.write(id, self.engine_bleed_leak(*number));
+        }
+        for (number, id) in [1, 2].iter().zip(&self.wing_leak_id) {
+            writer.write(id, self.wing_leak(*number));
+        }
+        writer.write(&self.apu_bleed_leak_id, self.apu_bleed_leak());
+    }
+}
@@ -29,8 +29,9 @@ use systems::air_conditioning::{
 };
 use systems::failures::FailureType;
 use systems::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId,
-    HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedAirDuctZone, ElectricalBusType,
+    FlightControlSurface, GearActuatorId, HydraulicColor, HydraulicSectionId, LgciuId,
+    ProximityDetectorId,
 };
 use systems_wasm::aspects::ExecuteOn;
 use systems_wasm::{MsfsSimulationBuilder, Variable};
@@ -98,6 +99,25 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (21_017, FailureType::OutflowValveFault),
         (21_018, FailureType::SafetyValveFault),
         (21_019, FailureType::RapidDecompression),
+        (21_020, FailureType::DoorSealLeak(1)),
+        (21_021, FailureType::DoorSealLeak(2)),
+        (21_022, FailureType::DoorSealLeak(3)),
+        (21_023, FailureType::DoorSealLeak(4)),
+        (21_024, FailureType::DoorSealLeak(5)),
+        (21_025, FailureType::DoorSealLeak(6)),
+        (21_026, FailureType::DoorSealLeak(7)),
+        (
+            21_027,
+            FailureType::ProgressiveHullBreach(ZoneType::Cockpit),
+        ),
+        (
+            21_028,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(1)),
+        ),
+        (
+            21_029,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(2)),
+        ),
         (24_000, FailureType::TransformerRectifier(1)),
         (24_001, FailureType::TransformerRectifier(2)),
         (24_002, FailureType::TransformerRectifier(3)),
@@ -161,6 +181,90 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             24_113,
             FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
         ),
+        (
+            27_100,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_101,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_102,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_103,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_104,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_105,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_106,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_107,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_108,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_109,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Blue,
+            ),
+        ),
+        (
+            27_110,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (28_000, FailureType::FuelTankLeak(0)),
+        (28_001, FailureType::FuelTankLeak(1)),
+        (28_002, FailureType::FuelTankLeak(2)),
+        (28_003, FailureType::FuelTankLeak(3)),
+        (28_004, FailureType::FuelTankLeak(4)),
+        (28_010, FailureType::FuelEngineFeedLeak(1)),
+        (28_011, FailureType::FuelEngineFeedLeak(2)),
         (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
         (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
         (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
@@ -198,6 +302,30 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             29_012,
             FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
         ),
+        (
+            29_013,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(1)),
+        ),
+        (
+            29_014,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Blue, HydraulicSectionId::Pump(1)),
+        ),
+        (
+            29_015,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(1)),
+        ),
+        (
+            29_016,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::System),
+        ),
+        (
+            29_017,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Blue, HydraulicSectionId::System),
+        ),
+        (
+            29_018,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::System),
+        ),
         (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
         (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
         (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
@@ -274,6 +402,30 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             32_025,
             FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
         ),
+        (
+            32_030,
+            FailureType::GearActuatorLeak(GearActuatorId::GearNose),
+        ),
+        (
+            32_031,
+            FailureType::GearActuatorLeak(GearActuatorId::GearLeft),
+        ),
+        (
+            32_032,
+            FailureType::GearActuatorLeak(GearActuatorId::GearRight),
+        ),
+        (
+            32_033,
+            FailureType::GearActuatorLeak(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_034,
+            FailureType::GearActuatorLeak(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_035,
+            FailureType::GearActuatorLeak(GearActuatorId::GearDoorRight),
+        ),
         (
             32_100,
             FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
@@ -289,6 +441,64 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (34_011, FailureType::RadioAntennaInterrupted(2)),
         (34_020, FailureType::RadioAntennaDirectCoupling(1)),
         (34_021, FailureType::RadioAntennaDirectCoupling(2)),
+        (34_100, FailureType::AirDataReference(1)),
+        (34_101, FailureType::AirDataReference(2)),
+        (34_102, FailureType::AirDataReference(3)),
+        (34_110, FailureType::AirDataReferenceFault(1)),
+        (34_111, FailureType::AirDataReferenceFault(2)),
+        (34_112, FailureType::AirDataReferenceFault(3)),
+        (34_120, FailureType::InertialReference(1)),
+        (34_121, FailureType::InertialReference(2)),
+        (34_122, FailureType::InertialReference(3)),
+        (34_130, FailureType::InertialReferenceAttitudeOnly(1)),
+        (34_131, FailureType::InertialReferenceAttitudeOnly(2)),
+        (34_132, FailureType::InertialReferenceAttitudeOnly(3)),
+        (34_140, FailureType::InertialReferenceAlignmentFault(1)),
+        (34_141, FailureType::InertialReferenceAlignmentFault(2)),
+        (34_142, FailureType::InertialReferenceAlignmentFault(3)),
+        (
+            36_000,
+            FailureType::BleedAirDuctLeak(BleedAirDuctZone::Pylon(1)),
+        ),
+        (
+            36_001,
+            FailureType::BleedAirDuctLeak(BleedAirDuctZone::Pylon(2)),
+        ),
+        (
+            36_010,
+            FailureType::BleedAirDuctLeak(BleedAirDuctZone::Wing(1)),
+        ),
+        (
+            36_011,
+            FailureType::BleedAirDuctLeak(BleedAirDuctZone::Wing(2)),
+        ),
+        (
+            36_020,
+            FailureType::BleedAirDuctLeak(BleedAirDuctZone::ApuDuct),
+        ),
+        (
+            36_030,
+            FailureType::BleedAirDuctLeak(BleedAirDuctZone::PackBay),
+        ),
+        (36_100, FailureType::FanAirValveStuckClosed(1)),
+        (36_101, FailureType::FanAirValveStuckClosed(2)),
+        (36_110, FailureType::FanAirValveStuckOpen(1)),
+        (36_111, FailureType::FanAirValveStuckOpen(2)),
+        (36_120, FailureType::PrecoolerFouling(1)),
+        (36_121, FailureType::PrecoolerFouling(2)),
+        (49_000, FailureType::ApuOilLeak),
+        (49_001, FailureType::ApuIgnitionFault),
+        (49_002, FailureType::ApuEgtMarginDegradation),
+        (72_000, FailureType::EngineFanBladeDamage(1)),
+        (72_001, FailureType::EngineFanBladeDamage(2)),
+        (72_010, FailureType::EngineBirdStrike(1)),
+        (72_011, FailureType::EngineBirdStrike(2)),
+        (79_000, FailureType::EngineOilLowQuantity(1)),
+        (79_001, FailureType::EngineOilLowQuantity(2)),
+        (79_010, FailureType::EngineOilFilterClog(1)),
+        (79_011, FailureType::EngineOilFilterClog(2)),
+        (79_020, FailureType::EngineOilPressureTransmitter(1)),
+        (79_021, FailureType::EngineOilPressureTransmitter(2)),
     ])
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
@@ -316,8 +526,9 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("FUEL TANK RIGHT MAIN QUANTITY", "gallons", 0)?
     .provides_aircraft_variable("FUEL TANK RIGHT AUX QUANTITY", "gallons", 0)?
     .provides_aircraft_variable("FUEL TOTAL QUANTITY WEIGHT", "Pounds", 0)?
-    .provides_aircraft_variable("FUELSYSTEM LINE FUEL FLOW", "gallons per hour", 18)?
     .provides_aircraft_variable_range("FUELSYSTEM PUMP ACTIVE", "Bool", 1..=7)?
+    .provides_aircraft_variable_range("FUELSYSTEM PUMP SWITCH", "Bool", [2, 3, 5, 6])?
+    .provides_aircraft_variable_range("FUELSYSTEM VALVE SWITCH", "Bool", [3, 9, 10])?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 0)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 1)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 2)?
@@ -382,6 +593,10 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 8)?
     .provides_named_variable("FSDT_GSX_BOARDING_STATE")?
     .provides_named_variable("FSDT_GSX_DEBOARDING_STATE")?
+    .provides_named_variable("FSDT_GSX_CATERING_STATE")?
+    .provides_named_variable("FSDT_GSX_REFUELING_STATE")?
+    .provides_named_variable("FSDT_GSX_GPU_STATE")?
+    .provides_named_variable("FSDT_GSX_DEPARTURE_STATE")?
     .provides_named_variable("FSDT_GSX_NUMPASSENGERS_BOARDING_TOTAL")?
     .provides_named_variable("FSDT_GSX_NUMPASSENGERS_DEBOARDING_TOTAL")?
     .provides_named_variable("FSDT_GSX_BOARDING_CARGO_PERCENT")?
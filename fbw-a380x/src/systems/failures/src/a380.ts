@@ -54,6 +54,43 @@ export const A380Failure = Object.freeze({
   CpcsApp2: 21047,
   CpcsApp3: 21048,
   CpcsApp4: 21049,
+  DoorSealLeak1: 21050,
+  DoorSealLeak2: 21051,
+  DoorSealLeak3: 21052,
+  DoorSealLeak4: 21053,
+  DoorSealLeak5: 21054,
+  DoorSealLeak6: 21055,
+  DoorSealLeak7: 21056,
+  DoorSealLeak8: 21057,
+  DoorSealLeak9: 21058,
+  DoorSealLeak10: 21059,
+  DoorSealLeak11: 21060,
+  DoorSealLeak12: 21061,
+  DoorSealLeak13: 21062,
+  DoorSealLeak14: 21063,
+  DoorSealLeak15: 21064,
+  DoorSealLeak16: 21065,
+  DoorSealLeak17: 21066,
+  DoorSealLeak18: 21067,
+  DoorSealLeak19: 21068,
+  CockpitHullBreach: 21069,
+  MainDeck1CabinHullBreach: 21070,
+  MainDeck2CabinHullBreach: 21071,
+  MainDeck3CabinHullBreach: 21072,
+  MainDeck4CabinHullBreach: 21073,
+  MainDeck5CabinHullBreach: 21074,
+  MainDeck6CabinHullBreach: 21075,
+  MainDeck7CabinHullBreach: 21076,
+  MainDeck8CabinHullBreach: 21077,
+  UpperDeck1CabinHullBreach: 21078,
+  UpperDeck2CabinHullBreach: 21079,
+  UpperDeck3CabinHullBreach: 21080,
+  UpperDeck4CabinHullBreach: 21081,
+  UpperDeck5CabinHullBreach: 21082,
+  UpperDeck6CabinHullBreach: 21083,
+  UpperDeck7CabinHullBreach: 21084,
+  FwdCargoHullBreach: 21085,
+  BulkCargoHullBreach: 21086,
 
   FmcA: 22100,
   FmcB: 22101,
@@ -125,6 +162,32 @@ export const A380Failure = Object.freeze({
   Sec3: 27005,
   Fcdc1: 27006,
   Fcdc2: 27007,
+  LeftAileronGreenActuatorLeak: 27100,
+  LeftAileronYellowActuatorLeak: 27101,
+  RightAileronGreenActuatorLeak: 27102,
+  RightAileronYellowActuatorLeak: 27103,
+  LeftElevatorGreenActuatorLeak: 27104,
+  RightElevatorYellowActuatorLeak: 27105,
+  RudderGreenActuatorLeak: 27106,
+  RudderYellowActuatorLeak: 27107,
+
+  FuelAutoCgControlFault: 28000,
+  FuelTrimTankTransferFault: 28001,
+  LeftOuterTankLeak: 28010,
+  FeedTank1Leak: 28011,
+  LeftMidTankLeak: 28012,
+  LeftInnerTankLeak: 28013,
+  FeedTank2Leak: 28014,
+  FeedTank3Leak: 28015,
+  RightInnerTankLeak: 28016,
+  RightMidTankLeak: 28017,
+  FeedTank4Leak: 28018,
+  RightOuterTankLeak: 28019,
+  TrimTankLeak: 28020,
+  Engine1FeedLineLeak: 28030,
+  Engine2FeedLineLeak: 28031,
+  Engine3FeedLineLeak: 28032,
+  Engine4FeedLineLeak: 28033,
 
   GreenReservoirLeak: 29000,
   YellowReservoirLeak: 29001,
@@ -144,6 +207,20 @@ export const A380Failure = Object.freeze({
   EnginePump3BOHeat: 29015,
   EnginePump4AOHeat: 29016,
   EnginePump4BOHeat: 29017,
+  GreenSystemSectionLeak: 29018,
+  YellowSystemSectionLeak: 29019,
+  EnginePump1ASectionLeak: 29020,
+  EnginePump1BSectionLeak: 29021,
+  EnginePump2ASectionLeak: 29022,
+  EnginePump2BSectionLeak: 29023,
+  GreenElecPumpASectionLeak: 29024,
+  GreenElecPumpBSectionLeak: 29025,
+  EnginePump3ASectionLeak: 29026,
+  EnginePump3BSectionLeak: 29027,
+  EnginePump4ASectionLeak: 29028,
+  EnginePump4BSectionLeak: 29029,
+  YellowElecPumpASectionLeak: 29030,
+  YellowElecPumpBSectionLeak: 29031,
 
   Fws1: 31100,
   Fws2: 31101,
@@ -161,6 +238,13 @@ export const A380Failure = Object.freeze({
   GearProxSensorDamageGearUplockNose1: 32006,
   GearProxSensorDamageDoorUplockLeft2: 32007,
 
+  GearActuatorLeakGearNose: 32030,
+  GearActuatorLeakGearLeft: 32031,
+  GearActuatorLeakGearRight: 32032,
+  GearActuatorLeakGearDoorNose: 32033,
+  GearActuatorLeakGearDoorLeft: 32034,
+  GearActuatorLeakGearDoorRight: 32035,
+
   RadioAltimeter1: 34000,
   RadioAltimeter2: 34001,
   RadioAltimeter3: 34002,
@@ -170,6 +254,21 @@ export const A380Failure = Object.freeze({
   RadioAntennaDirectCoupling1: 34020,
   RadioAntennaDirectCoupling2: 34021,
   RadioAntennaDirectCoupling3: 34022,
+  AirDataReference1: 34100,
+  AirDataReference2: 34101,
+  AirDataReference3: 34102,
+  AirDataReferenceFault1: 34110,
+  AirDataReferenceFault2: 34111,
+  AirDataReferenceFault3: 34112,
+  InertialReference1: 34120,
+  InertialReference2: 34121,
+  InertialReference3: 34122,
+  InertialReferenceAttitudeOnly1: 34130,
+  InertialReferenceAttitudeOnly2: 34131,
+  InertialReferenceAttitudeOnly3: 34132,
+  InertialReferenceAlignmentFault1: 34140,
+  InertialReferenceAlignmentFault2: 34141,
+  InertialReferenceAlignmentFault3: 34142,
 
   Transponder1: 34003,
   Transponder2: 34004,
@@ -186,6 +285,32 @@ export const A380Failure = Object.freeze({
   FirstOfficerLaptop: 46005,
   CaptainOit: 46006,
   FirstOfficerOit: 46007,
+
+  ApuOilLeak: 49000,
+  ApuIgnitionFault: 49001,
+  ApuEgtMarginDegradation: 49002,
+
+  Engine1FanBladeDamage: 72000,
+  Engine2FanBladeDamage: 72001,
+  Engine3FanBladeDamage: 72002,
+  Engine4FanBladeDamage: 72003,
+  Engine1BirdStrike: 72010,
+  Engine2BirdStrike: 72011,
+  Engine3BirdStrike: 72012,
+  Engine4BirdStrike: 72013,
+
+  Engine1OilLowQuantity: 79000,
+  Engine2OilLowQuantity: 79001,
+  Engine3OilLowQuantity: 79002,
+  Engine4OilLowQuantity: 79003,
+  Engine1OilFilterClog: 79010,
+  Engine2OilFilterClog: 79011,
+  Engine3OilFilterClog: 79012,
+  Engine4OilFilterClog: 79013,
+  Engine1OilPressureTransmitter: 79020,
+  Engine2OilPressureTransmitter: 79021,
+  Engine3OilPressureTransmitter: 79022,
+  Engine4OilPressureTransmitter: 79023,
 });
 
 export const A380FailureDefinitions: FailureDefinition[] = [
@@ -239,6 +364,43 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [21, A380Failure.CpcsApp2, 'CPCS Application in CPIOM B2'],
   [21, A380Failure.CpcsApp3, 'CPCS Application in CPIOM B3'],
   [21, A380Failure.CpcsApp4, 'CPCS Application in CPIOM B4'],
+  [21, A380Failure.DoorSealLeak1, 'Door Seal Leak - Door 1'],
+  [21, A380Failure.DoorSealLeak2, 'Door Seal Leak - Door 2'],
+  [21, A380Failure.DoorSealLeak3, 'Door Seal Leak - Door 3'],
+  [21, A380Failure.DoorSealLeak4, 'Door Seal Leak - Door 4'],
+  [21, A380Failure.DoorSealLeak5, 'Door Seal Leak - Door 5'],
+  [21, A380Failure.DoorSealLeak6, 'Door Seal Leak - Door 6'],
+  [21, A380Failure.DoorSealLeak7, 'Door Seal Leak - Door 7'],
+  [21, A380Failure.DoorSealLeak8, 'Door Seal Leak - Door 8'],
+  [21, A380Failure.DoorSealLeak9, 'Door Seal Leak - Door 9'],
+  [21, A380Failure.DoorSealLeak10, 'Door Seal Leak - Door 10'],
+  [21, A380Failure.DoorSealLeak11, 'Door Seal Leak - Door 11'],
+  [21, A380Failure.DoorSealLeak12, 'Door Seal Leak - Door 12'],
+  [21, A380Failure.DoorSealLeak13, 'Door Seal Leak - Door 13'],
+  [21, A380Failure.DoorSealLeak14, 'Door Seal Leak - Door 14'],
+  [21, A380Failure.DoorSealLeak15, 'Door Seal Leak - Door 15'],
+  [21, A380Failure.DoorSealLeak16, 'Door Seal Leak - Door 16'],
+  [21, A380Failure.DoorSealLeak17, 'Door Seal Leak - Door 17'],
+  [21, A380Failure.DoorSealLeak18, 'Door Seal Leak - Door 18'],
+  [21, A380Failure.DoorSealLeak19, 'Door Seal Leak - Door 19'],
+  [21, A380Failure.CockpitHullBreach, 'Progressive Hull Breach - Cockpit'],
+  [21, A380Failure.MainDeck1CabinHullBreach, 'Progressive Hull Breach - Main Deck 1'],
+  [21, A380Failure.MainDeck2CabinHullBreach, 'Progressive Hull Breach - Main Deck 2'],
+  [21, A380Failure.MainDeck3CabinHullBreach, 'Progressive Hull Breach - Main Deck 3'],
+  [21, A380Failure.MainDeck4CabinHullBreach, 'Progressive Hull Breach - Main Deck 4'],
+  [21, A380Failure.MainDeck5CabinHullBreach, 'Progressive Hull Breach - Main Deck 5'],
+  [21, A380Failure.MainDeck6CabinHullBreach, 'Progressive Hull Breach - Main Deck 6'],
+  [21, A380Failure.MainDeck7CabinHullBreach, 'Progressive Hull Breach - Main Deck 7'],
+  [21, A380Failure.MainDeck8CabinHullBreach, 'Progressive Hull Breach - Main Deck 8'],
+  [21, A380Failure.UpperDeck1CabinHullBreach, 'Progressive Hull Breach - Upper Deck 1'],
+  [21, A380Failure.UpperDeck2CabinHullBreach, 'Progressive Hull Breach - Upper Deck 2'],
+  [21, A380Failure.UpperDeck3CabinHullBreach, 'Progressive Hull Breach - Upper Deck 3'],
+  [21, A380Failure.UpperDeck4CabinHullBreach, 'Progressive Hull Breach - Upper Deck 4'],
+  [21, A380Failure.UpperDeck5CabinHullBreach, 'Progressive Hull Breach - Upper Deck 5'],
+  [21, A380Failure.UpperDeck6CabinHullBreach, 'Progressive Hull Breach - Upper Deck 6'],
+  [21, A380Failure.UpperDeck7CabinHullBreach, 'Progressive Hull Breach - Upper Deck 7'],
+  [21, A380Failure.FwdCargoHullBreach, 'Progressive Hull Breach - Forward Cargo'],
+  [21, A380Failure.BulkCargoHullBreach, 'Progressive Hull Breach - Bulk Cargo'],
 
   [22, A380Failure.FmcA, 'FMC-A'],
   [22, A380Failure.FmcB, 'FMC-B'],
@@ -310,6 +472,32 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [27, A380Failure.Sec3, 'SEC 3'],
   [27, A380Failure.Fcdc1, 'FCDC 1'],
   [27, A380Failure.Fcdc2, 'FCDC 2'],
+  [27, A380Failure.LeftAileronGreenActuatorLeak, 'Left aileron green actuators leak'],
+  [27, A380Failure.LeftAileronYellowActuatorLeak, 'Left aileron yellow actuators leak'],
+  [27, A380Failure.RightAileronGreenActuatorLeak, 'Right aileron green actuators leak'],
+  [27, A380Failure.RightAileronYellowActuatorLeak, 'Right aileron yellow actuators leak'],
+  [27, A380Failure.LeftElevatorGreenActuatorLeak, 'Left elevator green actuators leak'],
+  [27, A380Failure.RightElevatorYellowActuatorLeak, 'Right elevator yellow actuators leak'],
+  [27, A380Failure.RudderGreenActuatorLeak, 'Rudder green actuators leak'],
+  [27, A380Failure.RudderYellowActuatorLeak, 'Rudder yellow actuators leak'],
+
+  [28, A380Failure.FuelAutoCgControlFault, 'Fuel auto CG control fault'],
+  [28, A380Failure.FuelTrimTankTransferFault, 'Trim tank transfer fault'],
+  [28, A380Failure.LeftOuterTankLeak, 'Left outer tank leak'],
+  [28, A380Failure.FeedTank1Leak, 'Feed tank 1 leak'],
+  [28, A380Failure.LeftMidTankLeak, 'Left mid tank leak'],
+  [28, A380Failure.LeftInnerTankLeak, 'Left inner tank leak'],
+  [28, A380Failure.FeedTank2Leak, 'Feed tank 2 leak'],
+  [28, A380Failure.FeedTank3Leak, 'Feed tank 3 leak'],
+  [28, A380Failure.RightInnerTankLeak, 'Right inner tank leak'],
+  [28, A380Failure.RightMidTankLeak, 'Right mid tank leak'],
+  [28, A380Failure.FeedTank4Leak, 'Feed tank 4 leak'],
+  [28, A380Failure.RightOuterTankLeak, 'Right outer tank leak'],
+  [28, A380Failure.TrimTankLeak, 'Trim tank leak'],
+  [28, A380Failure.Engine1FeedLineLeak, 'Engine 1 feed line leak'],
+  [28, A380Failure.Engine2FeedLineLeak, 'Engine 2 feed line leak'],
+  [28, A380Failure.Engine3FeedLineLeak, 'Engine 3 feed line leak'],
+  [28, A380Failure.Engine4FeedLineLeak, 'Engine 4 feed line leak'],
 
   [29, A380Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A380Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
@@ -329,6 +517,20 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [29, A380Failure.EnginePump3BOHeat, 'Engine 3 pump B overheat'],
   [29, A380Failure.EnginePump4AOHeat, 'Engine 4 pump A overheat'],
   [29, A380Failure.EnginePump4BOHeat, 'Engine 4 pump B overheat'],
+  [29, A380Failure.GreenSystemSectionLeak, 'Green system section leak'],
+  [29, A380Failure.YellowSystemSectionLeak, 'Yellow system section leak'],
+  [29, A380Failure.EnginePump1ASectionLeak, 'Engine 1 pump A section leak'],
+  [29, A380Failure.EnginePump1BSectionLeak, 'Engine 1 pump B section leak'],
+  [29, A380Failure.EnginePump2ASectionLeak, 'Engine 2 pump A section leak'],
+  [29, A380Failure.EnginePump2BSectionLeak, 'Engine 2 pump B section leak'],
+  [29, A380Failure.GreenElecPumpASectionLeak, 'Green A elec pump section leak'],
+  [29, A380Failure.GreenElecPumpBSectionLeak, 'Green B elec pump section leak'],
+  [29, A380Failure.EnginePump3ASectionLeak, 'Engine 3 pump A section leak'],
+  [29, A380Failure.EnginePump3BSectionLeak, 'Engine 3 pump B section leak'],
+  [29, A380Failure.EnginePump4ASectionLeak, 'Engine 4 pump A section leak'],
+  [29, A380Failure.EnginePump4BSectionLeak, 'Engine 4 pump B section leak'],
+  [29, A380Failure.YellowElecPumpASectionLeak, 'Yellow A elec pump section leak'],
+  [29, A380Failure.YellowElecPumpBSectionLeak, 'Yellow B elec pump section leak'],
 
   [31, A380Failure.Fws1, 'FWS 1'],
   [31, A380Failure.Fws2, 'FWS 2'],
@@ -343,6 +545,13 @@ export const A380FailureDefinitions: FailureDefinition[] = [
 
   [32, A380Failure.GearProxSensorDamageGearUplockNose1, 'Proximity sensor damage uplock nose gear #1'],
 
+  [32, A380Failure.GearActuatorLeakGearNose, 'Nose gear actuator leak'],
+  [32, A380Failure.GearActuatorLeakGearLeft, 'Main left gear actuator leak'],
+  [32, A380Failure.GearActuatorLeakGearRight, 'Main right gear actuator leak'],
+  [32, A380Failure.GearActuatorLeakGearDoorNose, 'Nose gear door actuator leak'],
+  [32, A380Failure.GearActuatorLeakGearDoorLeft, 'Main left gear door actuator leak'],
+  [32, A380Failure.GearActuatorLeakGearDoorRight, 'Main right gear door actuator leak'],
+
   [34, A380Failure.RadioAltimeter1, 'RA SYS A'],
   [34, A380Failure.RadioAltimeter2, 'RA SYS B'],
   [34, A380Failure.RadioAltimeter3, 'RA SYS C'],
@@ -352,6 +561,21 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [34, A380Failure.RadioAntennaDirectCoupling1, 'RA SYS A Direct Coupling'],
   [34, A380Failure.RadioAntennaDirectCoupling2, 'RA SYS B Direct Coupling'],
   [34, A380Failure.RadioAntennaDirectCoupling3, 'RA SYS C Direct Coupling'],
+  [34, A380Failure.AirDataReference1, 'ADR 1'],
+  [34, A380Failure.AirDataReference2, 'ADR 2'],
+  [34, A380Failure.AirDataReference3, 'ADR 3'],
+  [34, A380Failure.AirDataReferenceFault1, 'ADR 1 Fault'],
+  [34, A380Failure.AirDataReferenceFault2, 'ADR 2 Fault'],
+  [34, A380Failure.AirDataReferenceFault3, 'ADR 3 Fault'],
+  [34, A380Failure.InertialReference1, 'IR 1'],
+  [34, A380Failure.InertialReference2, 'IR 2'],
+  [34, A380Failure.InertialReference3, 'IR 3'],
+  [34, A380Failure.InertialReferenceAttitudeOnly1, 'IR 1 Attitude Only'],
+  [34, A380Failure.InertialReferenceAttitudeOnly2, 'IR 2 Attitude Only'],
+  [34, A380Failure.InertialReferenceAttitudeOnly3, 'IR 3 Attitude Only'],
+  [34, A380Failure.InertialReferenceAlignmentFault1, 'IR 1 Alignment Fault'],
+  [34, A380Failure.InertialReferenceAlignmentFault2, 'IR 2 Alignment Fault'],
+  [34, A380Failure.InertialReferenceAlignmentFault3, 'IR 3 Alignment Fault'],
   [34, A380Failure.Terr1, 'TERR 1'],
   [34, A380Failure.Terr2, 'TERR 2'],
   [34, A380Failure.Gpws1, 'GPWS 1'],
@@ -366,4 +590,30 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [46, A380Failure.FirstOfficerLaptop, 'F/O Laptop'],
   [46, A380Failure.CaptainOit, 'Captain OIT'],
   [46, A380Failure.FirstOfficerOit, 'F/O OIT'],
+
+  [49, A380Failure.ApuOilLeak, 'APU oil leak'],
+  [49, A380Failure.ApuIgnitionFault, 'APU ignition fault'],
+  [49, A380Failure.ApuEgtMarginDegradation, 'APU EGT margin degradation'],
+
+  [72, A380Failure.Engine1FanBladeDamage, 'Engine 1 fan blade damage'],
+  [72, A380Failure.Engine2FanBladeDamage, 'Engine 2 fan blade damage'],
+  [72, A380Failure.Engine3FanBladeDamage, 'Engine 3 fan blade damage'],
+  [72, A380Failure.Engine4FanBladeDamage, 'Engine 4 fan blade damage'],
+  [72, A380Failure.Engine1BirdStrike, 'Engine 1 bird strike'],
+  [72, A380Failure.Engine2BirdStrike, 'Engine 2 bird strike'],
+  [72, A380Failure.Engine3BirdStrike, 'Engine 3 bird strike'],
+  [72, A380Failure.Engine4BirdStrike, 'Engine 4 bird strike'],
+
+  [79, A380Failure.Engine1OilLowQuantity, 'Engine 1 oil low quantity'],
+  [79, A380Failure.Engine2OilLowQuantity, 'Engine 2 oil low quantity'],
+  [79, A380Failure.Engine3OilLowQuantity, 'Engine 3 oil low quantity'],
+  [79, A380Failure.Engine4OilLowQuantity, 'Engine 4 oil low quantity'],
+  [79, A380Failure.Engine1OilFilterClog, 'Engine 1 oil filter clog'],
+  [79, A380Failure.Engine2OilFilterClog, 'Engine 2 oil filter clog'],
+  [79, A380Failure.Engine3OilFilterClog, 'Engine 3 oil filter clog'],
+  [79, A380Failure.Engine4OilFilterClog, 'Engine 4 oil filter clog'],
+  [79, A380Failure.Engine1OilPressureTransmitter, 'Engine 1 oil pressure transmitter'],
+  [79, A380Failure.Engine2OilPressureTransmitter, 'Engine 2 oil pressure transmitter'],
+  [79, A380Failure.Engine3OilPressureTransmitter, 'Engine 3 oil pressure transmitter'],
+  [79, A380Failure.Engine4OilPressureTransmitter, 'Engine 4 oil pressure transmitter'],
 ];
@@ -35,10 +35,8 @@ const EngineColumn: FC<Position & EngineNumber & IgnitionActive & EngineColumnPr
   const n2Vibration = n1Vibration;
   const n3Vibration = n1Vibration;
 
-  const [oilQuantity] = useSimVar(`L:A32NX_ENGINE_OIL_QTY:${engine}`, 'number', 500); // TODO: Update with correct SimVars
-  const [engineOilTemperature] = useSimVar(`GENERAL ENG OIL TEMPERATURE:${engine}`, 'celsius', 100); // TODO: Update with correct SimVars
-  // fbw-a380x\src\wasm\fadec_a380x\src\Fadec\EngineControl_A380X.cpp
-  // has all the engine oil simvars in a large section commented out
+  const [oilQuantity] = useSimVar(`L:A32NX_ENGINE_OIL_QTY:${engine}`, 'number', 500);
+  const [engineOilTemperature] = useSimVar(`L:A32NX_ENGINE_OIL_TEMPERATURE:${engine}`, 'celsius', 100);
 
   return (
     <>
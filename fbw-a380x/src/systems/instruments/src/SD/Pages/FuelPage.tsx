@@ -8,7 +8,6 @@ import { useInterval } from '@flybywiresim/fbw-sdk';
 export const FuelPage = () => {
   const CROSS_FEED_VALVE_CLOSED_THRESHOLD = 0.1;
   const TRANSFER_VALVE_CLOSED_THRESHOLD = 0.1;
-  const JETTISON_VALVE_CLOSED_THRESHOLD = 0.1;
   const FEED_TANK_LOW_LEVEL_THRESHOLD_KG = 1375;
 
   const [showMore] = useState(false);
@@ -146,12 +145,23 @@ export const FuelPage = () => {
     galleryAuxRefuelValveLeftOpen >= TRANSFER_VALVE_CLOSED_THRESHOLD ||
     galleryAuxRefuelValveRightOpen >= TRANSFER_VALVE_CLOSED_THRESHOLD;
 
+  // Transfers commanded by the FQMS
+  const [isFeedTank1FwdTransferActive] = useSimVar('L:A32NX_FUEL_FEED_TANK_1_FWD_TRANSFER', 'bool', 1000);
+  const [isFeedTank2FwdTransferActive] = useSimVar('L:A32NX_FUEL_FEED_TANK_2_FWD_TRANSFER', 'bool', 1000);
+  const [isFeedTank3FwdTransferActive] = useSimVar('L:A32NX_FUEL_FEED_TANK_3_FWD_TRANSFER', 'bool', 1000);
+  const [isFeedTank4FwdTransferActive] = useSimVar('L:A32NX_FUEL_FEED_TANK_4_FWD_TRANSFER', 'bool', 1000);
+  const [isLeftOuterTankTransferActive] = useSimVar('L:A32NX_FUEL_LEFT_OUTER_TANK_TRANSFER', 'bool', 1000);
+  const [isRightOuterTankTransferActive] = useSimVar('L:A32NX_FUEL_RIGHT_OUTER_TANK_TRANSFER', 'bool', 1000);
+  const [isTrimTankAftTransferActive] = useSimVar('L:A32NX_FUEL_TRIM_TANK_AFT_TRANSFER', 'bool', 1000);
+  const [isTrimTankFwdTransferActive] = useSimVar('L:A32NX_FUEL_TRIM_TANK_FWD_TRANSFER', 'bool', 1000);
+
   // Into tank transfer valves
   //  FWD
   //      Feed tanks
   const [feedTank1FwdTransferValve1Open] = useSimVar('FUELSYSTEM VALVE OPEN:5', 'Percent over 100', 1000);
   const [feedTank1FwdTransferValve2Open] = useSimVar('FUELSYSTEM VALVE OPEN:11', 'Percent over 100', 1000);
   const isAnyFeedTank1FwdTransferValveOpen =
+    isFeedTank1FwdTransferActive ||
     feedTank1FwdTransferValve1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD ||
     feedTank1FwdTransferValve2Open >= TRANSFER_VALVE_CLOSED_THRESHOLD;
 
@@ -160,6 +170,7 @@ export const FuelPage = () => {
   const [feedTank2FwdTransferValve2_1Open] = useSimVar('FUELSYSTEM VALVE OPEN:12', 'Percent over 100', 1000);
   const [feedTank2FwdTransferValve2_2Open] = useSimVar('FUELSYSTEM VALVE OPEN:14', 'Percent over 100', 1000);
   const isAnyFeedTank2FwdTransferValveOpen =
+    isFeedTank2FwdTransferActive ||
     feedTank2FwdTransferValve1_1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD ||
     feedTank2FwdTransferValve1_2Open >= TRANSFER_VALVE_CLOSED_THRESHOLD ||
     feedTank2FwdTransferValve2_1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD ||
@@ -170,6 +181,7 @@ export const FuelPage = () => {
   const [feedTank3FwdTransferValve2_1Open] = useSimVar('FUELSYSTEM VALVE OPEN:13', 'Percent over 100', 1000);
   const [feedTank3FwdTransferValve2_2Open] = useSimVar('FUELSYSTEM VALVE OPEN:15', 'Percent over 100', 1000);
   const isAnyFeedTank3FwdTransferValveOpen =
+    isFeedTank3FwdTransferActive ||
     feedTank3FwdTransferValve1_1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD ||
     feedTank3FwdTransferValve1_2Open >= TRANSFER_VALVE_CLOSED_THRESHOLD ||
     feedTank3FwdTransferValve2_1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD ||
@@ -178,6 +190,7 @@ export const FuelPage = () => {
   const [feedTank4FwdTransferValve1Open] = useSimVar('FUELSYSTEM VALVE OPEN:10', 'Percent over 100', 1000);
   const [feedTank4FwdTransferValve2Open] = useSimVar('FUELSYSTEM VALVE OPEN:16', 'Percent over 100', 1000);
   const isAnyFeedTank4FwdTransferValveOpen =
+    isFeedTank4FwdTransferActive ||
     feedTank4FwdTransferValve1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD ||
     feedTank4FwdTransferValve2Open >= TRANSFER_VALVE_CLOSED_THRESHOLD;
 
@@ -194,26 +207,30 @@ export const FuelPage = () => {
   const [feedTank1AftTransferValve1Open] = useSimVar('FUELSYSTEM VALVE OPEN:23', 'Percent over 100', 1000);
   const [feedTank1AftTransferValve2Open] = useSimVar('FUELSYSTEM VALVE OPEN:27', 'Percent over 100', 1000);
   const areBothFeedTank1AftTransferValvesOpen =
-    feedTank1AftTransferValve1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD &&
-    feedTank1AftTransferValve2Open >= TRANSFER_VALVE_CLOSED_THRESHOLD;
+    isTrimTankFwdTransferActive ||
+    (feedTank1AftTransferValve1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD &&
+      feedTank1AftTransferValve2Open >= TRANSFER_VALVE_CLOSED_THRESHOLD);
 
   const [feedTank2AftTransferValve1Open] = useSimVar('FUELSYSTEM VALVE OPEN:24', 'Percent over 100', 1000);
   const [feedTank2AftTransferValve2Open] = useSimVar('FUELSYSTEM VALVE OPEN:28', 'Percent over 100', 1000);
   const areBothFeedTank2AftTransferValvesOpen =
-    feedTank2AftTransferValve1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD &&
-    feedTank2AftTransferValve2Open >= TRANSFER_VALVE_CLOSED_THRESHOLD;
+    isTrimTankFwdTransferActive ||
+    (feedTank2AftTransferValve1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD &&
+      feedTank2AftTransferValve2Open >= TRANSFER_VALVE_CLOSED_THRESHOLD);
 
   const [feedTank3AftTransferValve1Open] = useSimVar('FUELSYSTEM VALVE OPEN:25', 'Percent over 100', 1000);
   const [feedTank3AftTransferValve2Open] = useSimVar('FUELSYSTEM VALVE OPEN:29', 'Percent over 100', 1000);
   const areBothFeedTank3AftTransferValvesOpen =
-    feedTank3AftTransferValve1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD &&
-    feedTank3AftTransferValve2Open >= TRANSFER_VALVE_CLOSED_THRESHOLD;
+    isTrimTankFwdTransferActive ||
+    (feedTank3AftTransferValve1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD &&
+      feedTank3AftTransferValve2Open >= TRANSFER_VALVE_CLOSED_THRESHOLD);
 
   const [feedTank4AftTransferValve1Open] = useSimVar('FUELSYSTEM VALVE OPEN:26', 'Percent over 100', 1000);
   const [feedTank4AftTransferValve2Open] = useSimVar('FUELSYSTEM VALVE OPEN:30', 'Percent over 100', 1000);
   const areBothFeedTank4AftTransferValvesOpen =
-    feedTank4AftTransferValve1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD &&
-    feedTank4AftTransferValve2Open >= TRANSFER_VALVE_CLOSED_THRESHOLD;
+    isTrimTankFwdTransferActive ||
+    (feedTank4AftTransferValve1Open >= TRANSFER_VALVE_CLOSED_THRESHOLD &&
+      feedTank4AftTransferValve2Open >= TRANSFER_VALVE_CLOSED_THRESHOLD);
 
   //    Transfer tanks
   const [leftOuterAftTransferValve1Open] = useSimVar('FUELSYSTEM VALVE OPEN:33', 'Percent over 100', 1000);
@@ -268,7 +285,11 @@ export const FuelPage = () => {
     trimTankInletValve1Open < TRANSFER_VALVE_CLOSED_THRESHOLD &&
     trimTankInletValve2Open < TRANSFER_VALVE_CLOSED_THRESHOLD;
 
-  const isTrimLineIsolated = areTrimLineIsolationValvesClosed && areTrimTankInletValvesClosed;
+  const isTrimLineIsolated =
+    !isTrimTankAftTransferActive &&
+    !isTrimTankFwdTransferActive &&
+    areTrimLineIsolationValvesClosed &&
+    areTrimTankInletValvesClosed;
 
   const fwdGalleryPumps: PumpProps[] = [
     // Pump.9
@@ -334,7 +355,7 @@ export const FuelPage = () => {
     {
       x: 274,
       y: 452,
-      running: isLeftInnerTankPumpAftActive,
+      running: isLeftInnerTankPumpAftActive || isTrimTankAftTransferActive,
       hasFault: isLeftInnerTankPumpAftSwitchOff,
       displayWhenInactive: showMore,
     },
@@ -342,7 +363,7 @@ export const FuelPage = () => {
     {
       x: 524,
       y: 452,
-      running: isRightInnerTankPumpAftActive,
+      running: isRightInnerTankPumpAftActive || isTrimTankAftTransferActive,
       hasFault: isRightInnerTankPumpAftSwitchOff,
       displayWhenInactive: showMore,
     },
@@ -373,7 +394,7 @@ export const FuelPage = () => {
       y1: 382,
       x2: 54,
       y2: 362,
-      active: leftOuterFwdTransferValveOpen >= TRANSFER_VALVE_CLOSED_THRESHOLD,
+      active: isLeftOuterTankTransferActive || leftOuterFwdTransferValveOpen >= TRANSFER_VALVE_CLOSED_THRESHOLD,
       startArrow: 'in',
       displayWhenInactive: showMore,
     },
@@ -423,7 +444,7 @@ export const FuelPage = () => {
       y1: 346,
       x2: 284,
       y2: 366,
-      active: leftInnerFwdTransferValveOpen >= TRANSFER_VALVE_CLOSED_THRESHOLD,
+      active: isLeftOuterTankTransferActive || leftInnerFwdTransferValveOpen >= TRANSFER_VALVE_CLOSED_THRESHOLD,
       endArrow: 'out',
       displayWhenInactive: showMore,
     },
@@ -443,7 +464,7 @@ export const FuelPage = () => {
       y1: 346,
       x2: 520,
       y2: 366,
-      active: rightInnerFwdTransferValveOpen >= TRANSFER_VALVE_CLOSED_THRESHOLD,
+      active: isRightOuterTankTransferActive || rightInnerFwdTransferValveOpen >= TRANSFER_VALVE_CLOSED_THRESHOLD,
       endArrow: 'out',
       displayWhenInactive: showMore,
     },
@@ -483,7 +504,7 @@ export const FuelPage = () => {
       y1: 382,
       x2: 712,
       y2: 362,
-      active: rightOuterFwdTransferValveOpen >= TRANSFER_VALVE_CLOSED_THRESHOLD,
+      active: isRightOuterTankTransferActive || rightOuterFwdTransferValveOpen >= TRANSFER_VALVE_CLOSED_THRESHOLD,
       startArrow: 'in',
       displayWhenInactive: showMore,
     },
@@ -701,11 +722,9 @@ export const FuelPage = () => {
   ];
 
   // Jettison valves
-  const [leftJettisonValveOpen] = useSimVar('FUELSYSTEM VALVE OPEN:57', 'Percent over 100', 1000);
-  const isLeftJettisonValveOpen = leftJettisonValveOpen >= JETTISON_VALVE_CLOSED_THRESHOLD;
-  const [rightJettisonValveOpen] = useSimVar('FUELSYSTEM VALVE OPEN:58', 'Percent over 100', 1000);
-  const isRightJettisonValveOpen = rightJettisonValveOpen >= JETTISON_VALVE_CLOSED_THRESHOLD;
-  const isJettisonActive = false; // TODO
+  const [isLeftJettisonValveOpen] = useSimVar('L:A32NX_FUEL_JETTISON_VALVE_1_OPEN', 'Bool', 1000);
+  const [isRightJettisonValveOpen] = useSimVar('L:A32NX_FUEL_JETTISON_VALVE_2_OPEN', 'Bool', 1000);
+  const [isJettisonActive] = useSimVar('L:A32NX_FUEL_JETTISON_ACTIVE', 'Bool', 1000);
 
   // Collector cells
   const collectorCell1Weight = 1200;
@@ -1238,7 +1257,7 @@ export const FuelPage = () => {
         <Pump
           x={298}
           y={610}
-          running={isLeftTrimTankPumpActive}
+          running={isLeftTrimTankPumpActive || isTrimTankFwdTransferActive}
           hasFault={isLeftTrimTankPumpSwitchOff}
           displayWhenInactive={showMore}
         />
@@ -1246,7 +1265,7 @@ export const FuelPage = () => {
         <Pump
           x={468}
           y={610}
-          running={isRightTrimTankPumpActive}
+          running={isRightTrimTankPumpActive || isTrimTankFwdTransferActive}
           hasFault={isRightTrimTankPumpSwitchOff}
           displayWhenInactive={showMore}
         />
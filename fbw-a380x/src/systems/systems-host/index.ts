@@ -165,7 +165,7 @@ class SystemsHost extends BaseInstrument {
   );
 
   //FIXME add some deltatime functionality to backplane instruments so we dont have to pass SystemHost
-  private readonly legacyFuel = new LegacyFuel(this.bus, this);
+  private readonly legacyFuel = new LegacyFuel(this.bus);
 
   // For now, pass ATSU to the ANSUs. In our target architecture, there should be no ATSU
   private readonly nssAnsu1 = new AircraftNetworkServerUnit(this.bus, 1, 'nss', this.failuresConsumer, this.atsu);
@@ -146,12 +146,7 @@ void EngineControl_A380X::initializeEngineControlData() {
   prevSimEngineN3[2] = simData.simVarsDataPtr->data().simEngineN2[2];
   prevSimEngineN3[3] = simData.simVarsDataPtr->data().simEngineN2[3];
 
-  // Setting initial Oil Quantity and adding some randomness to it
-  std::srand(std::time(0));
-  simData.engineOilTotal[E1]->set((std::rand() % (MAX_OIL - MIN_OIL + 1) + MIN_OIL) / 10.0);
-  simData.engineOilTotal[E2]->set((std::rand() % (MAX_OIL - MIN_OIL + 1) + MIN_OIL) / 10.0);
-  simData.engineOilTotal[E3]->set((std::rand() % (MAX_OIL - MIN_OIL + 1) + MIN_OIL) / 10.0);
-  simData.engineOilTotal[E4]->set((std::rand() % (MAX_OIL - MIN_OIL + 1) + MIN_OIL) / 10.0);
+  // Oil quantities (A32NX_ENGINE_OIL_QTY/TOTAL) are owned by the systems oil model
 
   // Setting initial Oil Temperature
   const bool simOnGround = msfsHandlerPtr->getSimOnGround();
@@ -1008,10 +1003,6 @@ void EngineControl_A380X::updateOil(int          engine,
   const int engineIdx         = engine - 1;
   double    steadyTemperature = simData.engineEgt[engineIdx]->get();
 
-  double oilQtyActual   = simData.engineOil[engineIdx]->get();
-  double oilTotalActual = simData.engineOilTotal[engineIdx]->get();
-  double oilQtyObjective;
-  double oilBurn;
   double oilPressureIdle;
   double oilPressure;
   double oilTemperature;
@@ -1031,18 +1022,7 @@ void EngineControl_A380X::updateOil(int          engine,
     oilTemperature = Polynomial_A380X::oilTemperature(thermalEnergy[engineIdx], oilTemperaturePre, MAX_OIL_TEMP, deltaTime);
   }
 
-  //--------------------------------------------
-  // Oil Quantity
-  //--------------------------------------------
-  // Calculating Oil Qty as a function of thrust
-  double thrust   = simData.simVarsDataPtr->data().simEngineThrust[engineIdx] * FORCE_LB_TO_N;
-  oilQtyObjective = oilTotalActual * (1 - Polynomial_A380X::oilGulpPct(thrust));
-  oilQtyActual    = oilQtyObjective;
-
-  // Oil burnt taken into account for tank and total oil
-  oilBurn        = (0.00011111 * deltaTime);
-  oilQtyActual   = oilQtyActual - oilBurn;
-  oilTotalActual = oilTotalActual - oilBurn;
+  // Oil quantities are computed by the systems oil model
 
   //--------------------------------------------
   // Oil Pressure
@@ -1057,8 +1037,6 @@ void EngineControl_A380X::updateOil(int          engine,
   //--------------------------------------------
   simData.oilTempDataPtr[engineIdx]->data().oilTemp = oilTemperature;
   simData.oilTempDataPtr[engineIdx]->writeDataToSim();
-  simData.engineOil[engineIdx]->set(oilQtyActual);
-  simData.engineOilTotal[engineIdx]->set(oilTotalActual);
   simData.oilPsiDataPtr[engineIdx]->data().oilPsi = oilPressure;
   simData.oilPsiDataPtr[engineIdx]->writeDataToSim();
 }
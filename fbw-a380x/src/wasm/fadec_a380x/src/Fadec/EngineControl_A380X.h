@@ -63,10 +63,7 @@ class EngineControl_A380X {
   double thermalEnergy[4] = {0.0, 0.0, 0.0, 0.0};
 
   // additional constants
-  static constexpr int    MAX_OIL             = 200;
-  static constexpr int    MIN_OIL             = 170;
   static constexpr int    MAX_OIL_TEMP        = 85;
-  static constexpr double FORCE_LB_TO_N       = 4.4482216153;
   static constexpr double FUEL_RATE_THRESHOLD = 661;  // lbs/sec for determining fuel ui tampering
 
   /**
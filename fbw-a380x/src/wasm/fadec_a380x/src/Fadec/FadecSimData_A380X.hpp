@@ -213,8 +213,6 @@ class FadecSimData_A380X {
   NamedVariablePtr engineN1[4];   // Percent
   NamedVariablePtr engineN2[4];   // Percent
   NamedVariablePtr engineN3[4];   // Percent
-  NamedVariablePtr engineOilTotal[4];
-  NamedVariablePtr engineOil[4];
   NamedVariablePtr enginePreFF[4];  // kg/hour
   NamedVariablePtr engineState[4];
   NamedVariablePtr engineTimer[4];
@@ -381,16 +379,6 @@ class FadecSimData_A380X {
     engineFuelUsed[E3] = dm->make_named_var("A32NX_FUEL_USED:3", UNITS.Number, AUTO_READ_WRITE);
     engineFuelUsed[E4] = dm->make_named_var("A32NX_FUEL_USED:4", UNITS.Number, AUTO_READ_WRITE);
 
-    engineOil[E1] = dm->make_named_var("A32NX_ENGINE_OIL_QTY:1", UNITS.Number, AUTO_READ_WRITE);
-    engineOil[E2] = dm->make_named_var("A32NX_ENGINE_OIL_QTY:2", UNITS.Number, AUTO_READ_WRITE);
-    engineOil[E3] = dm->make_named_var("A32NX_ENGINE_OIL_QTY:3", UNITS.Number, AUTO_READ_WRITE);
-    engineOil[E4] = dm->make_named_var("A32NX_ENGINE_OIL_QTY:4", UNITS.Number, AUTO_READ_WRITE);
-
-    engineOilTotal[E1] = dm->make_named_var("A32NX_ENGINE_OIL_TOTAL:1", UNITS.Number, AUTO_READ_WRITE);
-    engineOilTotal[E2] = dm->make_named_var("A32NX_ENGINE_OIL_TOTAL:2", UNITS.Number, AUTO_READ_WRITE);
-    engineOilTotal[E3] = dm->make_named_var("A32NX_ENGINE_OIL_TOTAL:3", UNITS.Number, AUTO_READ_WRITE);
-    engineOilTotal[E4] = dm->make_named_var("A32NX_ENGINE_OIL_TOTAL:4", UNITS.Number, AUTO_READ_WRITE);
-
     enginePreFF[E1] = dm->make_named_var("A32NX_ENGINE_PRE_FF:1", UNITS.Number, AUTO_READ_WRITE);
     enginePreFF[E2] = dm->make_named_var("A32NX_ENGINE_PRE_FF:2", UNITS.Number, AUTO_READ_WRITE);
     enginePreFF[E3] = dm->make_named_var("A32NX_ENGINE_PRE_FF:3", UNITS.Number, AUTO_READ_WRITE);
@@ -461,14 +449,6 @@ class FadecSimData_A380X {
     engineN2[E2]->setAndWriteToSim(0);
     engineN2[E3]->setAndWriteToSim(0);
     engineN2[E4]->setAndWriteToSim(0);
-    engineOilTotal[E1]->setAndWriteToSim(0);
-    engineOilTotal[E2]->setAndWriteToSim(0);
-    engineOilTotal[E3]->setAndWriteToSim(0);
-    engineOilTotal[E4]->setAndWriteToSim(0);
-    engineOil[E1]->setAndWriteToSim(0);
-    engineOil[E2]->setAndWriteToSim(0);
-    engineOil[E3]->setAndWriteToSim(0);
-    engineOil[E4]->setAndWriteToSim(0);
     enginePreFF[E1]->setAndWriteToSim(0);
     enginePreFF[E2]->setAndWriteToSim(0);
     enginePreFF[E3]->setAndWriteToSim(0);
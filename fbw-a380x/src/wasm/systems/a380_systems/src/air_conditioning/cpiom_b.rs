@@ -595,6 +595,8 @@ impl TemperatureControlSystemApplication {
         trim_air_drive_device: &impl TaddShared,
     ) {
         let tcs_is_active = !self.has_failed();
+        let cabin_co2_concentration = cabin_temperature.cabin_co2_concentration();
+        let cabin_relative_humidity = cabin_temperature.cabin_relative_humidity();
         for zone in self.zone_controllers.iter_mut() {
             // Acsc is irrelevant for the A380 so we set it to 1
             zone.update(
@@ -605,6 +607,7 @@ impl TemperatureControlSystemApplication {
                 cabin_temperature.cabin_temperature(),
                 pressurization,
             );
+            zone.update_air_quality(&cabin_co2_concentration, &cabin_relative_humidity);
         }
 
         self.hot_air_is_enabled = [
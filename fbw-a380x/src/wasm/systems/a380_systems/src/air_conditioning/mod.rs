@@ -6,8 +6,8 @@ use systems::{
         cabin_air::CabinAirSimulation,
         pressure_valve::{NegativeRelieveValveSignal, SafetyValve},
         AdirsToAirCondInterface, Air, AirConditioningOverheadShared, AirConditioningPack,
-        AirHeater, CabinFan, DuctTemperature, FdacId, MixerUnit, OcsmId, OutletAir,
-        OverheadFlowSelector, PackFlow, PackFlowControllers, PressurizationConstants,
+        AirHeater, CabinFan, CabinRecirculation, DuctTemperature, FdacId, MixerUnit, OcsmId,
+        OutletAir, OverheadFlowSelector, PackFlow, PackFlowControllers, PressurizationConstants,
         PressurizationOverheadShared, TrimAirSystem, VcmId, VcmShared, ZoneType,
     },
     integrated_modular_avionics::{
@@ -275,7 +275,11 @@ impl A380Cabin {
     fn update(
         &mut self,
         context: &UpdateContext,
-        air_conditioning_system: &(impl OutletAir + DuctTemperature + VcmShared),
+        air_conditioning_system: &(impl OutletAir
+              + DuctTemperature
+              + PackFlow
+              + CabinRecirculation
+              + VcmShared),
         lgciu: [&impl LgciuWeightOnWheels; 2],
         number_of_passengers: &impl NumberOfPassengers,
         pressurization: &A380PressurizationSystem,
@@ -351,6 +355,14 @@ impl CabinSimulation for A380Cabin {
     fn cabin_pressure(&self) -> Pressure {
         self.cabin_air_simulation.cabin_pressure()
     }
+
+    fn cabin_co2_concentration(&self) -> Vec<Ratio> {
+        self.cabin_air_simulation.cabin_co2_concentration()
+    }
+
+    fn cabin_relative_humidity(&self) -> Vec<Ratio> {
+        self.cabin_air_simulation.cabin_relative_humidity()
+    }
 }
 
 impl SimulationElement for A380Cabin {
@@ -732,6 +744,24 @@ impl DuctTemperature for A380AirConditioningSystem {
     }
 }
 
+impl PackFlow for A380AirConditioningSystem {
+    fn pack_flow(&self) -> MassRate {
+        self.packs
+            .iter()
+            .map(|pack| pack.outlet_air().flow_rate())
+            .sum()
+    }
+}
+
+impl CabinRecirculation for A380AirConditioningSystem {
+    fn recirculation_flow(&self) -> MassRate {
+        self.cabin_fans
+            .iter()
+            .map(|fan| fan.outlet_air().flow_rate())
+            .sum()
+    }
+}
+
 impl OutletAir for A380AirConditioningSystem {
     fn outlet_air(&self) -> Air {
         self.trim_air_system.outlet_air()
@@ -1110,6 +1140,7 @@ impl PressurizationConstants for A380PressurizationConstants {
     const SAFETY_VALVE_SIZE: f64 = 0.1; // m2
     const DOOR_OPENING_AREA: f64 = 1.5; // m2
     const HULL_BREACH_AREA: f64 = 0.2; // m2
+    const NUMBER_OF_DOORS: usize = 19; // 16 passenger doors and 3 cargo doors
 
     const MAX_CLIMB_RATE: f64 = 1000.; // fpm
     const MAX_CLIMB_RATE_IN_DESCENT: f64 = 500.; // fpm
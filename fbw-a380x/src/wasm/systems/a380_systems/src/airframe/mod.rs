@@ -1,5 +1,9 @@
 use systems::{
-    airframe::{CenterOfGravityData, WeightData},
+    airframe::{
+        CenterOfGravityData, CenterOfGravityEnvelope, CenterOfGravityEnvelopes,
+        CenterOfGravityLimits, Loadsheet, LoadsheetData, LoadsheetLayout, TakeOffTrimTable,
+        WeightData,
+    },
     fuel::FuelPayload,
     payload::{CargoPayload, LoadsheetInfo, PassengerPayload},
     simulation::{InitContext, SimulationElement, SimulationElementVisitor},
@@ -12,22 +16,77 @@ mod test;
 pub struct A380Airframe {
     center_of_gravity: CenterOfGravityData,
     weight: WeightData,
-    // trim_horizontal_stabiliser: f64,
+    loadsheet: LoadsheetData,
+    limits: CenterOfGravityLimits,
 }
 impl A380Airframe {
-    const LOADSHEET: LoadsheetInfo = LoadsheetInfo {
+    pub(crate) const LOADSHEET: LoadsheetInfo = LoadsheetInfo {
         operating_empty_weight_kg: 300007.12,
         operating_empty_position: (6.47, 0., 0.),
         per_pax_weight_kg: 84.,
         mean_aerodynamic_chord_size: 40.35,
         leading_edge_mean_aerodynamic_chord: 21.09,
+        index_constant: 20000.,
+        index_offset: 50.,
+    };
+
+    const LOADSHEET_LAYOUT: LoadsheetLayout<5> = LoadsheetLayout {
+        info: &Self::LOADSHEET,
+        passenger_sections: &[
+            ("MAIN FWD", &[0, 1]),
+            ("MAIN MID 1", &[2, 3, 4]),
+            ("MAIN MID 2", &[5, 6, 7]),
+            ("MAIN AFT", &[8, 9]),
+            ("UPPER FWD", &[10]),
+            ("UPPER MID", &[11, 12]),
+            ("UPPER AFT", &[13]),
+        ],
+        cargo_holds: &["FWD", "AFT", "BULK"],
+        fuel_tanks: &[
+            "LEFT OUTER",
+            "FEED 1",
+            "LEFT MID",
+            "LEFT INNER",
+            "FEED 2",
+            "FEED 3",
+            "RIGHT INNER",
+            "RIGHT MID",
+            "FEED 4",
+            "RIGHT OUTER",
+            "TRIM",
+        ],
+        take_off_trim: TakeOffTrimTable::new([29., 33., 37., 41., 43.], [4., 2.5, 1., -0.5, -1.2]),
+    };
+
+    const ENVELOPES: CenterOfGravityEnvelopes = CenterOfGravityEnvelopes {
+        zero_fuel: CenterOfGravityEnvelope::new(
+            &[300000., 340000., 373000.],
+            &[29., 29.5, 30.],
+            &[44., 44., 43.],
+        ),
+        take_off: CenterOfGravityEnvelope::new(
+            &[300000., 400000., 510000.],
+            &[29., 30., 33.],
+            &[43., 43., 41.],
+        ),
+        landing: CenterOfGravityEnvelope::new(
+            &[300000., 350000., 386000.],
+            &[29., 29.5, 30.],
+            &[44., 44., 43.],
+        ),
+        in_flight: CenterOfGravityEnvelope::new(
+            &[300000., 400000., 510000.],
+            &[28., 29., 31.],
+            &[44., 44., 42.5],
+        ),
     };
 
     pub fn new(context: &mut InitContext) -> Self {
         A380Airframe {
             center_of_gravity: CenterOfGravityData::new(context),
             weight: WeightData::new(context),
-            // trim_horizontal_stabiliser: 0.,
+            loadsheet: LoadsheetData::new(context),
+            limits: CenterOfGravityLimits::new(context),
         }
     }
 
@@ -65,9 +124,16 @@ impl A380Airframe {
         self.center_of_gravity.target_take_off_center_of_gravity()
     }
 
+    pub(crate) fn loadsheet(&self) -> &Loadsheet {
+        self.loadsheet.loadsheet()
+    }
+
+    pub(crate) fn is_target_within_limits(&self) -> bool {
+        self.limits.is_target_within_limits()
+    }
+
     fn convert_cg(cg: f64) -> f64 {
-        -100. * (cg - Self::LOADSHEET.leading_edge_mean_aerodynamic_chord)
-            / Self::LOADSHEET.mean_aerodynamic_chord_size
+        Self::LOADSHEET.percent_mac(cg)
     }
 
     fn set_zero_fuel_weight_center_of_gravity(&mut self, zero_fuel_weight_cg: f64) {
@@ -196,25 +262,49 @@ impl A380Airframe {
         self.set_target_gross_weight(target_gross_weight);
         self.set_target_gross_weight_center_of_gravity(target_gross_weight_cg);
 
-        // TODO: Implement Taxi Fuel Input/Calculation
+        let take_off_fuel = (fuel - self.loadsheet.taxi_fuel()).max(Mass::default());
+        let take_off_fuel_moment = take_off_fuel * fuel_payload.fore_aft_center_of_gravity();
 
-        let tow = gross_weight;
-        let to_cg = gross_weight_cg;
+        let tow = zero_fuel_weight + take_off_fuel;
+        let to_cg = (zero_fuel_weight_moment + take_off_fuel_moment).get::<kilogram>()
+            / tow.get::<kilogram>();
 
         self.set_take_off_weight(tow);
         self.set_take_off_center_of_gravity(to_cg);
 
-        let target_tow = target_gross_weight;
-        let target_to_cg = target_gross_weight_cg;
+        let target_tow = target_zero_fuel_weight + take_off_fuel;
+        let target_to_cg = (target_zero_fuel_weight_moment + take_off_fuel_moment)
+            .get::<kilogram>()
+            / target_tow.get::<kilogram>();
 
         self.set_target_take_off_weight(target_tow);
         self.set_target_take_off_center_of_gravity(target_to_cg);
+
+        self.loadsheet.update(
+            &Self::LOADSHEET_LAYOUT,
+            &self.weight,
+            &self.center_of_gravity,
+            fuel_payload,
+            pax_payload,
+            cargo_payload,
+        );
+
+        self.limits.update(
+            &Self::ENVELOPES,
+            &Self::LOADSHEET,
+            &self.weight,
+            &self.center_of_gravity,
+            &self.loadsheet,
+            fuel_payload,
+        );
     }
 }
 impl SimulationElement for A380Airframe {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.center_of_gravity.accept(visitor);
         self.weight.accept(visitor);
+        self.loadsheet.accept(visitor);
+        self.limits.accept(visitor);
 
         visitor.visit(self);
     }
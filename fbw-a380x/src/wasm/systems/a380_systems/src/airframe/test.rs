@@ -2,7 +2,7 @@ use super::*;
 use crate::{
     payload::A380Payload,
     systems::simulation::{
-        test::{SimulationTestBed, TestBed},
+        test::{SimulationTestBed, TestBed, WriteByName},
         Aircraft, SimulationElement, SimulationElementVisitor,
     },
 };
@@ -127,6 +127,14 @@ impl PassengerPayload for TestPayload {
     fn target_fore_aft_center_of_gravity(&self) -> f64 {
         self.passenger_target_center_of_gravity.x
     }
+
+    fn station_passengers(&self, _ps: usize) -> i8 {
+        0
+    }
+
+    fn station_passenger_load(&self, _ps: usize) -> Mass {
+        Mass::default()
+    }
 }
 
 impl CargoPayload for TestPayload {
@@ -153,6 +161,10 @@ impl CargoPayload for TestPayload {
     fn target_fore_aft_center_of_gravity(&self) -> f64 {
         self.cargo_target_center_of_gravity.x
     }
+
+    fn station_cargo_load(&self, _cs: usize) -> Mass {
+        Mass::default()
+    }
 }
 
 struct AirframeTestAircraft {
@@ -235,6 +247,10 @@ impl AirframeTestAircraft {
     fn set_fuel(&mut self, total_fuel_load: Mass, center_of_gravity: Vector3<f64>) {
         self.fuel.set_fuel(total_fuel_load, center_of_gravity);
     }
+
+    fn loadsheet(&self) -> Loadsheet {
+        self.airframe.loadsheet().clone()
+    }
 }
 impl Aircraft for AirframeTestAircraft {
     fn update_before_power_distribution(
@@ -435,6 +451,15 @@ impl AirframeTestBed {
     fn target_take_off_center_of_gravity(&self) -> f64 {
         self.query(|a| a.target_take_off_center_of_gravity())
     }
+
+    fn taxi_fuel(mut self, taxi_fuel: Mass) -> Self {
+        self.write_by_name("AIRFRAME_TAXI_FUEL", taxi_fuel.get::<kilogram>());
+        self
+    }
+
+    fn loadsheet(&self) -> Loadsheet {
+        self.query(|a| a.loadsheet())
+    }
 }
 impl TestBed for AirframeTestBed {
     type Aircraft = AirframeTestAircraft;
@@ -573,3 +598,30 @@ fn half_pax_cargo_target_full() {
     assert!(target_gross_weight_center_of_gravity > 39.);
     assert!(target_gross_weight_center_of_gravity < 40.);
 }
+
+#[test]
+fn taxi_fuel_is_not_part_of_the_take_off_weight() {
+    let test_bed = test_bed_with()
+        .load_full_pax()
+        .target_full_pax()
+        .load_full_cargo()
+        .target_full_cargo()
+        .load_high_fuel()
+        .taxi_fuel(Mass::new::<kilogram>(500.))
+        .and_run();
+
+    let loadsheet = test_bed.loadsheet();
+    let gross_weight =
+        loadsheet.zero_fuel_weight + Mass::new::<kilogram>(5600. * FUEL_GALLONS_TO_KG);
+
+    assert!(
+        (loadsheet.take_off_weight - (gross_weight - Mass::new::<kilogram>(500.)))
+            .get::<kilogram>()
+            .abs()
+            < 0.01
+    );
+    assert!(
+        (test_bed.take_off_center_of_gravity() - test_bed.gross_weight_center_of_gravity()).abs()
+            > 0.
+    );
+}
@@ -574,7 +574,7 @@ mod a380_electrical_circuit_tests {
         electrical::{
             ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
             Electricity, ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency,
-            ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+            ProvideLoad, ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
         },
         failures::FailureType,
         shared::{
@@ -3245,6 +3245,15 @@ mod a380_electrical_circuit_tests {
             self.is_available
         }
     }
+    impl ProvideLoad for TestApuGenerator {
+        fn load(&self) -> Ratio {
+            Ratio::default()
+        }
+
+        fn load_normal(&self) -> bool {
+            true
+        }
+    }
     impl ElectricitySource for TestApuGenerator {
         fn output_potential(&self) -> Potential {
             if self.is_available {
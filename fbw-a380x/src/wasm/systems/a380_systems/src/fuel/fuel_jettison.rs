@@ -0,0 +1,210 @@
+use systems::{
+    accept_iterable,
+    fuel::FuelSystem,
+    shared::{ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{f64::Mass, mass::kilogram};
+
+use super::{A380FuelOverheadPanel, A380FuelTankType};
+
+/// A jettison valve at the trailing edge of a wing. The valve motor holds its last position
+/// when it loses power.
+struct JettisonValve {
+    open_id: VariableIdentifier,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    is_open: bool,
+}
+impl JettisonValve {
+    fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            open_id: context.get_identifier(format!("FUEL_JETTISON_VALVE_{}_OPEN", number)),
+            powered_by,
+            is_powered: false,
+            is_open: false,
+        }
+    }
+
+    fn update(&mut self, should_open: bool) {
+        if self.is_powered {
+            self.is_open = should_open;
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+impl SimulationElement for JettisonValve {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.open_id, self.is_open);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// Dumps fuel overboard through the jettison valves while the JETTISON ARM and ACTIVE push
+/// buttons are both pressed in. Jettison stops by itself once the target gross weight is reached
+/// or every jettisonable tank is down to its minimum quantity, and doesn't restart until the
+/// ACTIVE push button is cycled.
+pub struct FuelJettisonApplication {
+    jettison_active_id: VariableIdentifier,
+    target_gross_weight_id: VariableIdentifier,
+    zero_fuel_weight_id: VariableIdentifier,
+
+    target_gross_weight: Mass,
+    zero_fuel_weight: Mass,
+
+    valves: [JettisonValve; 2],
+    is_jettisoning: bool,
+    auto_stopped: bool,
+}
+impl FuelJettisonApplication {
+    const RATE_PER_VALVE_KG_SEC: f64 = 20.8;
+    const MAX_LANDING_WEIGHT_KG: f64 = 386000.;
+    const FEED_TANK_MINIMUM_KG: f64 = 8000.;
+
+    // The trim tank goes first to keep the CG forward, the feed tanks last.
+    const JETTISON_SEQUENCE: [&'static [A380FuelTankType]; 4] = [
+        &[A380FuelTankType::Trim],
+        &[A380FuelTankType::LeftMid, A380FuelTankType::RightMid],
+        &[A380FuelTankType::LeftInner, A380FuelTankType::RightInner],
+        &[
+            A380FuelTankType::FeedOne,
+            A380FuelTankType::FeedTwo,
+            A380FuelTankType::FeedThree,
+            A380FuelTankType::FeedFour,
+        ],
+    ];
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            jettison_active_id: context.get_identifier("FUEL_JETTISON_ACTIVE".to_owned()),
+            target_gross_weight_id: context.get_identifier("FUEL_JETTISON_TARGET_GW".to_owned()),
+            zero_fuel_weight_id: context.get_identifier("AIRFRAME_ZFW".to_owned()),
+
+            target_gross_weight: Mass::default(),
+            zero_fuel_weight: Mass::default(),
+
+            valves: [
+                JettisonValve::new(context, 1, ElectricalBusType::DirectCurrentEssential),
+                JettisonValve::new(context, 2, ElectricalBusType::DirectCurrent(2)),
+            ],
+            is_jettisoning: false,
+            auto_stopped: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11, 20>,
+        overhead: &A380FuelOverheadPanel,
+    ) {
+        let is_selected = overhead.jettison_is_armed() && overhead.jettison_is_active();
+        if !is_selected {
+            self.auto_stopped = false;
+        }
+
+        let remaining_to_jettison = self.remaining_to_jettison(fuel_system);
+        if remaining_to_jettison <= Mass::default() {
+            self.auto_stopped = true;
+        }
+
+        let should_open = is_selected && !self.auto_stopped && !context.is_on_ground();
+        self.valves
+            .iter_mut()
+            .for_each(|valve| valve.update(should_open));
+
+        let open_valves = self.valves.iter().filter(|valve| valve.is_open()).count();
+        let max_quantity = Mass::new::<kilogram>(
+            Self::RATE_PER_VALVE_KG_SEC * open_valves as f64 * context.delta_as_secs_f64(),
+        )
+        .min(remaining_to_jettison);
+
+        let jettisoned = if max_quantity > Mass::default() {
+            Self::jettison(fuel_system, max_quantity)
+        } else {
+            Mass::default()
+        };
+        self.is_jettisoning = jettisoned > Mass::default();
+
+        if open_valves > 0 && !self.is_jettisoning {
+            self.auto_stopped = true;
+        }
+    }
+
+    fn remaining_to_jettison(&self, fuel_system: &FuelSystem<11, 20>) -> Mass {
+        let target_gross_weight = if self.target_gross_weight > Mass::default() {
+            self.target_gross_weight
+        } else {
+            Mass::new::<kilogram>(Self::MAX_LANDING_WEIGHT_KG)
+        };
+
+        self.zero_fuel_weight + fuel_system.fuel_on_board() - target_gross_weight
+    }
+
+    fn minimum_quantity(tank: A380FuelTankType) -> Mass {
+        match tank {
+            A380FuelTankType::FeedOne
+            | A380FuelTankType::FeedTwo
+            | A380FuelTankType::FeedThree
+            | A380FuelTankType::FeedFour => Mass::new::<kilogram>(Self::FEED_TANK_MINIMUM_KG),
+            _ => Mass::default(),
+        }
+    }
+
+    /// Removes up to `max_quantity` of fuel, evenly within each group of the jettison sequence
+    /// and only from tanks with a powered pump. Returns the quantity which was jettisoned.
+    fn jettison(fuel_system: &mut FuelSystem<11, 20>, max_quantity: Mass) -> Mass {
+        let mut remaining = max_quantity;
+
+        for group in Self::JETTISON_SEQUENCE {
+            while remaining > Mass::default() {
+                let tanks: Vec<A380FuelTankType> = group
+                    .iter()
+                    .copied()
+                    .filter(|tank| {
+                        tank.has_powered_pump(fuel_system)
+                            && fuel_system.tank_mass(*tank as usize) > Self::minimum_quantity(*tank)
+                    })
+                    .collect();
+                if tanks.is_empty() {
+                    break;
+                }
+
+                let share = remaining / tanks.len() as f64;
+                for tank in tanks {
+                    let quantity = fuel_system.tank_mass(tank as usize);
+                    let removed = (quantity - Self::minimum_quantity(tank)).min(share);
+                    fuel_system.set_tank_quantity(tank as usize, quantity - removed);
+                    remaining -= removed;
+                }
+            }
+        }
+
+        max_quantity - remaining
+    }
+}
+impl SimulationElement for FuelJettisonApplication {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.valves, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.target_gross_weight = Mass::new::<kilogram>(reader.read(&self.target_gross_weight_id));
+        self.zero_fuel_weight = Mass::new::<kilogram>(reader.read(&self.zero_fuel_weight_id));
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.jettison_active_id, self.is_jettisoning);
+    }
+}
@@ -0,0 +1,541 @@
+use systems::{
+    failures::{Failure, FailureType},
+    fuel::FuelSystem,
+    pneumatic::EngineState,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    f64::{Length, Mass},
+    length::foot,
+    mass::{kilogram, pound},
+};
+
+use super::A380FuelTankType;
+use crate::airframe::A380Airframe;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum TrimTankTransfer {
+    None,
+    Aft,
+    Forward,
+}
+
+/// The in-flight part of the FQMS. It controls the CG by moving fuel into and out of the trim
+/// tank, keeps the feed tanks supplied from the wing tanks as fuel burns and transfers the outer
+/// tanks inboard late in the flight.
+pub struct FuelManagementApplication {
+    trim_tank_aft_transfer_id: VariableIdentifier,
+    trim_tank_forward_transfer_id: VariableIdentifier,
+    auto_cg_control_fault_id: VariableIdentifier,
+    trim_tank_transfer_fault_id: VariableIdentifier,
+    target_center_of_gravity_id: VariableIdentifier,
+    feed_tank_transfer_ids: [VariableIdentifier; 4],
+    outer_tank_transfer_ids: [VariableIdentifier; 2],
+
+    cruise_altitude_id: VariableIdentifier,
+    zero_fuel_weight_id: VariableIdentifier,
+    zero_fuel_weight_center_of_gravity_id: VariableIdentifier,
+    engine_state_ids: [VariableIdentifier; 4],
+
+    cruise_altitude: Length,
+    zero_fuel_weight: Mass,
+    zero_fuel_weight_center_of_gravity: f64,
+    engine_states: [EngineState; 4],
+
+    target_center_of_gravity: f64,
+    highest_altitude: Length,
+    forward_transfer_commanded: bool,
+    trim_tank_transfer: TrimTankTransfer,
+    feed_tank_targets: Option<[Mass; 4]>,
+    feed_tanks_refilling: [bool; 4],
+    feed_tanks_balancing: bool,
+    balanced_feed_tank: Option<usize>,
+    outer_tanks_transferring: [bool; 2],
+
+    auto_cg_control_failure: Failure,
+    trim_tank_transfer_failure: Failure,
+}
+impl FuelManagementApplication {
+    // % MAC
+    const CENTER_OF_GRAVITY_HYSTERESIS: f64 = 0.5;
+
+    const CG_CONTROL_MIN_ALTITUDE_FEET: f64 = 25500.;
+    const DESCENT_DETECTION_MARGIN_FEET: f64 = 2000.;
+    const CRUISE_ALTITUDE_MARGIN_FEET: f64 = 1000.;
+    const FORWARD_TRANSFER_TOTAL_FUEL_KG: f64 = 30000.;
+
+    const AFT_TRANSFER_RATE_KG_SEC: f64 = 2.;
+    const FORWARD_TRANSFER_RATE_KG_SEC: f64 = 5.;
+    const FEED_REFILL_RATE_KG_SEC: f64 = 10.;
+    const OUTER_TRANSFER_RATE_KG_SEC: f64 = 3.;
+
+    const FEED_REFILL_HYSTERESIS_KG: f64 = 1000.;
+    const OUTER_TRANSFER_INNER_TANK_KG: f64 = 4000.;
+    const FEED_IMBALANCE_START_KG: f64 = 3000.;
+    const FEED_IMBALANCE_STOP_KG: f64 = 500.;
+
+    const FEED_TANKS: [A380FuelTankType; 4] = [
+        A380FuelTankType::FeedOne,
+        A380FuelTankType::FeedTwo,
+        A380FuelTankType::FeedThree,
+        A380FuelTankType::FeedFour,
+    ];
+    const LEFT_WING_SOURCES: [&'static [A380FuelTankType]; 2] =
+        [&[A380FuelTankType::LeftInner], &[A380FuelTankType::LeftMid]];
+    const RIGHT_WING_SOURCES: [&'static [A380FuelTankType]; 2] = [
+        &[A380FuelTankType::RightInner],
+        &[A380FuelTankType::RightMid],
+    ];
+    const AFT_TRANSFER_SOURCES: [&'static [A380FuelTankType]; 2] = [
+        &[A380FuelTankType::LeftInner, A380FuelTankType::RightInner],
+        &[A380FuelTankType::LeftMid, A380FuelTankType::RightMid],
+    ];
+    const OUTER_TANKS: [(A380FuelTankType, A380FuelTankType); 2] = [
+        (A380FuelTankType::LeftOuter, A380FuelTankType::LeftInner),
+        (A380FuelTankType::RightOuter, A380FuelTankType::RightInner),
+    ];
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            trim_tank_aft_transfer_id: context
+                .get_identifier("FUEL_TRIM_TANK_AFT_TRANSFER".to_owned()),
+            trim_tank_forward_transfer_id: context
+                .get_identifier("FUEL_TRIM_TANK_FWD_TRANSFER".to_owned()),
+            auto_cg_control_fault_id: context
+                .get_identifier("FUEL_AUTO_CG_CONTROL_FAULT".to_owned()),
+            trim_tank_transfer_fault_id: context
+                .get_identifier("FUEL_TRIM_TANK_TRANSFER_FAULT".to_owned()),
+            target_center_of_gravity_id: context
+                .get_identifier("FUEL_CG_TARGET_PERCENT_MAC".to_owned()),
+            feed_tank_transfer_ids: [1, 2, 3, 4]
+                .map(|id| context.get_identifier(format!("FUEL_FEED_TANK_{id}_FWD_TRANSFER"))),
+            outer_tank_transfer_ids: ["LEFT", "RIGHT"]
+                .map(|side| context.get_identifier(format!("FUEL_{side}_OUTER_TANK_TRANSFER"))),
+
+            cruise_altitude_id: context.get_identifier("AIRLINER_CRUISE_ALTITUDE".to_owned()),
+            zero_fuel_weight_id: context.get_identifier("AIRFRAME_ZFW".to_owned()),
+            zero_fuel_weight_center_of_gravity_id: context
+                .get_identifier("AIRFRAME_ZFW_CG_PERCENT_MAC".to_owned()),
+            engine_state_ids: [1, 2, 3, 4]
+                .map(|id| context.get_identifier(format!("ENGINE_STATE:{id}"))),
+
+            cruise_altitude: Length::default(),
+            zero_fuel_weight: Mass::default(),
+            zero_fuel_weight_center_of_gravity: 0.,
+            engine_states: [EngineState::Off; 4],
+
+            target_center_of_gravity: Self::target_center_of_gravity(Mass::default()),
+            highest_altitude: Length::default(),
+            forward_transfer_commanded: false,
+            trim_tank_transfer: TrimTankTransfer::None,
+            feed_tank_targets: None,
+            feed_tanks_refilling: [false; 4],
+            feed_tanks_balancing: false,
+            balanced_feed_tank: None,
+            outer_tanks_transferring: [false; 2],
+
+            auto_cg_control_failure: Failure::new(FailureType::FuelAutoCgControlFault),
+            trim_tank_transfer_failure: Failure::new(FailureType::FuelTrimTankTransferFault),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, fuel_system: &mut FuelSystem<11, 20>) {
+        self.target_center_of_gravity =
+            Self::target_center_of_gravity(self.zero_fuel_weight + fuel_system.total_load());
+
+        if context.is_on_ground() {
+            self.reset(fuel_system);
+            return;
+        }
+
+        // Nothing burns fuel, so there is nothing to manage.
+        if !self.engine_states.contains(&EngineState::On) {
+            self.trim_tank_transfer = TrimTankTransfer::None;
+            return;
+        }
+
+        self.highest_altitude = self.highest_altitude.max(context.pressure_altitude());
+        if self.descent_is_detected(context)
+            || fuel_system.total_load()
+                < Mass::new::<kilogram>(Self::FORWARD_TRANSFER_TOTAL_FUEL_KG)
+        {
+            self.forward_transfer_commanded = true;
+        }
+
+        self.update_trim_tank_transfer(context, fuel_system);
+        self.update_outer_tank_transfer(context, fuel_system);
+        self.update_feed_tank_refill(context, fuel_system);
+        self.update_feed_tank_balancing(context, fuel_system);
+    }
+
+    fn reset(&mut self, fuel_system: &FuelSystem<11, 20>) {
+        self.highest_altitude = Length::default();
+        self.forward_transfer_commanded = false;
+        self.trim_tank_transfer = TrimTankTransfer::None;
+        self.feed_tank_targets = Some(Self::feed_tank_quantities(fuel_system));
+        self.feed_tanks_refilling = [false; 4];
+        self.feed_tanks_balancing = false;
+        self.balanced_feed_tank = None;
+        self.outer_tanks_transferring = [false; 2];
+    }
+
+    fn descent_is_detected(&self, context: &UpdateContext) -> bool {
+        let reached_cruise = self.highest_altitude
+            > Length::new::<foot>(Self::CG_CONTROL_MIN_ALTITUDE_FEET)
+            && self.highest_altitude
+                > self.cruise_altitude - Length::new::<foot>(Self::CRUISE_ALTITUDE_MARGIN_FEET);
+
+        reached_cruise
+            && context.pressure_altitude()
+                < self.highest_altitude - Length::new::<foot>(Self::DESCENT_DETECTION_MARGIN_FEET)
+    }
+
+    /// The cruise CG target moves aft as the aircraft gets heavier. The coefficients were
+    /// determined using regression on the FCOM diagram, which is given in thousands of pounds.
+    fn target_center_of_gravity(gross_weight: Mass) -> f64 {
+        let weight = gross_weight.get::<pound>() / 1000.;
+        let target = 1.52792360195336e-14 * weight.powi(5) - 7.7447769532209e-11 * weight.powi(4)
+            + 1.57545973208929e-7 * weight.powi(3)
+            - 0.000162820304673144 * weight.powi(2)
+            + 0.0884071656630996 * weight
+            + 20.6522282591408;
+
+        (target * 100.).round() / 100.
+    }
+
+    fn cg_control_is_available(&self) -> bool {
+        !self.auto_cg_control_failure.is_active() && self.zero_fuel_weight > Mass::default()
+    }
+
+    fn update_trim_tank_transfer(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11, 20>,
+    ) {
+        let trim_tank_has_fuel = fuel_system.tank_has_fuel(A380FuelTankType::Trim as usize);
+
+        self.trim_tank_transfer = if self.trim_tank_transfer_failure.is_active() {
+            TrimTankTransfer::None
+        } else if self.forward_transfer_commanded || !self.cg_control_is_available() {
+            // Without CG control the trim tank fuel is moved forward to keep the CG safe.
+            if trim_tank_has_fuel {
+                TrimTankTransfer::Forward
+            } else {
+                TrimTankTransfer::None
+            }
+        } else if context.pressure_altitude()
+            < Length::new::<foot>(Self::CG_CONTROL_MIN_ALTITUDE_FEET)
+        {
+            TrimTankTransfer::None
+        } else {
+            let cg = self.gross_weight_center_of_gravity(fuel_system);
+            match self.trim_tank_transfer {
+                TrimTankTransfer::Aft if cg < self.target_center_of_gravity => {
+                    TrimTankTransfer::Aft
+                }
+                TrimTankTransfer::Forward
+                    if cg > self.target_center_of_gravity && trim_tank_has_fuel =>
+                {
+                    TrimTankTransfer::Forward
+                }
+                _ if cg < self.target_center_of_gravity - Self::CENTER_OF_GRAVITY_HYSTERESIS => {
+                    TrimTankTransfer::Aft
+                }
+                _ if cg > self.target_center_of_gravity + Self::CENTER_OF_GRAVITY_HYSTERESIS
+                    && trim_tank_has_fuel =>
+                {
+                    TrimTankTransfer::Forward
+                }
+                _ => TrimTankTransfer::None,
+            }
+        };
+
+        let transferred = match self.trim_tank_transfer {
+            TrimTankTransfer::Aft => Self::transfer_in_sequence(
+                fuel_system,
+                &Self::AFT_TRANSFER_SOURCES,
+                &[A380FuelTankType::Trim],
+                Self::rate_to_mass(context, Self::AFT_TRANSFER_RATE_KG_SEC),
+            ),
+            TrimTankTransfer::Forward => Self::transfer_in_sequence(
+                fuel_system,
+                &[&[A380FuelTankType::Trim]],
+                &Self::FEED_TANKS,
+                Self::rate_to_mass(context, Self::FORWARD_TRANSFER_RATE_KG_SEC),
+            ),
+            TrimTankTransfer::None => Mass::default(),
+        };
+
+        if transferred <= Mass::default() {
+            self.trim_tank_transfer = TrimTankTransfer::None;
+        }
+    }
+
+    fn update_outer_tank_transfer(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11, 20>,
+    ) {
+        for (transferring, (outer, inner)) in self
+            .outer_tanks_transferring
+            .iter_mut()
+            .zip(Self::OUTER_TANKS)
+        {
+            if fuel_system.tank_mass(inner as usize)
+                < Mass::new::<kilogram>(Self::OUTER_TRANSFER_INNER_TANK_KG)
+            {
+                *transferring = true;
+            }
+
+            if *transferring {
+                let transferred = Self::transfer(
+                    fuel_system,
+                    &[outer],
+                    &[inner],
+                    Self::rate_to_mass(context, Self::OUTER_TRANSFER_RATE_KG_SEC),
+                );
+                *transferring = transferred > Mass::default();
+            }
+        }
+    }
+
+    fn update_feed_tank_refill(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11, 20>,
+    ) {
+        let targets = *self
+            .feed_tank_targets
+            .get_or_insert_with(|| Self::feed_tank_quantities(fuel_system));
+
+        for (index, feed_tank) in Self::FEED_TANKS.iter().enumerate() {
+            let quantity = fuel_system.tank_mass(*feed_tank as usize);
+            if quantity < targets[index] - Mass::new::<kilogram>(Self::FEED_REFILL_HYSTERESIS_KG) {
+                self.feed_tanks_refilling[index] = true;
+            }
+
+            if self.feed_tanks_refilling[index] {
+                let sources = if index < 2 {
+                    &Self::LEFT_WING_SOURCES
+                } else {
+                    &Self::RIGHT_WING_SOURCES
+                };
+                let transferred = Self::transfer_in_sequence(
+                    fuel_system,
+                    sources,
+                    &[*feed_tank],
+                    Self::rate_to_mass(context, Self::FEED_REFILL_RATE_KG_SEC)
+                        .min(targets[index] - quantity),
+                );
+                self.feed_tanks_refilling[index] = transferred > Mass::default()
+                    && fuel_system.tank_mass(*feed_tank as usize) < targets[index];
+            }
+        }
+    }
+
+    fn update_feed_tank_balancing(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11, 20>,
+    ) {
+        let quantities = Self::feed_tank_quantities(fuel_system);
+        let (fullest, _) = quantities
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.get::<kilogram>().total_cmp(&b.get::<kilogram>()))
+            .unwrap();
+        let (emptiest, _) = quantities
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.get::<kilogram>().total_cmp(&b.get::<kilogram>()))
+            .unwrap();
+        let imbalance = quantities[fullest] - quantities[emptiest];
+
+        if imbalance > Mass::new::<kilogram>(Self::FEED_IMBALANCE_START_KG) {
+            self.feed_tanks_balancing = true;
+        } else if imbalance < Mass::new::<kilogram>(Self::FEED_IMBALANCE_STOP_KG) {
+            self.feed_tanks_balancing = false;
+        }
+
+        if self.feed_tanks_balancing {
+            let average = quantities
+                .iter()
+                .fold(Mass::default(), |acc, quantity| acc + *quantity)
+                / quantities.len() as f64;
+            let transferred = Self::transfer(
+                fuel_system,
+                &[Self::FEED_TANKS[fullest]],
+                &[Self::FEED_TANKS[emptiest]],
+                Self::rate_to_mass(context, Self::FEED_REFILL_RATE_KG_SEC)
+                    .min(quantities[fullest] - average)
+                    .min(average - quantities[emptiest]),
+            );
+            self.feed_tanks_balancing = transferred > Mass::default();
+        }
+
+        self.balanced_feed_tank = self.feed_tanks_balancing.then_some(emptiest);
+    }
+
+    /// Whether fuel is transferred into the feed tank through the forward gallery, either to
+    /// refill it or to balance the feed tanks.
+    fn feed_tank_is_transferred_into(&self, index: usize) -> bool {
+        self.feed_tanks_refilling[index] || self.balanced_feed_tank == Some(index)
+    }
+
+    /// Moves up to `max_quantity` of fuel, emptying each group of source tanks before moving
+    /// on to the next one. Returns the quantity which was moved.
+    fn transfer_in_sequence(
+        fuel_system: &mut FuelSystem<11, 20>,
+        source_groups: &[&[A380FuelTankType]],
+        destinations: &[A380FuelTankType],
+        max_quantity: Mass,
+    ) -> Mass {
+        source_groups
+            .iter()
+            .fold(Mass::default(), |transferred, sources| {
+                transferred
+                    + Self::transfer(
+                        fuel_system,
+                        sources,
+                        destinations,
+                        max_quantity - transferred,
+                    )
+            })
+    }
+
+    /// Moves up to `max_quantity` of fuel out of the source tanks which have a powered pump,
+    /// sharing it between the destination tanks according to the room left in them.
+    fn transfer(
+        fuel_system: &mut FuelSystem<11, 20>,
+        sources: &[A380FuelTankType],
+        destinations: &[A380FuelTankType],
+        max_quantity: Mass,
+    ) -> Mass {
+        let available: Vec<(A380FuelTankType, Mass)> = sources
+            .iter()
+            .filter(|tank| tank.has_powered_pump(fuel_system))
+            .map(|&tank| (tank, fuel_system.tank_mass(tank as usize)))
+            .collect();
+        let room: Vec<(A380FuelTankType, Mass)> = destinations
+            .iter()
+            .map(|&tank| {
+                (
+                    tank,
+                    (tank.capacity() - fuel_system.tank_mass(tank as usize)).max(Mass::default()),
+                )
+            })
+            .collect();
+
+        let total_available = available
+            .iter()
+            .fold(Mass::default(), |acc, (_, quantity)| acc + *quantity);
+        let total_room = room
+            .iter()
+            .fold(Mass::default(), |acc, (_, quantity)| acc + *quantity);
+        let transferred = max_quantity.min(total_available).min(total_room);
+        if transferred <= Mass::default() {
+            return Mass::default();
+        }
+
+        for (tank, quantity) in available {
+            fuel_system.set_tank_quantity(
+                tank as usize,
+                quantity - transferred * (quantity / total_available),
+            );
+        }
+        for (tank, quantity) in room {
+            fuel_system.set_tank_quantity(
+                tank as usize,
+                fuel_system.tank_mass(tank as usize) + transferred * (quantity / total_room),
+            );
+        }
+
+        transferred
+    }
+
+    fn gross_weight_center_of_gravity(&self, fuel_system: &FuelSystem<11, 20>) -> f64 {
+        let loadsheet = A380Airframe::LOADSHEET;
+        let fuel = fuel_system.total_load();
+        if fuel <= Mass::default() {
+            return self.zero_fuel_weight_center_of_gravity;
+        }
+
+        let zero_fuel_weight_cg = loadsheet.leading_edge_mean_aerodynamic_chord
+            - self.zero_fuel_weight_center_of_gravity * loadsheet.mean_aerodynamic_chord_size
+                / 100.;
+        let gross_weight_moment = self.zero_fuel_weight.get::<kilogram>() * zero_fuel_weight_cg
+            + fuel.get::<kilogram>() * fuel_system.center_of_gravity().x;
+        let gross_weight_cg =
+            gross_weight_moment / (self.zero_fuel_weight + fuel).get::<kilogram>();
+
+        -100. * (gross_weight_cg - loadsheet.leading_edge_mean_aerodynamic_chord)
+            / loadsheet.mean_aerodynamic_chord_size
+    }
+
+    fn feed_tank_quantities(fuel_system: &FuelSystem<11, 20>) -> [Mass; 4] {
+        Self::FEED_TANKS.map(|tank| fuel_system.tank_mass(tank as usize))
+    }
+
+    fn rate_to_mass(context: &UpdateContext, rate_kg_sec: f64) -> Mass {
+        Mass::new::<kilogram>(rate_kg_sec * context.delta_as_secs_f64())
+    }
+}
+impl SimulationElement for FuelManagementApplication {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.auto_cg_control_failure.accept(visitor);
+        self.trim_tank_transfer_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.cruise_altitude = Length::new::<foot>(reader.read(&self.cruise_altitude_id));
+        self.zero_fuel_weight = Mass::new::<kilogram>(reader.read(&self.zero_fuel_weight_id));
+        self.zero_fuel_weight_center_of_gravity =
+            reader.read(&self.zero_fuel_weight_center_of_gravity_id);
+
+        for (id, state) in self
+            .engine_state_ids
+            .iter()
+            .zip(self.engine_states.iter_mut())
+        {
+            *state = reader.read(id);
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.trim_tank_aft_transfer_id,
+            self.trim_tank_transfer == TrimTankTransfer::Aft,
+        );
+        writer.write(
+            &self.trim_tank_forward_transfer_id,
+            self.trim_tank_transfer == TrimTankTransfer::Forward,
+        );
+        writer.write(
+            &self.auto_cg_control_fault_id,
+            self.auto_cg_control_failure.is_active(),
+        );
+        writer.write(
+            &self.trim_tank_transfer_fault_id,
+            self.trim_tank_transfer_failure.is_active(),
+        );
+        writer.write(
+            &self.target_center_of_gravity_id,
+            self.target_center_of_gravity,
+        );
+        for (index, id) in self.feed_tank_transfer_ids.iter().enumerate() {
+            writer.write(id, self.feed_tank_is_transferred_into(index));
+        }
+        for (id, &transferring) in self
+            .outer_tank_transfer_ids
+            .iter()
+            .zip(&self.outer_tanks_transferring)
+        {
+            writer.write(id, transferring);
+        }
+    }
+}
@@ -3,31 +3,51 @@ use std::{collections::HashMap, time::Duration};
 use crate::systems::simulation::SimulationElement;
 use serde::Deserialize;
 use systems::{
-    fuel::{self, FuelInfo, FuelPump, FuelPumpProperties, FuelSystem, RefuelRate},
+    accept_iterable,
+    engine::Engine,
+    failures::{Failure, FailureType},
+    fuel::{self, FuelInfo, FuelLeakMonitor, FuelPump, FuelPumpProperties, FuelSystem, RefuelRate},
+    ground_services::{GroundService, GroundServices},
     pneumatic::EngineState,
-    shared::{ElectricalBusType, ElectricalBuses},
+    shared::{AdirsMeasurementOutputs, ElectricalBusType, ElectricalBuses, EngineFuelFlow},
     simulation::{
         InitContext, Read, SimulationElementVisitor, SimulatorReader, SimulatorWriter,
         UpdateContext, VariableIdentifier, Write,
     },
 };
 use uom::si::{
-    f64::{Mass, Velocity},
+    f64::{Mass, MassRate, Velocity},
     mass::kilogram,
+    mass_rate::kilogram_per_second,
     velocity::knot,
 };
 
-use super::A380FuelTankType;
+use super::{
+    fuel_jettison::FuelJettisonApplication, fuel_management_application::FuelManagementApplication,
+    fuel_temperature::FuelTemperatureApplication, A380FuelOverheadPanel, A380FuelTankType,
+};
 
 use serde_with::{serde_as, DisplayFromStr};
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 enum ModeSelect {
     AutoRefuel,
-    _Off,
-    _ManualRefuel,
-    _Defuel,
-    _Transfer,
+    Off,
+    ManualRefuel,
+    Defuel,
+    Transfer,
+}
+impl From<f64> for ModeSelect {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => ModeSelect::AutoRefuel,
+            1 => ModeSelect::Off,
+            2 => ModeSelect::ManualRefuel,
+            3 => ModeSelect::Defuel,
+            4 => ModeSelect::Transfer,
+            _ => ModeSelect::Off,
+        }
+    }
 }
 
 #[derive(Deserialize)]
@@ -59,6 +79,9 @@ pub struct RefuelPanelInput {
     total_desired_fuel_id: VariableIdentifier,
     total_desired_fuel_input: Mass,
 
+    desired_tank_quantity_ids: [VariableIdentifier; 11],
+    desired_tank_quantities: [Mass; 11],
+
     refuel_status_id: VariableIdentifier,
     refuel_status: bool,
 
@@ -81,6 +104,10 @@ impl RefuelPanelInput {
             total_desired_fuel_id: context.get_identifier("FUEL_DESIRED".to_owned()),
             total_desired_fuel_input: Mass::default(),
 
+            desired_tank_quantity_ids: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]
+                .map(|id| context.get_identifier(format!("FUEL_DESIRED_TANK_{id}"))),
+            desired_tank_quantities: [Mass::default(); 11],
+
             refuel_status_id: context.get_identifier("REFUEL_STARTED_BY_USR".to_owned()),
             refuel_status: false,
 
@@ -108,6 +135,10 @@ impl RefuelPanelInput {
         self.total_desired_fuel_input
     }
 
+    fn desired_tank_quantity(&self, tank: A380FuelTankType) -> Mass {
+        self.desired_tank_quantities[tank as usize]
+    }
+
     fn refuel_status(&self) -> bool {
         self.refuel_status
     }
@@ -143,6 +174,13 @@ impl SimulationElement for RefuelPanelInput {
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.total_desired_fuel_input =
             Mass::new::<kilogram>(reader.read(&self.total_desired_fuel_id));
+        for (id, quantity) in self
+            .desired_tank_quantity_ids
+            .iter()
+            .zip(self.desired_tank_quantities.iter_mut())
+        {
+            *quantity = Mass::new::<kilogram>(reader.read(id));
+        }
         self.refuel_status = reader.read(&self.refuel_status_id);
         self.refuel_rate_setting = reader.read(&self.refuel_rate_setting_id);
 
@@ -170,7 +208,8 @@ impl SimulationElement for RefuelPanelInput {
 pub struct IntegratedRefuelPanel {
     powered_by: ElectricalBusType,
     is_powered: bool,
-    _mode_select: ModeSelect,
+    mode_select_id: VariableIdentifier,
+    mode_select: ModeSelect,
     input: RefuelPanelInput,
 }
 impl IntegratedRefuelPanel {
@@ -178,15 +217,24 @@ impl IntegratedRefuelPanel {
         Self {
             powered_by,
             is_powered: false,
-            _mode_select: ModeSelect::AutoRefuel,
+            mode_select_id: context.get_identifier("REFUEL_PANEL_MODE_SELECT".to_owned()),
+            mode_select: ModeSelect::AutoRefuel,
             input: RefuelPanelInput::new(context),
         }
     }
 
+    fn mode_select(&self) -> ModeSelect {
+        self.mode_select
+    }
+
     fn total_desired_fuel(&self) -> Mass {
         self.input.total_desired_fuel_input()
     }
 
+    fn desired_tank_quantity(&self, tank: A380FuelTankType) -> Mass {
+        self.input.desired_tank_quantity(tank)
+    }
+
     fn refuel_status(&self) -> bool {
         self.input.refuel_status()
     }
@@ -225,12 +273,20 @@ impl SimulationElement for IntegratedRefuelPanel {
         self.input.accept(visitor);
         visitor.visit(self);
     }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let mode_select: f64 = reader.read(&self.mode_select_id);
+        self.mode_select = mode_select.into();
+    }
 }
 
 const TRIM_TANK_TOML: &str = include_str!("./trim_tank_targets.toml");
 pub struct RefuelApplication {
     refuel_driver: RefuelDriver,
     trim_tank_map: TrimTankMapping,
+    refuel_service_performing: bool,
+    target_load_within_limits: bool,
+    target_tank_quantities: [Mass; 11],
 }
 impl RefuelApplication {
     pub fn new(_context: &mut InitContext, _powered_by: ElectricalBusType) -> Self {
@@ -238,27 +294,119 @@ impl RefuelApplication {
             refuel_driver: RefuelDriver::new(),
             trim_tank_map: toml::from_str(TRIM_TANK_TOML)
                 .expect("Failed to parse trim tank TOML file"),
+            refuel_service_performing: false,
+            target_load_within_limits: true,
+            target_tank_quantities: [Mass::default(); 11],
         }
     }
 
+    /// The tank quantities the selected refuel mode aims for, the current quantities when not
+    /// refuelling.
+    pub fn target_tank_quantities(&self) -> &[Mass; 11] {
+        &self.target_tank_quantities
+    }
+
+    /// Refuelling is refused while the target load, i.e. the load once refuelled, is outside the
+    /// weight and balance envelopes.
+    pub fn set_target_load_within_limits(&mut self, target_load_within_limits: bool) {
+        self.target_load_within_limits = target_load_within_limits;
+    }
+
     pub fn update(
         &mut self,
         context: &UpdateContext,
         fuel_system: &mut FuelSystem<11, 20>,
         refuel_panel_input: &mut IntegratedRefuelPanel,
+        ground_services: &mut impl GroundServices,
     ) {
-        // Automatic Refueling
-        let desired_quantities = self.calculate_auto_refuel(
-            refuel_panel_input.total_desired_fuel(),
-            // TODO FIXME: Add values from either MFD (or EFB)
-            refuel_panel_input.target_zero_fuel_weight(),
-            refuel_panel_input.target_zero_fuel_weight_cg_mac(),
-        );
-
         if !context.is_sim_ready() {
             refuel_panel_input.set_fuel_desired(fuel_system.total_load());
         }
 
+        self.update_refuel_service(ground_services, refuel_panel_input);
+
+        let desired_quantities = self.desired_quantities(fuel_system, refuel_panel_input);
+        for (tank, quantity) in &desired_quantities {
+            self.target_tank_quantities[*tank as usize] = *quantity;
+        }
+
+        match refuel_panel_input.mode_select() {
+            ModeSelect::AutoRefuel | ModeSelect::ManualRefuel
+                if !self.target_load_within_limits =>
+            {
+                refuel_panel_input.set_refuel_status(false)
+            }
+            ModeSelect::AutoRefuel => {
+                self.auto_refuel(context, fuel_system, refuel_panel_input, desired_quantities)
+            }
+            ModeSelect::ManualRefuel => {
+                self.manual_refuel(context, fuel_system, refuel_panel_input, desired_quantities)
+            }
+            ModeSelect::Defuel => self.defuel(context, fuel_system, refuel_panel_input),
+            ModeSelect::Transfer => self.ground_transfer(context, fuel_system, refuel_panel_input),
+            ModeSelect::Off => {}
+        }
+
+        if self.refuel_service_performing && !refuel_panel_input.refuel_status() {
+            ground_services.complete(GroundService::Refuel);
+        }
+    }
+
+    /// The refuel starts once the ground services are performing it, and stops when they no
+    /// longer are. The service is completed as soon as the refuel is.
+    fn update_refuel_service(
+        &mut self,
+        ground_services: &impl GroundServices,
+        refuel_panel_input: &mut IntegratedRefuelPanel,
+    ) {
+        let is_performing = ground_services.is_performing(GroundService::Refuel);
+        if is_performing && !self.refuel_service_performing {
+            refuel_panel_input.set_refuel_status(true);
+        } else if !is_performing && self.refuel_service_performing {
+            refuel_panel_input.set_refuel_status(false);
+        }
+        self.refuel_service_performing = is_performing;
+    }
+
+    /// The tank quantities the selected refuel mode aims for.
+    fn desired_quantities(
+        &mut self,
+        fuel_system: &FuelSystem<11, 20>,
+        refuel_panel_input: &IntegratedRefuelPanel,
+    ) -> HashMap<A380FuelTankType, Mass> {
+        match refuel_panel_input.mode_select() {
+            ModeSelect::AutoRefuel => self.calculate_auto_refuel(
+                refuel_panel_input.total_desired_fuel(),
+                // TODO FIXME: Add values from either MFD (or EFB)
+                refuel_panel_input.target_zero_fuel_weight(),
+                refuel_panel_input.target_zero_fuel_weight_cg_mac(),
+            ),
+            // Refuelling only ever adds fuel, tanks above their target keep their quantity.
+            ModeSelect::ManualRefuel => A380FuelTankType::iterator()
+                .map(|tank| {
+                    (
+                        tank,
+                        refuel_panel_input
+                            .desired_tank_quantity(tank)
+                            .max(fuel_system.tank_mass(tank as usize)),
+                    )
+                })
+                .collect(),
+            ModeSelect::Defuel | ModeSelect::Transfer | ModeSelect::Off => {
+                A380FuelTankType::iterator()
+                    .map(|tank| (tank, fuel_system.tank_mass(tank as usize)))
+                    .collect()
+            }
+        }
+    }
+
+    fn auto_refuel(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11, 20>,
+        refuel_panel_input: &mut IntegratedRefuelPanel,
+        desired_quantities: HashMap<A380FuelTankType, Mass>,
+    ) {
         match refuel_panel_input.refuel_rate() {
             RefuelRate::Real => {
                 if refuel_panel_input.refuel_is_enabled(context) {
@@ -294,6 +442,97 @@ impl RefuelApplication {
         }
     }
 
+    fn manual_refuel(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11, 20>,
+        refuel_panel_input: &mut IntegratedRefuelPanel,
+        desired_quantities: HashMap<A380FuelTankType, Mass>,
+    ) {
+        match refuel_panel_input.refuel_rate() {
+            RefuelRate::Real | RefuelRate::Fast => {
+                if refuel_panel_input.refuel_is_enabled(context) {
+                    self.refuel_driver.execute_timed_refuel(
+                        context.delta(),
+                        refuel_panel_input.refuel_rate() == RefuelRate::Fast,
+                        fuel_system,
+                        refuel_panel_input,
+                        desired_quantities,
+                    );
+                }
+            }
+            RefuelRate::Instant => {
+                if refuel_panel_input.refuel_status() {
+                    for (tank, quantity) in desired_quantities {
+                        fuel_system.set_tank_quantity(tank as usize, quantity);
+                    }
+                    refuel_panel_input.set_refuel_status(false);
+                }
+            }
+        }
+    }
+
+    fn defuel(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11, 20>,
+        refuel_panel_input: &mut IntegratedRefuelPanel,
+    ) {
+        if let Some(max_delta) = Self::max_tick_delta(
+            context,
+            refuel_panel_input,
+            RefuelDriver::WING_FUELRATE_GAL_SEC,
+        ) {
+            self.refuel_driver
+                .execute_defuel(max_delta, fuel_system, refuel_panel_input);
+        }
+    }
+
+    fn ground_transfer(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11, 20>,
+        refuel_panel_input: &mut IntegratedRefuelPanel,
+    ) {
+        if let Some(max_delta) = Self::max_tick_delta(
+            context,
+            refuel_panel_input,
+            RefuelDriver::TRANSFER_FUELRATE_GAL_SEC,
+        ) {
+            self.refuel_driver
+                .execute_transfer(max_delta, fuel_system, refuel_panel_input);
+        }
+    }
+
+    /// The quantity of fuel which may be moved during this tick, `Some(None)` when there's no
+    /// limit, or `None` when the operation isn't running.
+    fn max_tick_delta(
+        context: &UpdateContext,
+        refuel_panel_input: &IntegratedRefuelPanel,
+        rate_gal_sec: f64,
+    ) -> Option<Option<Mass>> {
+        match refuel_panel_input.refuel_rate() {
+            RefuelRate::Real | RefuelRate::Fast => {
+                if refuel_panel_input.refuel_is_enabled(context) {
+                    let speed_multi = if refuel_panel_input.refuel_rate() == RefuelRate::Fast {
+                        RefuelDriver::FAST_SPEED_FACTOR
+                    } else {
+                        1.
+                    };
+                    Some(Some(Mass::new::<kilogram>(
+                        context.delta_as_secs_f64()
+                            * rate_gal_sec
+                            * speed_multi
+                            * fuel::FUEL_GALLONS_TO_KG,
+                    )))
+                } else {
+                    None
+                }
+            }
+            RefuelRate::Instant => refuel_panel_input.refuel_status().then_some(None),
+        }
+    }
+
     fn lookup_trim_fuel_from_target_fuel_range(
         target_zfw_cg_keys: &[u32],
         target_fuel_range: &[u32],
@@ -463,8 +702,23 @@ impl SimulationElement for RefuelApplication {
 pub struct RefuelDriver;
 impl RefuelDriver {
     const WING_FUELRATE_GAL_SEC: f64 = 16.;
+    const TRANSFER_FUELRATE_GAL_SEC: f64 = 8.;
     const FAST_SPEED_FACTOR: f64 = 5.;
 
+    // Tanks are emptied group by group, evenly within a group to keep the aircraft balanced.
+    const DEFUEL_SEQUENCE: [&'static [A380FuelTankType]; 5] = [
+        &[A380FuelTankType::Trim],
+        &[A380FuelTankType::LeftOuter, A380FuelTankType::RightOuter],
+        &[A380FuelTankType::LeftMid, A380FuelTankType::RightMid],
+        &[A380FuelTankType::LeftInner, A380FuelTankType::RightInner],
+        &[
+            A380FuelTankType::FeedOne,
+            A380FuelTankType::FeedTwo,
+            A380FuelTankType::FeedThree,
+            A380FuelTankType::FeedFour,
+        ],
+    ];
+
     pub fn new() -> Self {
         Self
     }
@@ -594,14 +848,133 @@ impl RefuelDriver {
         }
     }
 }
+impl RefuelDriver {
+    fn execute_defuel(
+        &mut self,
+        max_delta: Option<Mass>,
+        fuel_system: &mut FuelSystem<11, 20>,
+        refuel_panel_input: &mut IntegratedRefuelPanel,
+    ) {
+        let total_quantity = A380FuelTankType::iterator().fold(Mass::default(), |acc, tank| {
+            acc + fuel_system.tank_mass(tank as usize)
+        });
+        let excess_fuel = total_quantity - refuel_panel_input.total_desired_fuel();
+
+        let mut remaining_delta = match max_delta {
+            Some(max_delta) => excess_fuel.min(max_delta),
+            None => excess_fuel,
+        };
+        if excess_fuel <= remaining_delta {
+            refuel_panel_input.set_refuel_status(false);
+        }
+
+        for group in Self::DEFUEL_SEQUENCE {
+            while remaining_delta > Mass::default() {
+                let tanks_with_fuel: Vec<A380FuelTankType> = group
+                    .iter()
+                    .copied()
+                    .filter(|&tank| fuel_system.tank_mass(tank as usize) > Mass::default())
+                    .collect();
+                if tanks_with_fuel.is_empty() {
+                    break;
+                }
+
+                let share = remaining_delta / tanks_with_fuel.len() as f64;
+                for tank in tanks_with_fuel {
+                    let quantity = fuel_system.tank_mass(tank as usize);
+                    let removed = quantity.min(share);
+                    fuel_system.set_tank_quantity(tank as usize, quantity - removed);
+                    remaining_delta -= removed;
+                }
+            }
+        }
+    }
+
+    fn execute_transfer(
+        &mut self,
+        max_delta: Option<Mass>,
+        fuel_system: &mut FuelSystem<11, 20>,
+        refuel_panel_input: &mut IntegratedRefuelPanel,
+    ) {
+        // Fuel can only leave a tank through one of its powered pumps.
+        let sources: Vec<A380FuelTankType> = A380FuelTankType::iterator()
+            .filter(|&tank| {
+                fuel_system.tank_mass(tank as usize)
+                    > refuel_panel_input.desired_tank_quantity(tank)
+                    && tank.has_powered_pump(fuel_system)
+            })
+            .collect();
+        let destinations: Vec<A380FuelTankType> = A380FuelTankType::iterator()
+            .filter(|&tank| {
+                fuel_system.tank_mass(tank as usize)
+                    < refuel_panel_input.desired_tank_quantity(tank)
+            })
+            .collect();
+
+        let available = sources.iter().fold(Mass::default(), |acc, &tank| {
+            acc + fuel_system.tank_mass(tank as usize)
+                - refuel_panel_input.desired_tank_quantity(tank)
+        });
+        let required = destinations.iter().fold(Mass::default(), |acc, &tank| {
+            acc + refuel_panel_input.desired_tank_quantity(tank)
+                - fuel_system.tank_mass(tank as usize)
+        });
+
+        let transferable = available.min(required);
+        let transferred = match max_delta {
+            Some(max_delta) => transferable.min(max_delta),
+            None => transferable,
+        };
+        if transferable <= transferred {
+            refuel_panel_input.set_refuel_status(false);
+        }
+
+        let mut remaining_to_take = transferred;
+        for tank in sources {
+            let quantity = fuel_system.tank_mass(tank as usize);
+            let taken =
+                (quantity - refuel_panel_input.desired_tank_quantity(tank)).min(remaining_to_take);
+            fuel_system.set_tank_quantity(tank as usize, quantity - taken);
+            remaining_to_take -= taken;
+        }
+
+        let mut remaining_to_give = transferred;
+        for tank in destinations {
+            let quantity = fuel_system.tank_mass(tank as usize);
+            let given =
+                (refuel_panel_input.desired_tank_quantity(tank) - quantity).min(remaining_to_give);
+            fuel_system.set_tank_quantity(tank as usize, quantity + given);
+            remaining_to_give -= given;
+        }
+    }
+}
 impl SimulationElement for RefuelDriver {}
 
 pub struct A380FuelQuantityManagementSystem {
     fuel_system: FuelSystem<11, 20>,
     refuel_application: RefuelApplication,
+    fuel_management_application: FuelManagementApplication,
+    fuel_jettison_application: FuelJettisonApplication,
+    fuel_temperature_application: FuelTemperatureApplication,
     integrated_refuel_panel: IntegratedRefuelPanel,
+    leak_monitor: FuelLeakMonitor,
+
+    tank_leak_failures: [Failure; 11],
+    engine_feed_leak_failures: [Failure; 4],
 }
 impl A380FuelQuantityManagementSystem {
+    // Kilograms per second
+    const TANK_LEAK_FLOW: f64 = 1.;
+    const ENGINE_FEED_LEAK_FLOW: f64 = 2.;
+    const LEAK_SUSPECTED_DISCREPANCY_KG: f64 = 2000.;
+
+    const FEED_TANKS: [A380FuelTankType; 4] = [
+        A380FuelTankType::FeedOne,
+        A380FuelTankType::FeedTwo,
+        A380FuelTankType::FeedThree,
+        A380FuelTankType::FeedFour,
+    ];
+
     pub fn new(
         context: &mut InitContext,
         fuel_tanks_info: [FuelInfo; 11],
@@ -621,19 +994,99 @@ impl A380FuelQuantityManagementSystem {
                 context,
                 ElectricalBusType::DirectCurrentEssential, // 501PP
             ),
+            fuel_management_application: FuelManagementApplication::new(context),
+            fuel_jettison_application: FuelJettisonApplication::new(context),
+            fuel_temperature_application: FuelTemperatureApplication::new(context),
             integrated_refuel_panel: IntegratedRefuelPanel::new(
                 context,
                 ElectricalBusType::DirectCurrentEssential, // 501PP
             ),
+            leak_monitor: FuelLeakMonitor::new(
+                context,
+                Mass::new::<kilogram>(Self::LEAK_SUSPECTED_DISCREPANCY_KG),
+            ),
+
+            tank_leak_failures: std::array::from_fn(|tank| {
+                Failure::new(FailureType::FuelTankLeak(tank))
+            }),
+            engine_feed_leak_failures: [1, 2, 3, 4]
+                .map(|number| Failure::new(FailureType::FuelEngineFeedLeak(number))),
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext) {
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &A380FuelOverheadPanel,
+        adirs: &impl AdirsMeasurementOutputs,
+        ground_services: &mut impl GroundServices,
+        apu_fuel_flow: MassRate,
+        engines: [&(impl Engine + EngineFuelFlow); 4],
+    ) {
+        self.update_apu_fuel_burn(context, apu_fuel_flow);
         self.refuel_application.update(
             context,
             &mut self.fuel_system,
             &mut self.integrated_refuel_panel,
+            ground_services,
         );
+        self.fuel_management_application
+            .update(context, &mut self.fuel_system);
+        self.fuel_jettison_application
+            .update(context, &mut self.fuel_system, overhead);
+        self.update_leaks(context, engines);
+        self.fuel_temperature_application
+            .update(context, &mut self.fuel_system, adirs);
+
+        self.leak_monitor
+            .update(context, self.fuel_system.fuel_on_board(), engines);
+    }
+
+    /// A leaking engine feed line loses fuel from the feed tank of its engine, upstream of the
+    /// fuel flow meter, for as long as the engine is running.
+    fn update_leaks(
+        &mut self,
+        context: &UpdateContext,
+        engines: [&(impl Engine + EngineFuelFlow); 4],
+    ) {
+        let tank_leak =
+            MassRate::new::<kilogram_per_second>(Self::TANK_LEAK_FLOW) * context.delta_as_time();
+        for (tank, failure) in self.tank_leak_failures.iter().enumerate() {
+            if failure.is_active() {
+                self.fuel_system.leak_from_tank(tank, tank_leak);
+            }
+        }
+
+        let engine_feed_leak = MassRate::new::<kilogram_per_second>(Self::ENGINE_FEED_LEAK_FLOW)
+            * context.delta_as_time();
+        for ((feed_tank, failure), engine) in Self::FEED_TANKS
+            .iter()
+            .zip(&self.engine_feed_leak_failures)
+            .zip(engines)
+        {
+            if failure.is_active() && engine.is_above_minimum_idle() {
+                self.fuel_system
+                    .leak_from_tank(*feed_tank as usize, engine_feed_leak);
+            }
+        }
+    }
+
+    /// The APU is fed from feed tank 4. The simulator doesn't burn any APU fuel, so the flow
+    /// metered by the APU ECB is drawn here and matches the APU fuel used.
+    fn update_apu_fuel_burn(&mut self, context: &UpdateContext, apu_fuel_flow: MassRate) {
+        let tank = A380FuelTankType::FeedFour as usize;
+        let quantity = self.fuel_system.tank_mass(tank) - apu_fuel_flow * context.delta_as_time();
+        self.fuel_system
+            .set_tank_quantity(tank, quantity.max(Mass::default()));
+    }
+
+    pub fn set_target_load_within_limits(&mut self, target_load_within_limits: bool) {
+        self.refuel_application
+            .set_target_load_within_limits(target_load_within_limits);
+    }
+
+    pub fn target_tank_quantities(&self) -> &[Mass; 11] {
+        self.refuel_application.target_tank_quantities()
     }
 
     #[allow(dead_code)]
@@ -649,7 +1102,13 @@ impl SimulationElement for A380FuelQuantityManagementSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
         self.refuel_application.accept(visitor);
+        self.fuel_management_application.accept(visitor);
+        self.fuel_jettison_application.accept(visitor);
+        self.fuel_temperature_application.accept(visitor);
         self.integrated_refuel_panel.accept(visitor);
+        self.leak_monitor.accept(visitor);
+        accept_iterable!(self.tank_leak_failures, visitor);
+        accept_iterable!(self.engine_feed_leak_failures, visitor);
         visitor.visit(self);
     }
 }
@@ -0,0 +1,151 @@
+use systems::{
+    fuel::FuelSystem,
+    shared::AdirsMeasurementOutputs,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    f64::{MassRate, Power, TemperatureInterval, ThermodynamicTemperature},
+    mass_rate::{kilogram_per_hour, kilogram_per_second},
+    power::watt,
+    temperature_interval,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+use super::A380FuelTankType;
+
+/// Keeps track of the tank fuel temperatures and raises the low and high fuel temperature
+/// warnings. The wing and trim tank skins cool the fuel down towards the total air temperature,
+/// while the fuel recirculated through the engine oil and IDG coolers warms up the feed tanks.
+/// The heat picked up in the coolers grows with the engine power, hence with the fuel flow.
+pub struct FuelTemperatureApplication {
+    temperature_low_id: VariableIdentifier,
+    feed_tank_temperature_high_id: VariableIdentifier,
+    engine_fuel_flow_ids: [VariableIdentifier; 4],
+
+    engine_fuel_flows: [MassRate; 4],
+
+    temperature_low: bool,
+    feed_tank_temperature_high: bool,
+}
+impl FuelTemperatureApplication {
+    // Heat returned to the feed tank per kilogram of fuel burnt, i.e. about 40 kW at cruise.
+    const RECIRCULATION_HEAT_JOULE_PER_KG: f64 = 48000.;
+
+    // Margin above the freezing point of the fuel type.
+    const TEMPERATURE_LOW_MARGIN_DEGREE_CELSIUS: f64 = 3.;
+    const TEMPERATURE_LOW_HYSTERESIS_DEGREE_CELSIUS: f64 = 2.;
+    const FEED_TANK_TEMPERATURE_HIGH_DEGREE_CELSIUS: f64 = 55.;
+    const FEED_TANK_TEMPERATURE_HIGH_HYSTERESIS_DEGREE_CELSIUS: f64 = 2.;
+
+    const FEED_TANKS: [A380FuelTankType; 4] = [
+        A380FuelTankType::FeedOne,
+        A380FuelTankType::FeedTwo,
+        A380FuelTankType::FeedThree,
+        A380FuelTankType::FeedFour,
+    ];
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            temperature_low_id: context.get_identifier("FUEL_TEMPERATURE_LOW".to_owned()),
+            feed_tank_temperature_high_id: context
+                .get_identifier("FUEL_FEED_TANK_TEMPERATURE_HIGH".to_owned()),
+            engine_fuel_flow_ids: [1, 2, 3, 4]
+                .map(|id| context.get_identifier(format!("ENGINE_FF:{id}"))),
+
+            engine_fuel_flows: [MassRate::default(); 4],
+
+            temperature_low: false,
+            feed_tank_temperature_high: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11, 20>,
+        adirs: &impl AdirsMeasurementOutputs,
+    ) {
+        let mut heat_input = [Power::default(); 11];
+        for (feed_tank, fuel_flow) in Self::FEED_TANKS.iter().zip(self.engine_fuel_flows) {
+            heat_input[*feed_tank as usize] = Power::new::<watt>(
+                fuel_flow.get::<kilogram_per_second>().max(0.)
+                    * Self::RECIRCULATION_HEAT_JOULE_PER_KG,
+            );
+        }
+
+        fuel_system.update_temperatures(
+            context,
+            Self::skin_temperature(context, adirs),
+            heat_input,
+        );
+
+        self.update_warnings(fuel_system);
+    }
+
+    /// The skin is at the total air temperature. When no ADR provides it, it is derived from the
+    /// static air temperature at the current altitude and the Mach number.
+    fn skin_temperature(
+        context: &UpdateContext,
+        adirs: &impl AdirsMeasurementOutputs,
+    ) -> ThermodynamicTemperature {
+        [1, 2, 3]
+            .iter()
+            .find_map(|&adiru_number| adirs.total_air_temperature(adiru_number).normal_value())
+            .unwrap_or_else(|| {
+                let mach = f64::from(context.mach_number());
+                ThermodynamicTemperature::new::<kelvin>(
+                    context.ambient_temperature().get::<kelvin>() * (1. + 0.2 * mach.powi(2)),
+                )
+            })
+    }
+
+    fn update_warnings(&mut self, fuel_system: &FuelSystem<11, 20>) {
+        let low_threshold = fuel_system.freezing_point()
+            + TemperatureInterval::new::<temperature_interval::degree_celsius>(
+                if self.temperature_low {
+                    Self::TEMPERATURE_LOW_MARGIN_DEGREE_CELSIUS
+                        + Self::TEMPERATURE_LOW_HYSTERESIS_DEGREE_CELSIUS
+                } else {
+                    Self::TEMPERATURE_LOW_MARGIN_DEGREE_CELSIUS
+                },
+            );
+        self.temperature_low = A380FuelTankType::iterator().any(|tank| {
+            fuel_system.tank_has_fuel(tank as usize)
+                && fuel_system.tank_temperature(tank as usize) < low_threshold
+        });
+
+        let high_threshold =
+            ThermodynamicTemperature::new::<degree_celsius>(if self.feed_tank_temperature_high {
+                Self::FEED_TANK_TEMPERATURE_HIGH_DEGREE_CELSIUS
+                    - Self::FEED_TANK_TEMPERATURE_HIGH_HYSTERESIS_DEGREE_CELSIUS
+            } else {
+                Self::FEED_TANK_TEMPERATURE_HIGH_DEGREE_CELSIUS
+            });
+        self.feed_tank_temperature_high = Self::FEED_TANKS.iter().any(|&tank| {
+            fuel_system.tank_has_fuel(tank as usize)
+                && fuel_system.tank_temperature(tank as usize) > high_threshold
+        });
+    }
+}
+impl SimulationElement for FuelTemperatureApplication {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        for (id, fuel_flow) in self
+            .engine_fuel_flow_ids
+            .iter()
+            .zip(self.engine_fuel_flows.iter_mut())
+        {
+            *fuel_flow = MassRate::new::<kilogram_per_hour>(reader.read(id));
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.temperature_low_id, self.temperature_low);
+        writer.write(
+            &self.feed_tank_temperature_high_id,
+            self.feed_tank_temperature_high,
+        );
+    }
+}
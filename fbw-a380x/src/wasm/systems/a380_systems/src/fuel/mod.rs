@@ -1,14 +1,22 @@
 // Note: Fuel system for now is still handled in MSFS. This is used for calculating fuel-related factors.
 
+mod fuel_jettison;
+mod fuel_management_application;
 mod fuel_quantity_management_system;
+mod fuel_temperature;
 use fuel_quantity_management_system::A380FuelQuantityManagementSystem;
 use nalgebra::Vector3;
 use systems::{
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelPumpProperties, FuelSystem},
-    shared::ElectricalBusType,
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+    engine::Engine,
+    fuel::{FuelCG, FuelInfo, FuelPayload, FuelPumpProperties, FuelSystem, FUEL_GALLONS_TO_KG},
+    ground_services::GroundServices,
+    shared::{AdirsMeasurementOutputs, ElectricalBusType, EngineFuelFlow},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        UpdateContext, VariableIdentifier,
+    },
 };
-use uom::si::f64::*;
+use uom::si::{f64::*, mass::kilogram};
 
 #[cfg(test)]
 mod test;
@@ -60,6 +68,68 @@ impl A380FuelTankType {
         .iter()
         .copied()
     }
+
+    /// Indices into [A380Fuel::FUEL_PUMPS] of the pumps immersed in the tank.
+    pub fn fuel_pump_indices(&self) -> &'static [usize] {
+        match self {
+            A380FuelTankType::FeedOne => &[0, 1],
+            A380FuelTankType::FeedTwo => &[2, 3],
+            A380FuelTankType::FeedThree => &[4, 5],
+            A380FuelTankType::FeedFour => &[6, 7],
+            A380FuelTankType::LeftOuter => &[8],
+            A380FuelTankType::LeftMid => &[9, 10],
+            A380FuelTankType::LeftInner => &[11, 12],
+            A380FuelTankType::RightOuter => &[13],
+            A380FuelTankType::RightMid => &[14, 15],
+            A380FuelTankType::RightInner => &[16, 17],
+            A380FuelTankType::Trim => &[18, 19],
+        }
+    }
+
+    pub fn has_powered_pump(&self, fuel_system: &FuelSystem<11, 20>) -> bool {
+        self.fuel_pump_indices()
+            .iter()
+            .any(|&pump| fuel_system.fuel_pump_is_powered(pump))
+    }
+
+    pub fn capacity(&self) -> Mass {
+        Mass::new::<kilogram>(
+            A380Fuel::A380_FUEL[*self as usize].total_capacity_gallons * FUEL_GALLONS_TO_KG,
+        )
+    }
+}
+
+pub struct A380FuelOverheadPanel {
+    jettison_arm_id: VariableIdentifier,
+    jettison_active_id: VariableIdentifier,
+    jettison_is_armed: bool,
+    jettison_is_active: bool,
+}
+impl A380FuelOverheadPanel {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            jettison_arm_id: context
+                .get_identifier("A380X_OVHD_FUEL_JETTISON_ARM_PB_IS_ON".to_owned()),
+            jettison_active_id: context
+                .get_identifier("A380X_OVHD_FUEL_JETTISON_ACTIVE_PB_IS_ON".to_owned()),
+            jettison_is_armed: false,
+            jettison_is_active: false,
+        }
+    }
+
+    fn jettison_is_armed(&self) -> bool {
+        self.jettison_is_armed
+    }
+
+    fn jettison_is_active(&self) -> bool {
+        self.jettison_is_active
+    }
+}
+impl SimulationElement for A380FuelOverheadPanel {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.jettison_is_armed = reader.read(&self.jettison_arm_id);
+        self.jettison_is_active = reader.read(&self.jettison_active_id);
+    }
 }
 
 pub struct A380Fuel {
@@ -310,8 +380,28 @@ impl A380Fuel {
         }
     }
 
-    pub(crate) fn update(&mut self, context: &UpdateContext) {
-        self.fuel_quantity_management_system.update(context);
+    pub(crate) fn update(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &A380FuelOverheadPanel,
+        adirs: &impl AdirsMeasurementOutputs,
+        ground_services: &mut impl GroundServices,
+        apu_fuel_flow: MassRate,
+        engines: [&(impl Engine + EngineFuelFlow); 4],
+    ) {
+        self.fuel_quantity_management_system.update(
+            context,
+            overhead,
+            adirs,
+            ground_services,
+            apu_fuel_flow,
+            engines,
+        );
+    }
+
+    pub(crate) fn set_target_load_within_limits(&mut self, target_load_within_limits: bool) {
+        self.fuel_quantity_management_system
+            .set_target_load_within_limits(target_load_within_limits);
     }
 
     fn fuel_system(&self) -> &FuelSystem<11, 20> {
@@ -429,6 +519,23 @@ impl FuelPayload for A380Fuel {
         self.fore_aft_center_of_gravity()
     }
 
+    fn total_target_load(&self) -> Mass {
+        self.fuel_quantity_management_system
+            .target_tank_quantities()
+            .iter()
+            .copied()
+            .sum()
+    }
+
+    fn target_fore_aft_center_of_gravity(&self) -> f64 {
+        self.fuel_system()
+            .center_of_gravity_with(
+                self.fuel_quantity_management_system
+                    .target_tank_quantities(),
+            )
+            .x
+    }
+
     fn tank_mass(&self, t: usize) -> Mass {
         self.fuel_system().tank_mass(t)
     }
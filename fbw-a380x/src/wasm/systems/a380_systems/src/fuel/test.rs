@@ -1,46 +1,204 @@
 use std::{collections::HashMap, time::Duration};
 
-use ntest::{assert_false, assert_true};
-use systems::{electrical::Electricity, fuel::RefuelRate, simulation::test::ReadByName};
-use uom::si::mass::kilogram;
+use ntest::{assert_about_eq, assert_false, assert_true};
+use systems::{
+    accept_iterable,
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    engine::trent_engine::TrentEngine,
+    failures::FailureType,
+    fuel::{FuelType, RefuelRate},
+    ground_services::{GroundService, GroundServiceState, GroundServices, GroundServicesProvider},
+    shared::{
+        arinc429::{Arinc429Word, SignStatus},
+        PotentialOrigin,
+    },
+    simulation::test::ReadByName,
+};
+use uom::si::{length::foot, mass::kilogram, mass_rate, thermodynamic_temperature::degree_celsius};
 
 use super::*;
 use crate::systems::simulation::{
     test::{SimulationTestBed, TestBed, WriteByName},
     Aircraft, SimulationElement, SimulationElementVisitor,
 };
+struct TestAdirs {
+    total_air_temperature: Option<ThermodynamicTemperature>,
+}
+impl TestAdirs {
+    fn new() -> Self {
+        Self {
+            total_air_temperature: None,
+        }
+    }
+
+    fn no_computed_data<T: Copy + Default>() -> Arinc429Word<T> {
+        Arinc429Word::new(T::default(), SignStatus::NoComputedData)
+    }
+}
+impl AdirsMeasurementOutputs for TestAdirs {
+    fn is_fully_aligned(&self, _adiru_number: usize) -> bool {
+        true
+    }
+
+    fn latitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+        Self::no_computed_data()
+    }
+
+    fn longitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+        Self::no_computed_data()
+    }
+
+    fn heading(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+        Self::no_computed_data()
+    }
+
+    fn true_heading(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+        Self::no_computed_data()
+    }
+
+    fn vertical_speed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+        Self::no_computed_data()
+    }
+
+    fn altitude(&self, _adiru_number: usize) -> Arinc429Word<Length> {
+        Self::no_computed_data()
+    }
+
+    fn angle_of_attack(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+        Self::no_computed_data()
+    }
+
+    fn total_air_temperature(
+        &self,
+        _adiru_number: usize,
+    ) -> Arinc429Word<ThermodynamicTemperature> {
+        match self.total_air_temperature {
+            Some(temperature) => Arinc429Word::new(temperature, SignStatus::NormalOperation),
+            None => Self::no_computed_data(),
+        }
+    }
+}
+
 struct FuelTestAircraft {
     fuel: A380Fuel,
+    fuel_overhead: A380FuelOverheadPanel,
+    adirs: TestAdirs,
+    ground_services: GroundServicesProvider,
+    apu_fuel_flow: MassRate,
+    engines: [TrentEngine; 4],
+
+    powered_source: TestElectricitySource,
+    fuel_pump_buses: [ElectricalBus; 5],
+    fuel_pump_buses_are_powered: bool,
+    dc_ess_bus: ElectricalBus,
+    dc_2_bus: ElectricalBus,
+    dc_2_bus_is_powered: bool,
 }
 
 impl FuelTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
             fuel: A380Fuel::new(context),
+            fuel_overhead: A380FuelOverheadPanel::new(context),
+            adirs: TestAdirs::new(),
+            ground_services: GroundServicesProvider::new(context),
+            apu_fuel_flow: MassRate::default(),
+            engines: [1, 2, 3, 4].map(|number| TrentEngine::new(context, number)),
+
+            powered_source: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            fuel_pump_buses: [
+                ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(2)),
+                ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(3)),
+                ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(4)),
+                ElectricalBus::new(context, ElectricalBusType::AlternatingCurrentEssential),
+            ],
+            fuel_pump_buses_are_powered: true,
+            dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+            dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+            dc_2_bus_is_powered: true,
         }
     }
 
+    fn set_fuel_pump_buses_power(&mut self, is_powered: bool) {
+        self.fuel_pump_buses_are_powered = is_powered;
+    }
+
+    fn set_total_air_temperature(&mut self, temperature: Option<ThermodynamicTemperature>) {
+        self.adirs.total_air_temperature = temperature;
+    }
+
+    fn set_dc_2_bus_power(&mut self, is_powered: bool) {
+        self.dc_2_bus_is_powered = is_powered;
+    }
+
+    fn set_apu_fuel_flow(&mut self, fuel_flow: MassRate) {
+        self.apu_fuel_flow = fuel_flow;
+    }
+
+    fn set_target_load_within_limits(&mut self, target_load_within_limits: bool) {
+        self.fuel
+            .set_target_load_within_limits(target_load_within_limits);
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.fuel.fore_aft_center_of_gravity()
     }
 
+    fn total_target_load(&self) -> Mass {
+        self.fuel.total_target_load()
+    }
+
     fn tank_mass(&self, tank: usize) -> Mass {
         self.fuel.tank_mass(tank)
     }
+
+    fn refuel_service_state(&self) -> GroundServiceState {
+        self.ground_services.state(GroundService::Refuel)
+    }
 }
 
 impl Aircraft for FuelTestAircraft {
     fn update_before_power_distribution(
         &mut self,
         context: &UpdateContext,
-        _electricity: &mut Electricity,
+        electricity: &mut Electricity,
     ) {
-        self.fuel.update(context);
+        electricity.supplied_by(&self.powered_source);
+
+        if self.fuel_pump_buses_are_powered {
+            for bus in &self.fuel_pump_buses {
+                electricity.flow(&self.powered_source, bus);
+            }
+        }
+        electricity.flow(&self.powered_source, &self.dc_ess_bus);
+        if self.dc_2_bus_is_powered {
+            electricity.flow(&self.powered_source, &self.dc_2_bus);
+        }
+
+        self.ground_services.update(context);
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            &self.adirs,
+            &mut self.ground_services,
+            self.apu_fuel_flow,
+            self.engines.each_ref(),
+        );
     }
 }
 impl SimulationElement for FuelTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
+        self.ground_services.accept(visitor);
+        accept_iterable!(self.engines, visitor);
+        accept_iterable!(self.fuel_pump_buses, visitor);
+        self.dc_ess_bus.accept(visitor);
+        self.dc_2_bus.accept(visitor);
 
         visitor.visit(self);
     }
@@ -219,6 +377,198 @@ impl FuelTestBed {
         self
     }
 
+    fn request_refuel_service(mut self) -> Self {
+        self.write_by_name("GROUND_SERVICES_TIMELINE_ENABLED", true);
+        self.write_by_name("GROUND_SERVICES_REFUEL_REQUESTED", true);
+        self
+    }
+
+    fn mode_select(mut self, mode: f64) -> Self {
+        self.write_by_name("REFUEL_PANEL_MODE_SELECT", mode);
+        self
+    }
+
+    fn manual_refuel_mode(self) -> Self {
+        self.mode_select(2.)
+    }
+
+    fn defuel_mode(self) -> Self {
+        self.mode_select(3.)
+    }
+
+    fn transfer_mode(self) -> Self {
+        self.mode_select(4.)
+    }
+
+    fn desired_tank_quantities(mut self, quantities: &HashMap<A380FuelTankType, Mass>) -> Self {
+        for tank in A380FuelTankType::iterator() {
+            self.write_by_name(
+                &format!("FUEL_DESIRED_TANK_{}", tank as usize + 1),
+                quantities
+                    .get(&tank)
+                    .copied()
+                    .unwrap_or_default()
+                    .get::<kilogram>(),
+            );
+        }
+        self
+    }
+
+    fn fuel_pump_buses_unpowered(mut self) -> Self {
+        self.command(|a| a.set_fuel_pump_buses_power(false));
+        self
+    }
+
+    fn in_flight_at(mut self, altitude_feet: f64) -> Self {
+        self.set_on_ground(false);
+        self.set_pressure_altitude(Length::new::<foot>(altitude_feet));
+        for id in 1..=4 {
+            self.write_by_name(&format!("ENGINE_STATE:{id}"), 1.);
+            self.write_by_name(&format!("ENGINE_N2:{id}"), 70.);
+            self.write_by_name(&format!("ENGINE_FF:{id}"), 3000.);
+        }
+        self
+    }
+
+    fn cruise_altitude(mut self, altitude_feet: f64) -> Self {
+        self.write_by_name("AIRLINER_CRUISE_ALTITUDE", altitude_feet);
+        self
+    }
+
+    fn zero_fuel_weight(mut self, zero_fuel_weight_kg: f64, center_of_gravity: f64) -> Self {
+        self.write_by_name("AIRFRAME_ZFW", zero_fuel_weight_kg);
+        self.write_by_name("AIRFRAME_ZFW_CG_PERCENT_MAC", center_of_gravity);
+        self
+    }
+
+    fn set_tank_quantity(&mut self, tank: A380FuelTankType, quantity_kg: f64) {
+        self.write_by_name(
+            &format!("FUEL_TANK_QUANTITY_{}", tank as usize + 1),
+            quantity_kg / FUEL_GALLONS_TO_KG,
+        );
+    }
+
+    fn feed_tank_fwd_transfer(&mut self, number: usize) -> bool {
+        self.read_by_name(&format!("FUEL_FEED_TANK_{number}_FWD_TRANSFER"))
+    }
+
+    fn trim_tank_aft_transfer(&mut self) -> bool {
+        self.read_by_name("FUEL_TRIM_TANK_AFT_TRANSFER")
+    }
+
+    fn trim_tank_forward_transfer(&mut self) -> bool {
+        self.read_by_name("FUEL_TRIM_TANK_FWD_TRANSFER")
+    }
+
+    fn target_load_out_of_limits(mut self) -> Self {
+        self.command(|a| a.set_target_load_within_limits(false));
+        self
+    }
+
+    fn dc_2_bus_unpowered(mut self) -> Self {
+        self.command(|a| a.set_dc_2_bus_power(false));
+        self
+    }
+
+    fn jettison_arm(mut self, is_on: bool) -> Self {
+        self.write_by_name("A380X_OVHD_FUEL_JETTISON_ARM_PB_IS_ON", is_on);
+        self
+    }
+
+    fn jettison_active(mut self, is_on: bool) -> Self {
+        self.write_by_name("A380X_OVHD_FUEL_JETTISON_ACTIVE_PB_IS_ON", is_on);
+        self
+    }
+
+    fn jettison_target_gross_weight(mut self, gross_weight_kg: f64) -> Self {
+        self.write_by_name("FUEL_JETTISON_TARGET_GW", gross_weight_kg);
+        self
+    }
+
+    fn is_jettisoning(&mut self) -> bool {
+        self.read_by_name("FUEL_JETTISON_ACTIVE")
+    }
+
+    fn total_fuel_quantity(&self) -> Mass {
+        A380FuelTankType::iterator()
+            .map(|tank| self.tank_mass(tank as usize))
+            .fold(Mass::default(), |total, quantity| total + quantity)
+    }
+
+    fn total_air_temperature(mut self, temperature_celsius: f64) -> Self {
+        self.command(|a| {
+            a.set_total_air_temperature(Some(ThermodynamicTemperature::new::<degree_celsius>(
+                temperature_celsius,
+            )))
+        });
+        self
+    }
+
+    fn ambient_temperature(mut self, temperature_celsius: f64) -> Self {
+        self.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(
+            temperature_celsius,
+        ));
+        self
+    }
+
+    fn engines_running(mut self) -> Self {
+        for id in 1..=4 {
+            self.write_by_name(&format!("ENGINE_STATE:{id}"), 1.);
+            self.write_by_name(&format!("ENGINE_N2:{id}"), 70.);
+            self.write_by_name(&format!("ENGINE_FF:{id}"), 3000.);
+        }
+        self
+    }
+
+    fn engine_fuel_flow(mut self, kilogram_per_hour: f64) -> Self {
+        for id in 1..=4 {
+            self.write_by_name(&format!("ENGINE_FF:{id}"), kilogram_per_hour);
+        }
+        self
+    }
+
+    fn apu_running_with_fuel_flow(mut self, kilogram_per_hour: f64) -> Self {
+        self.command(|a| {
+            a.set_apu_fuel_flow(MassRate::new::<mass_rate::kilogram_per_hour>(
+                kilogram_per_hour,
+            ))
+        });
+        self
+    }
+
+    fn fuel_type(mut self, fuel_type: FuelType) -> Self {
+        self.write_by_name("FUEL_TYPE", fuel_type);
+        self
+    }
+
+    fn tank_temperature(&mut self, tank: A380FuelTankType) -> f64 {
+        let temperature: ThermodynamicTemperature =
+            self.read_by_name(&format!("FUEL_TANK_TEMPERATURE_{}", tank as usize + 1));
+        temperature.get::<degree_celsius>()
+    }
+
+    fn fuel_temperature_low(&mut self) -> bool {
+        self.read_by_name("FUEL_TEMPERATURE_LOW")
+    }
+
+    fn feed_tank_temperature_high(&mut self) -> bool {
+        self.read_by_name("FUEL_FEED_TANK_TEMPERATURE_HIGH")
+    }
+
+    fn fuel_on_board(&self) -> Mass {
+        A380FuelTankType::iterator().fold(Mass::default(), |total, tank| {
+            total + self.tank_mass(tank as usize)
+        })
+    }
+
+    fn leak_is_suspected(&mut self) -> bool {
+        self.read_by_name("FUEL_LEAK_SUSPECTED")
+    }
+
+    fn fob_fu_discrepancy(&mut self) -> f64 {
+        self.read_by_name("FUEL_FOB_FU_DISCREPANCY")
+    }
+
     fn refuel_status(&mut self) -> bool {
         self.read_by_name("REFUEL_STARTED_BY_USR")
     }
@@ -227,9 +577,17 @@ impl FuelTestBed {
         self.query(|a: &FuelTestAircraft| a.fore_aft_center_of_gravity())
     }
 
+    fn total_target_load(&self) -> Mass {
+        self.query(|a: &FuelTestAircraft| a.total_target_load())
+    }
+
     fn tank_mass(&self, tank: usize) -> Mass {
         self.query(|a: &FuelTestAircraft| a.tank_mass(tank))
     }
+
+    fn refuel_service_state(&self) -> GroundServiceState {
+        self.query(|a| a.refuel_service_state())
+    }
 }
 
 impl TestBed for FuelTestBed {
@@ -269,6 +627,18 @@ fn assert_fuel_quantity(
     }
 }
 
+fn fuel_high_quantities() -> HashMap<A380FuelTankType, Mass> {
+    A380FuelTankType::iterator()
+        .map(|tank| {
+            let quantity = match tank {
+                A380FuelTankType::LeftInner | A380FuelTankType::RightInner => 10000.,
+                _ => 1500.,
+            };
+            (tank, Mass::new::<kilogram>(quantity))
+        })
+        .collect()
+}
+
 fn assert_fuel_quantity_0(test_bed: &FuelTestBed) {
     let expected_quantities = HashMap::new();
     assert_fuel_quantity(test_bed, expected_quantities);
@@ -492,6 +862,61 @@ fn spawn_no_fuel_load_desired_real_50000_done() {
     assert_false!(test_bed.refuel_status());
 }
 
+#[test]
+fn refuel_is_refused_while_the_target_load_is_out_of_limits() {
+    let mut test_bed: FuelTestBed = test_bed();
+    test_bed.set_on_ground(true);
+
+    test_bed = test_bed
+        .desired_fuel_50000()
+        .trigger_real_refuel()
+        .target_load_out_of_limits()
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert_fuel_quantity_0(&test_bed);
+    assert_false!(test_bed.refuel_status());
+}
+
+#[test]
+fn refuel_service_starts_refuel_once_performing() {
+    let mut test_bed: FuelTestBed = test_bed();
+    test_bed.set_on_ground(true);
+
+    test_bed = test_bed
+        .desired_fuel_50000()
+        .request_refuel_service()
+        .and_run()
+        .and_stabilize();
+
+    assert_eq!(
+        test_bed.refuel_service_state(),
+        GroundServiceState::Performing
+    );
+    assert_true!(test_bed.refuel_status());
+}
+
+#[test]
+fn refuel_service_completes_with_refuel() {
+    let mut test_bed: FuelTestBed = test_bed();
+    test_bed.set_on_ground(true);
+
+    let twenty_five_minutes = 25 * MINUTES_TO_SECONDS;
+
+    test_bed = test_bed
+        .desired_fuel_50000()
+        .request_refuel_service()
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(twenty_five_minutes));
+
+    assert_fuel_quantity_50000(&test_bed);
+    assert_false!(test_bed.refuel_status());
+    assert_eq!(
+        test_bed.refuel_service_state(),
+        GroundServiceState::Completed
+    );
+}
+
 #[test]
 fn spawn_no_fuel_load_desired_real_100000_done() {
     let mut test_bed: FuelTestBed = test_bed();
@@ -561,3 +986,869 @@ fn spawn_high_fuel_load_desired_real_200000_done() {
     assert_fuel_quantity_200000(&test_bed);
     assert_false!(test_bed.refuel_status());
 }
+
+#[test]
+fn manual_refuel_instant_fills_tanks_to_their_targets() {
+    let mut desired_quantities = HashMap::new();
+    desired_quantities.insert(A380FuelTankType::LeftOuter, Mass::new::<kilogram>(2000.));
+    desired_quantities.insert(A380FuelTankType::RightOuter, Mass::new::<kilogram>(2000.));
+    desired_quantities.insert(A380FuelTankType::FeedOne, Mass::new::<kilogram>(100.));
+
+    let mut test_bed = test_bed_with()
+        .fuel_low()
+        .manual_refuel_mode()
+        .desired_tank_quantities(&desired_quantities)
+        .trigger_instant_refuel()
+        .and_run();
+
+    let mut expected_quantities: HashMap<A380FuelTankType, Mass> = A380FuelTankType::iterator()
+        .map(|tank| (tank, Mass::new::<kilogram>(300.)))
+        .collect();
+    expected_quantities.insert(A380FuelTankType::LeftOuter, Mass::new::<kilogram>(2000.));
+    expected_quantities.insert(A380FuelTankType::RightOuter, Mass::new::<kilogram>(2000.));
+
+    assert_fuel_quantity(&test_bed, expected_quantities);
+    assert_false!(test_bed.refuel_status());
+}
+
+#[test]
+fn target_load_is_the_manual_refuel_targets_before_the_refuel_starts() {
+    let mut desired_quantities = HashMap::new();
+    desired_quantities.insert(A380FuelTankType::LeftOuter, Mass::new::<kilogram>(2000.));
+    desired_quantities.insert(A380FuelTankType::RightOuter, Mass::new::<kilogram>(2000.));
+    desired_quantities.insert(A380FuelTankType::FeedOne, Mass::new::<kilogram>(100.));
+
+    let test_bed = test_bed_with()
+        .fuel_low()
+        .manual_refuel_mode()
+        .desired_tank_quantities(&desired_quantities)
+        .and_run();
+
+    // The feed tank is already above its target, the other tanks keep their 300 kg.
+    assert_about_eq!(test_bed.fuel_on_board().get::<kilogram>(), 3300.);
+    assert_about_eq!(test_bed.total_target_load().get::<kilogram>(), 6700.);
+}
+
+#[test]
+fn manual_refuel_real_fills_tanks_to_their_targets() {
+    let mut test_bed: FuelTestBed = test_bed();
+    test_bed.set_on_ground(true);
+
+    let mut desired_quantities = HashMap::new();
+    desired_quantities.insert(A380FuelTankType::FeedOne, Mass::new::<kilogram>(5000.));
+    desired_quantities.insert(A380FuelTankType::FeedFour, Mass::new::<kilogram>(5000.));
+
+    let ten_minutes = 10 * MINUTES_TO_SECONDS;
+
+    test_bed = test_bed
+        .fuel_low()
+        .manual_refuel_mode()
+        .desired_tank_quantities(&desired_quantities)
+        .trigger_real_refuel()
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(ten_minutes));
+
+    let mut expected_quantities: HashMap<A380FuelTankType, Mass> = A380FuelTankType::iterator()
+        .map(|tank| (tank, Mass::new::<kilogram>(300.)))
+        .collect();
+    expected_quantities.insert(A380FuelTankType::FeedOne, Mass::new::<kilogram>(5000.));
+    expected_quantities.insert(A380FuelTankType::FeedFour, Mass::new::<kilogram>(5000.));
+
+    assert_fuel_quantity(&test_bed, expected_quantities);
+    assert_false!(test_bed.refuel_status());
+}
+
+#[test]
+fn defuel_real_empties_trim_tank_first() {
+    let mut test_bed: FuelTestBed = test_bed();
+    test_bed.set_on_ground(true);
+
+    test_bed = test_bed.fuel_high().defuel_mode().trigger_real_refuel();
+    test_bed.write_by_name("FUEL_DESIRED", 30000.);
+    test_bed = test_bed.and_run();
+
+    assert!(test_bed.tank_mass(A380FuelTankType::Trim as usize) < Mass::new::<kilogram>(1500.));
+    assert_eq!(
+        test_bed.tank_mass(A380FuelTankType::LeftOuter as usize),
+        Mass::new::<kilogram>(1500.)
+    );
+    assert_true!(test_bed.refuel_status());
+}
+
+#[test]
+fn defuel_real_stops_at_desired_fuel() {
+    let mut test_bed: FuelTestBed = test_bed();
+    test_bed.set_on_ground(true);
+
+    test_bed = test_bed.fuel_high().defuel_mode().trigger_real_refuel();
+    test_bed.write_by_name("FUEL_DESIRED", 30000.);
+    test_bed = test_bed.and_run().and_stabilize();
+
+    let mut expected_quantities = fuel_high_quantities();
+    expected_quantities.insert(A380FuelTankType::Trim, Mass::default());
+    expected_quantities.insert(A380FuelTankType::LeftOuter, Mass::new::<kilogram>(500.));
+    expected_quantities.insert(A380FuelTankType::RightOuter, Mass::new::<kilogram>(500.));
+
+    assert_fuel_quantity(&test_bed, expected_quantities);
+    assert_false!(test_bed.refuel_status());
+}
+
+#[test]
+fn defuel_instant_empties_all_tanks() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .defuel_mode()
+        .desired_fuel_min()
+        .trigger_instant_refuel()
+        .and_run();
+
+    assert_fuel_quantity_0(&test_bed);
+    assert_false!(test_bed.refuel_status());
+}
+
+#[test]
+fn defuel_never_adds_fuel() {
+    let mut test_bed = test_bed_with()
+        .fuel_low()
+        .defuel_mode()
+        .desired_fuel_50000()
+        .trigger_instant_refuel()
+        .and_run();
+
+    let expected_quantities = A380FuelTankType::iterator()
+        .map(|tank| (tank, Mass::new::<kilogram>(300.)))
+        .collect();
+
+    assert_fuel_quantity(&test_bed, expected_quantities);
+    assert_false!(test_bed.refuel_status());
+}
+
+fn inner_to_feed_transfer_quantities() -> HashMap<A380FuelTankType, Mass> {
+    let mut quantities = fuel_high_quantities();
+    quantities.insert(A380FuelTankType::LeftInner, Mass::new::<kilogram>(8000.));
+    quantities.insert(A380FuelTankType::RightInner, Mass::new::<kilogram>(8000.));
+    quantities.insert(A380FuelTankType::FeedOne, Mass::new::<kilogram>(3500.));
+    quantities.insert(A380FuelTankType::FeedFour, Mass::new::<kilogram>(3500.));
+    quantities
+}
+
+#[test]
+fn ground_transfer_instant_moves_fuel_between_tanks() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .transfer_mode()
+        .desired_tank_quantities(&inner_to_feed_transfer_quantities())
+        .and_run()
+        .trigger_instant_refuel()
+        .and_run();
+
+    assert_fuel_quantity(&test_bed, inner_to_feed_transfer_quantities());
+    assert_false!(test_bed.refuel_status());
+}
+
+#[test]
+fn ground_transfer_real_moves_fuel_between_tanks() {
+    let mut test_bed: FuelTestBed = test_bed();
+    test_bed.set_on_ground(true);
+
+    test_bed = test_bed
+        .fuel_high()
+        .transfer_mode()
+        .desired_tank_quantities(&inner_to_feed_transfer_quantities())
+        .and_run()
+        .trigger_real_refuel()
+        .and_run();
+
+    assert!(test_bed.tank_mass(A380FuelTankType::FeedOne as usize) > Mass::new::<kilogram>(1500.));
+    assert_true!(test_bed.refuel_status());
+
+    test_bed = test_bed.and_stabilize();
+
+    assert_fuel_quantity(&test_bed, inner_to_feed_transfer_quantities());
+    assert_false!(test_bed.refuel_status());
+}
+
+#[test]
+fn ground_transfer_requires_powered_pumps_in_the_source_tank() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .fuel_pump_buses_unpowered()
+        .transfer_mode()
+        .desired_tank_quantities(&inner_to_feed_transfer_quantities())
+        .and_run()
+        .trigger_instant_refuel()
+        .and_run();
+
+    assert_fuel_quantity(&test_bed, fuel_high_quantities());
+    assert_false!(test_bed.refuel_status());
+}
+
+#[test]
+fn off_mode_does_not_move_fuel() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .mode_select(1.)
+        .desired_fuel_min()
+        .trigger_instant_refuel()
+        .and_run();
+
+    assert_fuel_quantity(&test_bed, fuel_high_quantities());
+    assert_true!(test_bed.refuel_status());
+}
+
+fn cruising_test_bed(zero_fuel_weight_cg: f64) -> FuelTestBed {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .zero_fuel_weight(300000., zero_fuel_weight_cg)
+        .cruise_altitude(35000.);
+    test_bed.set_on_ground(true);
+
+    test_bed.and_run().in_flight_at(35000.)
+}
+
+#[test]
+fn aft_transfer_to_trim_tank_in_cruise_when_cg_is_forward() {
+    let mut test_bed =
+        cruising_test_bed(30.).run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(test_bed.trim_tank_aft_transfer());
+    assert!(test_bed.tank_mass(A380FuelTankType::Trim as usize) > Mass::new::<kilogram>(1500.));
+    assert!(
+        test_bed.tank_mass(A380FuelTankType::LeftInner as usize) < Mass::new::<kilogram>(10000.)
+    );
+    assert_about_eq!(
+        test_bed
+            .tank_mass(A380FuelTankType::LeftInner as usize)
+            .get::<kilogram>(),
+        test_bed
+            .tank_mass(A380FuelTankType::RightInner as usize)
+            .get::<kilogram>(),
+        1.
+    );
+}
+
+#[test]
+fn aft_transfer_stops_once_cg_reaches_target() {
+    let mut test_bed = cruising_test_bed(38.);
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+    assert!(test_bed.trim_tank_aft_transfer());
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(40 * MINUTES_TO_SECONDS));
+    let trim_quantity = test_bed.tank_mass(A380FuelTankType::Trim as usize);
+
+    assert_false!(test_bed.trim_tank_aft_transfer());
+    assert!(trim_quantity < A380FuelTankType::Trim.capacity());
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+    assert_eq!(
+        test_bed.tank_mass(A380FuelTankType::Trim as usize),
+        trim_quantity
+    );
+}
+
+#[test]
+fn cg_target_moves_aft_with_gross_weight() {
+    let mut light = test_bed_with()
+        .fuel_low()
+        .zero_fuel_weight(300000., 38.)
+        .and_run();
+    let mut heavy = test_bed_with()
+        .fuel_high()
+        .zero_fuel_weight(360000., 38.)
+        .and_run();
+
+    let light_target: f64 = light.read_by_name("FUEL_CG_TARGET_PERCENT_MAC");
+    let heavy_target: f64 = heavy.read_by_name("FUEL_CG_TARGET_PERCENT_MAC");
+
+    assert_about_eq!(light_target, 40.6, 0.1);
+    assert!(heavy_target > light_target);
+}
+
+#[test]
+fn no_cg_control_below_cg_control_altitude() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .zero_fuel_weight(300000., 30.)
+        .cruise_altitude(35000.);
+    test_bed.set_on_ground(true);
+    test_bed = test_bed
+        .and_run()
+        .in_flight_at(20000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert_false!(test_bed.trim_tank_aft_transfer());
+    assert_fuel_quantity(&test_bed, fuel_high_quantities());
+}
+
+#[test]
+fn trim_tank_fuel_is_transferred_forward_after_descent() {
+    let mut test_bed = cruising_test_bed(38.5)
+        .and_run()
+        .in_flight_at(30000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(test_bed.trim_tank_forward_transfer());
+    assert!(test_bed.tank_mass(A380FuelTankType::Trim as usize) < Mass::new::<kilogram>(1500.));
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    assert_eq!(
+        test_bed.tank_mass(A380FuelTankType::Trim as usize),
+        Mass::default()
+    );
+    assert_false!(test_bed.trim_tank_forward_transfer());
+}
+
+#[test]
+fn auto_cg_control_fault_reverts_to_forward_transfer() {
+    let mut test_bed = cruising_test_bed(30.);
+    test_bed.fail(FailureType::FuelAutoCgControlFault);
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert_false!(test_bed.trim_tank_aft_transfer());
+    assert!(test_bed.trim_tank_forward_transfer());
+    assert!(test_bed.tank_mass(A380FuelTankType::Trim as usize) < Mass::new::<kilogram>(1500.));
+}
+
+#[test]
+fn trim_tank_transfer_fault_freezes_trim_tank_quantity() {
+    let mut test_bed = cruising_test_bed(30.);
+    test_bed.fail(FailureType::FuelTrimTankTransferFault);
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert_false!(test_bed.trim_tank_aft_transfer());
+    assert_false!(test_bed.trim_tank_forward_transfer());
+    assert_eq!(
+        test_bed.tank_mass(A380FuelTankType::Trim as usize),
+        Mass::new::<kilogram>(1500.)
+    );
+}
+
+#[test]
+fn trim_tank_transfer_requires_powered_pumps() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .zero_fuel_weight(300000., 30.)
+        .cruise_altitude(35000.)
+        .fuel_pump_buses_unpowered();
+    test_bed.set_on_ground(true);
+    test_bed = test_bed
+        .and_run()
+        .in_flight_at(35000.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert_false!(test_bed.trim_tank_aft_transfer());
+    assert_fuel_quantity(&test_bed, fuel_high_quantities());
+}
+
+#[test]
+fn feed_tanks_are_refilled_from_their_wing_tanks() {
+    let mut test_bed = cruising_test_bed(38.5).and_run();
+    test_bed.set_tank_quantity(A380FuelTankType::FeedOne, 300.);
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(3 * MINUTES_TO_SECONDS));
+
+    assert_about_eq!(
+        test_bed
+            .tank_mass(A380FuelTankType::FeedOne as usize)
+            .get::<kilogram>(),
+        1500.,
+        1.
+    );
+    // The refill comes out of the left wing only.
+    assert!(
+        test_bed.tank_mass(A380FuelTankType::RightInner as usize)
+            - test_bed.tank_mass(A380FuelTankType::LeftInner as usize)
+            > Mass::new::<kilogram>(1100.)
+    );
+}
+
+#[test]
+fn feed_tank_refill_is_indicated_while_it_lasts() {
+    let mut test_bed = cruising_test_bed(38.5).and_run();
+    test_bed.set_tank_quantity(A380FuelTankType::FeedOne, 300.);
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(30));
+
+    assert_true!(test_bed.feed_tank_fwd_transfer(1));
+    assert_false!(test_bed.feed_tank_fwd_transfer(4));
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(3 * MINUTES_TO_SECONDS));
+
+    assert_false!(test_bed.feed_tank_fwd_transfer(1));
+}
+
+#[test]
+fn feed_tanks_are_balanced() {
+    let mut test_bed = cruising_test_bed(38.5).and_run();
+    test_bed.set_tank_quantity(A380FuelTankType::FeedFour, 6000.);
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    let feed_quantities: Vec<f64> = [
+        A380FuelTankType::FeedOne,
+        A380FuelTankType::FeedTwo,
+        A380FuelTankType::FeedThree,
+        A380FuelTankType::FeedFour,
+    ]
+    .iter()
+    .map(|&tank| test_bed.tank_mass(tank as usize).get::<kilogram>())
+    .collect();
+    let imbalance = feed_quantities.iter().cloned().fold(f64::MIN, f64::max)
+        - feed_quantities.iter().cloned().fold(f64::MAX, f64::min);
+
+    assert!(imbalance < 500., "Feed tanks imbalance was {imbalance} kg");
+    assert_about_eq!(feed_quantities.iter().sum::<f64>(), 10500., 1.);
+}
+
+#[test]
+fn outer_tanks_are_transferred_inboard_when_inner_tanks_are_low() {
+    let mut test_bed = test_bed_with()
+        .fuel_low()
+        .zero_fuel_weight(300000., 38.5)
+        .cruise_altitude(35000.);
+    test_bed.set_on_ground(true);
+    test_bed = test_bed
+        .and_run()
+        .in_flight_at(35000.)
+        .run_multiple_frames(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    assert_eq!(
+        test_bed.tank_mass(A380FuelTankType::LeftOuter as usize),
+        Mass::default()
+    );
+    assert_eq!(
+        test_bed.tank_mass(A380FuelTankType::RightOuter as usize),
+        Mass::default()
+    );
+}
+
+#[test]
+fn no_fuel_management_without_running_engines() {
+    let test_bed = test_bed_with()
+        .fuel_high()
+        .zero_fuel_weight(300000., 30.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert_fuel_quantity(&test_bed, fuel_high_quantities());
+}
+
+fn jettisoning_test_bed(zero_fuel_weight_kg: f64) -> FuelTestBed {
+    test_bed_with()
+        .fuel_high()
+        .zero_fuel_weight(zero_fuel_weight_kg, 38.5)
+        .jettison_arm(true)
+        .jettison_active(true)
+}
+
+#[test]
+fn jettison_stops_at_max_landing_weight_without_target() {
+    let mut test_bed =
+        jettisoning_test_bed(370000.).run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert_true!(test_bed.is_jettisoning());
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    assert_false!(test_bed.is_jettisoning());
+    assert_about_eq!(
+        test_bed.total_fuel_quantity().get::<kilogram>(),
+        386000. - 370000.,
+        1.
+    );
+    // Trim and mid tanks are emptied before the inner tanks.
+    let mut expected_quantities = fuel_high_quantities();
+    expected_quantities.insert(A380FuelTankType::Trim, Mass::default());
+    expected_quantities.insert(A380FuelTankType::LeftMid, Mass::default());
+    expected_quantities.insert(A380FuelTankType::RightMid, Mass::default());
+    expected_quantities.insert(A380FuelTankType::LeftInner, Mass::new::<kilogram>(3500.));
+    expected_quantities.insert(A380FuelTankType::RightInner, Mass::new::<kilogram>(3500.));
+    assert_fuel_quantity(&test_bed, expected_quantities);
+}
+
+#[test]
+fn jettison_stops_at_target_gross_weight() {
+    let test_bed = jettisoning_test_bed(370000.)
+        .jettison_target_gross_weight(400000.)
+        .run_multiple_frames(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    let mut expected_quantities = fuel_high_quantities();
+    expected_quantities.insert(A380FuelTankType::Trim, Mass::default());
+    expected_quantities.insert(A380FuelTankType::LeftMid, Mass::new::<kilogram>(500.));
+    expected_quantities.insert(A380FuelTankType::RightMid, Mass::new::<kilogram>(500.));
+    assert_fuel_quantity(&test_bed, expected_quantities);
+}
+
+#[test]
+fn jettison_keeps_the_minimum_quantity_in_feed_and_outer_tanks() {
+    let mut test_bed = test_bed_with()
+        .zero_fuel_weight(360000., 38.5)
+        .jettison_arm(true)
+        .jettison_active(true);
+    for tank in A380FuelTankType::iterator() {
+        test_bed.set_tank_quantity(tank, 10000.);
+    }
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(30 * MINUTES_TO_SECONDS));
+
+    assert_false!(test_bed.is_jettisoning());
+    let expected_quantities = A380FuelTankType::iterator()
+        .map(|tank| {
+            let quantity = match tank {
+                A380FuelTankType::FeedOne
+                | A380FuelTankType::FeedTwo
+                | A380FuelTankType::FeedThree
+                | A380FuelTankType::FeedFour => 8000.,
+                A380FuelTankType::LeftOuter | A380FuelTankType::RightOuter => 10000.,
+                _ => 0.,
+            };
+            (tank, Mass::new::<kilogram>(quantity))
+        })
+        .collect();
+    assert_fuel_quantity(&test_bed, expected_quantities);
+}
+
+#[test]
+fn jettison_requires_arm_and_active() {
+    let mut test_bed = jettisoning_test_bed(370000.)
+        .jettison_arm(false)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert_false!(test_bed.is_jettisoning());
+    assert_fuel_quantity(&test_bed, fuel_high_quantities());
+
+    test_bed = test_bed
+        .jettison_arm(true)
+        .jettison_active(false)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert_false!(test_bed.is_jettisoning());
+    assert_fuel_quantity(&test_bed, fuel_high_quantities());
+}
+
+#[test]
+fn no_jettison_on_ground() {
+    let mut test_bed = jettisoning_test_bed(370000.);
+    test_bed.set_on_ground(true);
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert_false!(test_bed.is_jettisoning());
+    assert_fuel_quantity(&test_bed, fuel_high_quantities());
+}
+
+#[test]
+fn jettison_rate_halves_with_one_valve_unpowered() {
+    let both_valves =
+        jettisoning_test_bed(370000.).run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+    let one_valve = jettisoning_test_bed(370000.)
+        .dc_2_bus_unpowered()
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    let both_valves_jettisoned = 33500. - both_valves.total_fuel_quantity().get::<kilogram>();
+    let one_valve_jettisoned = 33500. - one_valve.total_fuel_quantity().get::<kilogram>();
+
+    assert_about_eq!(both_valves_jettisoned, 2. * 20.8 * 60., 50.);
+    assert_about_eq!(one_valve_jettisoned, 20.8 * 60., 25.);
+}
+
+#[test]
+fn jettison_does_not_restart_until_active_is_cycled() {
+    let mut test_bed = jettisoning_test_bed(370000.)
+        .jettison_target_gross_weight(400000.)
+        .run_multiple_frames(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+    assert_false!(test_bed.is_jettisoning());
+
+    test_bed = test_bed
+        .jettison_target_gross_weight(396500.)
+        .run_multiple_frames(Duration::from_secs(MINUTES_TO_SECONDS));
+    assert_about_eq!(test_bed.total_fuel_quantity().get::<kilogram>(), 30000., 1.);
+
+    test_bed = test_bed
+        .jettison_active(false)
+        .and_run()
+        .jettison_active(true)
+        .run_multiple_frames(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+    assert_about_eq!(test_bed.total_fuel_quantity().get::<kilogram>(), 26500., 1.);
+}
+
+#[test]
+fn fuel_is_cold_soaked_to_ambient_temperature_at_spawn() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .ambient_temperature(-30.)
+        .and_run();
+
+    for tank in A380FuelTankType::iterator() {
+        assert_about_eq!(test_bed.tank_temperature(tank), -30., 0.1);
+    }
+}
+
+#[test]
+fn fuel_cools_down_towards_total_air_temperature() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .ambient_temperature(15.)
+        .and_run()
+        .total_air_temperature(-40.)
+        .run_multiple_frames(Duration::from_secs(60 * MINUTES_TO_SECONDS));
+
+    let inner_tank_temperature = test_bed.tank_temperature(A380FuelTankType::LeftInner);
+    let outer_tank_temperature = test_bed.tank_temperature(A380FuelTankType::LeftOuter);
+
+    assert!(inner_tank_temperature < 15.);
+    assert!(inner_tank_temperature > -40.);
+    // A tank holding little fuel follows the skin temperature much faster.
+    assert!(outer_tank_temperature < inner_tank_temperature);
+    assert!(outer_tank_temperature < -20.);
+}
+
+#[test]
+fn total_air_temperature_is_derived_from_mach_number_without_adr() {
+    let mut test_bed = test_bed_with().fuel_low().ambient_temperature(-56.5);
+    test_bed.write_by_name("AIRSPEED MACH", 0.85);
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(60 * MINUTES_TO_SECONDS));
+
+    // 216.65 K * (1 + 0.2 * 0.85^2)
+    assert_about_eq!(
+        test_bed.tank_temperature(A380FuelTankType::LeftOuter),
+        -25.2,
+        0.5
+    );
+}
+
+#[test]
+fn feed_tanks_are_warmed_by_engine_fuel_recirculation() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .ambient_temperature(15.)
+        .total_air_temperature(15.)
+        .engines_running()
+        .run_multiple_frames(Duration::from_secs(30 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.tank_temperature(A380FuelTankType::FeedOne) > 20.);
+    assert_about_eq!(
+        test_bed.tank_temperature(A380FuelTankType::LeftOuter),
+        15.,
+        0.1
+    );
+}
+
+#[test]
+fn nearly_empty_feed_tank_temperature_stays_bounded_with_engine_fuel_recirculation() {
+    let mut test_bed = test_bed_with()
+        .fuel_low()
+        .ambient_temperature(15.)
+        .total_air_temperature(15.)
+        .engines_running();
+    test_bed.write_by_name("FUEL_TANK_QUANTITY_2", 0.1 / FUEL_GALLONS_TO_KG);
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(30 * MINUTES_TO_SECONDS));
+
+    let temperature = test_bed.tank_temperature(A380FuelTankType::FeedOne);
+    assert!(temperature > 15.);
+    assert!(temperature < 60.);
+}
+
+#[test]
+fn feed_tank_recirculation_heat_grows_with_engine_fuel_flow() {
+    let mut idle = test_bed_with()
+        .fuel_high()
+        .ambient_temperature(15.)
+        .total_air_temperature(15.)
+        .engines_running()
+        .engine_fuel_flow(700.)
+        .run_multiple_frames(Duration::from_secs(30 * MINUTES_TO_SECONDS));
+    let mut take_off = test_bed_with()
+        .fuel_high()
+        .ambient_temperature(15.)
+        .total_air_temperature(15.)
+        .engines_running()
+        .engine_fuel_flow(8000.)
+        .run_multiple_frames(Duration::from_secs(30 * MINUTES_TO_SECONDS));
+
+    let idle_temperature = idle.tank_temperature(A380FuelTankType::FeedOne);
+    assert!(idle_temperature > 15.);
+    assert!(take_off.tank_temperature(A380FuelTankType::FeedOne) > idle_temperature + 1.);
+}
+
+#[test]
+fn fuel_starts_at_preflight_temperature_when_spawning_in_cruise() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .in_flight_at(35000.)
+        .ambient_temperature(-54.)
+        .total_air_temperature(-28.)
+        .and_run();
+
+    assert_about_eq!(
+        test_bed.tank_temperature(A380FuelTankType::LeftOuter),
+        15.,
+        1.
+    );
+    assert_false!(test_bed.fuel_temperature_low());
+}
+
+#[test]
+fn fuel_starts_cold_soaked_on_ground() {
+    let mut test_bed = test_bed_with().fuel_high().ambient_temperature(-20.);
+    test_bed.set_on_ground(true);
+    test_bed = test_bed.and_run();
+
+    assert_about_eq!(
+        test_bed.tank_temperature(A380FuelTankType::LeftOuter),
+        -20.,
+        0.1
+    );
+}
+
+#[test]
+fn fuel_temperature_low_warning_depends_on_fuel_type() {
+    let mut jet_a1 = test_bed_with()
+        .fuel_high()
+        .ambient_temperature(-46.)
+        .total_air_temperature(-46.)
+        .and_run();
+    let mut jet_b = test_bed_with()
+        .fuel_high()
+        .fuel_type(FuelType::JetB)
+        .ambient_temperature(-46.)
+        .total_air_temperature(-46.)
+        .and_run();
+
+    assert_true!(jet_a1.fuel_temperature_low());
+    assert_false!(jet_b.fuel_temperature_low());
+}
+
+#[test]
+fn fuel_temperature_low_warning_clears_once_fuel_warms_up() {
+    let mut test_bed = test_bed_with()
+        .fuel_low()
+        .ambient_temperature(-50.)
+        .total_air_temperature(-50.)
+        .and_run();
+    assert_true!(test_bed.fuel_temperature_low());
+
+    test_bed = test_bed
+        .total_air_temperature(0.)
+        .run_multiple_frames(Duration::from_secs(30 * MINUTES_TO_SECONDS));
+    assert_false!(test_bed.fuel_temperature_low());
+}
+
+#[test]
+fn feed_tank_temperature_high_warning() {
+    let mut test_bed = test_bed_with()
+        .fuel_low()
+        .ambient_temperature(50.)
+        .total_air_temperature(50.)
+        .engines_running()
+        .and_run();
+    assert_false!(test_bed.feed_tank_temperature_high());
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+    assert_true!(test_bed.feed_tank_temperature_high());
+}
+
+#[test]
+fn apu_fuel_is_drawn_from_feed_tank_four() {
+    let mut test_bed = test_bed_with()
+        .fuel_low()
+        .apu_running_with_fuel_flow(120.)
+        .and_run();
+    let feed_four = test_bed.tank_mass(A380FuelTankType::FeedFour as usize);
+    let feed_one = test_bed.tank_mass(A380FuelTankType::FeedOne as usize);
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(30 * MINUTES_TO_SECONDS));
+
+    assert_about_eq!(
+        (feed_four - test_bed.tank_mass(A380FuelTankType::FeedFour as usize)).get::<kilogram>(),
+        60.,
+        1.
+    );
+    assert_about_eq!(
+        test_bed
+            .tank_mass(A380FuelTankType::FeedOne as usize)
+            .get::<kilogram>(),
+        feed_one.get::<kilogram>()
+    );
+}
+
+#[test]
+fn tank_leak_drains_the_tank() {
+    let mut test_bed = test_bed_with().fuel_high().and_run();
+    let fuel_on_board = test_bed.fuel_on_board();
+    let right_mid = test_bed.tank_mass(A380FuelTankType::RightMid as usize);
+    test_bed.fail(FailureType::FuelTankLeak(
+        A380FuelTankType::LeftMid as usize,
+    ));
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    assert_about_eq!(
+        (fuel_on_board - test_bed.fuel_on_board()).get::<kilogram>(),
+        300.,
+        1.
+    );
+    assert_about_eq!(
+        test_bed
+            .tank_mass(A380FuelTankType::RightMid as usize)
+            .get::<kilogram>(),
+        right_mid.get::<kilogram>()
+    );
+}
+
+#[test]
+fn engine_feed_leak_drains_the_fuel_while_the_engine_runs() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .engines_running()
+        .engine_fuel_flow(0.)
+        .and_run();
+    let fuel_on_board = test_bed.fuel_on_board();
+    test_bed.fail(FailureType::FuelEngineFeedLeak(2));
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    assert_about_eq!(
+        (fuel_on_board - test_bed.fuel_on_board()).get::<kilogram>(),
+        600.,
+        1.
+    );
+}
+
+#[test]
+fn engine_feed_leak_stops_with_the_engine() {
+    let mut test_bed = test_bed_with().fuel_high().and_run();
+    let fuel_on_board = test_bed.fuel_on_board();
+    test_bed.fail(FailureType::FuelEngineFeedLeak(2));
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    assert_about_eq!(
+        test_bed.fuel_on_board().get::<kilogram>(),
+        fuel_on_board.get::<kilogram>()
+    );
+}
+
+#[test]
+fn leak_is_suspected_when_fob_and_fuel_used_fall_short_of_fob_at_engine_start() {
+    // Without any fuel flow the fuel used stays at zero, so the discrepancy is the leaked fuel.
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .engines_running()
+        .engine_fuel_flow(0.);
+    test_bed.fail(FailureType::FuelTankLeak(
+        A380FuelTankType::LeftInner as usize,
+    ));
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(30 * MINUTES_TO_SECONDS));
+
+    assert_false!(test_bed.leak_is_suspected());
+    assert_about_eq!(test_bed.fob_fu_discrepancy(), 1800., 5.);
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(5 * MINUTES_TO_SECONDS));
+
+    assert_true!(test_bed.leak_is_suspected());
+}
+
+#[test]
+fn no_leak_is_suspected_without_a_leak() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .engines_running()
+        .engine_fuel_flow(0.)
+        .run_multiple_frames(Duration::from_secs(30 * MINUTES_TO_SECONDS));
+
+    assert_false!(test_bed.leak_is_suspected());
+    assert_about_eq!(test_bed.fob_fu_discrepancy(), 0., 1.);
+}
@@ -782,6 +782,16 @@ mod tests {
         fn angle_of_attack(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
             self.angle_of_attack
         }
+
+        fn total_air_temperature(
+            &self,
+            _adiru_number: usize,
+        ) -> Arinc429Word<ThermodynamicTemperature> {
+            Arinc429Word::new(
+                ThermodynamicTemperature::default(),
+                SignStatus::NoComputedData,
+            )
+        }
     }
     struct A380FlapsTestBed {
         test_bed: SimulationTestBed<A380FlapsTestAircraft>,
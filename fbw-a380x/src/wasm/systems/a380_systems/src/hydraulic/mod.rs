@@ -19,6 +19,7 @@ use uom::si::{
 use systems::{
     accept_iterable,
     engine::Engine,
+    failures::FailureType,
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
         brake::{BrakeAssembly, BrakeProperties},
@@ -51,9 +52,9 @@ use systems::{
         interpolation, random_from_range, update_iterator::MaxStepLoop, AdirsDiscreteOutputs,
         AdirsMeasurementOutputs, AirbusElectricPumpId, AirbusEngineDrivenPumpId, CargoDoorLocked,
         ControllerSignal, DelayedFalseLogicGate, DelayedTrueLogicGate, ElectricalBusType,
-        ElectricalBuses, EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle,
-        LgciuInterface, LgciuWeightOnWheels, ReservoirAirPressure, SectionPressure,
-        SurfacesPositions,
+        ElectricalBuses, EngineFirePushButtons, FlightControlSurface, GearWheel, HydraulicColor,
+        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, ReservoirAirPressure,
+        SectionPressure, SurfacesPositions,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -370,6 +371,21 @@ impl A380CargoDoorFactory {
     }
 }
 
+/// Binds each actuator of a flight control assembly to the leak failure of the
+/// circuit supplying it
+fn set_flight_control_leak_failures<const N: usize>(
+    assembly: &mut HydraulicLinearActuatorAssembly<N>,
+    surface: FlightControlSurface,
+    supply_colors: [HydraulicColor; N],
+) {
+    for (index, color) in supply_colors.into_iter().enumerate() {
+        assembly.set_actuator_external_leak_failure(
+            index,
+            FailureType::FlightControlActuatorLeak(surface, color),
+        );
+    }
+}
+
 struct A380AileronFactory {}
 impl A380AileronFactory {
     const FLOW_CONTROL_PROPORTIONAL_GAIN: f64 = 5.;
@@ -501,24 +517,44 @@ impl A380AileronFactory {
 
     fn new_aileron(context: &mut InitContext, id: ActuatorSide) -> AileronAssembly {
         let init_drooped_down = !context.is_in_flight();
-        let assembly_outward = Self::a380_aileron_assembly(
+        let mut assembly_outward = Self::a380_aileron_assembly(
             context,
             init_drooped_down,
             None,
             AileronPanelPosition::Outward,
         );
-        let assembly_middle = Self::a380_aileron_assembly(
+        let mut assembly_middle = Self::a380_aileron_assembly(
             context,
             init_drooped_down,
             Some(Self::MIDDLE_PANEL_EHA_BUS),
             AileronPanelPosition::Middle,
         );
-        let assembly_inward = Self::a380_aileron_assembly(
+        let mut assembly_inward = Self::a380_aileron_assembly(
             context,
             init_drooped_down,
             Some(Self::INWARD_PANEL_EHA_BUS),
             AileronPanelPosition::Inward,
         );
+
+        let surface = match id {
+            ActuatorSide::Left => FlightControlSurface::LeftAileron,
+            ActuatorSide::Right => FlightControlSurface::RightAileron,
+        };
+        set_flight_control_leak_failures(
+            &mut assembly_outward,
+            surface,
+            [HydraulicColor::Green, HydraulicColor::Yellow],
+        );
+        set_flight_control_leak_failures(
+            &mut assembly_middle,
+            surface,
+            [HydraulicColor::Yellow, HydraulicColor::Green],
+        );
+        set_flight_control_leak_failures(
+            &mut assembly_inward,
+            surface,
+            [HydraulicColor::Green, HydraulicColor::Yellow],
+        );
         AileronAssembly::new(
             context,
             id,
@@ -836,7 +872,7 @@ impl A380ElevatorFactory {
     fn new_elevator(context: &mut InitContext, id: ActuatorSide) -> ElevatorAssembly {
         let init_drooped_down = !context.is_in_flight();
 
-        let assembly_outward = Self::a380_elevator_assembly(
+        let mut assembly_outward = Self::a380_elevator_assembly(
             context,
             init_drooped_down,
             if id == ActuatorSide::Left {
@@ -846,7 +882,7 @@ impl A380ElevatorFactory {
             },
             true,
         );
-        let assembly_inward = Self::a380_elevator_assembly(
+        let mut assembly_inward = Self::a380_elevator_assembly(
             context,
             init_drooped_down,
             if id == ActuatorSide::Left {
@@ -856,6 +892,13 @@ impl A380ElevatorFactory {
             },
             false,
         );
+
+        let (surface, color) = match id {
+            ActuatorSide::Left => (FlightControlSurface::LeftElevator, HydraulicColor::Green),
+            ActuatorSide::Right => (FlightControlSurface::RightElevator, HydraulicColor::Yellow),
+        };
+        set_flight_control_leak_failures(&mut assembly_outward, surface, [color; 2]);
+        set_flight_control_leak_failures(&mut assembly_inward, surface, [color; 2]);
         ElevatorAssembly::new(
             context,
             id,
@@ -1018,20 +1061,31 @@ impl A380RudderFactory {
             || context.start_state() == StartState::Runway
             || context.is_in_flight();
 
-        let upper_assembly = Self::a380_rudder_assembly(
+        let mut upper_assembly = Self::a380_rudder_assembly(
             context,
             init_at_center,
             true,
             Self::UPPER_AND_LOWER_PANEL_UPPER_EBHA_BUS,
             Self::UPPER_PANEL_LOWER_EBHA_BUS,
         );
-        let lower_assembly = Self::a380_rudder_assembly(
+        let mut lower_assembly = Self::a380_rudder_assembly(
             context,
             init_at_center,
             false,
             Self::UPPER_AND_LOWER_PANEL_UPPER_EBHA_BUS,
             Self::LOWER_PANEL_LOWER_EBHA_BUS,
         );
+
+        set_flight_control_leak_failures(
+            &mut upper_assembly,
+            FlightControlSurface::Rudder,
+            [HydraulicColor::Yellow, HydraulicColor::Green],
+        );
+        set_flight_control_leak_failures(
+            &mut lower_assembly,
+            FlightControlSurface::Rudder,
+            [HydraulicColor::Green, HydraulicColor::Yellow],
+        );
         RudderAssembly::new(
             context,
             upper_assembly,
@@ -7158,6 +7212,15 @@ mod tests {
             fn angle_of_attack(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
                 Arinc429Word::new(Angle::default(), SignStatus::NormalOperation)
             }
+            fn total_air_temperature(
+                &self,
+                _adiru_number: usize,
+            ) -> Arinc429Word<ThermodynamicTemperature> {
+                Arinc429Word::new(
+                    ThermodynamicTemperature::default(),
+                    SignStatus::NoComputedData,
+                )
+            }
         }
 
         struct A380TestPneumatics {
@@ -7533,6 +7596,11 @@ mod tests {
             }
 
             fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+                self.engine_1.update(context);
+                self.engine_2.update(context);
+                self.engine_3.update(context);
+                self.engine_4.update(context);
+
                 self.electrical.update(context);
 
                 self.adirus.update(context);
@@ -20,7 +20,7 @@ use self::{
     air_conditioning::{A380AirConditioning, A380PressurizationOverheadPanel},
     avionics_data_communication_network::A380AvionicsDataCommunicationNetwork,
     control_display_system::A380ControlDisplaySystem,
-    fuel::A380Fuel,
+    fuel::{A380Fuel, A380FuelOverheadPanel},
     pneumatic::{A380Pneumatic, A380PneumaticOverheadPanel},
     structural_flex::A380StructuralFlex,
 };
@@ -42,13 +42,20 @@ use uom::si::{f64::Length, length::nautical_mile};
 
 use systems::{
     accept_iterable,
+    airframe::Loadsheet,
     apu::{
         AuxiliaryPowerUnit, AuxiliaryPowerUnitFactory, AuxiliaryPowerUnitFireOverheadPanel,
         AuxiliaryPowerUnitOverheadPanel, Pw980ApuGenerator, Pw980Constants, Pw980StartMotor,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
-    engine::{reverser_thrust::ReverserForce, trent_engine::TrentEngine, EngineFireOverheadPanel},
+    engine::{
+        reverser_thrust::ReverserForce,
+        trent_engine::TrentEngine,
+        vibration_monitoring::{EngineVibrationCharacteristics, EngineVibrationMonitoringUnit},
+        EngineFireOverheadPanel,
+    },
     enhanced_gpwc::EnhancedGroundProximityWarningComputer,
+    ground_services::GroundServicesProvider,
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
         AirDataInertialReferenceSystem, AirDataInertialReferenceSystemOverheadPanel,
@@ -68,6 +75,7 @@ pub struct A380 {
     apu: AuxiliaryPowerUnit<Pw980ApuGenerator, Pw980StartMotor, Pw980Constants, 2>,
     apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
     apu_overhead: AuxiliaryPowerUnitOverheadPanel,
+    ground_services: GroundServicesProvider,
     pneumatic_overhead: A380PneumaticOverheadPanel,
     pressurization_overhead: A380PressurizationOverheadPanel,
     electrical_overhead: A380ElectricalOverheadPanel,
@@ -76,11 +84,13 @@ pub struct A380 {
     airframe: A380Airframe,
     fire_and_smoke_protection: A380FireAndSmokeProtection,
     fuel: A380Fuel,
+    fuel_overhead: A380FuelOverheadPanel,
     engine_1: TrentEngine,
     engine_2: TrentEngine,
     engine_3: TrentEngine,
     engine_4: TrentEngine,
     engine_fire_overhead: EngineFireOverheadPanel<4>,
+    engine_vibration_monitoring: EngineVibrationMonitoringUnit<4>,
     electrical: A380Electrical,
     power_consumption: A380PowerConsumption,
     ext_pwrs: [ExternalPowerSource; 4],
@@ -119,6 +129,7 @@ impl A380 {
             ),
             apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel::new(context),
             apu_overhead: AuxiliaryPowerUnitOverheadPanel::new(context),
+            ground_services: GroundServicesProvider::new(context),
             pneumatic_overhead: A380PneumaticOverheadPanel::new(context),
             pressurization_overhead: A380PressurizationOverheadPanel::new(context),
             electrical_overhead: A380ElectricalOverheadPanel::new(context),
@@ -127,11 +138,16 @@ impl A380 {
             airframe: A380Airframe::new(context),
             fire_and_smoke_protection: A380FireAndSmokeProtection::new(context),
             fuel: A380Fuel::new(context),
+            fuel_overhead: A380FuelOverheadPanel::new(context),
             engine_1: TrentEngine::new(context, 1),
             engine_2: TrentEngine::new(context, 2),
             engine_3: TrentEngine::new(context, 3),
             engine_4: TrentEngine::new(context, 4),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
+            engine_vibration_monitoring: EngineVibrationMonitoringUnit::new(
+                context,
+                EngineVibrationCharacteristics::trent_900(),
+            ),
             electrical: A380Electrical::new(context),
             power_consumption: A380PowerConsumption::new(context),
             ext_pwrs: [1, 2, 3, 4].map(|i| ExternalPowerSource::new(context, i)),
@@ -173,6 +189,11 @@ impl A380 {
             reverse_thrust: ReverserForce::new(context),
         }
     }
+
+    /// The final loadsheet, built from the current payload and fuel.
+    pub fn loadsheet(&self) -> &Loadsheet {
+        self.airframe.loadsheet()
+    }
 }
 impl Aircraft for A380 {
     fn update_before_power_distribution(
@@ -180,6 +201,11 @@ impl Aircraft for A380 {
         context: &UpdateContext,
         electricity: &mut Electricity,
     ) {
+        self.engine_1.update(context);
+        self.engine_2.update(context);
+        self.engine_3.update(context);
+        self.engine_4.update(context);
+
         self.apu.update_before_electrical(
             context,
             &self.apu_overhead,
@@ -219,7 +245,10 @@ impl Aircraft for A380 {
             .update_after_electrical(&self.electrical, electricity);
         self.emergency_electrical_overhead
             .update_after_electrical(context, &self.electrical);
-        self.payload.update(context);
+        self.ground_services.update(context);
+        self.payload
+            .set_target_load_within_limits(self.airframe.is_target_within_limits());
+        self.payload.update(context, &self.ground_services);
         self.airframe
             .update(&self.fuel, &self.payload, &self.payload);
     }
@@ -329,12 +358,36 @@ impl Aircraft for A380 {
             &self.hydraulic,
             &self.fuel,
         );
+        self.engine_vibration_monitoring.update(
+            context,
+            [
+                &self.engine_1,
+                &self.engine_2,
+                &self.engine_3,
+                &self.engine_4,
+            ],
+            self.structural_flex.engines_wobble_amplitudes(),
+        );
         self.cds.update();
 
         self.icing_simulation.update(context);
 
         self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
-        self.fuel.update(context);
+        self.fuel
+            .set_target_load_within_limits(self.airframe.is_target_within_limits());
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            &self.adirs,
+            &mut self.ground_services,
+            self.apu.fuel_flow(),
+            [
+                &self.engine_1,
+                &self.engine_2,
+                &self.engine_3,
+                &self.engine_4,
+            ],
+        );
 
         self.engine_reverser_control[0].update(
             &self.engine_2,
@@ -367,10 +420,12 @@ impl SimulationElement for A380 {
         self.apu.accept(visitor);
         self.apu_fire_overhead.accept(visitor);
         self.apu_overhead.accept(visitor);
+        self.ground_services.accept(visitor);
         self.electrical_overhead.accept(visitor);
         self.emergency_electrical_overhead.accept(visitor);
         self.fire_and_smoke_protection.accept(visitor);
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
         self.payload.accept(visitor);
         self.airframe.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
@@ -380,6 +435,7 @@ impl SimulationElement for A380 {
         self.engine_3.accept(visitor);
         self.engine_4.accept(visitor);
         self.engine_fire_overhead.accept(visitor);
+        self.engine_vibration_monitoring.accept(visitor);
         self.electrical.accept(visitor);
         self.power_consumption.accept(visitor);
         accept_iterable!(self.ext_pwrs, visitor);
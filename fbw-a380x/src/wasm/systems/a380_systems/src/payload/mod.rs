@@ -5,6 +5,7 @@ use std::{cell::Cell, rc::Rc};
 use uom::si::{f64::Mass, mass::kilogram};
 
 use systems::{
+    ground_services::GroundServices,
     payload::{
         BoardingAgent, BoardingSounds, Cargo, CargoDeck, CargoInfo, CargoPayload,
         NumberOfPassengers, PassengerDeck, PassengerPayload, Pax, PaxInfo, PayloadManager,
@@ -274,8 +275,18 @@ impl A380Payload {
         }
     }
 
-    pub(crate) fn update(&mut self, context: &UpdateContext) {
-        self.payload_manager.update(context.delta());
+    pub(crate) fn update(
+        &mut self,
+        context: &UpdateContext,
+        ground_services: &impl GroundServices,
+    ) {
+        self.payload_manager
+            .update(context.delta(), ground_services);
+    }
+
+    pub(crate) fn set_target_load_within_limits(&mut self, target_load_within_limits: bool) {
+        self.payload_manager
+            .set_target_load_within_limits(target_load_within_limits);
     }
 
     fn pax_num(&self, ps: usize) -> i8 {
@@ -427,6 +438,14 @@ impl PassengerPayload for A380Payload {
     fn target_fore_aft_center_of_gravity(&self) -> f64 {
         self.target_passenger_center_of_gravity().x
     }
+
+    fn station_passengers(&self, ps: usize) -> i8 {
+        self.payload_manager.pax_num(ps)
+    }
+
+    fn station_passenger_load(&self, ps: usize) -> Mass {
+        self.payload_manager.pax_payload(ps)
+    }
 }
 impl CargoPayload for A380Payload {
     fn total_cargo_load(&self) -> Mass {
@@ -452,6 +471,10 @@ impl CargoPayload for A380Payload {
     fn target_fore_aft_center_of_gravity(&self) -> f64 {
         self.target_cargo_center_of_gravity().x
     }
+
+    fn station_cargo_load(&self, cs: usize) -> Mass {
+        self.payload_manager.cargo(cs)
+    }
 }
 impl SimulationElement for A380Payload {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -6,7 +6,8 @@ use std::time::Duration;
 use rand::seq::IteratorRandom;
 use rand::SeedableRng;
 use systems::electrical::Electricity;
-use systems::payload::{BoardingRate, GsxState};
+use systems::ground_services::{GroundServiceState, GroundServicesProvider};
+use systems::payload::{BoardingRate, BoardingStrategy};
 use uom::si::f64::Ratio;
 use uom::si::mass::pound;
 use uom::si::ratio::percent;
@@ -20,12 +21,14 @@ use crate::systems::simulation::{
 
 struct BoardingTestAircraft {
     payload: A380Payload,
+    ground_services: GroundServicesProvider,
 }
 
 impl BoardingTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
             payload: A380Payload::new(context),
+            ground_services: GroundServicesProvider::new(context),
         }
     }
 
@@ -104,12 +107,14 @@ impl Aircraft for BoardingTestAircraft {
         context: &UpdateContext,
         _electricity: &mut Electricity,
     ) {
-        self.payload.update(context);
+        self.ground_services.update(context);
+        self.payload.update(context, &self.ground_services);
     }
 }
 impl SimulationElement for BoardingTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.payload.accept(visitor);
+        self.ground_services.accept(visitor);
 
         visitor.visit(self);
     }
@@ -152,12 +157,36 @@ impl BoardingTestBed {
         self
     }
 
+    fn init_vars_timeline(mut self) -> Self {
+        self.write_by_name("GROUND_SERVICES_TIMELINE_ENABLED", true);
+
+        self
+    }
+
+    fn request_ground_service(mut self, service: &str) -> Self {
+        self.write_by_name(&format!("GROUND_SERVICES_{}_REQUESTED", service), true);
+
+        self
+    }
+
     fn instant_board_rate(mut self) -> Self {
         self.write_by_name("BOARDING_RATE", BoardingRate::Instant);
 
         self
     }
 
+    fn boarding_strategy(mut self, boarding_strategy: BoardingStrategy) -> Self {
+        self.write_by_name("BOARDING_STRATEGY", boarding_strategy);
+
+        self
+    }
+
+    fn and_run_for(mut self, duration: Duration) -> Self {
+        self.test_bed.run_multiple_frames(duration);
+
+        self
+    }
+
     fn fast_board_rate(mut self) -> Self {
         self.write_by_name("BOARDING_RATE", BoardingRate::Fast);
 
@@ -184,33 +213,33 @@ impl BoardingTestBed {
     }
 
     fn gsx_requested_board_state(mut self) -> Self {
-        self.write_by_name("FSDT_GSX_BOARDING_STATE", GsxState::Requested);
+        self.write_by_name("FSDT_GSX_BOARDING_STATE", GroundServiceState::Requested);
         self
     }
 
     fn gsx_performing_board_state(mut self) -> Self {
-        self.write_by_name("FSDT_GSX_BOARDING_STATE", GsxState::Performing);
+        self.write_by_name("FSDT_GSX_BOARDING_STATE", GroundServiceState::Performing);
         self
     }
 
     fn gsx_performing_deboard_state(mut self) -> Self {
-        self.write_by_name("FSDT_GSX_DEBOARDING_STATE", GsxState::Performing);
+        self.write_by_name("FSDT_GSX_DEBOARDING_STATE", GroundServiceState::Performing);
         self.write_by_name("FSDT_GSX_DEBOARDING_CARGO_PERCENT", 0.);
         self
     }
 
     fn gsx_requested_deboard_state(mut self) -> Self {
-        self.write_by_name("FSDT_GSX_DEBOARDING_STATE", GsxState::Requested);
+        self.write_by_name("FSDT_GSX_DEBOARDING_STATE", GroundServiceState::Requested);
         self
     }
 
     fn gsx_complete_board_state(mut self) -> Self {
-        self.write_by_name("FSDT_GSX_BOARDING_STATE", GsxState::Completed);
+        self.write_by_name("FSDT_GSX_BOARDING_STATE", GroundServiceState::Completed);
         self
     }
 
     fn gsx_complete_deboard_state(mut self) -> Self {
-        self.write_by_name("FSDT_GSX_DEBOARDING_STATE", GsxState::Completed);
+        self.write_by_name("FSDT_GSX_DEBOARDING_STATE", GroundServiceState::Completed);
         self
     }
 
@@ -394,6 +423,12 @@ impl BoardingTestBed {
         assert!(self.sound_pax_boarding());
     }
 
+    fn has_sound_pax_deboarding(&mut self) {
+        let pax_deboarding: bool = self.read_by_name("SOUND_PAX_DEBOARDING");
+        assert!(pax_deboarding);
+        assert!(self.sound_pax_deboarding());
+    }
+
     fn has_no_sound_pax_ambience(&mut self) {
         let pax_ambience: bool = self.read_by_name("SOUND_PAX_AMBIENCE");
         assert!(!self.sound_pax_ambience());
@@ -1553,6 +1588,55 @@ fn detailed_test_with_multiple_stops() {
     test_bed.sound_boarding_complete_reset();
 }
 
+#[test]
+fn timeline_boarding_full_pax() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .init_vars_timeline()
+        .target_full_pax()
+        .target_full_cargo()
+        .request_ground_service("BOARDING")
+        .request_ground_service("CARGO")
+        .and_run()
+        .and_run_for(Duration::from_secs(510));
+
+    test_bed.has_sound_pax_boarding();
+    let pax_num = test_bed.total_pax_num();
+    assert!(pax_num > 0);
+    test_bed.has_no_sound_pax_deboarding();
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(600)).and_run();
+
+    assert!(test_bed.total_pax_num() > pax_num);
+    test_bed.has_full_pax();
+    test_bed.has_full_cargo();
+    test_bed.has_no_sound_pax_boarding();
+    test_bed.has_sound_pax_complete();
+}
+
+#[test]
+fn timeline_deboarding_full_pax() {
+    let mut test_bed = test_bed_with()
+        .init_vars()
+        .init_vars_timeline()
+        .with_full_pax()
+        .with_full_cargo()
+        .target_no_pax()
+        .target_no_cargo()
+        .request_ground_service("DEBOARDING")
+        .and_run()
+        .and_run_for(Duration::from_secs(360));
+
+    test_bed.has_sound_pax_deboarding();
+    test_bed.has_no_sound_pax_boarding();
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(360)).and_run();
+
+    test_bed.has_no_pax();
+    test_bed.has_no_cargo();
+    test_bed.has_no_sound_pax_deboarding();
+}
+
 #[test]
 fn disable_if_gsx_enabled() {
     let mut test_bed = test_bed_with()
@@ -1721,3 +1805,21 @@ fn gsx_deboarding_half_pax() {
     test_bed.has_no_sound_pax_ambience();
     test_bed.sound_boarding_complete_reset();
 }
+
+#[test]
+fn back_to_front_boarding_fills_the_aft_stations_first() {
+    let test_bed = test_bed_with()
+        .init_vars()
+        .target_full_pax()
+        .fast_board_rate()
+        .boarding_strategy(BoardingStrategy::BackToFront)
+        .start_boarding()
+        .and_run_for(Duration::from_secs(20));
+
+    let aft_pax = test_bed
+        .query(|a| a.pax_num(A380Pax::MainAftA as usize) + a.pax_num(A380Pax::MainAftB as usize))
+        as i32;
+
+    assert!(aft_pax > 0);
+    assert_eq!(aft_pax, test_bed.query(|a| a.total_pax_num()));
+}
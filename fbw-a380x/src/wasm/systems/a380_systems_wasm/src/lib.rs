@@ -32,12 +32,13 @@ use reversers::reversers;
 use rudder::rudder;
 use spoilers::spoilers;
 use std::error::Error;
-use systems::air_conditioning::{Channel, FdacId, OcsmId, VcmId};
+use systems::air_conditioning::{Channel, FdacId, OcsmId, VcmId, ZoneType};
 use systems::failures::FailureType;
 use systems::integrated_modular_avionics::core_processing_input_output_module::CpiomId;
 use systems::shared::{
     AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FireDetectionLoopID,
-    FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    FireDetectionZone, FlightControlSurface, GearActuatorId, HydraulicColor, HydraulicSectionId,
+    LgciuId, ProximityDetectorId,
 };
 
 use systems_wasm::{MsfsSimulationBuilder, Variable};
@@ -131,6 +132,97 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (21_047, FailureType::CpcsApp(CpiomId::B2)),
         (21_048, FailureType::CpcsApp(CpiomId::B3)),
         (21_049, FailureType::CpcsApp(CpiomId::B4)),
+        (21_050, FailureType::DoorSealLeak(1)),
+        (21_051, FailureType::DoorSealLeak(2)),
+        (21_052, FailureType::DoorSealLeak(3)),
+        (21_053, FailureType::DoorSealLeak(4)),
+        (21_054, FailureType::DoorSealLeak(5)),
+        (21_055, FailureType::DoorSealLeak(6)),
+        (21_056, FailureType::DoorSealLeak(7)),
+        (21_057, FailureType::DoorSealLeak(8)),
+        (21_058, FailureType::DoorSealLeak(9)),
+        (21_059, FailureType::DoorSealLeak(10)),
+        (21_060, FailureType::DoorSealLeak(11)),
+        (21_061, FailureType::DoorSealLeak(12)),
+        (21_062, FailureType::DoorSealLeak(13)),
+        (21_063, FailureType::DoorSealLeak(14)),
+        (21_064, FailureType::DoorSealLeak(15)),
+        (21_065, FailureType::DoorSealLeak(16)),
+        (21_066, FailureType::DoorSealLeak(17)),
+        (21_067, FailureType::DoorSealLeak(18)),
+        (21_068, FailureType::DoorSealLeak(19)),
+        (
+            21_069,
+            FailureType::ProgressiveHullBreach(ZoneType::Cockpit),
+        ),
+        (
+            21_070,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(11)),
+        ),
+        (
+            21_071,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(12)),
+        ),
+        (
+            21_072,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(13)),
+        ),
+        (
+            21_073,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(14)),
+        ),
+        (
+            21_074,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(15)),
+        ),
+        (
+            21_075,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(16)),
+        ),
+        (
+            21_076,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(17)),
+        ),
+        (
+            21_077,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(18)),
+        ),
+        (
+            21_078,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(21)),
+        ),
+        (
+            21_079,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(22)),
+        ),
+        (
+            21_080,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(23)),
+        ),
+        (
+            21_081,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(24)),
+        ),
+        (
+            21_082,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(25)),
+        ),
+        (
+            21_083,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(26)),
+        ),
+        (
+            21_084,
+            FailureType::ProgressiveHullBreach(ZoneType::Cabin(27)),
+        ),
+        (
+            21_085,
+            FailureType::ProgressiveHullBreach(ZoneType::Cargo(1)),
+        ),
+        (
+            21_086,
+            FailureType::ProgressiveHullBreach(ZoneType::Cargo(2)),
+        ),
         (24_000, FailureType::TransformerRectifier(1)),
         (24_001, FailureType::TransformerRectifier(2)),
         (24_002, FailureType::TransformerRectifier(3)),
@@ -268,6 +360,79 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             26_018,
             FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Mlg),
         ),
+        (
+            27_100,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_101,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::LeftAileron,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_102,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_103,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::RightAileron,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_104,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::LeftElevator,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_105,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::RightElevator,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (
+            27_106,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Green,
+            ),
+        ),
+        (
+            27_107,
+            FailureType::FlightControlActuatorLeak(
+                FlightControlSurface::Rudder,
+                HydraulicColor::Yellow,
+            ),
+        ),
+        (28_000, FailureType::FuelAutoCgControlFault),
+        (28_001, FailureType::FuelTrimTankTransferFault),
+        (28_010, FailureType::FuelTankLeak(0)),
+        (28_011, FailureType::FuelTankLeak(1)),
+        (28_012, FailureType::FuelTankLeak(2)),
+        (28_013, FailureType::FuelTankLeak(3)),
+        (28_014, FailureType::FuelTankLeak(4)),
+        (28_015, FailureType::FuelTankLeak(5)),
+        (28_016, FailureType::FuelTankLeak(6)),
+        (28_017, FailureType::FuelTankLeak(7)),
+        (28_018, FailureType::FuelTankLeak(8)),
+        (28_019, FailureType::FuelTankLeak(9)),
+        (28_020, FailureType::FuelTankLeak(10)),
+        (28_030, FailureType::FuelEngineFeedLeak(1)),
+        (28_031, FailureType::FuelEngineFeedLeak(2)),
+        (28_032, FailureType::FuelEngineFeedLeak(3)),
+        (28_033, FailureType::FuelEngineFeedLeak(4)),
         (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
         (29_001, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
         (29_002, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
@@ -331,6 +496,62 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             29_017,
             FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp4b),
         ),
+        (
+            29_018,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::System),
+        ),
+        (
+            29_019,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::System),
+        ),
+        (
+            29_020,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(1)),
+        ),
+        (
+            29_021,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(2)),
+        ),
+        (
+            29_022,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(3)),
+        ),
+        (
+            29_023,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(4)),
+        ),
+        (
+            29_024,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(5)),
+        ),
+        (
+            29_025,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(6)),
+        ),
+        (
+            29_026,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(1)),
+        ),
+        (
+            29_027,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(2)),
+        ),
+        (
+            29_028,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(3)),
+        ),
+        (
+            29_029,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(4)),
+        ),
+        (
+            29_030,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(5)),
+        ),
+        (
+            29_031,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(6)),
+        ),
         (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
         (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
         (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
@@ -407,6 +628,30 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             32_025,
             FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
         ),
+        (
+            32_030,
+            FailureType::GearActuatorLeak(GearActuatorId::GearNose),
+        ),
+        (
+            32_031,
+            FailureType::GearActuatorLeak(GearActuatorId::GearLeft),
+        ),
+        (
+            32_032,
+            FailureType::GearActuatorLeak(GearActuatorId::GearRight),
+        ),
+        (
+            32_033,
+            FailureType::GearActuatorLeak(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_034,
+            FailureType::GearActuatorLeak(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_035,
+            FailureType::GearActuatorLeak(GearActuatorId::GearDoorRight),
+        ),
         (34_000, FailureType::RadioAltimeter(1)),
         (34_001, FailureType::RadioAltimeter(2)),
         (34_002, FailureType::RadioAltimeter(3)),
@@ -416,6 +661,44 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (34_020, FailureType::RadioAntennaDirectCoupling(1)),
         (34_021, FailureType::RadioAntennaDirectCoupling(2)),
         (34_022, FailureType::RadioAntennaDirectCoupling(3)),
+        (34_100, FailureType::AirDataReference(1)),
+        (34_101, FailureType::AirDataReference(2)),
+        (34_102, FailureType::AirDataReference(3)),
+        (34_110, FailureType::AirDataReferenceFault(1)),
+        (34_111, FailureType::AirDataReferenceFault(2)),
+        (34_112, FailureType::AirDataReferenceFault(3)),
+        (34_120, FailureType::InertialReference(1)),
+        (34_121, FailureType::InertialReference(2)),
+        (34_122, FailureType::InertialReference(3)),
+        (34_130, FailureType::InertialReferenceAttitudeOnly(1)),
+        (34_131, FailureType::InertialReferenceAttitudeOnly(2)),
+        (34_132, FailureType::InertialReferenceAttitudeOnly(3)),
+        (34_140, FailureType::InertialReferenceAlignmentFault(1)),
+        (34_141, FailureType::InertialReferenceAlignmentFault(2)),
+        (34_142, FailureType::InertialReferenceAlignmentFault(3)),
+        (49_000, FailureType::ApuOilLeak),
+        (49_001, FailureType::ApuIgnitionFault),
+        (49_002, FailureType::ApuEgtMarginDegradation),
+        (72_000, FailureType::EngineFanBladeDamage(1)),
+        (72_001, FailureType::EngineFanBladeDamage(2)),
+        (72_002, FailureType::EngineFanBladeDamage(3)),
+        (72_003, FailureType::EngineFanBladeDamage(4)),
+        (72_010, FailureType::EngineBirdStrike(1)),
+        (72_011, FailureType::EngineBirdStrike(2)),
+        (72_012, FailureType::EngineBirdStrike(3)),
+        (72_013, FailureType::EngineBirdStrike(4)),
+        (79_000, FailureType::EngineOilLowQuantity(1)),
+        (79_001, FailureType::EngineOilLowQuantity(2)),
+        (79_002, FailureType::EngineOilLowQuantity(3)),
+        (79_003, FailureType::EngineOilLowQuantity(4)),
+        (79_010, FailureType::EngineOilFilterClog(1)),
+        (79_011, FailureType::EngineOilFilterClog(2)),
+        (79_012, FailureType::EngineOilFilterClog(3)),
+        (79_013, FailureType::EngineOilFilterClog(4)),
+        (79_020, FailureType::EngineOilPressureTransmitter(1)),
+        (79_021, FailureType::EngineOilPressureTransmitter(2)),
+        (79_022, FailureType::EngineOilPressureTransmitter(3)),
+        (79_023, FailureType::EngineOilPressureTransmitter(4)),
     ])
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
@@ -456,7 +739,6 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "gallons", 9)?
     .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "gallons", 10)?
     .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "gallons", 11)?
-    .provides_aircraft_variable("FUELSYSTEM LINE FUEL FLOW", "gallons per hour", 141)?
     .provides_aircraft_variable_range("FUELSYSTEM PUMP ACTIVE", "Bool", 1..21)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 0)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 1)?
@@ -557,11 +839,17 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("PAYLOAD STATION WEIGHT", "Pounds", 18)?
     .provides_named_variable("FSDT_GSX_BOARDING_STATE")?
     .provides_named_variable("FSDT_GSX_DEBOARDING_STATE")?
+    .provides_named_variable("FSDT_GSX_CATERING_STATE")?
+    .provides_named_variable("FSDT_GSX_REFUELING_STATE")?
+    .provides_named_variable("FSDT_GSX_GPU_STATE")?
+    .provides_named_variable("FSDT_GSX_DEPARTURE_STATE")?
     .provides_named_variable("FSDT_GSX_NUMPASSENGERS_BOARDING_TOTAL")?
     .provides_named_variable("FSDT_GSX_NUMPASSENGERS_DEBOARDING_TOTAL")?
     .provides_named_variable("FSDT_GSX_BOARDING_CARGO_PERCENT")?
     .provides_named_variable("FSDT_GSX_DEBOARDING_CARGO_PERCENT")?
     .provides_named_variable("FSDT_GSX_BYPASS_PIN")?
+    .provides_named_variable("A380X_OVHD_FUEL_JETTISON_ARM_PB_IS_ON")?
+    .provides_named_variable("A380X_OVHD_FUEL_JETTISON_ACTIVE_PB_IS_ON")?
     .with_aspect(|builder| {
         builder.copy(
             Variable::named("FSDT_GSX_BYPASS_PIN"),
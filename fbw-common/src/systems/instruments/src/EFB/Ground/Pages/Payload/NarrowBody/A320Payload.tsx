@@ -31,6 +31,7 @@ import { CargoWidget } from './CargoWidget';
 import { ChartWidget } from '../Chart/ChartWidget';
 import { SeatMapWidget } from '../Seating/SeatMapWidget';
 import { PayloadProps } from '../PayloadPage';
+import { useViewListenerEvent } from '../../../../Utils/listener';
 
 export const A320Payload: React.FC<PayloadProps> = ({
   airframeInfo,
@@ -150,6 +151,19 @@ export const A320Payload: React.FC<PayloadProps> = ({
   const [gwCgMac] = useSimVar('L:A32NX_AIRFRAME_GW_CG_PERCENT_MAC', 'number', 1_301);
   const [desiredGwCgMac] = useSimVar('L:A32NX_AIRFRAME_GW_CG_PERCENT_MAC_DESIRED', 'number', 1_447);
 
+  // Loadsheet, broadcast by the systems WASM when it changes
+  const [landingWeight, setLandingWeight] = useState<number | null>(null);
+  const onLoadsheet = (data: string) => {
+    const loadsheet = JSON.parse(data);
+    setLandingWeight(loadsheet.lw);
+  };
+  useViewListenerEvent('JS_LISTENER_COMM_BUS', 'A32NX_AIRFRAME_LOADSHEET', onLoadsheet);
+
+  useEffect(() => {
+    // Ask for the current loadsheet, as it is only broadcast when it changes
+    Coherent.call('COMM_BUS_WASM_CALLBACK', 'FBW_SYSTEMS_STRING_REQUEST', '');
+  }, []);
+
   const [showSimbriefButton, setShowSimbriefButton] = useState(false);
   const [displayZfw, setDisplayZfw] = useState(true);
 
@@ -688,7 +702,7 @@ export const A320Payload: React.FC<PayloadProps> = ({
               cg={boardingStarted ? Math.round(gwCgMac * 100) / 100 : Math.round(desiredGwCgMac * 100) / 100}
               gw={boardingStarted ? Math.round(gw) : Math.round(gwDesired)}
               mldwCg={boardingStarted ? Math.round(gwCgMac * 100) / 100 : Math.round(desiredGwCgMac * 100) / 100}
-              mldw={boardingStarted ? Math.round(gw) : Math.round(gwDesired)}
+              mldw={boardingStarted ? Math.round(landingWeight ?? gw) : Math.round(gwDesired)}
               zfwCg={boardingStarted ? Math.round(zfwCgMac * 100) / 100 : Math.round(desiredZfwCgMac * 100) / 100}
               zfw={boardingStarted ? Math.round(zfw) : Math.round(zfwDesired)}
             />
@@ -157,6 +157,8 @@ impl<const ZONES: usize, const ENGINES: usize> AirConditioningSystemController<Z
         );
 
         let both_channels_failure = self.both_channels_failure();
+        let cabin_co2_concentration = cabin_temperature.cabin_co2_concentration();
+        let cabin_relative_humidity = cabin_temperature.cabin_relative_humidity();
         for zone in self.zone_controller.iter_mut() {
             zone.update(
                 context,
@@ -165,7 +167,8 @@ impl<const ZONES: usize, const ENGINES: usize> AirConditioningSystemController<Z
                 !both_channels_failure,
                 cabin_temperature.cabin_temperature(),
                 pressurization,
-            )
+            );
+            zone.update_air_quality(&cabin_co2_concentration, &cabin_relative_humidity);
         }
 
         self.trim_air_system_controller.update(
@@ -265,6 +268,22 @@ impl<const ZONES: usize, const ENGINES: usize> AirConditioningSystemController<Z
         self.trim_air_system_controller.duct_overheat_monitor()
     }
 
+    /// Returns None when the zone is not regulated by this ACSC
+    pub fn zone_co2_concentration(&self, zone_id: usize) -> Option<Ratio> {
+        self.zone_controller
+            .iter()
+            .find(|zone| zone.zone_id.id() == zone_id)
+            .map(|zone| zone.zone_co2_concentration())
+    }
+
+    /// Returns None when the zone is not regulated by this ACSC
+    pub fn zone_relative_humidity(&self, zone_id: usize) -> Option<Ratio> {
+        self.zone_controller
+            .iter()
+            .find(|zone| zone.zone_id.id() == zone_id)
+            .map(|zone| zone.zone_relative_humidity())
+    }
+
     pub fn galley_fan_fault(&self) -> bool {
         self.zone_controller
             .iter()
@@ -590,6 +609,8 @@ pub struct ZoneController {
     zone_id: ZoneType,
     duct_demand_temperature: ThermodynamicTemperature,
     zone_selected_temperature: ThermodynamicTemperature,
+    zone_co2_concentration: Ratio,
+    zone_relative_humidity: Ratio,
     pid_controller: PidController,
 
     galley_fan_failure: Failure,
@@ -650,6 +671,8 @@ impl ZoneController {
             zone_id: zone_type,
             duct_demand_temperature: ThermodynamicTemperature::new::<degree_celsius>(24.),
             zone_selected_temperature: ThermodynamicTemperature::new::<degree_celsius>(24.),
+            zone_co2_concentration: Ratio::default(),
+            zone_relative_humidity: Ratio::default(),
             pid_controller,
 
             galley_fan_failure: Failure::new(FailureType::GalleyFans),
@@ -687,6 +710,22 @@ impl ZoneController {
             };
     }
 
+    pub fn update_air_quality(
+        &mut self,
+        cabin_co2_concentration: &[Ratio],
+        cabin_relative_humidity: &[Ratio],
+    ) {
+        let zone_id = self.zone_id.id();
+        self.zone_co2_concentration = cabin_co2_concentration
+            .get(zone_id)
+            .copied()
+            .unwrap_or_default();
+        self.zone_relative_humidity = cabin_relative_humidity
+            .get(zone_id)
+            .copied()
+            .unwrap_or_default();
+    }
+
     fn calculate_duct_temp_demand(
         &mut self,
         context: &UpdateContext,
@@ -780,6 +819,14 @@ impl ZoneController {
     fn galley_fan_fault(&self) -> bool {
         self.galley_fan_failure.is_active()
     }
+
+    pub fn zone_co2_concentration(&self) -> Ratio {
+        self.zone_co2_concentration
+    }
+
+    pub fn zone_relative_humidity(&self) -> Ratio {
+        self.zone_relative_humidity
+    }
 }
 
 impl DuctTemperature for ZoneController {
@@ -1420,8 +1467,8 @@ mod acs_controller_tests {
     use super::*;
     use crate::{
         air_conditioning::{
-            cabin_air::CabinAirSimulation, Air, AirConditioningPack, CabinFan, MixerUnit,
-            OutletAir, PressurizationConstants, VcmShared,
+            cabin_air::CabinAirSimulation, Air, AirConditioningPack, CabinFan, CabinRecirculation,
+            MixerUnit, OutletAir, PressurizationConstants, VcmShared,
         },
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
         overhead::{
@@ -2121,6 +2168,14 @@ mod acs_controller_tests {
         }
     }
 
+    impl PackFlow for TestAirConditioningSystem {
+        fn pack_flow(&self) -> MassRate {
+            self.outlet_air.flow_rate()
+        }
+    }
+
+    impl CabinRecirculation for TestAirConditioningSystem {}
+
     impl VcmShared for TestAirConditioningSystem {}
 
     struct TestCabinAirSimulation {
@@ -2142,7 +2197,11 @@ mod acs_controller_tests {
         fn update(
             &mut self,
             context: &UpdateContext,
-            air_conditioning_system: &(impl OutletAir + DuctTemperature + VcmShared),
+            air_conditioning_system: &(impl OutletAir
+                  + DuctTemperature
+                  + PackFlow
+                  + CabinRecirculation
+                  + VcmShared),
             outflow_valve_open_amount: Ratio,
             safety_valve_open_amount: Ratio,
             lgciu_gear_compressed: bool,
@@ -2180,6 +2239,14 @@ mod acs_controller_tests {
         fn cabin_pressure(&self) -> Pressure {
             self.cabin_air_simulation.cabin_pressure()
         }
+
+        fn cabin_co2_concentration(&self) -> Vec<Ratio> {
+            self.cabin_air_simulation.cabin_co2_concentration()
+        }
+
+        fn cabin_relative_humidity(&self) -> Vec<Ratio> {
+            self.cabin_air_simulation.cabin_relative_humidity()
+        }
     }
 
     impl SimulationElement for TestCabinAirSimulation {
@@ -2204,6 +2271,7 @@ mod acs_controller_tests {
         const SAFETY_VALVE_SIZE: f64 = 0.02; //m2
         const DOOR_OPENING_AREA: f64 = 1.5; // m2
         const HULL_BREACH_AREA: f64 = 0.02; // m2
+        const NUMBER_OF_DOORS: usize = 6;
 
         const MAX_CLIMB_RATE: f64 = 750.; // fpm
         const MAX_CLIMB_RATE_IN_DESCENT: f64 = 500.; // fpm
@@ -2851,6 +2919,10 @@ mod acs_controller_tests {
             ]
         }
 
+        fn cabin_co2_concentration(&self) -> Ratio {
+            self.query(|a| a.acsc[1].zone_co2_concentration(1).unwrap())
+        }
+
         fn duct_temperature(&self) -> Vec<ThermodynamicTemperature> {
             self.query(|a| a.trim_air_system.duct_temperature())
                 .to_vec()
@@ -3605,6 +3677,23 @@ mod acs_controller_tests {
             test_bed = test_bed.iterate(1000);
             assert!(test_bed.duct_temperature()[1].get::<degree_celsius>() > 24.);
         }
+
+        #[test]
+        fn cabin_co2_concentration_is_higher_with_flow_selector_lo() {
+            let mut hi_flow_test_bed = test_bed().with().both_packs_on().and().engine_idle();
+            hi_flow_test_bed.command_pax_quantity(174);
+            hi_flow_test_bed.command_pack_flow_selector_position(2.);
+            hi_flow_test_bed = hi_flow_test_bed.iterate_with_delta(200, Duration::from_secs(10));
+
+            let co2_with_flow_selector_hi = hi_flow_test_bed.cabin_co2_concentration();
+
+            let mut lo_flow_test_bed = test_bed().with().both_packs_on().and().engine_idle();
+            lo_flow_test_bed.command_pax_quantity(174);
+            lo_flow_test_bed.command_pack_flow_selector_position(0.);
+            lo_flow_test_bed = lo_flow_test_bed.iterate_with_delta(200, Duration::from_secs(10));
+
+            assert!(lo_flow_test_bed.cabin_co2_concentration() > co2_with_flow_selector_hi);
+        }
     }
 
     mod pack_flow_controller_tests {
@@ -1,4 +1,7 @@
-use super::{Air, DuctTemperature, OutletAir, PressurizationConstants, VcmShared, ZoneType};
+use super::{
+    fuselage_leakage::FuselageLeakage, Air, CabinRecirculation, DuctTemperature, OutletAir,
+    PackFlow, PressurizationConstants, VcmShared, ZoneType,
+};
 use crate::{
     failures::{Failure, FailureType},
     shared::{AverageExt, CabinSimulation},
@@ -9,13 +12,14 @@ use crate::{
 };
 use num_traits::Pow;
 use uom::si::{
+    area::square_meter,
     f64::*,
     length::meter,
     mass_density::kilogram_per_cubic_meter,
     mass_rate::kilogram_per_second,
     power::{kilowatt, watt},
     pressure::{hectopascal, pascal},
-    ratio::ratio,
+    ratio::{part_per_million, ratio},
     thermodynamic_temperature::{degree_celsius, kelvin},
     velocity::meter_per_second,
     volume::cubic_meter,
@@ -38,6 +42,8 @@ pub struct CabinAirSimulation<C, const ZONES: usize> {
     cargo_air_in: Air,
 
     cabin_zones: [CabinZone<C>; ZONES],
+    fuselage_leakage: FuselageLeakage<C>,
+    structural_leakage_flow: MassRate,
 
     hull_breach: Failure,
     constants: PhantomData<C>,
@@ -68,6 +74,8 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
                 .unwrap_or_else(|v: Vec<CabinZone<C>>| {
                     panic!("Expected a Vec of length {} but it was {}", ZONES, v.len())
                 }),
+            fuselage_leakage: FuselageLeakage::new(context, cabin_zone_ids),
+            structural_leakage_flow: MassRate::default(),
 
             hull_breach: Failure::new(FailureType::RapidDecompression),
             constants: PhantomData,
@@ -77,7 +85,11 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
     pub fn update(
         &mut self,
         context: &UpdateContext,
-        air_conditioning_system: &(impl OutletAir + DuctTemperature + VcmShared),
+        air_conditioning_system: &(impl OutletAir
+              + DuctTemperature
+              + PackFlow
+              + CabinRecirculation
+              + VcmShared),
         outflow_valve_open_amount: Ratio,
         safety_valve_open_amount: Ratio,
         lgciu_gear_compressed: bool,
@@ -91,6 +103,10 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
             let initial_cabin_temperature =
                 self.initialize_cabin_temperature(context, lgciu_gear_compressed);
             self.internal_air.set_temperature(initial_cabin_temperature);
+            let ambient_air_quality = AirQuality::ambient(context);
+            self.cabin_zones
+                .iter_mut()
+                .for_each(|zone| zone.set_zone_air_quality(ambient_air_quality));
             self.is_initialised = true;
         }
         self.filtered_flow_in =
@@ -110,8 +126,8 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
             .set_temperature(self.internal_air.temperature());
 
         // Calculate zone temperatures
-        // let mut flow_rate_per_cubic_meter = self.flow_rate_per_cubic_meter();
-        let flow_rate_per_cubic_meter = self.flow_rate_determination(air_conditioning_system);
+        let flow_rate_per_cubic_meter =
+            self.flow_rate_determination(air_conditioning_system, self.air_in.flow_rate());
 
         for zone in self.cabin_zones.iter_mut() {
             zone.update(
@@ -124,6 +140,8 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
             );
         }
 
+        self.update_air_quality(context, air_conditioning_system, number_of_open_doors);
+
         let average_temperature: ThermodynamicTemperature = self
             .cabin_zones
             .iter()
@@ -132,11 +150,17 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
 
         // Calculate flow out properties
         self.filtered_exterior_pressure = self.exterior_pressure_low_pass_filter(context);
+        self.fuselage_leakage.update(context);
         self.air_out.set_flow_rate(self.calculate_cabin_flow_out(
             outflow_valve_open_amount,
             safety_valve_open_amount,
             number_of_open_doors,
         ));
+        self.structural_leakage_flow = self.calculate_structural_leakage_flow(
+            outflow_valve_open_amount,
+            safety_valve_open_amount,
+            number_of_open_doors,
+        );
 
         // Calculate internal air properties
         let mass_change = (self.air_in.flow_rate().get::<kilogram_per_second>()
@@ -151,6 +175,44 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
             .set_pressure(self.internal_air.pressure() + pressure_change);
     }
 
+    /// Fresh air from the packs dilutes the CO2 and water vapour exhaled by the occupants.
+    /// Recirculated air is filtered but keeps its CO2 and humidity, so it only evens out the zones.
+    fn update_air_quality(
+        &mut self,
+        context: &UpdateContext,
+        air_conditioning_system: &(impl PackFlow + CabinRecirculation + VcmShared),
+        number_of_open_doors: u8,
+    ) {
+        let fresh_air_flow_per_cubic_meter = self
+            .flow_rate_determination(air_conditioning_system, air_conditioning_system.pack_flow());
+        let recirculation_flow_per_cubic_meter = self.flow_rate_determination(
+            air_conditioning_system,
+            air_conditioning_system.recirculation_flow(),
+        );
+        let recirculated_air_quality = AirQuality {
+            co2_concentration: self
+                .cabin_zones
+                .iter()
+                .map(|zone| zone.zone_air.air_quality.co2_concentration)
+                .average(),
+            humidity_ratio: self
+                .cabin_zones
+                .iter()
+                .map(|zone| zone.zone_air.air_quality.humidity_ratio)
+                .average(),
+        };
+
+        for zone in self.cabin_zones.iter_mut() {
+            zone.update_air_quality(
+                context,
+                fresh_air_flow_per_cubic_meter[zone.zone_id().id()],
+                recirculation_flow_per_cubic_meter[zone.zone_id().id()],
+                recirculated_air_quality,
+                number_of_open_doors,
+            );
+        }
+    }
+
     fn initialize_cabin_pressure(
         &mut self,
         context: &UpdateContext,
@@ -185,8 +247,8 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
         }
     }
 
-    fn flow_rate_per_cubic_meter(&self) -> MassRate {
-        self.air_in.flow_rate()
+    fn flow_rate_per_cubic_meter(&self, flow_rate: MassRate) -> MassRate {
+        flow_rate
             / (C::COCKPIT_VOLUME_CUBIC_METER
                 + C::CABIN_ZONE_VOLUME_CUBIC_METER
                     * self
@@ -203,7 +265,11 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
         // The bulk cargo is fed with air from the cabin
     }
 
-    fn flow_rate_determination(&self, vcm_shared: &impl VcmShared) -> Vec<MassRate> {
+    fn flow_rate_determination(
+        &self,
+        vcm_shared: &impl VcmShared,
+        flow_rate: MassRate,
+    ) -> Vec<MassRate> {
         let mut flow_rate_per_cubic_meter = Vec::new();
         for zone in self.cabin_zones.iter() {
             flow_rate_per_cubic_meter.push(
@@ -214,7 +280,7 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
                 {
                     MassRate::default()
                 } else {
-                    self.flow_rate_per_cubic_meter()
+                    self.flow_rate_per_cubic_meter(flow_rate)
                 },
             )
         }
@@ -313,11 +379,11 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
     ) -> MassRate {
         const TRANSONIC_PR_VALUE: f64 = 0.53;
 
-        let outflow_valve_area = C::OUTFLOW_VALVE_SIZE * outflow_valve_open_amount.get::<ratio>(); // sq m
-        let leakage_area = C::CABIN_LEAKAGE_AREA
-            + C::SAFETY_VALVE_SIZE * safety_valve_open_amount.get::<ratio>()
-            + number_of_open_doors as f64 * C::DOOR_OPENING_AREA
-            + C::HULL_BREACH_AREA * self.hull_breach.is_active() as u32 as f64; // sq m
+        let flow_out_area = self.flow_out_area(
+            outflow_valve_open_amount,
+            safety_valve_open_amount,
+            number_of_open_doors,
+        ); // sq m
 
         let pressure_ratio =
             (self.filtered_exterior_pressure / self.internal_air.pressure()).get::<ratio>();
@@ -325,16 +391,55 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
         let flow_coefficient = self.flow_coefficient_calculation(pressure_ratio);
 
         if pressure_ratio < TRANSONIC_PR_VALUE {
-            flow_coefficient
-                * (outflow_valve_area + leakage_area)
-                * self.supersonic_flow_out_calculation()
+            flow_coefficient * flow_out_area * self.supersonic_flow_out_calculation()
+        } else {
+            flow_coefficient * flow_out_area * self.subsonic_flow_out_calculation()
+        }
+    }
+
+    /// Total area in square meters through which cabin air escapes to the exterior
+    fn flow_out_area(
+        &self,
+        outflow_valve_open_amount: Ratio,
+        safety_valve_open_amount: Ratio,
+        number_of_open_doors: u8,
+    ) -> f64 {
+        C::OUTFLOW_VALVE_SIZE * outflow_valve_open_amount.get::<ratio>()
+            + self.fuselage_leakage.leakage_area().get::<square_meter>()
+            + C::SAFETY_VALVE_SIZE * safety_valve_open_amount.get::<ratio>()
+            + number_of_open_doors as f64 * C::DOOR_OPENING_AREA
+            + C::HULL_BREACH_AREA * self.hull_breach.is_active() as u32 as f64
+    }
+
+    /// Share of the cabin flow out that escapes through the fuselage structure, door seals and breaches
+    fn calculate_structural_leakage_flow(
+        &self,
+        outflow_valve_open_amount: Ratio,
+        safety_valve_open_amount: Ratio,
+        number_of_open_doors: u8,
+    ) -> MassRate {
+        let structural_leakage_area = self.fuselage_leakage.leakage_area().get::<square_meter>();
+        let total_area = self.flow_out_area(
+            outflow_valve_open_amount,
+            safety_valve_open_amount,
+            number_of_open_doors,
+        );
+
+        if total_area > 0. {
+            self.air_out.flow_rate() * (structural_leakage_area / total_area)
         } else {
-            flow_coefficient
-                * (outflow_valve_area + leakage_area)
-                * self.subsonic_flow_out_calculation()
+            MassRate::default()
         }
     }
 
+    pub fn structural_leakage_flow(&self) -> MassRate {
+        self.structural_leakage_flow
+    }
+
+    pub fn fuselage_leakage(&self) -> &FuselageLeakage<C> {
+        &self.fuselage_leakage
+    }
+
     /// Mass balance calculation to determine pressure differential
     fn calculate_pressure_change(&self, mass_change: f64, temperature_change: f64) -> Pressure {
         let pressure_change_mass =
@@ -373,6 +478,20 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinSimulation
     fn cabin_pressure(&self) -> Pressure {
         self.internal_air.pressure()
     }
+
+    fn cabin_co2_concentration(&self) -> Vec<Ratio> {
+        self.cabin_zones
+            .iter()
+            .flat_map(|zone| zone.cabin_co2_concentration())
+            .collect()
+    }
+
+    fn cabin_relative_humidity(&self) -> Vec<Ratio> {
+        self.cabin_zones
+            .iter()
+            .flat_map(|zone| zone.cabin_relative_humidity())
+            .collect()
+    }
 }
 
 impl<C: PressurizationConstants, const ZONES: usize> SimulationElement
@@ -380,6 +499,7 @@ impl<C: PressurizationConstants, const ZONES: usize> SimulationElement
 {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.cabin_zones, visitor);
+        self.fuselage_leakage.accept(visitor);
         self.hull_breach.accept(visitor);
 
         visitor.visit(self);
@@ -388,6 +508,8 @@ impl<C: PressurizationConstants, const ZONES: usize> SimulationElement
 
 pub struct CabinZone<C> {
     zone_identifier: VariableIdentifier,
+    co2_concentration_id: VariableIdentifier,
+    relative_humidity_id: VariableIdentifier,
 
     zone_id: ZoneType,
     zone_air: ZoneAir,
@@ -418,6 +540,10 @@ impl<C: PressurizationConstants> CabinZone<C> {
 
         Self {
             zone_identifier: context.get_identifier(format!("COND_{}_TEMP", zone_id)),
+            co2_concentration_id: context
+                .get_identifier(format!("COND_{}_CO2_CONCENTRATION", zone_id)),
+            relative_humidity_id: context
+                .get_identifier(format!("COND_{}_RELATIVE_HUMIDITY", zone_id)),
 
             zone_id: *zone_id,
             zone_air: ZoneAir::new(),
@@ -452,10 +578,64 @@ impl<C: PressurizationConstants> CabinZone<C> {
         );
     }
 
+    fn update_air_quality(
+        &mut self,
+        context: &UpdateContext,
+        fresh_air_flow_per_cubic_meter: MassRate,
+        recirculation_flow_per_cubic_meter: MassRate,
+        recirculated_air_quality: AirQuality,
+        number_of_open_doors: u8,
+    ) {
+        // For the cockpit we reduce the effect of opening doors to 20%
+        let door_flow_factor = if matches!(self.zone_id, ZoneType::Cockpit) {
+            0.2
+        } else {
+            1.
+        };
+        let ambient_air_quality = AirQuality::ambient(context);
+        let zone_volume = self.zone_volume.get::<cubic_meter>();
+
+        self.zone_air.update_air_quality(
+            context,
+            &[
+                (
+                    fresh_air_flow_per_cubic_meter * zone_volume,
+                    ambient_air_quality,
+                ),
+                (
+                    recirculation_flow_per_cubic_meter * zone_volume,
+                    recirculated_air_quality,
+                ),
+                (
+                    MassRate::new::<kilogram_per_second>(
+                        number_of_open_doors as f64
+                            * ZoneAir::FLOW_RATE_THROUGH_OPEN_DOOR_KG_PER_SECOND
+                            * door_flow_factor,
+                    ),
+                    ambient_air_quality,
+                ),
+            ],
+            self.zone_volume,
+            self.passengers,
+        );
+    }
+
     fn zone_id(&self) -> ZoneType {
         self.zone_id
     }
 
+    fn set_zone_air_quality(&mut self, air_quality: AirQuality) {
+        self.zone_air.air_quality = air_quality;
+    }
+
+    pub fn zone_co2_concentration(&self) -> Ratio {
+        self.zone_air.air_quality.co2_concentration
+    }
+
+    pub fn zone_relative_humidity(&self) -> Ratio {
+        self.zone_air.relative_humidity()
+    }
+
     fn set_zone_air_temperature(&mut self, temperature: ThermodynamicTemperature) {
         self.zone_air.set_zone_air_temperature(temperature);
     }
@@ -469,17 +649,105 @@ impl<C: PressurizationConstants> CabinSimulation for CabinZone<C> {
     fn cabin_temperature(&self) -> Vec<ThermodynamicTemperature> {
         vec![self.zone_air.zone_air_temperature()]
     }
+
+    fn cabin_co2_concentration(&self) -> Vec<Ratio> {
+        vec![self.zone_co2_concentration()]
+    }
+
+    fn cabin_relative_humidity(&self) -> Vec<Ratio> {
+        vec![self.zone_relative_humidity()]
+    }
 }
 
 impl<C: PressurizationConstants> SimulationElement for CabinZone<C> {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.zone_identifier, self.zone_air_temperature());
+        writer.write(
+            &self.co2_concentration_id,
+            self.zone_co2_concentration().get::<part_per_million>(),
+        );
+        writer.write(&self.relative_humidity_id, self.zone_relative_humidity());
+    }
+}
+
+#[derive(Clone, Copy)]
+struct AirQuality {
+    co2_concentration: Ratio, // Volumetric
+    humidity_ratio: Ratio,    // kg of water vapour per kg of dry air
+}
+
+impl AirQuality {
+    const OUTSIDE_AIR_CO2_CONCENTRATION_PPM: f64 = 420.; // ppm
+
+    // The sim does not provide the ambient humidity, at altitude the air is dry regardless
+    const AMBIENT_RELATIVE_HUMIDITY: f64 = 0.5;
+    const WATER_TO_AIR_MOLAR_MASS_RATIO: f64 = 0.622;
+
+    fn ambient(context: &UpdateContext) -> Self {
+        Self {
+            co2_concentration: Ratio::new::<part_per_million>(
+                Self::OUTSIDE_AIR_CO2_CONCENTRATION_PPM,
+            ),
+            humidity_ratio: Self::humidity_ratio_calculation(
+                Ratio::new::<ratio>(Self::AMBIENT_RELATIVE_HUMIDITY),
+                context.ambient_temperature(),
+                context.ambient_pressure(),
+            ),
+        }
+    }
+
+    fn humidity_ratio_calculation(
+        relative_humidity: Ratio,
+        temperature: ThermodynamicTemperature,
+        pressure: Pressure,
+    ) -> Ratio {
+        let vapour_pressure = (relative_humidity.get::<ratio>()
+            * Self::saturation_vapour_pressure(temperature).get::<pascal>())
+        .min(pressure.get::<pascal>() / 2.);
+        Ratio::new::<ratio>(
+            Self::WATER_TO_AIR_MOLAR_MASS_RATIO * vapour_pressure
+                / (pressure.get::<pascal>() - vapour_pressure),
+        )
+    }
+
+    fn relative_humidity(
+        &self,
+        temperature: ThermodynamicTemperature,
+        pressure: Pressure,
+    ) -> Ratio {
+        let humidity_ratio = self.humidity_ratio.get::<ratio>();
+        let vapour_pressure = humidity_ratio * pressure.get::<pascal>()
+            / (Self::WATER_TO_AIR_MOLAR_MASS_RATIO + humidity_ratio);
+        Ratio::new::<ratio>(
+            (vapour_pressure / Self::saturation_vapour_pressure(temperature).get::<pascal>())
+                .clamp(0., 1.),
+        )
+    }
+
+    /// Magnus formula
+    fn saturation_vapour_pressure(temperature: ThermodynamicTemperature) -> Pressure {
+        let temperature_celsius = temperature.get::<degree_celsius>();
+        Pressure::new::<hectopascal>(
+            6.112 * (17.62 * temperature_celsius / (243.12 + temperature_celsius)).exp(),
+        )
+    }
+}
+
+impl Default for AirQuality {
+    fn default() -> Self {
+        Self {
+            co2_concentration: Ratio::new::<part_per_million>(
+                Self::OUTSIDE_AIR_CO2_CONCENTRATION_PPM,
+            ),
+            humidity_ratio: Ratio::default(),
+        }
     }
 }
 
 struct ZoneAir {
     flow_out: Air,
     internal_air: Air,
+    air_quality: AirQuality,
 }
 
 impl ZoneAir {
@@ -495,11 +763,15 @@ impl ZoneAir {
     const CLOTHED_AREA_OF_AVERAGE_HUMAN_METER: f64 = 1.8; // m2
     const HUMAN_LUNG_TIDAL_VOLUME_PER_SECOND_METER: f64 = 0.0001; // m3/s
     const HUMAN_EXHALE_AIR_TEMPERATURE_CELSIUS: f64 = 35.; // C
+    const HUMAN_CO2_GENERATION_KG_PER_SECOND: f64 = 9.6e-6; // kg/s - About 0.3 l/min when seated
+    const HUMAN_WATER_VAPOUR_GENERATION_KG_PER_SECOND: f64 = 1.9e-5; // kg/s - About 70 g/h
+    const AIR_TO_CO2_MOLAR_MASS_RATIO: f64 = 0.658;
 
     fn new() -> Self {
         Self {
             flow_out: Air::new(),
             internal_air: Air::new(),
+            air_quality: AirQuality::default(),
         }
     }
 
@@ -527,6 +799,90 @@ impl ZoneAir {
         self.flow_out.set_flow_rate(air_in.flow_rate());
     }
 
+    /// Species balance for CO2 and water vapour, assuming the zone air is fully mixed
+    fn update_air_quality(
+        &mut self,
+        context: &UpdateContext,
+        inlets: &[(MassRate, AirQuality)],
+        zone_volume: Volume,
+        zone_passengers: u8,
+    ) {
+        let internal_mass = self.internal_air.pressure().get::<pascal>()
+            * zone_volume.get::<cubic_meter>()
+            / (Air::R * self.internal_air.temperature().get::<kelvin>());
+
+        let co2_generation = zone_passengers as f64
+            * Self::HUMAN_CO2_GENERATION_KG_PER_SECOND
+            * Self::AIR_TO_CO2_MOLAR_MASS_RATIO;
+        let water_vapour_generation =
+            zone_passengers as f64 * Self::HUMAN_WATER_VAPOUR_GENERATION_KG_PER_SECOND;
+
+        let co2_inlets: Vec<(f64, f64)> = inlets
+            .iter()
+            .map(|(flow, quality)| {
+                (
+                    flow.get::<kilogram_per_second>(),
+                    quality.co2_concentration.get::<ratio>(),
+                )
+            })
+            .collect();
+        let humidity_inlets: Vec<(f64, f64)> = inlets
+            .iter()
+            .map(|(flow, quality)| {
+                (
+                    flow.get::<kilogram_per_second>(),
+                    quality.humidity_ratio.get::<ratio>(),
+                )
+            })
+            .collect();
+
+        self.air_quality.co2_concentration = Ratio::new::<ratio>(Self::mixed_concentration(
+            context,
+            self.air_quality.co2_concentration.get::<ratio>(),
+            &co2_inlets,
+            co2_generation,
+            internal_mass,
+        ));
+        self.air_quality.humidity_ratio = Ratio::new::<ratio>(Self::mixed_concentration(
+            context,
+            self.air_quality.humidity_ratio.get::<ratio>(),
+            &humidity_inlets,
+            water_vapour_generation,
+            internal_mass,
+        ));
+    }
+
+    /// Exact solution of the mixing equation over the time step, so it stays stable with large flows
+    fn mixed_concentration(
+        context: &UpdateContext,
+        concentration: f64,
+        inlets: &[(f64, f64)],
+        generation: f64,
+        internal_mass: f64,
+    ) -> f64 {
+        let total_flow: f64 = inlets.iter().map(|(flow, _)| flow.max(0.)).sum();
+        if total_flow > 0. {
+            let equilibrium_concentration = (inlets
+                .iter()
+                .map(|(flow, inlet_concentration)| flow.max(0.) * inlet_concentration)
+                .sum::<f64>()
+                + generation)
+                / total_flow;
+            equilibrium_concentration
+                + (concentration - equilibrium_concentration)
+                    * (-total_flow * context.delta_as_secs_f64() / internal_mass).exp()
+        } else {
+            concentration + generation * context.delta_as_secs_f64() / internal_mass
+        }
+    }
+
+    fn relative_humidity(&self) -> Ratio {
+        self.air_quality.relative_humidity(
+            self.internal_air.temperature(),
+            self.internal_air.pressure(),
+        )
+    }
+
     /// Energy balance calculation to determine equilibrium temperature in the cabin
     fn equilibrium_temperature_calculation(
         &self,
@@ -720,14 +1076,17 @@ mod cabin_air_tests {
     };
     use std::time::Duration;
     use uom::si::{
+        area::square_centimeter,
         length::foot,
         pressure::{hectopascal, psi},
+        ratio::percent,
         thermodynamic_temperature::degree_celsius,
     };
 
     struct TestAirConditioningSystem {
         duct_demand_temperature: ThermodynamicTemperature,
         pack_flow: MassRate,
+        recirculation_flow: MassRate,
     }
 
     impl TestAirConditioningSystem {
@@ -735,6 +1094,7 @@ mod cabin_air_tests {
             Self {
                 duct_demand_temperature: ThermodynamicTemperature::new::<degree_celsius>(24.),
                 pack_flow: MassRate::default(),
+                recirculation_flow: MassRate::default(),
             }
         }
 
@@ -745,6 +1105,10 @@ mod cabin_air_tests {
         fn set_pack_flow(&mut self, flow: MassRate) {
             self.pack_flow = flow;
         }
+
+        fn set_recirculation_flow(&mut self, flow: MassRate) {
+            self.recirculation_flow = flow;
+        }
     }
 
     impl DuctTemperature for TestAirConditioningSystem {
@@ -766,6 +1130,12 @@ mod cabin_air_tests {
         }
     }
 
+    impl CabinRecirculation for TestAirConditioningSystem {
+        fn recirculation_flow(&self) -> MassRate {
+            self.recirculation_flow
+        }
+    }
+
     impl OutletAir for TestAirConditioningSystem {
         fn outlet_air(&self) -> Air {
             let mut outlet_air = Air::new();
@@ -790,6 +1160,7 @@ mod cabin_air_tests {
         const SAFETY_VALVE_SIZE: f64 = 0.02; //m2
         const DOOR_OPENING_AREA: f64 = 1.5; // m2
         const HULL_BREACH_AREA: f64 = 0.02; // m2
+        const NUMBER_OF_DOORS: usize = 6;
 
         const MAX_CLIMB_RATE: f64 = 750.; // fpm
         const MAX_CLIMB_RATE_IN_DESCENT: f64 = 500.; // fpm
@@ -838,6 +1209,11 @@ mod cabin_air_tests {
             self.air_conditioning_system.set_pack_flow(flow_rate);
         }
 
+        fn set_recirculation_flow_rate(&mut self, flow_rate: MassRate) {
+            self.air_conditioning_system
+                .set_recirculation_flow(flow_rate);
+        }
+
         fn set_passengers(&mut self, passengers: u8) {
             self.number_of_passengers = passengers;
         }
@@ -916,6 +1292,11 @@ mod cabin_air_tests {
             self
         }
 
+        fn recirculation_flow_rate_of(mut self, flow_rate: MassRate) -> Self {
+            self.command(|a| a.set_recirculation_flow_rate(flow_rate));
+            self
+        }
+
         fn true_airspeed_of(mut self, speed: Velocity) -> Self {
             self.write_by_name("AIRSPEED TRUE", speed);
             self
@@ -944,6 +1325,59 @@ mod cabin_air_tests {
             self.query(|a| a.cabin_air_simulation.cabin_temperature()[1])
         }
 
+        fn cockpit_co2_concentration(&self) -> Ratio {
+            self.query(|a| a.cabin_air_simulation.cabin_co2_concentration()[0])
+        }
+
+        fn cabin_co2_concentration(&self) -> Ratio {
+            self.query(|a| a.cabin_air_simulation.cabin_co2_concentration()[1])
+        }
+
+        fn cabin_relative_humidity(&self) -> Ratio {
+            self.query(|a| a.cabin_air_simulation.cabin_relative_humidity()[1])
+        }
+
+        fn structural_leakage_flow(&self) -> MassRate {
+            self.query(|a| a.cabin_air_simulation.structural_leakage_flow())
+        }
+
+        fn door_seal_leak_area(&self, door_id: usize) -> Area {
+            self.query(|a| {
+                a.cabin_air_simulation
+                    .fuselage_leakage()
+                    .door_seal_leak_area(door_id)
+            })
+        }
+
+        fn hull_breach_area(&self, zone_id: ZoneType) -> Area {
+            self.query(|a| {
+                a.cabin_air_simulation
+                    .fuselage_leakage()
+                    .hull_breach_area(zone_id)
+            })
+        }
+
+        fn airframe_leakage_increase_of(mut self, increase: Ratio) -> Self {
+            self.write_by_name("PRESS_AIRFRAME_LEAKAGE_INCREASE", increase);
+            self
+        }
+
+        fn hull_breach_area_of(mut self, zone_id: ZoneType, area: Area) -> Self {
+            self.write_by_name(
+                &format!("PRESS_{}_HULL_BREACH_AREA", zone_id),
+                area.get::<square_centimeter>(),
+            );
+            self
+        }
+
+        fn in_cruise(self) -> Self {
+            self.flying(true)
+                .ambient_pressure_of(InternationalStandardAtmosphere::pressure_at_altitude(
+                    Length::new::<foot>(39000.),
+                ))
+                .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(-56.5))
+        }
+
         fn memorize_cabin_temperature(mut self) -> Self {
             self.stored_temperature = Some(self.cabin_temperature());
             self
@@ -1153,4 +1587,237 @@ mod cabin_air_tests {
 
         assert!(first_temperature_differential < second_temperature_differential);
     }
+
+    #[test]
+    fn empty_cabin_co2_stays_at_outside_level() {
+        let test_bed = test_bed_with()
+            .with_flow()
+            .iterate_with_delta(100, Duration::from_secs(10));
+
+        assert!((test_bed.cabin_co2_concentration().get::<part_per_million>() - 420.).abs() < 1.);
+    }
+
+    #[test]
+    fn cabin_co2_rises_with_passengers() {
+        let test_bed = test_bed_with()
+            .with_flow()
+            .and()
+            .with_passengers()
+            .iterate_with_delta(100, Duration::from_secs(10));
+
+        assert!(test_bed.cabin_co2_concentration().get::<part_per_million>() > 700.);
+        assert!(test_bed.cabin_co2_concentration().get::<part_per_million>() < 1500.);
+    }
+
+    #[test]
+    fn cabin_co2_is_higher_with_low_fresh_air_flow() {
+        let test_bed = test_bed_with()
+            .with_flow()
+            .and()
+            .with_passengers()
+            .iterate_with_delta(100, Duration::from_secs(10));
+
+        let normal_flow_co2 = test_bed.cabin_co2_concentration();
+
+        // Single pack operation with flow selector LO
+        let test_bed = test_bed_with()
+            .air_in_flow_rate_of(MassRate::new::<kilogram_per_second>(1.3 / 2. * 0.8))
+            .and()
+            .with_passengers()
+            .iterate_with_delta(100, Duration::from_secs(10));
+
+        assert!(
+            test_bed.cabin_co2_concentration().get::<part_per_million>()
+                > normal_flow_co2.get::<part_per_million>() + 500.
+        );
+    }
+
+    #[test]
+    fn cabin_co2_accumulates_without_fresh_air_flow() {
+        let test_bed = test_bed_with()
+            .air_in_flow_rate_of(MassRate::default())
+            .and()
+            .with_passengers()
+            .iterate_with_delta(100, Duration::from_secs(10));
+
+        assert!(test_bed.cabin_co2_concentration().get::<part_per_million>() > 3000.);
+    }
+
+    #[test]
+    fn recirculation_spreads_cabin_co2_to_the_cockpit() {
+        let test_bed = test_bed_with()
+            .with_flow()
+            .and()
+            .with_passengers()
+            .iterate_with_delta(100, Duration::from_secs(10));
+
+        let cockpit_co2_without_recirculation = test_bed.cockpit_co2_concentration();
+
+        let test_bed = test_bed_with()
+            .with_flow()
+            .and()
+            .recirculation_flow_rate_of(MassRate::new::<kilogram_per_second>(1.))
+            .and()
+            .with_passengers()
+            .iterate_with_delta(100, Duration::from_secs(10));
+
+        assert!(test_bed.cockpit_co2_concentration() > cockpit_co2_without_recirculation);
+        assert!(test_bed.cockpit_co2_concentration() < test_bed.cabin_co2_concentration());
+    }
+
+    #[test]
+    fn cabin_humidity_rises_with_passengers_at_cruise() {
+        let test_bed = test_bed_with()
+            .flying(true)
+            .and()
+            .ambient_pressure_of(InternationalStandardAtmosphere::pressure_at_altitude(
+                Length::new::<foot>(39000.),
+            ))
+            .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(-56.5))
+            .with_flow()
+            .iterate_with_delta(100, Duration::from_secs(10));
+
+        let empty_cabin_humidity = test_bed.cabin_relative_humidity();
+
+        let test_bed = test_bed_with()
+            .flying(true)
+            .and()
+            .ambient_pressure_of(InternationalStandardAtmosphere::pressure_at_altitude(
+                Length::new::<foot>(39000.),
+            ))
+            .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(-56.5))
+            .with_flow()
+            .and()
+            .with_passengers()
+            .iterate_with_delta(100, Duration::from_secs(10));
+
+        assert!(test_bed.cabin_relative_humidity() > empty_cabin_humidity);
+    }
+
+    #[test]
+    fn cabin_air_is_dry_at_cruise() {
+        let test_bed = test_bed_with()
+            .flying(true)
+            .and()
+            .ambient_pressure_of(InternationalStandardAtmosphere::pressure_at_altitude(
+                Length::new::<foot>(39000.),
+            ))
+            .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(-56.5))
+            .with_flow()
+            .and()
+            .with_passengers()
+            .iterate_with_delta(100, Duration::from_secs(10));
+
+        assert!(test_bed.cabin_relative_humidity().get::<percent>() > 5.);
+        assert!(test_bed.cabin_relative_humidity().get::<percent>() < 25.);
+    }
+
+    #[test]
+    fn worn_airframe_leaks_more_air() {
+        let test_bed = test_bed_with()
+            .in_cruise()
+            .iterate_with_delta(10, Duration::from_secs(1));
+
+        let new_airframe_leakage_flow = test_bed.structural_leakage_flow();
+
+        let test_bed = test_bed_with()
+            .in_cruise()
+            .airframe_leakage_increase_of(Ratio::new::<percent>(100.))
+            .iterate_with_delta(10, Duration::from_secs(1));
+
+        assert!(new_airframe_leakage_flow > MassRate::default());
+        assert!(test_bed.structural_leakage_flow() > new_airframe_leakage_flow * 1.5);
+    }
+
+    #[test]
+    fn door_seal_leak_grows_progressively() {
+        let mut test_bed = test_bed_with().in_cruise().iterate(1);
+
+        assert_eq!(test_bed.door_seal_leak_area(1), Area::default());
+
+        test_bed.fail(FailureType::DoorSealLeak(1));
+        test_bed = test_bed.iterate_with_delta(10, Duration::from_secs(1));
+
+        let early_leak_area = test_bed.door_seal_leak_area(1);
+        assert!(early_leak_area > Area::default());
+        assert!(early_leak_area < Area::new::<square_centimeter>(15.));
+
+        test_bed = test_bed.iterate_with_delta(100, Duration::from_secs(10));
+
+        assert!(
+            (test_bed.door_seal_leak_area(1) - Area::new::<square_centimeter>(15.)).abs()
+                < Area::new::<square_centimeter>(0.01)
+        );
+        assert_eq!(test_bed.door_seal_leak_area(2), Area::default());
+    }
+
+    #[test]
+    fn unknown_door_has_no_seal_leak() {
+        let mut test_bed = test_bed_with().in_cruise().iterate(1);
+
+        test_bed.fail(FailureType::DoorSealLeak(1));
+        test_bed = test_bed.iterate_with_delta(10, Duration::from_secs(1));
+
+        assert_eq!(test_bed.door_seal_leak_area(0), Area::default());
+        assert_eq!(test_bed.door_seal_leak_area(100), Area::default());
+    }
+
+    #[test]
+    fn door_seal_leak_is_cleared_when_failure_is_removed() {
+        let mut test_bed = test_bed_with().in_cruise().iterate(1);
+
+        test_bed.fail(FailureType::DoorSealLeak(1));
+        test_bed = test_bed.iterate_with_delta(10, Duration::from_secs(10));
+        assert!(test_bed.door_seal_leak_area(1) > Area::default());
+
+        test_bed.unfail(FailureType::DoorSealLeak(1));
+        test_bed = test_bed.iterate(1);
+        assert_eq!(test_bed.door_seal_leak_area(1), Area::default());
+    }
+
+    #[test]
+    fn progressive_hull_breach_grows_to_configured_area() {
+        let mut test_bed = test_bed_with()
+            .in_cruise()
+            .hull_breach_area_of(ZoneType::Cabin(1), Area::new::<square_centimeter>(20.))
+            .iterate(1);
+
+        test_bed.fail(FailureType::ProgressiveHullBreach(ZoneType::Cabin(1)));
+        test_bed = test_bed.iterate_with_delta(10, Duration::from_secs(1));
+
+        assert!(test_bed.hull_breach_area(ZoneType::Cabin(1)) > Area::default());
+        assert!(
+            test_bed.hull_breach_area(ZoneType::Cabin(1)) < Area::new::<square_centimeter>(20.)
+        );
+        assert_eq!(
+            test_bed.hull_breach_area(ZoneType::Cockpit),
+            Area::default()
+        );
+
+        test_bed = test_bed.iterate_with_delta(100, Duration::from_secs(1));
+
+        assert!(
+            (test_bed.hull_breach_area(ZoneType::Cabin(1)) - Area::new::<square_centimeter>(20.))
+                .abs()
+                < Area::new::<square_centimeter>(0.01)
+        );
+    }
+
+    #[test]
+    fn progressive_hull_breach_depressurizes_slower_than_rapid_decompression() {
+        let mut test_bed = test_bed_with().in_cruise().with_flow().iterate(10);
+        let initial_cabin_pressure = test_bed.cabin_pressure();
+
+        test_bed.fail(FailureType::ProgressiveHullBreach(ZoneType::Cabin(1)));
+        test_bed = test_bed.iterate_with_delta(30, Duration::from_secs(1));
+        let progressive_breach_cabin_pressure = test_bed.cabin_pressure();
+
+        let mut test_bed = test_bed_with().in_cruise().with_flow().iterate(10);
+
+        test_bed.fail(FailureType::RapidDecompression);
+        test_bed = test_bed.iterate_with_delta(30, Duration::from_secs(1));
+
+        assert!(progressive_breach_cabin_pressure < initial_cabin_pressure);
+        assert!(test_bed.cabin_pressure() < progressive_breach_cabin_pressure);
+    }
 }
@@ -4,7 +4,8 @@ use crate::{
         arinc429::{Arinc429Word, SignStatus},
         low_pass_filter::LowPassFilter,
         pid::PidController,
-        AverageExt, CabinSimulation, ControllerSignal, EngineCorrectedN1, Resolution,
+        AverageExt, CabinSimulation, ControllerSignal, EngineCorrectedN1,
+        InternationalStandardAtmosphere, Resolution,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -52,6 +53,7 @@ pub struct CabinPressureController<C: PressurizationConstants> {
 
     auto_landing_elevation_id: VariableIdentifier,
     destination_qnh_id: VariableIdentifier,
+    cruise_altitude_id: VariableIdentifier,
 
     pressure_schedule_manager: Option<PressureScheduleManager>,
     manual_partition: Option<CpcManualPartition>,
@@ -74,6 +76,7 @@ pub struct CabinPressureController<C: PressurizationConstants> {
     landing_elevation_is_auto: bool,
     departure_elevation: Length,
     destination_qnh: Pressure,
+    cruise_altitude: Length,
     is_in_man_mode: bool,
     man_mode_duration: Duration,
     manual_to_auto_switch: bool,
@@ -98,6 +101,12 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
     const TARGET_LANDING_ALT_DIFF: f64 = 187.818;
     const OFV_CONTROLLER_KP: f64 = 0.0001;
     const OFV_CONTROLLER_KI: f64 = 6.5;
+    // Empirical ratio of cabin to aircraft vertical speed in climb, linear with the aircraft altitude
+    const CLIMB_SCHEDULE_ALTITUDE_FACTOR: f64 = 0.00000525; // 1/ft
+    const CLIMB_SCHEDULE_CONSTANT_FACTOR: f64 = 0.09;
+    // The planned schedule is only followed in cruise when close to the planned cruise altitude
+    const CRUISE_ALTITUDE_MARGIN: f64 = 1000.; // feet
+    const CRUISE_CABIN_ALTITUDE_MARGIN: f64 = 30.; // feet
 
     pub fn new(context: &mut InitContext, id: CpcId) -> Self {
         Self {
@@ -114,6 +123,7 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
 
             auto_landing_elevation_id: context.get_identifier("FM1_LANDING_ELEVATION".to_owned()),
             destination_qnh_id: context.get_identifier("DESTINATION_QNH".to_owned()),
+            cruise_altitude_id: context.get_identifier("AIRLINER_CRUISE_ALTITUDE".to_owned()),
 
             pressure_schedule_manager: Some(PressureScheduleManager::new()),
             manual_partition: if id == CpcId::Cpc1 {
@@ -151,6 +161,7 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
             landing_elevation_is_auto: false,
             departure_elevation: Length::default(),
             destination_qnh: Pressure::default(),
+            cruise_altitude: Length::default(),
             is_in_man_mode: false,
             man_mode_duration: Duration::from_secs(0),
             manual_to_auto_switch: false,
@@ -329,12 +340,9 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
                 },
             ),
             Some(PressureScheduleManager::ClimbInternal(_)) => {
-                // Formula based on empirical graphs and tables to simulate climb schedule as per the real aircraft
                 let target_vs_fpm = self
-                    .exterior_vertical_speed
-                    .output()
-                    .get::<foot_per_minute>()
-                    * (0.00000525 * self.exterior_flight_altitude.get::<foot>() + 0.09);
+                    .calculate_climb_vertical_speed()
+                    .get::<foot_per_minute>();
                 Velocity::new::<foot_per_minute>(
                     if self.cabin_delta_p() >= Pressure::new::<psi>(C::MAX_CLIMB_DELTA_P) {
                         C::MAX_CLIMB_RATE
@@ -349,7 +357,7 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
                     },
                 )
             }
-            Some(PressureScheduleManager::Cruise(_)) => Velocity::default(),
+            Some(PressureScheduleManager::Cruise(_)) => self.calculate_cruise_vertical_speed(),
             Some(PressureScheduleManager::DescentInternal(_)) => {
                 let ext_diff_with_ldg_elev = self.get_ext_diff_with_ldg_elev().get::<foot>();
                 let target_vs_fpm = self.get_int_diff_with_ldg_elev().get::<foot>()
@@ -381,6 +389,85 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
         }
     }
 
+    /// When a cruise altitude is entered in the FMS, the cabin climbs at a rate that brings it
+    /// to the planned cruise cabin altitude when the aircraft reaches the cruise altitude.
+    /// Otherwise the schedule follows the aircraft vertical speed.
+    fn calculate_climb_vertical_speed(&self) -> Velocity {
+        let exterior_vertical_speed = self.exterior_vertical_speed.output();
+
+        match self.planned_cruise_cabin_altitude() {
+            Some(planned_cabin_altitude)
+                if self.cruise_altitude > self.exterior_flight_altitude
+                    && exterior_vertical_speed > Velocity::new::<foot_per_minute>(100.) =>
+            {
+                let time_to_cruise = (self.cruise_altitude - self.exterior_flight_altitude)
+                    / exterior_vertical_speed;
+                let target_vs = (planned_cabin_altitude - self.cabin_alt) / time_to_cruise;
+                Velocity::new::<foot_per_minute>(
+                    target_vs.get::<foot_per_minute>().min(C::MAX_CLIMB_RATE),
+                )
+            }
+            _ => {
+                // Formula based on empirical graphs and tables to simulate climb schedule as per the real aircraft
+                Velocity::new::<foot_per_minute>(
+                    exterior_vertical_speed.get::<foot_per_minute>()
+                        * (Self::CLIMB_SCHEDULE_ALTITUDE_FACTOR
+                            * self.exterior_flight_altitude.get::<foot>()
+                            + Self::CLIMB_SCHEDULE_CONSTANT_FACTOR),
+                )
+            }
+        }
+    }
+
+    /// In cruise the cabin captures the planned cruise cabin altitude, or holds its altitude
+    /// when the aircraft is not flying at the planned cruise altitude
+    fn calculate_cruise_vertical_speed(&self) -> Velocity {
+        match self.planned_cruise_cabin_altitude() {
+            Some(planned_cabin_altitude)
+                if (self.cruise_altitude - self.exterior_flight_altitude).abs()
+                    < Length::new::<foot>(Self::CRUISE_ALTITUDE_MARGIN)
+                    && (planned_cabin_altitude - self.cabin_alt).abs()
+                        > Length::new::<foot>(Self::CRUISE_CABIN_ALTITUDE_MARGIN) =>
+            {
+                // One foot per minute of vertical speed per foot of difference, for a smooth capture
+                Velocity::new::<foot_per_minute>(
+                    (planned_cabin_altitude - self.cabin_alt)
+                        .get::<foot>()
+                        .clamp(C::MAX_DESCENT_RATE, C::MAX_CLIMB_RATE_IN_DESCENT),
+                )
+            }
+            _ => Velocity::default(),
+        }
+    }
+
+    /// Cabin altitude planned for the FMS cruise altitude. This follows the climb schedule, limited
+    /// by the maximum cabin altitude and the maximum differential pressure at cruise.
+    /// Returns None when no cruise altitude has been entered.
+    pub fn planned_cruise_cabin_altitude(&self) -> Option<Length> {
+        if self.cruise_altitude <= Length::default() {
+            return None;
+        }
+
+        let cruise_altitude_ft = self.cruise_altitude.get::<foot>();
+        // Integral of the climb schedule from sea level to the cruise altitude
+        let scheduled_cabin_altitude = Length::new::<foot>(
+            0.5 * Self::CLIMB_SCHEDULE_ALTITUDE_FACTOR * cruise_altitude_ft.powi(2)
+                + Self::CLIMB_SCHEDULE_CONSTANT_FACTOR * cruise_altitude_ft,
+        );
+        let max_delta_p_cabin_altitude = self.calculate_altitude(
+            InternationalStandardAtmosphere::pressure_at_altitude(self.cruise_altitude)
+                + Pressure::new::<psi>(C::MAX_CLIMB_DELTA_P),
+            Pressure::new::<hectopascal>(Self::P_0),
+        );
+
+        Some(
+            scheduled_cabin_altitude
+                .max(max_delta_p_cabin_altitude)
+                .max(self.departure_elevation.min(self.landing_elevation))
+                .min(Length::new::<foot>(C::MAX_CLIMB_CABIN_ALTITUDE)),
+        )
+    }
+
     fn get_ext_diff_with_ldg_elev(&self) -> Length {
         // TODO: Replace constant target landing alt diff for pressure diff
         self.exterior_flight_altitude
@@ -695,6 +782,7 @@ impl<C: PressurizationConstants> SimulationElement for CabinPressureController<C
             reader.read_arinc429(&self.auto_landing_elevation_id);
         self.landing_elevation = landing_elevation_word.normal_value().unwrap_or_default();
         self.destination_qnh = Pressure::new::<hectopascal>(reader.read(&self.destination_qnh_id));
+        self.cruise_altitude = Length::new::<foot>(reader.read(&self.cruise_altitude_id));
     }
 
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -1212,7 +1300,7 @@ mod tests {
     use crate::{
         air_conditioning::{
             cabin_air::CabinAirSimulation,
-            {Air, DuctTemperature, OutletAir, PackFlow, ZoneType},
+            {Air, CabinRecirculation, DuctTemperature, OutletAir, PackFlow, ZoneType},
         },
         shared::arinc429::{Arinc429Word, SignStatus},
     };
@@ -1220,7 +1308,7 @@ mod tests {
         overhead::{AutoManFaultPushButton, NormalOnPushButton, SpringLoadedSwitch, ValueKnob},
         shared::{EngineCorrectedN1, LgciuWeightOnWheels},
         simulation::{
-            test::{SimulationTestBed, TestBed},
+            test::{SimulationTestBed, TestBed, WriteByName},
             UpdateContext,
         },
     };
@@ -1300,6 +1388,8 @@ mod tests {
         }
     }
 
+    impl CabinRecirculation for TestAirConditioningSystem {}
+
     impl OutletAir for TestAirConditioningSystem {
         fn outlet_air(&self) -> Air {
             let mut outlet_air = Air::new();
@@ -1452,6 +1542,7 @@ mod tests {
         const SAFETY_VALVE_SIZE: f64 = 0.02; //m2
         const DOOR_OPENING_AREA: f64 = 1.5; // m2
         const HULL_BREACH_AREA: f64 = 0.02; // m2
+        const NUMBER_OF_DOORS: usize = 6;
 
         const MAX_CLIMB_RATE: f64 = 750.; // fpm
         const MAX_CLIMB_RATE_IN_DESCENT: f64 = 500.; // fpm
@@ -2085,4 +2176,70 @@ mod tests {
 
         assert!(test_bed.query(|a| a.is_climb()));
     }
+
+    #[test]
+    fn planned_cruise_cabin_altitude_is_not_computed_without_cruise_altitude() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_vertical_speed();
+
+        assert!(test_bed
+            .query(|a| a.cpc.planned_cruise_cabin_altitude())
+            .is_none());
+    }
+
+    #[test]
+    fn planned_cruise_cabin_altitude_increases_with_cruise_altitude() {
+        let mut test_bed = test_bed();
+
+        test_bed.write_by_name("AIRLINER_CRUISE_ALTITUDE", 20000.);
+        test_bed.run_with_vertical_speed();
+        let low_cruise_cabin_altitude = test_bed
+            .query(|a| a.cpc.planned_cruise_cabin_altitude())
+            .unwrap();
+
+        test_bed.write_by_name("AIRLINER_CRUISE_ALTITUDE", 35000.);
+        test_bed.run_with_vertical_speed();
+        let high_cruise_cabin_altitude = test_bed
+            .query(|a| a.cpc.planned_cruise_cabin_altitude())
+            .unwrap();
+
+        assert!(low_cruise_cabin_altitude > Length::default());
+        assert!(high_cruise_cabin_altitude > low_cruise_cabin_altitude);
+    }
+
+    #[test]
+    fn planned_cruise_cabin_altitude_does_not_exceed_max_cabin_altitude() {
+        let mut test_bed = test_bed();
+
+        test_bed.write_by_name("AIRLINER_CRUISE_ALTITUDE", 41000.);
+        test_bed.run_with_vertical_speed();
+
+        assert!(
+            test_bed
+                .query(|a| a.cpc.planned_cruise_cabin_altitude())
+                .unwrap()
+                <= Length::new::<foot>(TestConstants::MAX_CLIMB_CABIN_ALTITUDE)
+        );
+    }
+
+    #[test]
+    fn planned_cruise_cabin_altitude_respects_max_differential_pressure() {
+        let mut test_bed = test_bed();
+
+        test_bed.write_by_name("AIRLINER_CRUISE_ALTITUDE", 39000.);
+        test_bed.run_with_vertical_speed();
+
+        let planned_cabin_pressure = InternationalStandardAtmosphere::pressure_at_altitude(
+            test_bed
+                .query(|a| a.cpc.planned_cruise_cabin_altitude())
+                .unwrap(),
+        );
+        let cruise_pressure =
+            InternationalStandardAtmosphere::pressure_at_altitude(Length::new::<foot>(39000.));
+
+        assert!(
+            planned_cabin_pressure - cruise_pressure
+                <= Pressure::new::<psi>(TestConstants::MAX_CLIMB_DELTA_P + 0.01)
+        );
+    }
 }
@@ -0,0 +1,190 @@
+use crate::{
+    failures::{Failure, FailureType},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        UpdateContext, VariableIdentifier,
+    },
+};
+
+use super::{PressurizationConstants, ZoneType};
+
+use std::marker::PhantomData;
+use uom::si::{
+    area::{square_centimeter, square_meter},
+    f64::*,
+    ratio::ratio,
+};
+
+/// Leakage paths of the pressurized fuselage other than the outflow valve and the safety valve.
+/// The resulting flow is calculated by the cabin air simulation from the differential pressure.
+pub struct FuselageLeakage<C> {
+    airframe_leakage_increase_id: VariableIdentifier,
+    airframe_leakage_increase: Ratio,
+
+    door_seals: Vec<DoorSeal>,
+    hull_breaches: Vec<ProgressiveHullBreach>,
+
+    constants: PhantomData<C>,
+}
+
+impl<C: PressurizationConstants> FuselageLeakage<C> {
+    pub fn new(context: &mut InitContext, cabin_zone_ids: &[ZoneType]) -> Self {
+        Self {
+            airframe_leakage_increase_id: context
+                .get_identifier("PRESS_AIRFRAME_LEAKAGE_INCREASE".to_owned()),
+            airframe_leakage_increase: Ratio::default(),
+
+            door_seals: (1..=C::NUMBER_OF_DOORS).map(DoorSeal::new).collect(),
+            hull_breaches: cabin_zone_ids
+                .iter()
+                .map(|zone| ProgressiveHullBreach::new(context, *zone))
+                .collect(),
+
+            constants: PhantomData,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.door_seals
+            .iter_mut()
+            .for_each(|seal| seal.update(context));
+        self.hull_breaches
+            .iter_mut()
+            .for_each(|breach| breach.update(context));
+    }
+
+    /// Total leakage area, including the nominal leakage of the airframe
+    pub fn leakage_area(&self) -> Area {
+        Area::new::<square_meter>(
+            C::CABIN_LEAKAGE_AREA * (1. + self.airframe_leakage_increase.get::<ratio>().max(0.)),
+        ) + self.door_seals.iter().map(|seal| seal.leak_area).sum()
+            + self.hull_breaches.iter().map(|breach| breach.area).sum()
+    }
+
+    /// Door ID from 1 to the number of doors of the aircraft. Unknown doors don't leak
+    pub fn door_seal_leak_area(&self, door_id: usize) -> Area {
+        door_id
+            .checked_sub(1)
+            .and_then(|index| self.door_seals.get(index))
+            .map_or_else(Area::default, |seal| seal.leak_area)
+    }
+
+    pub fn hull_breach_area(&self, zone_id: ZoneType) -> Area {
+        self.hull_breaches
+            .iter()
+            .find(|breach| breach.zone_id == zone_id)
+            .map_or_else(Area::default, |breach| breach.area)
+    }
+}
+
+impl<C: PressurizationConstants> SimulationElement for FuselageLeakage<C> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.door_seals, visitor);
+        accept_iterable!(self.hull_breaches, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.airframe_leakage_increase = reader.read(&self.airframe_leakage_increase_id);
+    }
+}
+
+/// A degraded door seal leaks progressively more until the failure is cleared
+struct DoorSeal {
+    leak_area: Area,
+    failure: Failure,
+}
+
+impl DoorSeal {
+    const MAX_LEAK_AREA_SQUARE_CENTIMETER: f64 = 15.; // cm2
+    const DEGRADATION_RATE_SQUARE_CENTIMETER_PER_SECOND: f64 = 0.05; // cm2/s
+
+    fn new(door_id: usize) -> Self {
+        Self {
+            leak_area: Area::default(),
+            failure: Failure::new(FailureType::DoorSealLeak(door_id)),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext) {
+        self.leak_area = if self.failure.is_active() {
+            (self.leak_area
+                + Area::new::<square_centimeter>(
+                    Self::DEGRADATION_RATE_SQUARE_CENTIMETER_PER_SECOND
+                        * context.delta_as_secs_f64(),
+                ))
+            .min(Area::new::<square_centimeter>(
+                Self::MAX_LEAK_AREA_SQUARE_CENTIMETER,
+            ))
+        } else {
+            Area::default()
+        };
+    }
+}
+
+impl SimulationElement for DoorSeal {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Breach in the skin of a cabin zone that grows up to the configured area
+struct ProgressiveHullBreach {
+    target_area_id: VariableIdentifier,
+
+    zone_id: ZoneType,
+    target_area: Area,
+    area: Area,
+    failure: Failure,
+}
+
+impl ProgressiveHullBreach {
+    const DEFAULT_TARGET_AREA_SQUARE_CENTIMETER: f64 = 50.; // cm2
+    const GROWTH_RATE_SQUARE_CENTIMETER_PER_SECOND: f64 = 0.5; // cm2/s
+
+    fn new(context: &mut InitContext, zone_id: ZoneType) -> Self {
+        Self {
+            target_area_id: context.get_identifier(format!("PRESS_{}_HULL_BREACH_AREA", zone_id)),
+
+            zone_id,
+            target_area: Area::new::<square_centimeter>(
+                Self::DEFAULT_TARGET_AREA_SQUARE_CENTIMETER,
+            ),
+            area: Area::default(),
+            failure: Failure::new(FailureType::ProgressiveHullBreach(zone_id)),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext) {
+        self.area = if self.failure.is_active() {
+            (self.area
+                + Area::new::<square_centimeter>(
+                    Self::GROWTH_RATE_SQUARE_CENTIMETER_PER_SECOND * context.delta_as_secs_f64(),
+                ))
+            .min(self.target_area)
+        } else {
+            Area::default()
+        };
+    }
+}
+
+impl SimulationElement for ProgressiveHullBreach {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let target_area_square_centimeter: f64 = reader.read(&self.target_area_id);
+        // An unset area uses the default breach size
+        self.target_area = Area::new::<square_centimeter>(if target_area_square_centimeter > 0. {
+            target_area_square_centimeter
+        } else {
+            Self::DEFAULT_TARGET_AREA_SQUARE_CENTIMETER
+        });
+    }
+}
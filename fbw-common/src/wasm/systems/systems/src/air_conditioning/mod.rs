@@ -34,6 +34,7 @@ use uom::si::{
 pub mod acs_controller;
 pub mod cabin_air;
 pub mod cabin_pressure_controller;
+pub mod fuselage_leakage;
 pub mod pressure_valve;
 
 pub trait DuctTemperature {
@@ -54,6 +55,12 @@ pub trait PackFlow {
     }
 }
 
+pub trait CabinRecirculation {
+    fn recirculation_flow(&self) -> MassRate {
+        MassRate::default()
+    }
+}
+
 pub trait PackFlowControllers {
     type PackFlowControllerSignal: ControllerSignal<PackFlowValveSignal>;
     fn pack_flow_controller(&self, pack_id: usize) -> &Self::PackFlowControllerSignal;
@@ -453,6 +460,7 @@ pub trait PressurizationConstants {
     const SAFETY_VALVE_SIZE: f64;
     const DOOR_OPENING_AREA: f64;
     const HULL_BREACH_AREA: f64;
+    const NUMBER_OF_DOORS: usize;
 
     const MAX_CLIMB_RATE: f64;
     const MAX_CLIMB_RATE_IN_DESCENT: f64;
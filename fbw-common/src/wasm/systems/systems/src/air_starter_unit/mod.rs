@@ -1,4 +1,5 @@
 use crate::{
+    ground_services::{GroundService, GroundServices},
     pneumatic::TargetPressureTemperatureSignal,
     shared::ControllerSignal,
     simulation::{Read, SimulationElement, SimulatorReader},
@@ -24,8 +25,9 @@ impl AirStarterUnit {
         }
     }
 
-    pub fn update(&mut self) {
-        if self.turned_on {
+    /// The unit is turned on from the cockpit, or connected by the ground services.
+    pub fn update(&mut self, ground_services: &impl GroundServices) {
+        if self.turned_on || ground_services.is_performing(GroundService::AirStart) {
             self.bleed_air_pressure = Pressure::new::<psi>(50.0);
             self.bleed_air_temperature = ThermodynamicTemperature::new::<degree_celsius>(165.);
         } else {
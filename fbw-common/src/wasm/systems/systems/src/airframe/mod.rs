@@ -1,8 +1,15 @@
+use std::fmt::Write as _;
+
 use uom::si::{f64::Mass, mass::kilogram};
 
-use crate::simulation::{
-    InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, VariableIdentifier,
-    Write,
+use crate::{
+    fuel::FuelPayload,
+    payload::{CargoPayload, PassengerPayload},
+    shared::interpolation,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, VariableIdentifier,
+        Write,
+    },
 };
 
 pub struct LoadsheetInfo {
@@ -276,3 +283,1010 @@ impl SimulationElement for WeightData {
         );
     }
 }
+
+/// A line of the loadsheet: a cabin section, a cargo hold or a fuel tank.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoadsheetEntry {
+    pub name: &'static str,
+    pub passengers: Option<i32>,
+    pub mass: Mass,
+}
+impl LoadsheetEntry {
+    pub fn new(name: &'static str, mass: Mass) -> Self {
+        Self {
+            name,
+            passengers: None,
+            mass,
+        }
+    }
+
+    pub fn with_passengers(name: &'static str, passengers: i32, mass: Mass) -> Self {
+        Self {
+            name,
+            passengers: Some(passengers),
+            mass,
+        }
+    }
+
+    fn serialise(&self, output: &mut String) {
+        write!(output, "{{\"name\":\"{}\"", self.name).unwrap();
+        if let Some(passengers) = self.passengers {
+            write!(output, ",\"pax\":{}", passengers).unwrap();
+        }
+        write!(output, ",\"kg\":{:.0}}}", self.mass.get::<kilogram>()).unwrap();
+    }
+}
+
+/// The final loadsheet, as handed to the crew before departure.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Loadsheet {
+    pub dry_operating_weight: Mass,
+    pub dry_operating_index: f64,
+    pub passengers: Vec<LoadsheetEntry>,
+    pub cargo: Vec<LoadsheetEntry>,
+    pub fuel: Vec<LoadsheetEntry>,
+    pub taxi_fuel: Mass,
+    pub trip_fuel: Mass,
+    pub zero_fuel_weight: Mass,
+    pub take_off_weight: Mass,
+    pub landing_weight: Mass,
+    pub zero_fuel_weight_center_of_gravity: f64, // in % MAC
+    pub take_off_center_of_gravity: f64,         // in % MAC
+    pub take_off_trim: f64,                      // THS in degrees, nose up positive
+}
+impl Loadsheet {
+    pub fn total_passengers(&self) -> i32 {
+        self.passengers
+            .iter()
+            .filter_map(|section| section.passengers)
+            .sum()
+    }
+
+    /// Serialises the loadsheet to JSON, with the masses in kilograms.
+    pub fn serialise(&self) -> String {
+        let mut output = String::new();
+
+        write!(
+            output,
+            "{{\"dow\":{:.0},\"doi\":{:.1}",
+            self.dry_operating_weight.get::<kilogram>(),
+            self.dry_operating_index
+        )
+        .unwrap();
+
+        for (key, entries) in [
+            ("pax", &self.passengers),
+            ("cargo", &self.cargo),
+            ("fuel", &self.fuel),
+        ] {
+            write!(output, ",\"{}\":[", key).unwrap();
+            for (i, entry) in entries.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                entry.serialise(&mut output);
+            }
+            output.push(']');
+        }
+
+        write!(
+            output,
+            ",\"taxiFuel\":{:.0},\"tripFuel\":{:.0},\"zfw\":{:.0},\"tow\":{:.0},\"lw\":{:.0},\"zfwCg\":{:.2},\"toCg\":{:.2},\"thsTrim\":{:.1}}}",
+            self.taxi_fuel.get::<kilogram>(),
+            self.trip_fuel.get::<kilogram>(),
+            self.zero_fuel_weight.get::<kilogram>(),
+            self.take_off_weight.get::<kilogram>(),
+            self.landing_weight.get::<kilogram>(),
+            self.zero_fuel_weight_center_of_gravity,
+            self.take_off_center_of_gravity,
+            self.take_off_trim,
+        )
+        .unwrap();
+
+        output
+    }
+}
+
+/// Maps the take-off CG to the THS setting to use for take-off.
+pub struct TakeOffTrimTable<const N: usize> {
+    center_of_gravity: [f64; N], // in % MAC
+    trim: [f64; N],              // THS in degrees, nose up positive
+}
+impl<const N: usize> TakeOffTrimTable<N> {
+    pub const fn new(center_of_gravity: [f64; N], trim: [f64; N]) -> Self {
+        Self {
+            center_of_gravity,
+            trim,
+        }
+    }
+
+    pub fn trim(&self, center_of_gravity: f64) -> f64 {
+        interpolation(&self.center_of_gravity, &self.trim, center_of_gravity)
+    }
+}
+
+/// The sections of an aircraft's loadsheet, each named after what it is on the balance chart.
+pub struct LoadsheetLayout<const N: usize> {
+    pub info: &'static crate::payload::LoadsheetInfo,
+    /// Every cabin section with the passenger stations it is made of.
+    pub passenger_sections: &'static [(&'static str, &'static [usize])],
+    pub cargo_holds: &'static [&'static str],
+    pub fuel_tanks: &'static [&'static str],
+    pub take_off_trim: TakeOffTrimTable<N>,
+}
+
+/// Reads the taxi and trip fuel entered for the flight and exposes the final loadsheet.
+pub struct LoadsheetData {
+    loadsheet_id: VariableIdentifier,
+    taxi_fuel_id: VariableIdentifier,
+    trip_fuel_id: VariableIdentifier,
+    dry_operating_weight_id: VariableIdentifier,
+    dry_operating_index_id: VariableIdentifier,
+    landing_weight_id: VariableIdentifier,
+    take_off_trim_id: VariableIdentifier,
+
+    taxi_fuel: Mass,
+    trip_fuel: Mass,
+    loadsheet: Loadsheet,
+    serialised_loadsheet: String,
+}
+impl LoadsheetData {
+    pub fn new(context: &mut InitContext) -> Self {
+        LoadsheetData {
+            loadsheet_id: context.get_identifier("AIRFRAME_LOADSHEET".to_owned()),
+            taxi_fuel_id: context.get_identifier("AIRFRAME_TAXI_FUEL".to_owned()),
+            trip_fuel_id: context.get_identifier("AIRFRAME_TRIP_FUEL".to_owned()),
+            dry_operating_weight_id: context.get_identifier("AIRFRAME_DOW".to_owned()),
+            dry_operating_index_id: context.get_identifier("AIRFRAME_DOI".to_owned()),
+            landing_weight_id: context.get_identifier("AIRFRAME_LW".to_owned()),
+            take_off_trim_id: context.get_identifier("AIRFRAME_TO_THS_TRIM".to_owned()),
+            taxi_fuel: Mass::default(),
+            trip_fuel: Mass::default(),
+            loadsheet: Loadsheet::default(),
+            serialised_loadsheet: Loadsheet::default().serialise(),
+        }
+    }
+
+    pub fn taxi_fuel(&self) -> Mass {
+        self.taxi_fuel
+    }
+
+    pub fn trip_fuel(&self) -> Mass {
+        self.trip_fuel
+    }
+
+    pub fn loadsheet(&self) -> &Loadsheet {
+        &self.loadsheet
+    }
+
+    /// Builds the loadsheet from the current payload and fuel, with the weights and CGs already
+    /// computed by the airframe.
+    pub fn update<const N: usize>(
+        &mut self,
+        layout: &LoadsheetLayout<N>,
+        weight: &WeightData,
+        center_of_gravity: &CenterOfGravityData,
+        fuel_payload: &impl FuelPayload,
+        pax_payload: &impl PassengerPayload,
+        cargo_payload: &impl CargoPayload,
+    ) {
+        let dry_operating_weight = Mass::new::<kilogram>(layout.info.operating_empty_weight_kg);
+        let zero_fuel_weight = weight.zero_fuel_weight();
+        let take_off_weight = weight.take_off_weight();
+        let take_off_center_of_gravity = center_of_gravity.take_off_center_of_gravity();
+
+        let loadsheet = Loadsheet {
+            dry_operating_weight,
+            dry_operating_index: layout
+                .info
+                .index(dry_operating_weight, layout.info.operating_empty_position.0),
+            passengers: layout
+                .passenger_sections
+                .iter()
+                .map(|(name, stations)| {
+                    LoadsheetEntry::with_passengers(
+                        name,
+                        stations
+                            .iter()
+                            .map(|&ps| pax_payload.station_passengers(ps) as i32)
+                            .sum(),
+                        stations
+                            .iter()
+                            .map(|&ps| pax_payload.station_passenger_load(ps))
+                            .sum(),
+                    )
+                })
+                .collect(),
+            cargo: layout
+                .cargo_holds
+                .iter()
+                .enumerate()
+                .map(|(cs, name)| LoadsheetEntry::new(name, cargo_payload.station_cargo_load(cs)))
+                .collect(),
+            fuel: layout
+                .fuel_tanks
+                .iter()
+                .enumerate()
+                .map(|(t, name)| LoadsheetEntry::new(name, fuel_payload.tank_mass(t)))
+                .collect(),
+            taxi_fuel: self.taxi_fuel,
+            trip_fuel: self.trip_fuel,
+            zero_fuel_weight,
+            take_off_weight,
+            landing_weight: (take_off_weight - self.trip_fuel).max(zero_fuel_weight),
+            zero_fuel_weight_center_of_gravity: center_of_gravity
+                .zero_fuel_weight_center_of_gravity(),
+            take_off_center_of_gravity,
+            take_off_trim: layout.take_off_trim.trim(take_off_center_of_gravity),
+        };
+
+        if loadsheet != self.loadsheet {
+            self.serialised_loadsheet = loadsheet.serialise();
+            self.loadsheet = loadsheet;
+        }
+    }
+}
+impl SimulationElement for LoadsheetData {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let taxi_fuel_kg: f64 = reader.read(&self.taxi_fuel_id);
+        let trip_fuel_kg: f64 = reader.read(&self.trip_fuel_id);
+        self.taxi_fuel = Mass::new::<kilogram>(taxi_fuel_kg.max(0.));
+        self.trip_fuel = Mass::new::<kilogram>(trip_fuel_kg.max(0.));
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.dry_operating_weight_id,
+            self.loadsheet
+                .dry_operating_weight
+                .get::<kilogram>()
+                .round(),
+        );
+        writer.write(
+            &self.dry_operating_index_id,
+            (self.loadsheet.dry_operating_index * 10.).round() / 10.,
+        );
+        writer.write(
+            &self.landing_weight_id,
+            self.loadsheet.landing_weight.get::<kilogram>().round(),
+        );
+        writer.write(
+            &self.take_off_trim_id,
+            (self.loadsheet.take_off_trim * 10.).round() / 10.,
+        );
+        writer.write_string(&self.loadsheet_id, &self.serialised_loadsheet);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CenterOfGravityEnvelopeType {
+    ZeroFuel,
+    TakeOff,
+    Landing,
+    InFlight,
+}
+impl CenterOfGravityEnvelopeType {
+    const ALL: [CenterOfGravityEnvelopeType; 4] = [
+        CenterOfGravityEnvelopeType::ZeroFuel,
+        CenterOfGravityEnvelopeType::TakeOff,
+        CenterOfGravityEnvelopeType::Landing,
+        CenterOfGravityEnvelopeType::InFlight,
+    ];
+
+    fn id_prefix(&self) -> &'static str {
+        match self {
+            CenterOfGravityEnvelopeType::ZeroFuel => "ZFW",
+            CenterOfGravityEnvelopeType::TakeOff => "TO",
+            CenterOfGravityEnvelopeType::Landing => "LW",
+            CenterOfGravityEnvelopeType::InFlight => "GW",
+        }
+    }
+}
+
+/// How far a load is from the limits of an envelope. A negative margin means the limit is
+/// exceeded.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EnvelopeMargin {
+    forward: f64, // in % MAC
+    aft: f64,     // in % MAC
+    weight: Mass,
+}
+impl EnvelopeMargin {
+    pub fn forward(&self) -> f64 {
+        self.forward
+    }
+
+    pub fn aft(&self) -> f64 {
+        self.aft
+    }
+
+    pub fn weight(&self) -> Mass {
+        self.weight
+    }
+
+    pub fn is_within_limits(&self) -> bool {
+        self.forward >= 0. && self.aft >= 0. && self.weight >= Mass::default()
+    }
+}
+
+/// A weight and balance envelope, bounded by a forward and an aft CG limit line which are
+/// defined at increasing weights. The last weight is the maximum weight of the envelope.
+pub struct CenterOfGravityEnvelope {
+    weight_kg: &'static [f64],
+    forward_limit: &'static [f64], // in % MAC
+    aft_limit: &'static [f64],     // in % MAC
+}
+impl CenterOfGravityEnvelope {
+    pub const fn new(
+        weight_kg: &'static [f64],
+        forward_limit: &'static [f64],
+        aft_limit: &'static [f64],
+    ) -> Self {
+        Self {
+            weight_kg,
+            forward_limit,
+            aft_limit,
+        }
+    }
+
+    pub fn margin(&self, weight: Mass, center_of_gravity: f64) -> EnvelopeMargin {
+        let weight_kg = weight.get::<kilogram>();
+
+        EnvelopeMargin {
+            forward: center_of_gravity
+                - interpolation(self.weight_kg, self.forward_limit, weight_kg),
+            aft: interpolation(self.weight_kg, self.aft_limit, weight_kg) - center_of_gravity,
+            weight: Mass::new::<kilogram>(self.weight_kg[self.weight_kg.len() - 1] - weight_kg),
+        }
+    }
+}
+
+/// The weight and balance envelopes an aircraft is checked against.
+pub struct CenterOfGravityEnvelopes {
+    pub zero_fuel: CenterOfGravityEnvelope,
+    pub take_off: CenterOfGravityEnvelope,
+    pub landing: CenterOfGravityEnvelope,
+    pub in_flight: CenterOfGravityEnvelope,
+}
+
+/// Checks the current and target loads against the weight and balance envelopes.
+pub struct CenterOfGravityLimits {
+    out_of_limits_id: VariableIdentifier,
+    target_out_of_limits_id: VariableIdentifier,
+    forward_margin_ids: [VariableIdentifier; 4],
+    aft_margin_ids: [VariableIdentifier; 4],
+
+    margins: [EnvelopeMargin; 4],
+    target_margins: [EnvelopeMargin; 4],
+}
+impl CenterOfGravityLimits {
+    pub fn new(context: &mut InitContext) -> Self {
+        CenterOfGravityLimits {
+            out_of_limits_id: context.get_identifier("AIRFRAME_CG_OUT_OF_LIMITS".to_owned()),
+            target_out_of_limits_id: context
+                .get_identifier("AIRFRAME_CG_OUT_OF_LIMITS_DESIRED".to_owned()),
+            forward_margin_ids: CenterOfGravityEnvelopeType::ALL.map(|envelope| {
+                context.get_identifier(format!("AIRFRAME_{}_CG_FWD_MARGIN", envelope.id_prefix()))
+            }),
+            aft_margin_ids: CenterOfGravityEnvelopeType::ALL.map(|envelope| {
+                context.get_identifier(format!("AIRFRAME_{}_CG_AFT_MARGIN", envelope.id_prefix()))
+            }),
+            margins: [EnvelopeMargin::default(); 4],
+            target_margins: [EnvelopeMargin::default(); 4],
+        }
+    }
+
+    pub fn margin(&self, envelope: CenterOfGravityEnvelopeType) -> EnvelopeMargin {
+        self.margins[envelope as usize]
+    }
+
+    pub fn target_margin(&self, envelope: CenterOfGravityEnvelopeType) -> EnvelopeMargin {
+        self.target_margins[envelope as usize]
+    }
+
+    /// Checks the current and target loads against the envelopes. The landing weight and CG are
+    /// not kept by the airframe, so they are derived from the zero fuel weight and the fuel left
+    /// after taxi and trip. The target load is the target payload with the target fuel, i.e. the
+    /// load once boarding and refuelling are done.
+    pub fn update(
+        &mut self,
+        envelopes: &CenterOfGravityEnvelopes,
+        info: &crate::payload::LoadsheetInfo,
+        weight: &WeightData,
+        center_of_gravity: &CenterOfGravityData,
+        loadsheet: &LoadsheetData,
+        fuel_payload: &impl FuelPayload,
+    ) {
+        let take_off_fuel = |fuel: Mass| (fuel - loadsheet.taxi_fuel()).max(Mass::default());
+        let landing_fuel =
+            |fuel: Mass| (take_off_fuel(fuel) - loadsheet.trip_fuel()).max(Mass::default());
+        let with_fuel = |zero_fuel_weight: Mass,
+                         zero_fuel_weight_center_of_gravity: f64,
+                         fuel: Mass,
+                         fuel_position: f64| {
+            let weight = zero_fuel_weight + fuel;
+            let moment = zero_fuel_weight * info.position(zero_fuel_weight_center_of_gravity)
+                + fuel * fuel_position;
+
+            (
+                weight,
+                info.percent_mac(moment.get::<kilogram>() / weight.get::<kilogram>()),
+            )
+        };
+
+        let (landing_weight, landing_center_of_gravity) = with_fuel(
+            weight.zero_fuel_weight(),
+            center_of_gravity.zero_fuel_weight_center_of_gravity(),
+            landing_fuel(fuel_payload.total_load()),
+            fuel_payload.fore_aft_center_of_gravity(),
+        );
+        self.margins = [
+            envelopes.zero_fuel.margin(
+                weight.zero_fuel_weight(),
+                center_of_gravity.zero_fuel_weight_center_of_gravity(),
+            ),
+            envelopes.take_off.margin(
+                weight.take_off_weight(),
+                center_of_gravity.take_off_center_of_gravity(),
+            ),
+            envelopes
+                .landing
+                .margin(landing_weight, landing_center_of_gravity),
+            envelopes.in_flight.margin(
+                weight.gross_weight(),
+                center_of_gravity.gross_weight_center_of_gravity(),
+            ),
+        ];
+
+        let target_fuel = fuel_payload.total_target_load();
+        let with_target_fuel = |fuel: Mass| {
+            with_fuel(
+                weight.target_zero_fuel_weight(),
+                center_of_gravity.target_zero_fuel_weight_center_of_gravity(),
+                fuel,
+                fuel_payload.target_fore_aft_center_of_gravity(),
+            )
+        };
+        let (target_take_off_weight, target_take_off_center_of_gravity) =
+            with_target_fuel(take_off_fuel(target_fuel));
+        let (target_landing_weight, target_landing_center_of_gravity) =
+            with_target_fuel(landing_fuel(target_fuel));
+        let (target_gross_weight, target_gross_weight_center_of_gravity) =
+            with_target_fuel(target_fuel);
+        self.target_margins = [
+            envelopes.zero_fuel.margin(
+                weight.target_zero_fuel_weight(),
+                center_of_gravity.target_zero_fuel_weight_center_of_gravity(),
+            ),
+            envelopes
+                .take_off
+                .margin(target_take_off_weight, target_take_off_center_of_gravity),
+            envelopes
+                .landing
+                .margin(target_landing_weight, target_landing_center_of_gravity),
+            envelopes
+                .in_flight
+                .margin(target_gross_weight, target_gross_weight_center_of_gravity),
+        ];
+    }
+
+    pub fn is_within_limits(&self) -> bool {
+        self.margins.iter().all(|margin| margin.is_within_limits())
+    }
+
+    pub fn is_target_within_limits(&self) -> bool {
+        self.target_margins
+            .iter()
+            .all(|margin| margin.is_within_limits())
+    }
+}
+impl SimulationElement for CenterOfGravityLimits {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.out_of_limits_id, !self.is_within_limits());
+        writer.write(
+            &self.target_out_of_limits_id,
+            !self.is_target_within_limits(),
+        );
+        for ((forward_margin_id, aft_margin_id), margin) in self
+            .forward_margin_ids
+            .iter()
+            .zip(self.aft_margin_ids.iter())
+            .zip(self.margins.iter())
+        {
+            writer.write(
+                forward_margin_id,
+                CenterOfGravityData::round_cg_value(margin.forward),
+            );
+            writer.write(
+                aft_margin_id,
+                CenterOfGravityData::round_cg_value(margin.aft),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::Electricity,
+        payload::LoadsheetInfo,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft, SimulationElementVisitor, UpdateContext,
+        },
+    };
+    use nalgebra::Vector3;
+
+    const LOADSHEET: LoadsheetInfo = LoadsheetInfo {
+        operating_empty_weight_kg: 42500.,
+        operating_empty_position: (-9.42, 0., 0.),
+        per_pax_weight_kg: 84.,
+        mean_aerodynamic_chord_size: 13.464,
+        leading_edge_mean_aerodynamic_chord: -5.383,
+        index_constant: 1000.,
+        index_offset: 50.,
+    };
+    const LAYOUT: LoadsheetLayout<2> = LoadsheetLayout {
+        info: &LOADSHEET,
+        passenger_sections: &[("FWD", &[0, 1]), ("AFT", &[2])],
+        cargo_holds: &["FWD", "AFT"],
+        fuel_tanks: &["LEFT", "CENTER", "RIGHT"],
+        take_off_trim: TakeOffTrimTable::new([20., 40.], [3., -2.]),
+    };
+    const ENVELOPES: CenterOfGravityEnvelopes = CenterOfGravityEnvelopes {
+        zero_fuel: CenterOfGravityEnvelope::new(
+            &[37230., 50000., 64300.],
+            &[17., 18., 21.],
+            &[39., 40., 39.],
+        ),
+        take_off: CenterOfGravityEnvelope::new(
+            &[37230., 53000., 63000., 73500., 78000.],
+            &[15., 17., 20., 22.5, 25.],
+            &[38.5, 40., 40., 38., 36.],
+        ),
+        landing: CenterOfGravityEnvelope::new(
+            &[37230., 50000., 67400.],
+            &[15., 16., 20.],
+            &[39., 41., 41.],
+        ),
+        in_flight: CenterOfGravityEnvelope::new(
+            &[37230., 53000., 78000.],
+            &[15., 16.5, 24.],
+            &[40., 41., 37.5],
+        ),
+    };
+
+    struct TestPayload {
+        passengers: [i8; 3],
+        cargo_kg: [f64; 2],
+        fuel_kg: [f64; 3],
+        fuel_position: f64,
+        target_fuel: Option<(f64, f64)>,
+    }
+    impl PassengerPayload for TestPayload {
+        fn total_passenger_load(&self) -> Mass {
+            (0..self.passengers.len())
+                .map(|ps| self.station_passenger_load(ps))
+                .sum()
+        }
+
+        fn total_target_passenger_load(&self) -> Mass {
+            self.total_passenger_load()
+        }
+
+        fn center_of_gravity(&self) -> Vector3<f64> {
+            Vector3::zeros()
+        }
+
+        fn fore_aft_center_of_gravity(&self) -> f64 {
+            0.
+        }
+
+        fn target_center_of_gravity(&self) -> Vector3<f64> {
+            Vector3::zeros()
+        }
+
+        fn target_fore_aft_center_of_gravity(&self) -> f64 {
+            0.
+        }
+
+        fn station_passengers(&self, ps: usize) -> i8 {
+            self.passengers[ps]
+        }
+
+        fn station_passenger_load(&self, ps: usize) -> Mass {
+            Mass::new::<kilogram>(self.passengers[ps] as f64 * LOADSHEET.per_pax_weight_kg)
+        }
+    }
+    impl CargoPayload for TestPayload {
+        fn total_cargo_load(&self) -> Mass {
+            Mass::new::<kilogram>(self.cargo_kg.iter().sum())
+        }
+
+        fn total_target_cargo_load(&self) -> Mass {
+            self.total_cargo_load()
+        }
+
+        fn center_of_gravity(&self) -> Vector3<f64> {
+            Vector3::zeros()
+        }
+
+        fn fore_aft_center_of_gravity(&self) -> f64 {
+            0.
+        }
+
+        fn target_center_of_gravity(&self) -> Vector3<f64> {
+            Vector3::zeros()
+        }
+
+        fn target_fore_aft_center_of_gravity(&self) -> f64 {
+            0.
+        }
+
+        fn station_cargo_load(&self, cs: usize) -> Mass {
+            Mass::new::<kilogram>(self.cargo_kg[cs])
+        }
+    }
+    impl FuelPayload for TestPayload {
+        fn total_load(&self) -> Mass {
+            Mass::new::<kilogram>(self.fuel_kg.iter().sum())
+        }
+
+        fn fore_aft_center_of_gravity(&self) -> f64 {
+            self.fuel_position
+        }
+
+        fn total_target_load(&self) -> Mass {
+            match self.target_fuel {
+                Some((fuel_kg, _)) => Mass::new::<kilogram>(fuel_kg),
+                None => self.total_load(),
+            }
+        }
+
+        fn target_fore_aft_center_of_gravity(&self) -> f64 {
+            match self.target_fuel {
+                Some((_, fuel_position)) => fuel_position,
+                None => self.fuel_position,
+            }
+        }
+
+        fn tank_mass(&self, t: usize) -> Mass {
+            Mass::new::<kilogram>(self.fuel_kg[t])
+        }
+    }
+
+    struct AirframeTestAircraft {
+        weight: WeightData,
+        center_of_gravity: CenterOfGravityData,
+        loadsheet: LoadsheetData,
+        limits: CenterOfGravityLimits,
+        payload: TestPayload,
+    }
+    impl AirframeTestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                weight: WeightData::new(context),
+                center_of_gravity: CenterOfGravityData::new(context),
+                loadsheet: LoadsheetData::new(context),
+                limits: CenterOfGravityLimits::new(context),
+                payload: TestPayload {
+                    passengers: [10, 20, 30],
+                    cargo_kg: [1000., 2000.],
+                    fuel_kg: [3000., 4000., 3000.],
+                    fuel_position: LOADSHEET.position(30.),
+                    target_fuel: None,
+                },
+            }
+        }
+
+        fn set_fuel_center_of_gravity(&mut self, center_of_gravity: f64) {
+            self.payload.fuel_position = LOADSHEET.position(center_of_gravity);
+        }
+
+        fn set_target_fuel(&mut self, fuel_kg: f64, center_of_gravity: f64) {
+            self.payload.target_fuel = Some((fuel_kg, LOADSHEET.position(center_of_gravity)));
+        }
+    }
+    impl Aircraft for AirframeTestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            _electricity: &mut Electricity,
+        ) {
+            self.loadsheet.update(
+                &LAYOUT,
+                &self.weight,
+                &self.center_of_gravity,
+                &self.payload,
+                &self.payload,
+                &self.payload,
+            );
+            self.limits.update(
+                &ENVELOPES,
+                &LOADSHEET,
+                &self.weight,
+                &self.center_of_gravity,
+                &self.loadsheet,
+                &self.payload,
+            );
+        }
+    }
+    impl SimulationElement for AirframeTestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.weight.accept(visitor);
+            self.center_of_gravity.accept(visitor);
+            self.loadsheet.accept(visitor);
+            self.limits.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed(
+        zero_fuel_weight_kg: f64,
+        take_off_weight_kg: f64,
+        take_off_center_of_gravity: f64,
+        trip_fuel_kg: f64,
+    ) -> SimulationTestBed<AirframeTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(AirframeTestAircraft::new);
+        test_bed.write_by_name("AIRFRAME_ZFW", Mass::new::<kilogram>(zero_fuel_weight_kg));
+        test_bed.write_by_name("AIRFRAME_TOW", Mass::new::<kilogram>(take_off_weight_kg));
+        test_bed.write_by_name("AIRFRAME_TO_CG_PERCENT_MAC", take_off_center_of_gravity);
+        test_bed.write_by_name("AIRFRAME_TRIP_FUEL", trip_fuel_kg);
+        test_bed.run();
+
+        test_bed
+    }
+
+    fn loadsheet(test_bed: &SimulationTestBed<AirframeTestAircraft>) -> Loadsheet {
+        test_bed.query(|a| a.loadsheet.loadsheet().clone())
+    }
+
+    /// Loads the aircraft with the 10000 kg of test fuel at the same CG as the zero fuel weight,
+    /// without any taxi fuel.
+    fn limits_test_bed(
+        zero_fuel_weight_kg: f64,
+        zero_fuel_weight_center_of_gravity: f64,
+        target_zero_fuel_weight_kg: f64,
+        target_zero_fuel_weight_center_of_gravity: f64,
+    ) -> SimulationTestBed<AirframeTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(AirframeTestAircraft::new);
+        for (suffix, weight_kg, center_of_gravity) in [
+            ("", zero_fuel_weight_kg, zero_fuel_weight_center_of_gravity),
+            (
+                "_DESIRED",
+                target_zero_fuel_weight_kg,
+                target_zero_fuel_weight_center_of_gravity,
+            ),
+        ] {
+            test_bed.write_by_name(
+                &format!("AIRFRAME_ZFW{suffix}"),
+                Mass::new::<kilogram>(weight_kg),
+            );
+            test_bed.write_by_name(
+                &format!("AIRFRAME_ZFW_CG_PERCENT_MAC{suffix}"),
+                center_of_gravity,
+            );
+            for weight in ["GW", "TOW"] {
+                test_bed.write_by_name(
+                    &format!("AIRFRAME_{weight}{suffix}"),
+                    Mass::new::<kilogram>(weight_kg + 10000.),
+                );
+            }
+            for weight in ["GW", "TO"] {
+                test_bed.write_by_name(
+                    &format!("AIRFRAME_{weight}_CG_PERCENT_MAC{suffix}"),
+                    center_of_gravity,
+                );
+            }
+        }
+        test_bed.command(|a| a.set_fuel_center_of_gravity(zero_fuel_weight_center_of_gravity));
+
+        test_bed
+    }
+
+    fn margin(
+        test_bed: &SimulationTestBed<AirframeTestAircraft>,
+        envelope: CenterOfGravityEnvelopeType,
+    ) -> EnvelopeMargin {
+        test_bed.query(|a| a.limits.margin(envelope))
+    }
+
+    #[test]
+    fn loadsheet_lists_every_cabin_section_cargo_hold_and_fuel_tank() {
+        let loadsheet = loadsheet(&test_bed(50000., 60000., 30., 0.));
+
+        assert_eq!(
+            loadsheet.passengers,
+            vec![
+                LoadsheetEntry::with_passengers("FWD", 30, Mass::new::<kilogram>(30. * 84.)),
+                LoadsheetEntry::with_passengers("AFT", 30, Mass::new::<kilogram>(30. * 84.)),
+            ]
+        );
+        assert_eq!(loadsheet.total_passengers(), 60);
+        assert_eq!(
+            loadsheet.cargo,
+            vec![
+                LoadsheetEntry::new("FWD", Mass::new::<kilogram>(1000.)),
+                LoadsheetEntry::new("AFT", Mass::new::<kilogram>(2000.)),
+            ]
+        );
+        assert_eq!(loadsheet.fuel.len(), 3);
+        assert_eq!(loadsheet.fuel[1].mass, Mass::new::<kilogram>(4000.));
+    }
+
+    #[test]
+    fn dry_operating_weight_and_index_come_from_the_loadsheet_info() {
+        let mut test_bed = test_bed(50000., 60000., 30., 0.);
+        let loadsheet = loadsheet(&test_bed);
+
+        assert_eq!(
+            loadsheet.dry_operating_weight,
+            Mass::new::<kilogram>(42500.)
+        );
+        let dry_operating_index: f64 = test_bed.read_by_name("AIRFRAME_DOI");
+        assert_eq!(dry_operating_index, 78.5);
+    }
+
+    #[test]
+    fn trip_fuel_is_deducted_from_the_take_off_weight_for_the_landing_weight() {
+        let mut test_bed = test_bed(50000., 60000., 30., 4000.);
+
+        assert!((loadsheet(&test_bed).landing_weight.get::<kilogram>() - 56000.).abs() < 0.01);
+        let landing_weight: f64 = test_bed.read_by_name("AIRFRAME_LW");
+        assert_eq!(landing_weight, 56000.);
+    }
+
+    #[test]
+    fn landing_weight_is_never_below_the_zero_fuel_weight() {
+        let loadsheet = loadsheet(&test_bed(50000., 60000., 30., 15000.));
+
+        assert_eq!(loadsheet.landing_weight, loadsheet.zero_fuel_weight);
+    }
+
+    #[test]
+    fn take_off_trim_is_more_nose_down_with_an_aft_cg() {
+        let mut forward = test_bed(50000., 60000., 25., 0.);
+        let mut aft = test_bed(50000., 60000., 35., 0.);
+
+        let forward_trim: f64 = forward.read_by_name("AIRFRAME_TO_THS_TRIM");
+        let aft_trim: f64 = aft.read_by_name("AIRFRAME_TO_THS_TRIM");
+        assert_eq!(forward_trim, 1.8);
+        assert_eq!(aft_trim, -0.8);
+    }
+
+    #[test]
+    fn loadsheet_is_published_as_json() {
+        let mut test_bed = test_bed(50000., 60000., 30., 4000.);
+
+        let published = test_bed.read_string_by_name("AIRFRAME_LOADSHEET");
+
+        assert_eq!(published, loadsheet(&test_bed).serialise());
+        assert!(published.starts_with(
+            "{\"dow\":42500,\"doi\":78.5,\"pax\":[{\"name\":\"FWD\",\"pax\":30,\"kg\":2520}"
+        ));
+        assert!(published.ends_with(
+            "\"tow\":60000,\"lw\":56000,\"zfwCg\":0.00,\"toCg\":30.00,\"thsTrim\":0.5}"
+        ));
+        assert_eq!(published.matches("\"name\"").count(), 7);
+    }
+
+    #[test]
+    fn load_inside_every_envelope_is_within_limits() {
+        let mut test_bed = limits_test_bed(50000., 30., 55000., 30.);
+        test_bed.run();
+
+        let out_of_limits: bool = test_bed.read_by_name("AIRFRAME_CG_OUT_OF_LIMITS");
+        let target_out_of_limits: bool = test_bed.read_by_name("AIRFRAME_CG_OUT_OF_LIMITS_DESIRED");
+
+        assert!(test_bed.query(|a| a.limits.is_within_limits()));
+        assert!(test_bed.query(|a| a.limits.is_target_within_limits()));
+        assert!(!out_of_limits);
+        assert!(!target_out_of_limits);
+        assert!(margin(&test_bed, CenterOfGravityEnvelopeType::ZeroFuel).forward() > 0.);
+        assert!(margin(&test_bed, CenterOfGravityEnvelopeType::ZeroFuel).aft() > 0.);
+    }
+
+    #[test]
+    fn target_load_above_maximum_zero_fuel_weight_is_out_of_limits() {
+        let mut test_bed = limits_test_bed(50000., 30., 70000., 30.);
+        test_bed.run();
+
+        let out_of_limits: bool = test_bed.read_by_name("AIRFRAME_CG_OUT_OF_LIMITS");
+        let target_out_of_limits: bool = test_bed.read_by_name("AIRFRAME_CG_OUT_OF_LIMITS_DESIRED");
+
+        assert!(test_bed.query(|a| a.limits.is_within_limits()));
+        assert!(!test_bed.query(|a| a.limits.is_target_within_limits()));
+        assert!(!out_of_limits);
+        assert!(target_out_of_limits);
+        assert!(
+            test_bed.query(|a| a
+                .limits
+                .target_margin(CenterOfGravityEnvelopeType::ZeroFuel)
+                .weight())
+                < Mass::default()
+        );
+    }
+
+    #[test]
+    fn cg_aft_of_the_envelope_exceeds_the_aft_limit() {
+        let mut test_bed = limits_test_bed(50000., 45., 50000., 30.);
+        test_bed.run();
+
+        let aft_margin: f64 = test_bed.read_by_name("AIRFRAME_ZFW_CG_AFT_MARGIN");
+
+        assert!(!test_bed.query(|a| a.limits.is_within_limits()));
+        assert!(margin(&test_bed, CenterOfGravityEnvelopeType::ZeroFuel).aft() < 0.);
+        assert!(
+            margin(&test_bed, CenterOfGravityEnvelopeType::ZeroFuel).weight() > Mass::default()
+        );
+        assert_eq!(aft_margin, -5.);
+    }
+
+    #[test]
+    fn landing_cg_combines_the_zero_fuel_weight_with_the_fuel_left_after_the_trip() {
+        let mut test_bed = limits_test_bed(50000., 30., 50000., 30.);
+        test_bed.command(|a| a.set_fuel_center_of_gravity(20.));
+        test_bed.write_by_name("AIRFRAME_TRIP_FUEL", 5000.);
+        test_bed.run();
+
+        // 55000 kg at (50000 * 30 + 5000 * 20) / 55000 % MAC.
+        let landing = margin(&test_bed, CenterOfGravityEnvelopeType::Landing);
+        let landing_center_of_gravity = 1600000. / 55000.;
+        let forward_limit = 16. + 4. * 5000. / 17400.;
+        assert!((landing.forward() - (landing_center_of_gravity - forward_limit)).abs() < 1e-6);
+        assert!((landing.aft() - (41. - landing_center_of_gravity)).abs() < 1e-6);
+        assert!((landing.weight().get::<kilogram>() - 12400.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn target_landing_cg_combines_the_target_zero_fuel_weight_with_the_target_fuel() {
+        let mut test_bed = limits_test_bed(50000., 30., 50000., 30.);
+        test_bed.command(|a| a.set_target_fuel(20000., 20.));
+        test_bed.write_by_name("AIRFRAME_TRIP_FUEL", 5000.);
+        test_bed.run();
+
+        // 65000 kg at (50000 * 30 + 15000 * 20) / 65000 % MAC.
+        let landing =
+            test_bed.query(|a| a.limits.target_margin(CenterOfGravityEnvelopeType::Landing));
+        let landing_center_of_gravity = 1800000. / 65000.;
+        let forward_limit = 16. + 4. * 15000. / 17400.;
+        assert!((landing.forward() - (landing_center_of_gravity - forward_limit)).abs() < 1e-6);
+        assert!((landing.aft() - (41. - landing_center_of_gravity)).abs() < 1e-6);
+        assert!((landing.weight().get::<kilogram>() - 2400.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn target_fuel_bringing_the_cg_back_in_the_envelopes_is_within_target_limits() {
+        let mut test_bed = limits_test_bed(50000., 18.5, 50000., 18.5);
+        test_bed.command(|a| a.set_fuel_center_of_gravity(15.));
+        test_bed.command(|a| a.set_target_fuel(10000., 30.));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.limits.is_within_limits()));
+        assert!(test_bed.query(|a| a.limits.is_target_within_limits()));
+    }
+
+    #[test]
+    fn target_fuel_keeping_the_cg_out_of_the_envelopes_is_out_of_target_limits() {
+        let mut test_bed = limits_test_bed(50000., 18.5, 50000., 18.5);
+        test_bed.command(|a| a.set_fuel_center_of_gravity(15.));
+        test_bed.command(|a| a.set_target_fuel(20000., 10.));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.limits.is_within_limits()));
+        assert!(!test_bed.query(|a| a.limits.is_target_within_limits()));
+    }
+
+    #[test]
+    fn landing_weight_is_the_zero_fuel_weight_when_the_trip_uses_all_the_fuel() {
+        let mut test_bed = limits_test_bed(50000., 30., 50000., 30.);
+        test_bed.write_by_name("AIRFRAME_TRIP_FUEL", 20000.);
+        test_bed.run();
+
+        let landing = margin(&test_bed, CenterOfGravityEnvelopeType::Landing);
+        assert!((landing.weight().get::<kilogram>() - 17400.).abs() < 1e-6);
+        assert!((landing.aft() - 11.).abs() < 1e-6);
+    }
+}
@@ -1,8 +1,9 @@
 use std::time::Duration;
 
 use uom::si::{
-    electric_potential::volt, f64::*, frequency::hertz, power::watt, pressure::psi, ratio::percent,
-    temperature_interval, thermodynamic_temperature::degree_celsius,
+    electric_potential::volt, f64::*, frequency::hertz, mass_rate::kilogram_per_hour, power::watt,
+    pressure::psi, ratio::percent, ratio::ratio, temperature_interval,
+    thermodynamic_temperature::degree_celsius,
 };
 
 use crate::{
@@ -13,13 +14,16 @@ use crate::{
     },
     failures::{Failure, FailureType},
     shared::{
-        calculate_towards_target_temperature, random_number, ConsumePower, ControllerSignal,
-        ElectricalBusType, ElectricalBuses, PotentialOrigin, PowerConsumptionReport,
+        calculate_towards_target_temperature, low_pass_filter::LowPassFilter, random_number,
+        ConsumePower, ControllerSignal, ElectricalBusType, ElectricalBuses, PotentialOrigin,
+        PowerConsumptionReport,
     },
     simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
 };
 
-use super::{ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
+use super::{
+    ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineLoad, TurbineSignal, TurbineState,
+};
 
 pub struct Aps3200Constants;
 
@@ -29,7 +33,15 @@ impl ApuConstants for Aps3200Constants {
     const COOLDOWN_DURATION: Duration = Duration::ZERO;
     const AIR_INTAKE_FLAP_CLOSURE_PERCENT: f64 = 7.;
     const SHOULD_BE_AVAILABLE_DURING_SHUTDOWN: bool = true;
-    const FUEL_LINE_ID: u8 = 18;
+    const OIL_CAPACITY: f64 = 5.5; // US quarts
+    const OIL_LOW_LEVEL_QUANTITY: f64 = 3.5; // US quarts
+    const OIL_MINIMUM_QUANTITY: f64 = 2.; // US quarts
+    const OIL_CONSUMPTION_PER_HOUR: f64 = 0.02; // US quarts
+    const STARTER_MAX_CONSECUTIVE_STARTS: u8 = 3;
+    const STARTER_COOLDOWN_DURATION: Duration = Duration::from_secs(60 * 60);
+    const EGT_MARGIN_LOSS_PER_OPERATING_HOUR: f64 = 0.01; // Deg C
+    const DEGRADED_EGT_MARGIN_LOSS: f64 = 250.; // Deg C
+    const NOMINAL_BLEED_AIR_FLOW: f64 = 0.9; // Kilograms per second
 }
 
 pub struct ShutdownAps3200Turbine {
@@ -50,8 +62,7 @@ impl Turbine for ShutdownAps3200Turbine {
     fn update(
         mut self: Box<Self>,
         context: &UpdateContext,
-        _: bool,
-        _: bool,
+        _: TurbineLoad,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.egt = calculate_towards_ambient_egt(self.egt, context);
@@ -196,8 +207,7 @@ impl Turbine for Starting {
     fn update(
         mut self: Box<Self>,
         context: &UpdateContext,
-        _: bool,
-        _: bool,
+        _: TurbineLoad,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
@@ -235,48 +245,54 @@ impl Turbine for Starting {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn fuel_flow(&self) -> MassRate {
+        MassRate::new::<kilogram_per_hour>(Running::NO_LOAD_FUEL_FLOW) * self.n.get::<ratio>()
+    }
 }
 
 struct BleedAirUsageEgtDelta {
     current: f64,
     target: f64,
-    max: f64,
-    min: f64,
+    nominal: f64,
 }
 impl BleedAirUsageEgtDelta {
+    // The load compressor cannot deliver much more than twice the flow the packs demand.
+    const MAX_BLEED_AIR_DEMAND: f64 = 2.;
+
     fn new() -> Self {
         let randomisation = 0.95 + ((random_number() % 101) as f64 / 1000.);
 
         Self {
             current: 0.,
             target: 0.,
-            max: 90. * randomisation,
-            min: 0.,
+            nominal: 90. * randomisation,
         }
     }
 
-    fn update(&mut self, context: &UpdateContext, apu_bleed_is_used: bool) {
-        self.target = if apu_bleed_is_used {
-            self.max
+    fn update(&mut self, context: &UpdateContext, bleed_air_demand: Ratio) {
+        self.target = self.nominal
+            * bleed_air_demand
+                .get::<ratio>()
+                .clamp(0., Self::MAX_BLEED_AIR_DEMAND);
+
+        let step = self.delta_per_second() * context.delta_as_secs_f64();
+        self.current = if self.current > self.target {
+            (self.current - step).max(self.target)
         } else {
-            self.min
+            (self.current + step).min(self.target)
         };
-
-        if (self.current - self.target).abs() > f64::EPSILON {
-            if self.current > self.target {
-                self.current -= self.delta_per_second() * context.delta_as_secs_f64();
-            } else {
-                self.current += self.delta_per_second() * context.delta_as_secs_f64();
-            }
-        }
-
-        self.current = self.current.max(self.min).min(self.max);
     }
 
     fn egt_delta(&self) -> TemperatureInterval {
         TemperatureInterval::new::<temperature_interval::degree_celsius>(self.current)
     }
 
+    /// The bleed air demand the load compressor is currently delivering.
+    fn demand(&self) -> f64 {
+        self.current / self.nominal
+    }
+
     fn delta_per_second(&self) -> f64 {
         // Loosely based on bleed on data provided in a video by Komp.
         // The very much relates to pneumatics and thus could be improved further
@@ -291,11 +307,9 @@ impl BleedAirUsageEgtDelta {
         const BLEED_AIR_DELTA_TEMP_X7: f64 = 0.00000000091837058563;
         const BLEED_AIR_DELTA_TEMP_X8: f64 = -0.00000000000246054885;
 
-        let difference = if self.current > self.target {
-            self.current - self.target
-        } else {
-            self.target - self.current
-        };
+        // The curve was fitted for differences up to the nominal bleed air usage
+        // and diverges beyond that.
+        let difference = (self.current - self.target).abs().min(100.);
 
         BLEED_AIR_DELTA_TEMP_CONST
             + (BLEED_AIR_DELTA_TEMP_X * difference)
@@ -341,14 +355,53 @@ impl ApuGenUsageEgtDelta {
     }
 }
 
+/// The shaft power taken by the generator raises the EGT further with the electrical
+/// load, on top of the rise caused by merely driving the generator.
+struct ApuGenLoadEgtDelta {
+    load: LowPassFilter<f64>,
+}
+impl ApuGenLoadEgtDelta {
+    const EGT_RISE_AT_FULL_LOAD: f64 = 70.;
+    const MAX_LOAD: f64 = 1.5;
+    const LOAD_TIME_CONSTANT: Duration = Duration::from_secs(5);
+
+    fn new() -> Self {
+        Self {
+            load: LowPassFilter::new(Self::LOAD_TIME_CONSTANT),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, generator_load: Ratio) {
+        self.load.update(
+            context.delta(),
+            generator_load.get::<ratio>().clamp(0., Self::MAX_LOAD),
+        );
+    }
+
+    fn egt_delta(&self) -> TemperatureInterval {
+        TemperatureInterval::new::<temperature_interval::degree_celsius>(
+            self.load() * Self::EGT_RISE_AT_FULL_LOAD,
+        )
+    }
+
+    fn load(&self) -> f64 {
+        self.load.output()
+    }
+}
+
 struct Running {
     egt: ThermodynamicTemperature,
     base_egt: ThermodynamicTemperature,
     base_egt_deviation: TemperatureInterval,
     bleed_air_usage: BleedAirUsageEgtDelta,
     apu_gen_usage: ApuGenUsageEgtDelta,
+    apu_gen_load: ApuGenLoadEgtDelta,
 }
 impl Running {
+    const NO_LOAD_FUEL_FLOW: f64 = 70.; // Kilograms per hour
+    const FULL_GENERATOR_LOAD_FUEL_FLOW: f64 = 30.; // Kilograms per hour
+    const NOMINAL_BLEED_AIR_FUEL_FLOW: f64 = 55.; // Kilograms per hour
+
     fn new(egt: ThermodynamicTemperature) -> Running {
         let base_egt = 340. + ((random_number() % 11) as f64);
         Running {
@@ -362,14 +415,14 @@ impl Running {
             ),
             bleed_air_usage: BleedAirUsageEgtDelta::new(),
             apu_gen_usage: ApuGenUsageEgtDelta::new(),
+            apu_gen_load: ApuGenLoadEgtDelta::new(),
         }
     }
 
     fn calculate_egt(
         &mut self,
         context: &UpdateContext,
-        apu_gen_is_used: bool,
-        apu_bleed_is_used: bool,
+        load: TurbineLoad,
     ) -> ThermodynamicTemperature {
         // Reduce the deviation by 1 per second to slowly creep back to normal temperatures
         self.base_egt_deviation -= TemperatureInterval::new::<temperature_interval::degree_celsius>(
@@ -380,10 +433,14 @@ impl Running {
         );
 
         let mut target = self.base_egt + self.base_egt_deviation;
-        self.apu_gen_usage.update(context, apu_gen_is_used);
+        self.apu_gen_usage.update(context, load.generator_is_used());
         target += self.apu_gen_usage.egt_delta();
 
-        self.bleed_air_usage.update(context, apu_bleed_is_used);
+        self.apu_gen_load.update(context, load.generator_load());
+        target += self.apu_gen_load.egt_delta();
+
+        self.bleed_air_usage
+            .update(context, load.bleed_air_demand());
         target += self.bleed_air_usage.egt_delta();
 
         target
@@ -393,11 +450,10 @@ impl Turbine for Running {
     fn update(
         mut self: Box<Self>,
         context: &UpdateContext,
-        apu_bleed_is_used: bool,
-        apu_gen_is_used: bool,
+        load: TurbineLoad,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
-        self.egt = self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used);
+        self.egt = self.calculate_egt(context, load);
 
         match controller.signal() {
             Some(TurbineSignal::StartOrContinue) => self,
@@ -423,6 +479,14 @@ impl Turbine for Running {
         // TODO: Figure out what value this is supposed to be.
         Pressure::new::<psi>(50.)
     }
+
+    fn fuel_flow(&self) -> MassRate {
+        MassRate::new::<kilogram_per_hour>(
+            Running::NO_LOAD_FUEL_FLOW
+                + self.apu_gen_load.load() * Running::FULL_GENERATOR_LOAD_FUEL_FLOW
+                + self.bleed_air_usage.demand() * Running::NOMINAL_BLEED_AIR_FUEL_FLOW,
+        )
+    }
 }
 
 struct Stopping {
@@ -527,8 +591,7 @@ impl Turbine for Stopping {
     fn update(
         mut self: Box<Self>,
         context: &UpdateContext,
-        _: bool,
-        _: bool,
+        _: TurbineLoad,
         _: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
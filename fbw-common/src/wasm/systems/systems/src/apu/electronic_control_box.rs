@@ -2,12 +2,16 @@ use std::marker::PhantomData;
 use std::time::Duration;
 
 use uom::si::{
-    f64::*, mass_concentration::kilogram_per_liter, power::watt, pressure::bar, pressure::psi,
-    ratio::percent, thermodynamic_temperature::degree_celsius, volume_rate::gallon_per_minute,
+    f64::*, mass_rate::kilogram_per_hour, power::watt, pressure::bar, pressure::psi,
+    ratio::percent, ratio::ratio, temperature_interval, thermodynamic_temperature::degree_celsius,
+    time::hour,
 };
 
+use crate::failures::{Failure, FailureType};
 use crate::shared::{EngineCorrectedN1, LgciuWeightOnWheels};
-use crate::simulation::{InitContext, SimulatorReader, VariableIdentifier};
+use crate::simulation::{
+    InitContext, SimulationElementVisitor, SimulatorReader, VariableIdentifier,
+};
 use crate::{
     pneumatic::PneumaticValveSignal,
     shared::{
@@ -19,7 +23,7 @@ use crate::{
 
 use super::ApuConstants;
 use super::{
-    air_intake_flap::AirIntakeFlapSignal, AirIntakeFlap, ApuStartMotor,
+    air_intake_flap::AirIntakeFlapSignal, oil_system::ApuOilSystem, AirIntakeFlap, ApuStartMotor,
     AuxiliaryPowerUnitFireOverheadPanel, AuxiliaryPowerUnitOverheadPanel, FuelPressureSwitch,
     Turbine, TurbineSignal, TurbineState,
 };
@@ -34,11 +38,14 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     apu_low_fuel_pressure_fault_id: VariableIdentifier,
     apu_flap_fully_open_id: VariableIdentifier,
     apu_fuel_used_id: VariableIdentifier,
+    apu_fuel_flow_id: VariableIdentifier,
     ecam_inop_sys_apu_id: VariableIdentifier,
     apu_is_auto_shutdown_id: VariableIdentifier,
     apu_is_emergency_shutdown_id: VariableIdentifier,
     apu_bleed_air_pressure_id: VariableIdentifier,
-    apu_fuel_line_flow_id: VariableIdentifier,
+    apu_low_oil_level_id: VariableIdentifier,
+    apu_starter_cooldown_id: VariableIdentifier,
+    apu_operating_hours_id: VariableIdentifier,
 
     powered_by: ElectricalBusType,
     is_powered: bool,
@@ -53,8 +60,9 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     bleed_air_valve_last_open_time_ago: Duration,
     bleed_air_pressure: Pressure,
     fault: Option<ApuFault>,
-    fuel_flow: VolumeRate,
     fuel_used: Mass,
+    /// The fuel flow the fuel control unit meters to the turbine for the current load.
+    metered_fuel_flow: MassRate,
     air_intake_flap_open_amount: Ratio,
     egt: ThermodynamicTemperature,
     egt_warning_temperature: ThermodynamicTemperature,
@@ -68,12 +76,27 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     /// of the aircraft.
     /// In the context of the ecb this means that the APU cooldown is skipped.
     aircraft_preset_quick_mode: bool,
+    oil_level_is_low: bool,
+    /// Consecutive start attempts which were aborted or failed
+    start_attempts: u8,
+    last_start_attempt_ago: Duration,
+    start_motor_was_powered: bool,
+    starting_for: Duration,
+    egt_above_warning_for: Duration,
+    /// Operating time since the last hot section overhaul. It is read back from the
+    /// operating hours variable, which starts at zero when the aircraft is loaded.
+    operating_time: Time,
+    ignition_failure: Failure,
+    egt_margin_degradation_failure: Failure,
 
     constants: PhantomData<C>,
 }
 impl<C: ApuConstants> ElectronicControlBox<C> {
     const START_MOTOR_POWERED_UNTIL_N: f64 = 55.;
-    const JET_A_1_DENSITY: f64 = 0.804; // Kilograms per Liter
+    // Ignition is commanded 1.5 seconds after the start, the ECB expects an EGT rise shortly after
+    const LIGHT_OFF_DETECTION_TIME: Duration = Duration::from_secs(6);
+    const EGT_OVER_TEMPERATURE_CONFIRMATION_TIME: Duration = Duration::from_secs(5);
+    const MINIMUM_LOAD_SHEDDING_BLEED_AIR_VALVE_OPEN_AMOUNT: f64 = 0.3;
 
     pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
         ElectronicControlBox {
@@ -87,13 +110,15 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
                 .get_identifier("APU_LOW_FUEL_PRESSURE_FAULT".to_owned()),
             apu_flap_fully_open_id: context.get_identifier("APU_FLAP_FULLY_OPEN".to_owned()),
             apu_fuel_used_id: context.get_identifier("APU_FUEL_USED".to_owned()),
+            apu_fuel_flow_id: context.get_identifier("APU_FUEL_FLOW".to_owned()),
             ecam_inop_sys_apu_id: context.get_identifier("ECAM_INOP_SYS_APU".to_owned()),
             apu_is_auto_shutdown_id: context.get_identifier("APU_IS_AUTO_SHUTDOWN".to_owned()),
             apu_is_emergency_shutdown_id: context
                 .get_identifier("APU_IS_EMERGENCY_SHUTDOWN".to_owned()),
             apu_bleed_air_pressure_id: context.get_identifier("APU_BLEED_AIR_PRESSURE".to_owned()),
-            apu_fuel_line_flow_id: context
-                .get_identifier(format!("FUELSYSTEM LINE FUEL FLOW:{}", C::FUEL_LINE_ID)),
+            apu_low_oil_level_id: context.get_identifier("APU_LOW_OIL_LEVEL".to_owned()),
+            apu_starter_cooldown_id: context.get_identifier("APU_STARTER_COOLDOWN".to_owned()),
+            apu_operating_hours_id: context.get_identifier("APU_OPERATING_HOURS".to_owned()),
 
             powered_by,
             is_powered: false,
@@ -108,8 +133,8 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             bleed_air_valve_last_open_time_ago: Duration::from_secs(1000),
             bleed_air_pressure: Pressure::new::<psi>(0.),
             fault: None,
-            fuel_flow: VolumeRate::default(),
             fuel_used: Mass::default(),
+            metered_fuel_flow: MassRate::default(),
             air_intake_flap_open_amount: Ratio::new::<percent>(0.),
             egt: ThermodynamicTemperature::new::<degree_celsius>(0.),
             egt_warning_temperature: ThermodynamicTemperature::new::<degree_celsius>(
@@ -121,6 +146,15 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             on_ground: false,
             inlet_pressure: Pressure::new::<bar>(0.94),
             aircraft_preset_quick_mode: false,
+            oil_level_is_low: false,
+            start_attempts: 0,
+            last_start_attempt_ago: C::STARTER_COOLDOWN_DURATION,
+            start_motor_was_powered: false,
+            starting_for: Duration::ZERO,
+            egt_above_warning_for: Duration::ZERO,
+            operating_time: Time::default(),
+            ignition_failure: Failure::new(FailureType::ApuIgnitionFault),
+            egt_margin_degradation_failure: Failure::new(FailureType::ApuEgtMarginDegradation),
 
             constants: PhantomData,
         }
@@ -170,6 +204,22 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
     pub fn update_start_motor_state(&mut self, start_motor: &impl ApuStartMotor) {
         self.start_motor_is_powered = start_motor.is_powered();
 
+        if self.start_motor_is_powered && !self.start_motor_was_powered {
+            if self.last_start_attempt_ago > C::STARTER_COOLDOWN_DURATION {
+                self.start_attempts = 0;
+            }
+
+            self.last_start_attempt_ago = Duration::ZERO;
+        } else if !self.start_motor_is_powered
+            && self.start_motor_was_powered
+            && self.n.get::<percent>() < Self::START_MOTOR_POWERED_UNTIL_N
+        {
+            // The start motor was released before the APU reached the motor cut-off speed,
+            // the start attempt was aborted or failed.
+            self.start_attempts += 1;
+        }
+        self.start_motor_was_powered = self.start_motor_is_powered;
+
         if matches!(
             <ElectronicControlBox<C> as ControllerSignal<ContactorSignal>>::signal(self),
             Some(ContactorSignal::Close)
@@ -183,15 +233,23 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         self.aircraft_preset_quick_mode = context.aircraft_preset_quick_mode();
 
         self.update_air_intake_state(context);
-        self.update_fuel_used(context);
 
         self.n2 = turbine.n2();
         self.n = turbine.n();
-        self.egt = turbine.egt();
         self.turbine_state = turbine.state();
+        self.egt = turbine.egt() + self.egt_margin_loss() * self.n.get::<ratio>();
         self.bleed_air_pressure = turbine.bleed_air_pressure();
+        self.metered_fuel_flow = turbine.fuel_flow();
+        self.update_fuel_used(context);
+
+        self.last_start_attempt_ago += context.delta();
+        if self.turbine_state == TurbineState::Running {
+            self.operating_time += context.delta_as_time();
+        }
 
         self.egt_warning_temperature = self.calculate_egt_warning_temperature(&self.turbine_state);
+        self.update_light_off(context);
+        self.update_egt_over_temperature(context);
         if self.n.get::<percent>() > 95. {
             self.n_above_95_duration += context.delta();
         } else {
@@ -221,6 +279,76 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         }
     }
 
+    pub fn update_oil_system_state(&mut self, oil_system: &ApuOilSystem) {
+        self.oil_level_is_low = oil_system.level_is_low();
+
+        if self.fault.is_none()
+            && self.n.get::<percent>() > 0.
+            && oil_system.quantity_is_below_minimum()
+        {
+            self.fault = Some(ApuFault::LowOilPressure);
+        }
+    }
+
+    fn update_light_off(&mut self, context: &UpdateContext) {
+        if self.turbine_state == TurbineState::Starting {
+            self.starting_for += context.delta();
+        } else {
+            self.starting_for = Duration::ZERO;
+        }
+
+        if self.fault.is_none()
+            && self.ignition_failure.is_active()
+            && self.starting_for > Self::LIGHT_OFF_DETECTION_TIME
+        {
+            self.fault = Some(ApuFault::NoLightOff);
+        }
+    }
+
+    fn update_egt_over_temperature(&mut self, context: &UpdateContext) {
+        if self.egt > self.egt_warning_temperature {
+            self.egt_above_warning_for += context.delta();
+        } else {
+            self.egt_above_warning_for = Duration::ZERO;
+        }
+
+        if self.fault.is_none()
+            && self.egt_above_warning_for > Self::EGT_OVER_TEMPERATURE_CONFIRMATION_TIME
+        {
+            self.fault = Some(ApuFault::EgtOverTemperature);
+        }
+    }
+
+    fn egt_margin_loss(&self) -> TemperatureInterval {
+        let mut margin_loss =
+            self.operating_time.get::<hour>() * C::EGT_MARGIN_LOSS_PER_OPERATING_HOUR;
+        if self.egt_margin_degradation_failure.is_active() {
+            margin_loss += C::DEGRADED_EGT_MARGIN_LOSS;
+        }
+
+        TemperatureInterval::new::<temperature_interval::degree_celsius>(margin_loss)
+    }
+
+    /// Above the EGT caution temperature the ECB progressively closes the bleed air valve to
+    /// unload the load compressor, so that the generator can keep supplying electrical power.
+    fn bleed_air_load_shedding_open_amount(&self) -> Ratio {
+        let caution_temperature = self.egt_caution_temperature().get::<degree_celsius>();
+        let shedding = ((self.egt.get::<degree_celsius>() - caution_temperature)
+            / (self.egt_warning_temperature.get::<degree_celsius>() - caution_temperature))
+            .clamp(0., 1.);
+
+        Ratio::new::<ratio>(
+            1. - shedding * (1. - Self::MINIMUM_LOAD_SHEDDING_BLEED_AIR_VALVE_OPEN_AMOUNT),
+        )
+    }
+
+    /// The starter motor is limited to a number of consecutive start attempts,
+    /// after which it has to cool down before the next start.
+    pub fn starter_is_cooling_down(&self) -> bool {
+        self.start_attempts >= C::STARTER_MAX_CONSECUTIVE_STARTS
+            && self.last_start_attempt_ago <= C::STARTER_COOLDOWN_DURATION
+    }
+
     pub fn update_fuel_pressure_switch_state(&mut self, fuel_pressure_switch: &FuelPressureSwitch) {
         if self.fault.is_none()
             && 0. < self.n.get::<percent>()
@@ -231,9 +359,7 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
     }
 
     fn update_fuel_used(&mut self, context: &UpdateContext) {
-        self.fuel_used += self.fuel_flow
-            * MassConcentration::new::<kilogram_per_liter>(Self::JET_A_1_DENSITY)
-            * context.delta_as_time();
+        self.fuel_used += self.metered_fuel_flow * context.delta_as_time();
     }
 
     pub(super) fn update_fuel_used_reset(
@@ -363,6 +489,10 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         self.fuel_used
     }
 
+    pub fn metered_fuel_flow(&self) -> MassRate {
+        self.metered_fuel_flow
+    }
+
     /// Determines if a cooldown is required for the APU.
     ///
     /// This method checks if the APU is in quick mode (for Aircraft Presets) or not.
@@ -405,7 +535,10 @@ impl<C: ApuConstants> ControllerSignal<ContactorSignal> for ElectronicControlBox
         match self.turbine_state {
             TurbineState::Shutdown
                 if {
-                    self.master_is_on && self.start_is_on && self.air_intake_flap_is_fully_open()
+                    self.master_is_on
+                        && self.start_is_on
+                        && self.air_intake_flap_is_fully_open()
+                        && !self.starter_is_cooling_down()
                 } =>
             {
                 Some(ContactorSignal::Close)
@@ -475,13 +608,22 @@ impl<C: ApuConstants> ControllerSignal<ApuBleedAirValveSignal> for ElectronicCon
             && self.n.get::<percent>() > 95.
             && self.bleed_is_on
         {
-            Some(ApuBleedAirValveSignal::new_open())
+            Some(ApuBleedAirValveSignal::new(
+                self.bleed_air_load_shedding_open_amount(),
+            ))
         } else {
             Some(ApuBleedAirValveSignal::new_closed())
         }
     }
 }
 impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.ignition_failure.accept(visitor);
+        self.egt_margin_degradation_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         let ssm = if self.is_on() {
             SignStatus::NormalOperation
@@ -512,11 +654,17 @@ impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
             ssm,
         );
         writer.write_arinc429(&self.apu_fuel_used_id, self.fuel_used(), ssm);
+        writer.write_arinc429(
+            &self.apu_fuel_flow_id,
+            self.metered_fuel_flow.get::<kilogram_per_hour>(),
+            ssm,
+        );
         writer.write_arinc429(
             &self.apu_bleed_air_pressure_id,
             self.bleed_air_pressure,
             ssm,
         );
+        writer.write_arinc429(&self.apu_low_oil_level_id, self.oil_level_is_low, ssm);
 
         // Flight Warning Computer related information.
         writer.write(&self.ecam_inop_sys_apu_id, self.is_inoperable());
@@ -525,11 +673,18 @@ impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
             &self.apu_is_emergency_shutdown_id,
             self.is_emergency_shutdown(),
         );
+        writer.write(
+            &self.apu_starter_cooldown_id,
+            self.starter_is_cooling_down(),
+        );
+        writer.write(
+            &self.apu_operating_hours_id,
+            self.operating_time.get::<hour>(),
+        );
     }
 
     fn read(&mut self, reader: &mut SimulatorReader) {
-        let fuel_flow_gallon_per_hour: f64 = reader.read(&self.apu_fuel_line_flow_id);
-        self.fuel_flow = VolumeRate::new::<gallon_per_minute>(fuel_flow_gallon_per_hour / 60.);
+        self.operating_time = Time::new::<hour>(reader.read(&self.apu_operating_hours_id));
     }
 
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
@@ -548,4 +703,7 @@ enum ApuFault {
     ApuFire,
     FuelLowPressure,
     DcPowerLoss,
+    LowOilPressure,
+    NoLightOff,
+    EgtOverTemperature,
 }
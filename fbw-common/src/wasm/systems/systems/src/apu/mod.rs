@@ -1,9 +1,12 @@
 use self::{
     air_intake_flap::AirIntakeFlap, aps3200::ShutdownAps3200Turbine,
-    electronic_control_box::ElectronicControlBox, pw980::ShutdownPw980Turbine,
+    electronic_control_box::ElectronicControlBox, oil_system::ApuOilSystem,
+    pw980::ShutdownPw980Turbine,
 };
 use crate::{
-    electrical::{ElectricalElement, ElectricitySource, ProvideFrequency, ProvidePotential},
+    electrical::{
+        ElectricalElement, ElectricitySource, ProvideFrequency, ProvideLoad, ProvidePotential,
+    },
     overhead::{FirePushButton, OnOffAvailablePushButton, OnOffFaultPushButton},
     pneumatic::{ControllablePneumaticValve, TargetPressureTemperatureSignal},
     shared::{
@@ -17,10 +20,13 @@ use crate::{
 };
 use std::time::Duration;
 use uom::si::f64::*;
-use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::{
+    mass_rate::kilogram_per_second, ratio::ratio, thermodynamic_temperature::degree_celsius,
+};
 
 mod air_intake_flap;
 mod aps3200;
+mod oil_system;
 mod pw980;
 use crate::simulation::{InitContext, VariableIdentifier};
 pub use aps3200::{Aps3200ApuGenerator, Aps3200Constants, Aps3200StartMotor};
@@ -109,6 +115,7 @@ pub struct AuxiliaryPowerUnit<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants
     start_motor: U,
     air_intake_flap: AirIntakeFlap,
     fuel_pressure_switch: FuelPressureSwitch,
+    oil_system: ApuOilSystem,
 }
 impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
     AuxiliaryPowerUnit<T, U, C, N>
@@ -131,6 +138,7 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
             start_motor,
             air_intake_flap: AirIntakeFlap::new(air_intake_flap_powered_by),
             fuel_pressure_switch: FuelPressureSwitch::new(),
+            oil_system: ApuOilSystem::new::<C>(context),
         }
     }
 
@@ -160,8 +168,11 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         if let Some(turbine) = self.turbine.take() {
             let updated_turbine = turbine.update(
                 context,
-                bleed_air_valve.is_open(),
-                apu_gen_is_used,
+                TurbineLoad::new(
+                    Self::bleed_air_demand(bleed_air_valve),
+                    apu_gen_is_used,
+                    self.generator_load(),
+                ),
                 &self.ecb,
             );
 
@@ -170,6 +181,9 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
             self.turbine = Some(updated_turbine);
         }
 
+        self.oil_system.update(context, self.ecb.n());
+        self.ecb.update_oil_system_state(&self.oil_system);
+
         let emergency_shutdown = self.is_emergency_shutdown();
         for gen in &mut self.generators {
             gen.update(self.ecb.n(), emergency_shutdown);
@@ -185,6 +199,33 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         self.ecb.update_fuel_used_reset(engines, lgciu);
     }
 
+    /// The bleed air demand relative to supplying the packs. Engine starts draw
+    /// considerably more air from the load compressor than the packs do.
+    fn bleed_air_demand(bleed_air_valve: &impl ControllablePneumaticValve) -> Ratio {
+        if bleed_air_valve.is_open() {
+            bleed_air_valve.open_amount().max(Ratio::new::<ratio>(
+                bleed_air_valve.fluid_flow().get::<kilogram_per_second>()
+                    / C::NOMINAL_BLEED_AIR_FLOW,
+            ))
+        } else {
+            Ratio::default()
+        }
+    }
+
+    fn generator_load(&self) -> Ratio {
+        self.generators
+            .iter()
+            .fold(Ratio::default(), |total, generator| {
+                total + generator.load()
+            })
+            / N as f64
+    }
+
+    /// The fuel flow metered by the ECB, which is also what it integrates into the fuel used.
+    pub fn fuel_flow(&self) -> MassRate {
+        self.ecb.metered_fuel_flow()
+    }
+
     fn is_available(&self) -> bool {
         self.ecb.is_available()
     }
@@ -205,6 +246,10 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         self.ecb.is_on()
     }
 
+    fn starter_is_cooling_down(&self) -> bool {
+        self.ecb.starter_is_cooling_down()
+    }
+
     #[cfg(test)]
     fn set_turbine(&mut self, turbine: Option<Box<dyn Turbine>>) {
         self.turbine = turbine;
@@ -214,6 +259,11 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
     fn set_air_intake_flap_travel_time(&mut self, duration: Duration) {
         self.air_intake_flap.set_travel_time(duration);
     }
+
+    #[cfg(test)]
+    fn set_oil_quantity(&mut self, quantity: Volume) {
+        self.oil_system.set_quantity(quantity);
+    }
 }
 impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
     AuxiliaryPowerUnitElectrical for AuxiliaryPowerUnit<T, U, C, N>
@@ -266,6 +316,7 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> Simulat
         accept_iterable!(self.generators, visitor);
         self.start_motor.accept(visitor);
         self.air_intake_flap.accept(visitor);
+        self.oil_system.accept(visitor);
         self.ecb.accept(visitor);
 
         visitor.visit(self);
@@ -279,12 +330,46 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> Simulat
     }
 }
 
+/// The demand put on the turbine by the bleed air and electrical users of the APU.
+#[derive(Clone, Copy, Default)]
+pub struct TurbineLoad {
+    bleed_air_demand: Ratio,
+    generator_is_used: bool,
+    generator_load: Ratio,
+}
+impl TurbineLoad {
+    pub fn new(bleed_air_demand: Ratio, generator_is_used: bool, generator_load: Ratio) -> Self {
+        Self {
+            bleed_air_demand,
+            generator_is_used,
+            generator_load,
+        }
+    }
+
+    /// The bleed air demand relative to supplying the packs.
+    pub fn bleed_air_demand(&self) -> Ratio {
+        self.bleed_air_demand
+    }
+
+    pub fn bleed_air_is_used(&self) -> bool {
+        self.bleed_air_demand > Ratio::default()
+    }
+
+    pub fn generator_is_used(&self) -> bool {
+        self.generator_is_used
+    }
+
+    /// The average load of the APU generators.
+    pub fn generator_load(&self) -> Ratio {
+        self.generator_load
+    }
+}
+
 pub trait Turbine {
     fn update(
         self: Box<Self>,
         context: &UpdateContext,
-        apu_bleed_is_used: bool,
-        apu_gen_is_used: bool,
+        load: TurbineLoad,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine>;
     fn n(&self) -> Ratio;
@@ -294,6 +379,9 @@ pub trait Turbine {
     fn egt(&self) -> ThermodynamicTemperature;
     fn state(&self) -> TurbineState;
     fn bleed_air_pressure(&self) -> Pressure;
+    fn fuel_flow(&self) -> MassRate {
+        MassRate::default()
+    }
 }
 
 #[derive(PartialEq, Eq)]
@@ -305,7 +393,12 @@ pub enum TurbineState {
 }
 
 pub trait ApuGenerator:
-    SimulationElement + ProvidePotential + ProvideFrequency + ElectricalElement + ElectricitySource
+    SimulationElement
+    + ProvidePotential
+    + ProvideFrequency
+    + ProvideLoad
+    + ElectricalElement
+    + ElectricitySource
 {
     fn update(&mut self, n: Ratio, is_emergency_shutdown: bool);
     fn output_within_normal_parameters(&self) -> bool;
@@ -317,7 +410,22 @@ pub trait ApuConstants {
     const COOLDOWN_DURATION: Duration;
     const AIR_INTAKE_FLAP_CLOSURE_PERCENT: f64;
     const SHOULD_BE_AVAILABLE_DURING_SHUTDOWN: bool;
-    const FUEL_LINE_ID: u8;
+    const OIL_CAPACITY: f64;
+    /// Below this quantity the ECB indicates LOW OIL LEVEL.
+    const OIL_LOW_LEVEL_QUANTITY: f64;
+    /// Below this quantity the oil pressure can no longer be maintained.
+    const OIL_MINIMUM_QUANTITY: f64;
+    const OIL_CONSUMPTION_PER_HOUR: f64;
+    /// Number of consecutive start attempts after which the starter
+    /// motor has to cool down.
+    const STARTER_MAX_CONSECUTIVE_STARTS: u8;
+    const STARTER_COOLDOWN_DURATION: Duration;
+    /// Long term EGT increase caused by wear of the hot section.
+    const EGT_MARGIN_LOSS_PER_OPERATING_HOUR: f64;
+    /// EGT increase of an APU with a deteriorated hot section.
+    const DEGRADED_EGT_MARGIN_LOSS: f64;
+    /// Bleed air flow when supplying the packs.
+    const NOMINAL_BLEED_AIR_FLOW: f64;
 }
 
 pub struct AuxiliaryPowerUnitFireOverheadPanel {
@@ -363,7 +471,8 @@ impl AuxiliaryPowerUnitOverheadPanel {
             && (apu.is_available()
                 || apu.has_fault()
                 || !apu.electronic_control_box_is_on()
-                || (!self.master_sw_is_on() && !apu.is_starting()))
+                || (!self.master_sw_is_on() && !apu.is_starting())
+                || (apu.starter_is_cooling_down() && !apu.is_starting()))
         {
             self.start.turn_off();
         }
@@ -396,9 +505,10 @@ mod tests {
         electrical::{
             consumption::PowerConsumer, test::TestElectricitySource, ElectricalBus, Electricity,
         },
-        pneumatic::valve::*,
+        pneumatic::PneumaticValveSignal,
         shared::{
-            arinc429::Arinc429Word, ElectricalBusType, PotentialOrigin, PowerConsumptionReport,
+            arinc429::Arinc429Word, ElectricalBusType, PneumaticValve, PotentialOrigin,
+            PowerConsumptionReport,
         },
         simulation::{
             test::{SimulationTestBed, TestBed},
@@ -407,15 +517,18 @@ mod tests {
     };
 
     use super::*;
+    use crate::failures::FailureType;
     use crate::simulation::test::{ReadByName, WriteByName};
     use crate::simulation::InitContext;
     use rstest::rstest;
     use std::time::Duration;
     use uom::si::{
+        mass_rate::kilogram_per_second,
         power::watt,
         pressure::{bar, psi},
         ratio::percent,
         thermodynamic_temperature::degree_celsius,
+        volume::quart_liquid,
     };
 
     pub fn test_bed_with(
@@ -453,8 +566,7 @@ mod tests {
         fn update(
             self: Box<Self>,
             _: &UpdateContext,
-            _: bool,
-            _: bool,
+            _: TurbineLoad,
             _: &dyn ControllerSignal<TurbineSignal>,
         ) -> Box<dyn Turbine> {
             self
@@ -477,13 +589,54 @@ mod tests {
         }
     }
 
+    /// A bleed air valve through which the users draw a given flow when it is fully open.
+    struct TestBleedAirValve {
+        open_amount: Ratio,
+        demanded_flow: MassRate,
+    }
+    impl TestBleedAirValve {
+        fn new() -> Self {
+            Self {
+                open_amount: Ratio::default(),
+                demanded_flow: MassRate::default(),
+            }
+        }
+
+        fn set_demanded_flow(&mut self, flow: MassRate) {
+            self.demanded_flow = flow;
+        }
+    }
+    impl PneumaticValve for TestBleedAirValve {
+        fn is_open(&self) -> bool {
+            self.open_amount > Ratio::default()
+        }
+    }
+    impl ControllablePneumaticValve for TestBleedAirValve {
+        fn update_open_amount<T: PneumaticValveSignal, U: ControllerSignal<T> + ?Sized>(
+            &mut self,
+            controller: &U,
+        ) {
+            if let Some(signal) = controller.signal() {
+                self.open_amount = signal.target_open_amount();
+            }
+        }
+
+        fn open_amount(&self) -> Ratio {
+            self.open_amount
+        }
+
+        fn fluid_flow(&self) -> MassRate {
+            self.demanded_flow * self.open_amount.get::<ratio>()
+        }
+    }
+
     struct TestPneumatic {
-        apu_bleed_air_valve: DefaultValve,
+        apu_bleed_air_valve: TestBleedAirValve,
     }
     impl TestPneumatic {
         fn new() -> Self {
             Self {
-                apu_bleed_air_valve: DefaultValve::new_closed(),
+                apu_bleed_air_valve: TestBleedAirValve::new(),
             }
         }
 
@@ -649,6 +802,10 @@ mod tests {
             self.apu.set_air_intake_flap_travel_time(duration);
         }
 
+        fn set_oil_quantity(&mut self, quantity: Volume) {
+            self.apu.set_oil_quantity(quantity);
+        }
+
         fn set_apu_gen_is_used(&mut self, value: bool) {
             self.apu_gen_is_used = value;
         }
@@ -657,6 +814,14 @@ mod tests {
             self.has_fuel_remaining = value;
         }
 
+        fn set_bleed_air_demanded_flow(&mut self, flow: MassRate) {
+            self.pneumatic.apu_bleed_air_valve.set_demanded_flow(flow);
+        }
+
+        fn bleed_air_valve_open_amount(&self) -> Ratio {
+            self.pneumatic.apu_bleed_air_valve.open_amount()
+        }
+
         fn set_turbine_infinitely_running_at(&mut self, n: Ratio) {
             self.apu
                 .set_turbine(Some(Box::new(InfinitelyAtNTestTurbine::new(n))));
@@ -893,6 +1058,26 @@ mod tests {
                 .run(Duration::from_secs(0))
         }
 
+        fn oil_quantity_of(mut self, quantity: Volume) -> Self {
+            self.command(|a| a.set_oil_quantity(quantity));
+            self
+        }
+
+        fn operating_hours_of(mut self, hours: f64) -> Self {
+            self.write_by_name("APU_OPERATING_HOURS", hours);
+            self
+        }
+
+        fn failure(mut self, failure: FailureType) -> Self {
+            self.fail(failure);
+            self
+        }
+
+        fn bleed_air_demanded_flow_of(mut self, flow: MassRate) -> Self {
+            self.command(|a| a.set_bleed_air_demanded_flow(flow));
+            self
+        }
+
         fn apu_gen_not_used(mut self) -> Self {
             self.command(|a| a.set_apu_gen_is_used(false));
             self
@@ -969,15 +1154,6 @@ mod tests {
             self.running_apu()
         }
 
-        fn apu_fuel_line_flowing(mut self, flowing: bool, fuel_line_id: u8) -> Self {
-            if flowing {
-                self.write_by_name(&format!("FUELSYSTEM LINE FUEL FLOW:{}", fuel_line_id), 33.);
-            } else {
-                self.write_by_name(&format!("FUELSYSTEM LINE FUEL FLOW:{}", fuel_line_id), 0.);
-            }
-            self
-        }
-
         fn ambient_pressure(mut self, ambient: Pressure) -> Self {
             self.ambient_pressure = ambient;
             self
@@ -1208,6 +1384,31 @@ mod tests {
         fn apu_fuel_used(&mut self) -> Arinc429Word<Mass> {
             self.read_arinc429_by_name("APU_FUEL_USED")
         }
+
+        fn apu_fuel_flow_kg_per_hour(&mut self) -> f64 {
+            let fuel_flow: Arinc429Word<f64> = self.read_arinc429_by_name("APU_FUEL_FLOW");
+            fuel_flow.normal_value().unwrap()
+        }
+
+        fn bleed_air_valve_open_amount(&self) -> Ratio {
+            self.query(|a| a.bleed_air_valve_open_amount())
+        }
+
+        fn has_low_oil_level(&mut self) -> Arinc429Word<bool> {
+            self.read_arinc429_by_name("APU_LOW_OIL_LEVEL")
+        }
+
+        fn oil_quantity(&mut self) -> Volume {
+            Volume::new::<quart_liquid>(self.read_by_name("APU_OIL_QUANTITY"))
+        }
+
+        fn starter_is_cooling_down(&mut self) -> bool {
+            self.read_by_name("APU_STARTER_COOLDOWN")
+        }
+
+        fn operating_hours(&mut self) -> f64 {
+            self.read_by_name("APU_OPERATING_HOURS")
+        }
     }
     impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> TestBed
         for AuxiliaryPowerUnitTestBed<T, U, C, N>
@@ -3146,11 +3347,7 @@ mod tests {
         >(
             #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
         ) {
-            let mut test_bed = bed_with
-                .running_apu()
-                .and()
-                .apu_fuel_line_flowing(true, C::FUEL_LINE_ID)
-                .run(Duration::from_millis(1));
+            let mut test_bed = bed_with.running_apu().run(Duration::from_millis(1));
 
             assert!(
                 test_bed
@@ -3173,11 +3370,7 @@ mod tests {
         >(
             #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
         ) {
-            let mut test_bed = bed_with
-                .running_apu()
-                .and()
-                .apu_fuel_line_flowing(true, C::FUEL_LINE_ID)
-                .run(Duration::from_millis(1));
+            let mut test_bed = bed_with.running_apu().run(Duration::from_millis(1));
 
             let initial_fuel_used = test_bed
                 .apu_fuel_used()
@@ -3200,7 +3393,7 @@ mod tests {
         #[rstest]
         #[case::aps3200(test_bed_aps3200())]
         #[case::pw980(test_bed_pw980())]
-        fn fuel_used_stops_when_no_fuel_flow<
+        fn fuel_used_stops_when_apu_is_shut_down<
             T: ApuGenerator,
             U: ApuStartMotor,
             C: ApuConstants,
@@ -3208,37 +3401,18 @@ mod tests {
         >(
             #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
         ) {
-            let mut test_bed = bed_with
-                .running_apu()
-                .and()
-                .apu_fuel_line_flowing(true, C::FUEL_LINE_ID)
-                .run(Duration::from_millis(1));
+            let mut test_bed = bed_with.running_apu().master_off();
+            while !test_bed.turbine_is_shutdown() {
+                test_bed = test_bed.run(Duration::from_secs(1));
+            }
 
-            assert!(
-                test_bed
-                    .apu_fuel_used()
-                    .normal_value()
-                    .unwrap()
-                    .get::<kilogram>()
-                    > 0.
-            );
+            let initial_fuel_used = test_bed.apu_fuel_used().value().get::<kilogram>();
+            assert!(initial_fuel_used > 0.);
 
-            let initial_fuel_used = test_bed
-                .apu_fuel_used()
-                .normal_value()
-                .unwrap()
-                .get::<kilogram>();
-
-            test_bed = test_bed
-                .apu_fuel_line_flowing(false, C::FUEL_LINE_ID)
-                .run(Duration::from_millis(1000));
+            test_bed = test_bed.run(Duration::from_millis(1000));
 
             assert_eq!(
-                test_bed
-                    .apu_fuel_used()
-                    .normal_value()
-                    .unwrap()
-                    .get::<kilogram>(),
+                test_bed.apu_fuel_used().value().get::<kilogram>(),
                 initial_fuel_used
             );
         }
@@ -3256,8 +3430,6 @@ mod tests {
             let mut test_bed = bed_with
                 .running_apu()
                 .on_ground(true)
-                .and()
-                .apu_fuel_line_flowing(true, C::FUEL_LINE_ID)
                 .run(Duration::from_secs(1000));
 
             let initial_fuel_used = test_bed
@@ -3294,8 +3466,6 @@ mod tests {
             let mut test_bed = bed_with
                 .running_apu()
                 .on_ground(false)
-                .and()
-                .apu_fuel_line_flowing(true, C::FUEL_LINE_ID)
                 .run(Duration::from_secs(1000));
 
             let initial_fuel_used = test_bed
@@ -3309,13 +3479,14 @@ mod tests {
                 .on_ground(true)
                 .run(Duration::from_secs(1000));
 
-            assert_eq!(
+            // Without the reset, the fuel used would keep growing from its value before touchdown
+            assert!(
                 test_bed
                     .apu_fuel_used()
                     .normal_value()
                     .unwrap()
-                    .get::<kilogram>(),
-                initial_fuel_used
+                    .get::<kilogram>()
+                    < initial_fuel_used
             );
         }
 
@@ -3332,8 +3503,6 @@ mod tests {
             let mut test_bed = bed_with
                 .running_apu()
                 .on_ground(true)
-                .and()
-                .apu_fuel_line_flowing(true, C::FUEL_LINE_ID)
                 .run(Duration::from_secs(1000));
 
             let initial_fuel_used = test_bed
@@ -3370,8 +3539,6 @@ mod tests {
             let mut test_bed = bed_with
                 .running_apu()
                 .on_ground(true)
-                .and()
-                .apu_fuel_line_flowing(true, C::FUEL_LINE_ID)
                 .run(Duration::from_secs(1000));
 
             assert!(
@@ -3408,5 +3575,456 @@ mod tests {
                     < 1.
             );
         }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn low_oil_level_is_indicated_below_low_level_quantity<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .oil_quantity_of(Volume::new::<quart_liquid>(C::OIL_LOW_LEVEL_QUANTITY + 0.2))
+                .master_on()
+                .run(Duration::from_secs(1));
+
+            assert!(!test_bed.has_low_oil_level().normal_value().unwrap());
+
+            test_bed = test_bed
+                .oil_quantity_of(Volume::new::<quart_liquid>(C::OIL_LOW_LEVEL_QUANTITY - 0.2))
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.has_low_oil_level().normal_value().unwrap());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_with_low_oil_level_keeps_running<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .oil_quantity_of(Volume::new::<quart_liquid>(C::OIL_LOW_LEVEL_QUANTITY - 0.2))
+                .running_apu()
+                .run(Duration::from_secs(60));
+
+            assert!(test_bed.apu_is_available());
+            assert!(!test_bed.master_has_fault());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_auto_shuts_down_when_oil_quantity_below_minimum<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .oil_quantity_of(Volume::new::<quart_liquid>(C::OIL_MINIMUM_QUANTITY - 0.1))
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.master_has_fault());
+        }
+
+        #[test]
+        fn running_apu_consumes_oil() {
+            let mut test_bed = test_bed().running_apu();
+            let initial_quantity = test_bed.oil_quantity();
+
+            for _ in 0..60 {
+                test_bed = test_bed.run(Duration::from_secs(60));
+            }
+
+            assert!(test_bed.oil_quantity() < initial_quantity);
+            assert!(initial_quantity - test_bed.oil_quantity() < Volume::new::<quart_liquid>(0.05));
+        }
+
+        #[test]
+        fn oil_leak_leads_to_low_oil_level_then_auto_shutdown() {
+            let mut test_bed = test_bed()
+                .oil_quantity_of(Volume::new::<quart_liquid>(Aps3200Constants::OIL_CAPACITY))
+                .running_apu()
+                .failure(FailureType::ApuOilLeak);
+
+            let mut low_oil_level_before_shutdown = false;
+            for _ in 0..30 {
+                test_bed = test_bed.run(Duration::from_secs(30));
+
+                if test_bed.is_auto_shutdown() {
+                    break;
+                }
+                low_oil_level_before_shutdown |=
+                    test_bed.has_low_oil_level().normal_value().unwrap();
+            }
+
+            assert!(low_oil_level_before_shutdown);
+            assert!(test_bed.is_auto_shutdown());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn apu_with_ignition_fault_auto_shuts_down_during_start<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .failure(FailureType::ApuIgnitionFault)
+                .starting_apu()
+                .run(Duration::from_secs(10));
+
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.master_has_fault());
+            assert!(!test_bed.start_is_on());
+        }
+
+        fn failed_start<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>(
+            mut test_bed: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) -> AuxiliaryPowerUnitTestBed<T, U, C, N> {
+            test_bed = test_bed.starting_apu().run(Duration::from_secs(10));
+            assert!(test_bed.is_auto_shutdown());
+
+            test_bed = test_bed.master_off();
+            while !test_bed.turbine_is_shutdown() || !test_bed.is_air_intake_flap_fully_closed() {
+                test_bed = test_bed.run(Duration::from_secs(1));
+            }
+
+            test_bed
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn starter_cools_down_after_three_consecutive_start_attempts<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.failure(FailureType::ApuIgnitionFault);
+
+            for _ in 0..2 {
+                test_bed = failed_start(test_bed);
+                assert!(!test_bed.starter_is_cooling_down());
+            }
+            test_bed = failed_start(test_bed);
+            assert!(test_bed.starter_is_cooling_down());
+
+            test_bed = test_bed
+                .apu_ready_to_start()
+                .start_on()
+                .run(Duration::from_secs(0))
+                .run(Duration::from_secs(5));
+
+            assert!(!test_bed.should_close_start_contactors_commanded());
+            assert!(!test_bed.start_is_on());
+            assert_about_eq!(test_bed.n().normal_value().unwrap().get::<percent>(), 0.);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn successful_starts_do_not_count_towards_starter_cool_down<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with;
+
+            for _ in 0..3 {
+                test_bed = test_bed.running_apu().master_off();
+                while !test_bed.turbine_is_shutdown() || !test_bed.is_air_intake_flap_fully_closed()
+                {
+                    test_bed = test_bed.run(Duration::from_secs(1));
+                }
+            }
+
+            assert!(!test_bed.starter_is_cooling_down());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn apu_can_start_again_once_starter_cooled_down<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.failure(FailureType::ApuIgnitionFault);
+            for _ in 0..3 {
+                test_bed = failed_start(test_bed);
+            }
+
+            test_bed.unfail(FailureType::ApuIgnitionFault);
+            test_bed = test_bed.run(C::STARTER_COOLDOWN_DURATION);
+            assert!(!test_bed.starter_is_cooling_down());
+
+            test_bed = test_bed.running_apu();
+
+            assert!(test_bed.apu_is_available());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn egt_margin_degradation_increases_running_egt<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_without_bleed_air()
+                .run(Duration::from_secs(60));
+            let healthy_egt = test_bed.egt().normal_value().unwrap();
+
+            test_bed = test_bed
+                .failure(FailureType::ApuEgtMarginDegradation)
+                .run(Duration::from_secs(1));
+            let degraded_egt = test_bed.egt().normal_value().unwrap();
+
+            assert_about_eq!(
+                degraded_egt.get::<degree_celsius>() - healthy_egt.get::<degree_celsius>(),
+                C::DEGRADED_EGT_MARGIN_LOSS,
+                1.
+            );
+        }
+
+        #[test]
+        fn degraded_apu_sheds_bleed_air_load_instead_of_exceeding_egt_limit() {
+            let mut test_bed = test_bed()
+                .failure(FailureType::ApuEgtMarginDegradation)
+                .running_apu_without_bleed_air()
+                .run(Duration::from_secs(60));
+
+            test_bed.write_by_name("OVHD_APU_BLEED_PB_IS_ON", true);
+            for _ in 0..120 {
+                test_bed = test_bed.run(Duration::from_secs(1));
+            }
+
+            assert!(test_bed.bleed_air_valve_open_amount() < Ratio::new::<percent>(100.));
+            assert!(test_bed.bleed_air_valve_open_amount() > Ratio::default());
+            assert!(
+                test_bed.egt().normal_value().unwrap()
+                    < ThermodynamicTemperature::new::<degree_celsius>(
+                        Aps3200Constants::RUNNING_WARNING_EGT
+                    )
+            );
+            assert!(!test_bed.is_auto_shutdown());
+        }
+
+        #[test]
+        fn degraded_apu_auto_shuts_down_on_egt_over_temperature_when_load_shedding_is_insufficient()
+        {
+            let mut test_bed = test_bed()
+                .failure(FailureType::ApuEgtMarginDegradation)
+                .running_apu_without_bleed_air()
+                .power_demand(Power::new::<watt>(110000.))
+                .run(Duration::from_secs(60));
+
+            test_bed.write_by_name("OVHD_APU_BLEED_PB_IS_ON", true);
+            for _ in 0..120 {
+                test_bed = test_bed.run(Duration::from_secs(1));
+            }
+
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.master_has_fault());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn bleed_air_valve_fully_opens_in_normal_operation<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let test_bed = bed_with
+                .running_apu_with_bleed_air()
+                .power_demand(Power::new::<watt>(50000.))
+                .run(Duration::from_secs(120));
+
+            assert_about_eq!(
+                test_bed.bleed_air_valve_open_amount().get::<percent>(),
+                100.
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn egt_rises_with_generator_load<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_without_bleed_air()
+                .run(Duration::from_secs(60));
+            let unloaded_egt = test_bed.egt().normal_value().unwrap();
+
+            test_bed = test_bed
+                .power_demand(Power::new::<watt>(90000.))
+                .run(Duration::from_secs(60));
+            let loaded_egt = test_bed.egt().normal_value().unwrap();
+
+            assert!(
+                loaded_egt.get::<degree_celsius>() - unloaded_egt.get::<degree_celsius>() > 10.
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn egt_rises_with_bleed_air_demand_above_pack_supply<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .bleed_air_demanded_flow_of(MassRate::new::<kilogram_per_second>(
+                    C::NOMINAL_BLEED_AIR_FLOW,
+                ))
+                .running_apu_with_bleed_air()
+                .run(Duration::from_secs(120));
+            let pack_supply_egt = test_bed.egt().normal_value().unwrap();
+
+            // An engine start draws much more air than the packs.
+            test_bed = test_bed
+                .bleed_air_demanded_flow_of(MassRate::new::<kilogram_per_second>(
+                    1.8 * C::NOMINAL_BLEED_AIR_FLOW,
+                ))
+                .run(Duration::from_secs(120));
+            let engine_start_egt = test_bed.egt().normal_value().unwrap();
+
+            assert!(
+                engine_start_egt.get::<degree_celsius>() - pack_supply_egt.get::<degree_celsius>()
+                    > 20.
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn fuel_flow_increases_with_load<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_without_bleed_air()
+                .run(Duration::from_secs(60));
+            let no_load_fuel_flow = test_bed.apu_fuel_flow_kg_per_hour();
+
+            test_bed = test_bed
+                .power_demand(Power::new::<watt>(90000.))
+                .run(Duration::from_secs(60));
+            let electrical_load_fuel_flow = test_bed.apu_fuel_flow_kg_per_hour();
+
+            test_bed.write_by_name("OVHD_APU_BLEED_PB_IS_ON", true);
+            test_bed = test_bed.run(Duration::from_secs(120));
+            let full_load_fuel_flow = test_bed.apu_fuel_flow_kg_per_hour();
+
+            assert!(no_load_fuel_flow > 0.);
+            assert!(electrical_load_fuel_flow > no_load_fuel_flow);
+            assert!(full_load_fuel_flow > electrical_load_fuel_flow);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn no_fuel_flow_when_apu_is_shut_down<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.master_on().and().run(Duration::from_secs(1));
+
+            assert_about_eq!(test_bed.apu_fuel_flow_kg_per_hour(), 0.);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn operating_hours_since_overhaul_increase_egt<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_without_bleed_air()
+                .run(Duration::from_secs(60));
+            let new_egt = test_bed.egt().normal_value().unwrap();
+
+            test_bed = test_bed
+                .operating_hours_of(3000.)
+                .run(Duration::from_secs(1));
+            let worn_egt = test_bed.egt().normal_value().unwrap();
+
+            assert_about_eq!(
+                worn_egt.get::<degree_celsius>() - new_egt.get::<degree_celsius>(),
+                3000. * C::EGT_MARGIN_LOSS_PER_OPERATING_HOUR,
+                1.
+            );
+        }
+
+        #[test]
+        fn operating_hours_accumulate_while_running() {
+            let mut test_bed = test_bed().operating_hours_of(100.).running_apu();
+
+            for _ in 0..60 {
+                test_bed = test_bed.run(Duration::from_secs(60));
+            }
+
+            assert!(test_bed.operating_hours() > 100.9);
+            assert!(test_bed.operating_hours() < 101.1);
+        }
     }
 }
@@ -0,0 +1,94 @@
+use uom::si::{f64::*, ratio::ratio, volume::quart_liquid};
+
+use crate::{
+    failures::{Failure, FailureType},
+    shared::random_from_range,
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use super::ApuConstants;
+
+/// The APU oil tank and its level sensor.
+///
+/// Oil is slowly consumed while the APU is turning. When the quantity falls under
+/// the minimum operating level the lubrication pump cavitates and the ECB sees
+/// low oil pressure.
+pub(super) struct ApuOilSystem {
+    apu_oil_quantity_id: VariableIdentifier,
+
+    quantity: Volume,
+    low_level_quantity: Volume,
+    minimum_quantity: Volume,
+    consumption_per_hour: Volume,
+
+    leak: Failure,
+}
+impl ApuOilSystem {
+    const LEAK_RATE_QUARTS_PER_MINUTE: f64 = 0.25;
+
+    pub(super) fn new<C: ApuConstants>(context: &mut InitContext) -> Self {
+        Self {
+            apu_oil_quantity_id: context.get_identifier("APU_OIL_QUANTITY".to_owned()),
+
+            // Oil is serviced at irregular intervals, but the APU is never dispatched
+            // with a low oil level.
+            quantity: Volume::new::<quart_liquid>(random_from_range(
+                C::OIL_LOW_LEVEL_QUANTITY + 0.5,
+                C::OIL_CAPACITY,
+            )),
+            low_level_quantity: Volume::new::<quart_liquid>(C::OIL_LOW_LEVEL_QUANTITY),
+            minimum_quantity: Volume::new::<quart_liquid>(C::OIL_MINIMUM_QUANTITY),
+            consumption_per_hour: Volume::new::<quart_liquid>(C::OIL_CONSUMPTION_PER_HOUR),
+
+            leak: Failure::new(FailureType::ApuOilLeak),
+        }
+    }
+
+    pub(super) fn update(&mut self, context: &UpdateContext, n: Ratio) {
+        let speed_ratio = n.get::<ratio>().clamp(0., 1.);
+
+        let mut consumed =
+            self.consumption_per_hour * speed_ratio * context.delta_as_secs_f64() / 3600.;
+        if self.leak.is_active() {
+            consumed += Volume::new::<quart_liquid>(
+                Self::LEAK_RATE_QUARTS_PER_MINUTE * speed_ratio * context.delta_as_secs_f64() / 60.,
+            );
+        }
+
+        self.quantity = (self.quantity - consumed).max(Volume::default());
+    }
+
+    pub(super) fn quantity(&self) -> Volume {
+        self.quantity
+    }
+
+    pub(super) fn level_is_low(&self) -> bool {
+        self.quantity < self.low_level_quantity
+    }
+
+    pub(super) fn quantity_is_below_minimum(&self) -> bool {
+        self.quantity < self.minimum_quantity
+    }
+
+    #[cfg(test)]
+    pub(super) fn set_quantity(&mut self, quantity: Volume) {
+        self.quantity = quantity;
+    }
+}
+impl SimulationElement for ApuOilSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.leak.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.apu_oil_quantity_id,
+            self.quantity().get::<quart_liquid>(),
+        );
+    }
+}
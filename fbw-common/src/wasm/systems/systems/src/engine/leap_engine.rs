@@ -1,11 +1,18 @@
-use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent};
+use uom::si::{
+    angular_velocity::revolution_per_minute, f64::*, mass_rate::kilogram_per_hour, ratio::percent,
+};
 
 use crate::{
-    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
-    simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
+    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineFuelFlow, EngineUncorrectedN2},
+    simulation::{
+        Read, SimulationElement, SimulationElementVisitor, SimulatorReader, UpdateContext,
+    },
 };
 
-use super::Engine;
+use super::{
+    oil_system::{EngineOilCharacteristics, EngineOilSystem},
+    Engine,
+};
 use crate::simulation::{InitContext, VariableIdentifier};
 
 pub struct LeapEngine {
@@ -21,7 +28,9 @@ pub struct LeapEngine {
 
     n2_speed: AngularVelocity,
     hydraulic_pump_output_speed: AngularVelocity,
-    oil_pressure: Pressure,
+    fuel_flow_id: VariableIdentifier,
+    fuel_flow: MassRate,
+    oil_system: EngineOilSystem,
 
     net_thrust: Mass,
 }
@@ -35,8 +44,6 @@ impl LeapEngine {
 
     const MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT: f64 = 55.;
 
-    const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64 = 18.;
-
     pub fn new(context: &mut InitContext, number: usize) -> LeapEngine {
         LeapEngine {
             corrected_n1_id: context.get_identifier(format!("TURB ENG CORRECTED N1:{}", number)),
@@ -50,30 +57,43 @@ impl LeapEngine {
             uncorrected_n2: Ratio::new::<percent>(0.),
             n2_speed: AngularVelocity::new::<revolution_per_minute>(0.),
             hydraulic_pump_output_speed: AngularVelocity::new::<revolution_per_minute>(0.),
-            oil_pressure: Pressure::new::<psi>(0.),
+            fuel_flow_id: context.get_identifier(format!("ENGINE_FF:{}", number)),
+            fuel_flow: MassRate::default(),
+            oil_system: EngineOilSystem::new(
+                context,
+                number,
+                EngineOilCharacteristics::leap_1a26(),
+            ),
 
             net_thrust: Mass::default(),
         }
     }
 
-    pub fn update(&mut self, _: &UpdateContext) {}
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.oil_system
+            .update(context, self.uncorrected_n2, self.fuel_flow);
+    }
 
     fn update_parameters(&mut self) {
         self.n2_speed = AngularVelocity::new::<revolution_per_minute>(
             self.uncorrected_n2.get::<percent>() * Self::LEAP_1A26_MAX_N2_RPM / 100.,
         );
         self.hydraulic_pump_output_speed = self.n2_speed * Self::PUMP_N2_GEAR_RATIO;
-
-        // Ultra stupid model just to have 18psi crossing at 25% N2
-        self.oil_pressure = Pressure::new::<psi>(18. / 25. * self.uncorrected_n2.get::<percent>());
     }
 }
 impl SimulationElement for LeapEngine {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.oil_system.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.corrected_n1 = reader.read(&self.corrected_n1_id);
         self.corrected_n2 = reader.read(&self.corrected_n2_id);
         self.uncorrected_n2 = reader.read(&self.uncorrected_n2_id);
         self.net_thrust = reader.read(&self.thrust_id);
+        self.fuel_flow = MassRate::new::<kilogram_per_hour>(reader.read(&self.fuel_flow_id));
         self.update_parameters();
     }
 }
@@ -92,13 +112,18 @@ impl EngineUncorrectedN2 for LeapEngine {
         self.uncorrected_n2
     }
 }
+impl EngineFuelFlow for LeapEngine {
+    fn fuel_flow(&self) -> MassRate {
+        self.fuel_flow
+    }
+}
 impl Engine for LeapEngine {
     fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
         self.hydraulic_pump_output_speed
     }
 
     fn oil_pressure_is_low(&self) -> bool {
-        self.oil_pressure.get::<psi>() < LeapEngine::LOW_OIL_PRESSURE_THRESHOLD_PSI
+        self.oil_system.pressure_is_low()
     }
 
     fn is_above_minimum_idle(&self) -> bool {
@@ -8,9 +8,13 @@ use crate::{
 };
 
 pub mod leap_engine;
+pub mod native_engine;
+pub mod oil_system;
 pub mod reverser;
 pub mod reverser_thrust;
+pub mod start_sequence;
 pub mod trent_engine;
+pub mod vibration_monitoring;
 
 pub trait Engine: EngineCorrectedN2 + EngineUncorrectedN2 + EngineCorrectedN1 {
     fn hydraulic_pump_output_speed(&self) -> AngularVelocity;
@@ -0,0 +1,847 @@
+use std::time::Duration;
+
+use uom::si::{
+    angular_velocity::revolution_per_minute,
+    f64::*,
+    mass::pound,
+    mass_rate::kilogram_per_hour,
+    pressure::{hectopascal, psi},
+    ratio::{percent, ratio},
+    temperature_interval,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    velocity::knot,
+};
+
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{
+        interpolation, update_iterator::MaxStepLoop, EngineCorrectedN1, EngineCorrectedN2,
+        EngineUncorrectedN2,
+    },
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+
+use super::{
+    oil_system::{EngineOilCharacteristics, EngineOilSystem},
+    Engine,
+};
+
+/// Speed map and inertia of a spool driven by the core of the engine
+pub struct SpoolCharacteristics {
+    rated_speed: AngularVelocity,
+    core_speed_breakpoints_percent: [f64; 7],
+    speed_map_percent: [f64; 7],
+    time_constant: Duration,
+}
+impl SpoolCharacteristics {
+    fn new(
+        rated_speed_rpm: f64,
+        core_speed_breakpoints_percent: [f64; 7],
+        speed_map_percent: [f64; 7],
+        time_constant: Duration,
+    ) -> Self {
+        Self {
+            rated_speed: AngularVelocity::new::<revolution_per_minute>(rated_speed_rpm),
+            core_speed_breakpoints_percent,
+            speed_map_percent,
+            time_constant,
+        }
+    }
+}
+
+pub struct NativeEngineCharacteristics {
+    core_rated_speed: AngularVelocity,
+    // Time the core takes to reach rated speed under rated torque, representing its inertia
+    core_inertia_time: Duration,
+    idle_core_speed: Ratio,
+    max_core_speed: Ratio,
+    light_off_core_speed: Ratio,
+
+    // Low pressure spools first, the fan being the first one
+    spools: Vec<SpoolCharacteristics>,
+
+    max_fuel_flow: MassRate,
+    rated_thrust: Mass,
+    max_egt_rise: TemperatureInterval,
+    pump_gear_ratio: f64,
+}
+impl NativeEngineCharacteristics {
+    // According to the Type Certificate Data Sheet of LEAP 1A26
+    // 100% N2 @ 16645 RPM, 100% N1 @ 3894 RPM
+    const LEAP_1A26_CORE_RATED_SPEED_RPM: f64 = 16645.;
+    const LEAP_1A26_FAN_RATED_SPEED_RPM: f64 = 3894.;
+    const LEAP_1A26_N2_BREAKPOINTS_PERCENT: [f64; 7] = [0., 20., 59., 70., 80., 90., 100.];
+    const LEAP_1A26_N1_MAP_PERCENT: [f64; 7] = [0., 3., 19.5, 33., 50., 72., 100.];
+
+    // 100% N1 @ 2900 RPM
+    // 100% N2 @ 8300 RPM
+    // 100% N3 @ 12200 RPM
+    const TRENT_900_CORE_RATED_SPEED_RPM: f64 = 12200.;
+    const TRENT_900_IP_RATED_SPEED_RPM: f64 = 8300.;
+    const TRENT_900_FAN_RATED_SPEED_RPM: f64 = 2900.;
+    const TRENT_900_N3_BREAKPOINTS_PERCENT: [f64; 7] = [0., 20., 62., 75., 85., 93., 100.];
+    const TRENT_900_N2_MAP_PERCENT: [f64; 7] = [0., 8., 40., 60., 75., 88., 100.];
+    const TRENT_900_N1_MAP_PERCENT: [f64; 7] = [0., 3., 20., 38., 55., 75., 100.];
+
+    pub fn leap_1a26() -> Self {
+        Self {
+            core_rated_speed: AngularVelocity::new::<revolution_per_minute>(
+                Self::LEAP_1A26_CORE_RATED_SPEED_RPM,
+            ),
+            core_inertia_time: Duration::from_secs(3),
+            idle_core_speed: Ratio::new::<percent>(59.),
+            max_core_speed: Ratio::new::<percent>(100.),
+            light_off_core_speed: Ratio::new::<percent>(20.),
+
+            spools: vec![SpoolCharacteristics::new(
+                Self::LEAP_1A26_FAN_RATED_SPEED_RPM,
+                Self::LEAP_1A26_N2_BREAKPOINTS_PERCENT,
+                Self::LEAP_1A26_N1_MAP_PERCENT,
+                Duration::from_millis(1500),
+            )],
+
+            max_fuel_flow: MassRate::new::<kilogram_per_hour>(4300.),
+            rated_thrust: Mass::new::<pound>(27120.),
+            max_egt_rise: TemperatureInterval::new::<temperature_interval::degree_celsius>(885.),
+            // Gear ratio from primary gearbox input to EDP drive shaft
+            pump_gear_ratio: 0.211,
+        }
+    }
+
+    pub fn trent_900() -> Self {
+        Self {
+            core_rated_speed: AngularVelocity::new::<revolution_per_minute>(
+                Self::TRENT_900_CORE_RATED_SPEED_RPM,
+            ),
+            core_inertia_time: Duration::from_secs(4),
+            idle_core_speed: Ratio::new::<percent>(62.),
+            max_core_speed: Ratio::new::<percent>(100.),
+            light_off_core_speed: Ratio::new::<percent>(20.),
+
+            spools: vec![
+                SpoolCharacteristics::new(
+                    Self::TRENT_900_FAN_RATED_SPEED_RPM,
+                    Self::TRENT_900_N3_BREAKPOINTS_PERCENT,
+                    Self::TRENT_900_N1_MAP_PERCENT,
+                    Duration::from_secs(3),
+                ),
+                SpoolCharacteristics::new(
+                    Self::TRENT_900_IP_RATED_SPEED_RPM,
+                    Self::TRENT_900_N3_BREAKPOINTS_PERCENT,
+                    Self::TRENT_900_N2_MAP_PERCENT,
+                    Duration::from_secs(2),
+                ),
+            ],
+
+            max_fuel_flow: MassRate::new::<kilogram_per_hour>(10500.),
+            rated_thrust: Mass::new::<pound>(70000.),
+            max_egt_rise: TemperatureInterval::new::<temperature_interval::degree_celsius>(850.),
+            // TODO find real value, 0.31 is guessed to get the target 3775 pump rpm
+            pump_gear_ratio: 0.31,
+        }
+    }
+}
+
+struct Spool {
+    speed: Ratio,
+}
+impl Spool {
+    fn new() -> Self {
+        Self {
+            speed: Ratio::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        delta: Duration,
+        characteristics: &SpoolCharacteristics,
+        core_speed: Ratio,
+        windmill_speed: Ratio,
+    ) {
+        let target_speed = Ratio::new::<percent>(interpolation(
+            &characteristics.core_speed_breakpoints_percent,
+            &characteristics.speed_map_percent,
+            core_speed.get::<percent>(),
+        ))
+        .max(windmill_speed);
+
+        // First order response of the spool inertia
+        let alpha = 1. - (-delta.as_secs_f64() / characteristics.time_constant.as_secs_f64()).exp();
+        self.speed += (target_speed - self.speed) * alpha;
+    }
+}
+
+/// Physics based turbofan model, as an alternative to reading the engine state from the simulator.
+///
+/// The core spool is accelerated by the torque balance between its turbine, the pneumatic starter
+/// and the compressor drag. Turbine torque comes from the fuel flow, scheduled by the fuel control
+/// between deceleration and acceleration limits. Combustion lights off with ignition and fuel once
+/// the starter has brought the core above light-off speed. The other spools follow the core
+/// through their speed maps, with a lag representing their inertia.
+///
+/// All torques are normalised so that the core runs at rated speed with rated fuel flow.
+pub struct NativeEngine {
+    characteristics: NativeEngineCharacteristics,
+    physics_updater: MaxStepLoop,
+
+    core_speed: Ratio,
+    spools: Vec<Spool>,
+
+    fuel_flow: Ratio,
+    combustion_is_lit: bool,
+    light_off_duration: Duration,
+    egt: ThermodynamicTemperature,
+    corrected_speed_factor: f64,
+    ambient_pressure_ratio: f64,
+
+    oil_system: EngineOilSystem,
+
+    hung_start_failure: Failure,
+    hot_start_failure: Failure,
+}
+impl NativeEngine {
+    const MAX_PHYSICS_TIME_STEP: Duration = Duration::from_millis(50);
+
+    // Steady state fuel flow is proportional to core speed to this power, with an additional
+    // friction term dominating at low speed
+    const FUEL_FLOW_SPEED_EXPONENT: f64 = 5.3;
+    const FRICTION_FACTOR: f64 = 0.1;
+
+    // Fuel flow limits of the fuel control, relative to the steady state fuel flow at current speed
+    const ACCELERATION_FUEL_FLOW_FACTOR: f64 = 1.5;
+    const DECELERATION_FUEL_FLOW_FACTOR: f64 = 0.5;
+
+    // Air turbine starter torque, relative to the rated core torque
+    const STARTER_TORQUE_FACTOR: f64 = 0.05;
+    const STARTER_NOMINAL_PRESSURE_PSI: f64 = 35.;
+    const STARTER_RUNAWAY_CORE_SPEED_PERCENT: f64 = 70.;
+
+    const LIGHT_OFF_DELAY: Duration = Duration::from_secs(2);
+    // Combustion is lost below this ratio of the light-off speed
+    const FLAMEOUT_SPEED_FACTOR: f64 = 0.8;
+
+    const WINDMILL_CORE_SPEED_PERCENT_PER_KNOT: f64 = 0.05;
+    const WINDMILL_FAN_SPEED_PERCENT_PER_KNOT: f64 = 0.06;
+
+    // EGT rise is proportional to the fuel air ratio, air flow being proportional to core speed
+    // to this power
+    const EGT_AIR_FLOW_SPEED_EXPONENT: f64 = 3.;
+    const MAX_FUEL_AIR_FACTOR: f64 = 2.;
+    const EGT_TIME_CONSTANT: Duration = Duration::from_secs(3);
+
+    const HOT_START_FUEL_FLOW_FACTOR: f64 = 2.;
+    const HUNG_START_TURBINE_EFFICIENCY: f64 = 0.5;
+
+    const MIN_IDLE_MARGIN_PERCENT: f64 = 3.;
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        characteristics: NativeEngineCharacteristics,
+        oil_characteristics: EngineOilCharacteristics,
+    ) -> Self {
+        let spools = characteristics
+            .spools
+            .iter()
+            .map(|_| Spool::new())
+            .collect();
+        Self {
+            characteristics,
+            physics_updater: MaxStepLoop::new(Self::MAX_PHYSICS_TIME_STEP),
+
+            core_speed: Ratio::default(),
+            spools,
+
+            fuel_flow: Ratio::default(),
+            combustion_is_lit: false,
+            light_off_duration: Duration::default(),
+            egt: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            corrected_speed_factor: 1.,
+            ambient_pressure_ratio: 1.,
+
+            oil_system: EngineOilSystem::new(context, number, oil_characteristics),
+
+            hung_start_failure: Failure::new(FailureType::EngineHungStart(number)),
+            hot_start_failure: Failure::new(FailureType::EngineHotStart(number)),
+        }
+    }
+
+    /// `thrust_lever_demand` goes from 0 at idle to 1 at maximum thrust.
+    /// `starter_air_pressure` is the relative pressure of the air supplied to the starter.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        fuel_valve_is_open: bool,
+        ignition_is_on: bool,
+        thrust_lever_demand: Ratio,
+        starter_air_pressure: Pressure,
+    ) {
+        self.physics_updater.update(context);
+
+        for cur_time_step in self.physics_updater {
+            self.update_combustion(cur_time_step, fuel_valve_is_open, ignition_is_on);
+            self.update_fuel_flow(thrust_lever_demand);
+            self.update_core_speed(&context.with_delta(cur_time_step), starter_air_pressure);
+
+            let windmill_speed = Ratio::new::<percent>(
+                Self::WINDMILL_FAN_SPEED_PERCENT_PER_KNOT
+                    * context.true_airspeed().get::<knot>().max(0.),
+            );
+            let core_speed = self.core_speed;
+            self.spools
+                .iter_mut()
+                .zip(self.characteristics.spools.iter())
+                .for_each(|(spool, characteristics)| {
+                    spool.update(cur_time_step, characteristics, core_speed, windmill_speed)
+                });
+
+            self.update_egt(cur_time_step, context.ambient_temperature());
+        }
+
+        self.corrected_speed_factor =
+            (288.15 / context.ambient_temperature().get::<kelvin>()).sqrt();
+        self.ambient_pressure_ratio = context.ambient_pressure().get::<hectopascal>() / 1013.25;
+
+        self.oil_system
+            .update(context, self.core_speed, self.fuel_flow());
+    }
+
+    fn update_combustion(
+        &mut self,
+        delta: Duration,
+        fuel_valve_is_open: bool,
+        ignition_is_on: bool,
+    ) {
+        if !fuel_valve_is_open
+            || self.core_speed
+                < self.characteristics.light_off_core_speed * Self::FLAMEOUT_SPEED_FACTOR
+        {
+            self.combustion_is_lit = false;
+            self.light_off_duration = Duration::default();
+        } else if !self.combustion_is_lit
+            && ignition_is_on
+            && self.core_speed >= self.characteristics.light_off_core_speed
+        {
+            self.light_off_duration += delta;
+            self.combustion_is_lit = self.light_off_duration >= Self::LIGHT_OFF_DELAY;
+        }
+    }
+
+    fn update_fuel_flow(&mut self, thrust_lever_demand: Ratio) {
+        self.fuel_flow = if self.combustion_is_lit {
+            let target_core_speed = self.characteristics.idle_core_speed
+                + (self.characteristics.max_core_speed - self.characteristics.idle_core_speed)
+                    * thrust_lever_demand.get::<ratio>().clamp(0., 1.);
+            let steady_state_fuel_flow = Self::steady_state_fuel_flow(self.core_speed);
+
+            let acceleration_factor = if self.hot_start_failure.is_active()
+                && self.core_speed < self.characteristics.idle_core_speed
+            {
+                Self::ACCELERATION_FUEL_FLOW_FACTOR * Self::HOT_START_FUEL_FLOW_FACTOR
+            } else {
+                Self::ACCELERATION_FUEL_FLOW_FACTOR
+            };
+
+            Ratio::new::<ratio>(Self::steady_state_fuel_flow(target_core_speed).clamp(
+                Self::DECELERATION_FUEL_FLOW_FACTOR * steady_state_fuel_flow,
+                acceleration_factor * steady_state_fuel_flow,
+            ))
+        } else {
+            Ratio::default()
+        };
+    }
+
+    fn update_core_speed(&mut self, context: &UpdateContext, starter_air_pressure: Pressure) {
+        let core_speed = self.core_speed.get::<ratio>();
+        let windmill_speed = Self::WINDMILL_CORE_SPEED_PERCENT_PER_KNOT / 100.
+            * context.true_airspeed().get::<knot>().max(0.);
+        // Torques are divided by speed, which is limited to keep them finite when at rest
+        let torque_speed = core_speed.max(0.05);
+
+        let turbine_efficiency = if self.hung_start_failure.is_active()
+            && self.core_speed < self.characteristics.idle_core_speed
+        {
+            Self::HUNG_START_TURBINE_EFFICIENCY
+        } else {
+            1.
+        };
+        let turbine_torque = turbine_efficiency * self.fuel_flow.get::<ratio>() / torque_speed;
+
+        let starter_torque = Self::STARTER_TORQUE_FACTOR
+            * (starter_air_pressure.get::<psi>() / Self::STARTER_NOMINAL_PRESSURE_PSI)
+                .clamp(0., 1.5)
+            * (1. - core_speed / (Self::STARTER_RUNAWAY_CORE_SPEED_PERCENT / 100.)).max(0.);
+
+        // Ram air drives the compressor when the core turns slower than the windmill speed
+        let drag_torque = (Self::steady_state_fuel_flow(self.core_speed)
+            - Self::steady_state_fuel_flow(Ratio::new::<ratio>(windmill_speed)))
+            / torque_speed;
+
+        let acceleration = (turbine_torque + starter_torque - drag_torque)
+            / self.characteristics.core_inertia_time.as_secs_f64();
+
+        self.core_speed =
+            Ratio::new::<ratio>((core_speed + acceleration * context.delta_as_secs_f64()).max(0.));
+    }
+
+    fn update_egt(&mut self, delta: Duration, ambient_temperature: ThermodynamicTemperature) {
+        let fuel_air_factor = if self.combustion_is_lit {
+            (self.fuel_flow.get::<ratio>()
+                / self
+                    .core_speed
+                    .get::<ratio>()
+                    .max(0.05)
+                    .powf(Self::EGT_AIR_FLOW_SPEED_EXPONENT))
+            .min(Self::MAX_FUEL_AIR_FACTOR)
+        } else {
+            0.
+        };
+        let target_egt = ambient_temperature + self.characteristics.max_egt_rise * fuel_air_factor;
+
+        let alpha = 1. - (-delta.as_secs_f64() / Self::EGT_TIME_CONSTANT.as_secs_f64()).exp();
+        self.egt = ThermodynamicTemperature::new::<kelvin>(
+            self.egt.get::<kelvin>()
+                + (target_egt.get::<kelvin>() - self.egt.get::<kelvin>()) * alpha,
+        );
+    }
+
+    /// Fuel flow needed to hold the core at the given speed, relative to the rated fuel flow
+    fn steady_state_fuel_flow(core_speed: Ratio) -> f64 {
+        let speed = core_speed.get::<ratio>().max(0.);
+        (speed.powf(Self::FUEL_FLOW_SPEED_EXPONENT) + Self::FRICTION_FACTOR * speed.powi(2))
+            / (1. + Self::FRICTION_FACTOR)
+    }
+
+    fn core_rotational_speed(&self) -> AngularVelocity {
+        self.characteristics.core_rated_speed * self.core_speed.get::<ratio>()
+    }
+
+    fn spool_speed(&self, index: usize) -> Ratio {
+        self.spools
+            .get(index)
+            .map_or(self.core_speed, |spool| spool.speed)
+    }
+
+    pub fn core_speed(&self) -> Ratio {
+        self.core_speed
+    }
+
+    /// Uncorrected N1 of the fan
+    pub fn fan_speed(&self) -> Ratio {
+        self.spool_speed(0)
+    }
+
+    pub fn fan_rotational_speed(&self) -> AngularVelocity {
+        self.characteristics
+            .spools
+            .first()
+            .map_or(self.core_rotational_speed(), |spool| {
+                spool.rated_speed * self.fan_speed().get::<ratio>()
+            })
+    }
+
+    pub fn combustion_is_lit(&self) -> bool {
+        self.combustion_is_lit
+    }
+
+    pub fn fuel_flow(&self) -> MassRate {
+        self.characteristics.max_fuel_flow * self.fuel_flow.get::<ratio>()
+    }
+
+    pub fn egt(&self) -> ThermodynamicTemperature {
+        self.egt
+    }
+}
+impl EngineCorrectedN1 for NativeEngine {
+    fn corrected_n1(&self) -> Ratio {
+        self.fan_speed() * self.corrected_speed_factor
+    }
+}
+impl EngineCorrectedN2 for NativeEngine {
+    fn corrected_n2(&self) -> Ratio {
+        self.uncorrected_n2() * self.corrected_speed_factor
+    }
+}
+impl EngineUncorrectedN2 for NativeEngine {
+    fn uncorrected_n2(&self) -> Ratio {
+        self.spool_speed(1)
+    }
+}
+impl Engine for NativeEngine {
+    fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
+        self.core_rotational_speed() * self.characteristics.pump_gear_ratio
+    }
+
+    fn oil_pressure_is_low(&self) -> bool {
+        self.oil_system.pressure_is_low()
+    }
+
+    fn is_above_minimum_idle(&self) -> bool {
+        self.combustion_is_lit
+            && self.core_speed
+                >= self.characteristics.idle_core_speed
+                    - Ratio::new::<percent>(Self::MIN_IDLE_MARGIN_PERCENT)
+    }
+
+    fn net_thrust(&self) -> Mass {
+        self.characteristics.rated_thrust
+            * self.fan_speed().get::<ratio>().powi(2)
+            * self.ambient_pressure_ratio
+    }
+
+    fn gearbox_speed(&self) -> AngularVelocity {
+        self.core_rotational_speed()
+    }
+}
+impl SimulationElement for NativeEngine {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.hung_start_failure.accept(visitor);
+        self.hot_start_failure.accept(visitor);
+        self.oil_system.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed},
+        Aircraft, InitContext,
+    };
+
+    use uom::si::velocity::knot;
+
+    struct TestAircraft {
+        engine: NativeEngine,
+
+        fuel_valve_is_open: bool,
+        ignition_is_on: bool,
+        thrust_lever_demand: Ratio,
+        starter_air_pressure: Pressure,
+    }
+    impl TestAircraft {
+        fn new(
+            context: &mut InitContext,
+            characteristics: NativeEngineCharacteristics,
+            oil_characteristics: EngineOilCharacteristics,
+        ) -> Self {
+            Self {
+                engine: NativeEngine::new(context, 1, characteristics, oil_characteristics),
+
+                fuel_valve_is_open: false,
+                ignition_is_on: false,
+                thrust_lever_demand: Ratio::default(),
+                starter_air_pressure: Pressure::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.engine.update(
+                context,
+                self.fuel_valve_is_open,
+                self.ignition_is_on,
+                self.thrust_lever_demand,
+                self.starter_air_pressure,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.engine.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct NativeEngineTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl NativeEngineTestBed {
+        fn new(
+            characteristics: fn() -> NativeEngineCharacteristics,
+            oil_characteristics: fn() -> EngineOilCharacteristics,
+        ) -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(|context| {
+                    TestAircraft::new(context, characteristics(), oil_characteristics())
+                }),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_true_airspeed(Velocity::default());
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed
+        }
+
+        fn starter_air_pressure(mut self, pressure: Pressure) -> Self {
+            self.command(|a| a.starter_air_pressure = pressure);
+            self
+        }
+
+        fn fuel_valve_open(mut self, is_open: bool) -> Self {
+            self.command(|a| a.fuel_valve_is_open = is_open);
+            self
+        }
+
+        fn ignition_on(mut self, is_on: bool) -> Self {
+            self.command(|a| a.ignition_is_on = is_on);
+            self
+        }
+
+        fn thrust_lever_demand(mut self, demand: Ratio) -> Self {
+            self.command(|a| a.thrust_lever_demand = demand);
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            for _ in 0..duration.as_secs() {
+                self.run_with_delta(Duration::from_secs(1));
+            }
+            self
+        }
+
+        /// Runs a start sequence, cutting the starter at 50% core speed.
+        /// Returns the maximum EGT reached during the start.
+        fn start_engine(&mut self, duration: Duration) -> ThermodynamicTemperature {
+            self.command(|a| {
+                a.starter_air_pressure = Pressure::new::<psi>(35.);
+                a.ignition_is_on = true;
+                a.fuel_valve_is_open = true;
+            });
+
+            let mut max_egt = self.egt();
+            for _ in 0..(duration.as_millis() / 100) {
+                self.run_with_delta(Duration::from_millis(100));
+                if self.core_speed() > Ratio::new::<percent>(50.) {
+                    self.command(|a| {
+                        a.starter_air_pressure = Pressure::default();
+                        a.ignition_is_on = false;
+                    });
+                }
+                max_egt = max_egt.max(self.egt());
+            }
+            max_egt
+        }
+
+        fn core_speed(&self) -> Ratio {
+            self.query(|a| a.engine.core_speed())
+        }
+
+        fn fan_speed(&self) -> Ratio {
+            self.query(|a| a.engine.fan_speed())
+        }
+
+        fn egt(&self) -> ThermodynamicTemperature {
+            self.query(|a| a.engine.egt())
+        }
+
+        fn fuel_flow(&self) -> MassRate {
+            self.query(|a| a.engine.fuel_flow())
+        }
+
+        fn combustion_is_lit(&self) -> bool {
+            self.query(|a| a.engine.combustion_is_lit())
+        }
+
+        fn is_above_minimum_idle(&self) -> bool {
+            self.query(|a| a.engine.is_above_minimum_idle())
+        }
+    }
+    impl TestBed for NativeEngineTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> NativeEngineTestBed {
+        NativeEngineTestBed::new(
+            NativeEngineCharacteristics::leap_1a26,
+            EngineOilCharacteristics::leap_1a26,
+        )
+    }
+
+    fn test_bed_at_idle() -> NativeEngineTestBed {
+        let mut test_bed = test_bed();
+        test_bed.start_engine(Duration::from_secs(90));
+        test_bed
+    }
+
+    #[test]
+    fn engine_at_rest_stays_at_rest() {
+        let test_bed = test_bed().run_for(Duration::from_secs(10));
+
+        assert!(test_bed.core_speed() < Ratio::new::<percent>(0.1));
+        assert!(test_bed.fan_speed() < Ratio::new::<percent>(0.1));
+        assert!(!test_bed.combustion_is_lit());
+    }
+
+    #[test]
+    fn starter_alone_motors_core_to_dry_crank_speed() {
+        let test_bed = test_bed()
+            .starter_air_pressure(Pressure::new::<psi>(35.))
+            .run_for(Duration::from_secs(120));
+
+        assert!(test_bed.core_speed() > Ratio::new::<percent>(20.));
+        assert!(test_bed.core_speed() < Ratio::new::<percent>(35.));
+        assert!(!test_bed.combustion_is_lit());
+    }
+
+    #[test]
+    fn combustion_does_not_light_off_without_ignition() {
+        let test_bed = test_bed()
+            .starter_air_pressure(Pressure::new::<psi>(35.))
+            .fuel_valve_open(true)
+            .run_for(Duration::from_secs(120));
+
+        assert!(!test_bed.combustion_is_lit());
+        assert_eq!(test_bed.fuel_flow(), MassRate::default());
+    }
+
+    #[test]
+    fn combustion_does_not_light_off_below_light_off_speed() {
+        let test_bed = test_bed()
+            .starter_air_pressure(Pressure::new::<psi>(35.))
+            .fuel_valve_open(true)
+            .ignition_on(true)
+            .run_for(Duration::from_secs(5));
+
+        assert!(test_bed.core_speed() < Ratio::new::<percent>(20.));
+        assert!(!test_bed.combustion_is_lit());
+    }
+
+    #[test]
+    fn engine_starts_and_stabilises_at_idle() {
+        let mut test_bed = test_bed();
+        let max_egt = test_bed.start_engine(Duration::from_secs(90));
+
+        assert!(test_bed.combustion_is_lit());
+        assert!(test_bed.is_above_minimum_idle());
+        assert!(
+            (test_bed.core_speed() - Ratio::new::<percent>(59.)).abs() < Ratio::new::<percent>(1.)
+        );
+        assert!(
+            (test_bed.fan_speed() - Ratio::new::<percent>(19.5)).abs() < Ratio::new::<percent>(1.)
+        );
+
+        assert!(max_egt > ThermodynamicTemperature::new::<degree_celsius>(300.));
+        assert!(max_egt < ThermodynamicTemperature::new::<degree_celsius>(750.));
+        assert!(test_bed.fuel_flow() > MassRate::new::<kilogram_per_hour>(200.));
+        assert!(test_bed.fuel_flow() < MassRate::new::<kilogram_per_hour>(600.));
+    }
+
+    #[test]
+    fn oil_pressure_builds_up_during_start() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+        assert!(test_bed.query(|a| a.engine.oil_pressure_is_low()));
+
+        test_bed.start_engine(Duration::from_secs(90));
+
+        assert!(!test_bed.query(|a| a.engine.oil_pressure_is_low()));
+    }
+
+    #[test]
+    fn engine_is_not_above_idle_before_end_of_start() {
+        let mut test_bed = test_bed();
+        test_bed.start_engine(Duration::from_secs(20));
+
+        assert!(!test_bed.is_above_minimum_idle());
+    }
+
+    #[test]
+    fn engine_accelerates_from_idle_to_max_thrust() {
+        let test_bed = test_bed_at_idle()
+            .thrust_lever_demand(Ratio::new::<ratio>(1.))
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.core_speed() > Ratio::new::<percent>(97.));
+        assert!(test_bed.fan_speed() > Ratio::new::<percent>(90.));
+        assert!(test_bed.query(|a| a.engine.net_thrust()) > Mass::new::<pound>(20000.));
+    }
+
+    #[test]
+    fn acceleration_is_limited_by_fuel_schedule() {
+        let test_bed = test_bed_at_idle()
+            .thrust_lever_demand(Ratio::new::<ratio>(1.))
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.core_speed() < Ratio::new::<percent>(75.));
+        assert!(test_bed.fuel_flow() < MassRate::new::<kilogram_per_hour>(4300.));
+    }
+
+    #[test]
+    fn engine_spools_down_when_fuel_is_cut() {
+        let test_bed = test_bed_at_idle()
+            .fuel_valve_open(false)
+            .run_for(Duration::from_secs(60));
+
+        assert!(!test_bed.combustion_is_lit());
+        assert!(test_bed.core_speed() < Ratio::new::<percent>(20.));
+        assert!(test_bed.egt() < ThermodynamicTemperature::new::<degree_celsius>(50.));
+    }
+
+    #[test]
+    fn engine_windmills_in_flight_without_fuel() {
+        let mut test_bed = test_bed();
+        test_bed.set_on_ground(false);
+        test_bed.set_true_airspeed(Velocity::new::<knot>(250.));
+        test_bed = test_bed.run_for(Duration::from_secs(300));
+
+        assert!(test_bed.core_speed() > Ratio::new::<percent>(5.));
+        assert!(test_bed.fan_speed() > Ratio::new::<percent>(5.));
+        assert!(!test_bed.combustion_is_lit());
+    }
+
+    #[test]
+    fn hot_start_exceeds_start_egt_limit() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::EngineHotStart(1));
+        let max_egt = test_bed.start_engine(Duration::from_secs(90));
+
+        assert!(max_egt > ThermodynamicTemperature::new::<degree_celsius>(750.));
+    }
+
+    #[test]
+    fn hung_start_does_not_reach_idle() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::EngineHungStart(1));
+        test_bed.start_engine(Duration::from_secs(120));
+
+        assert!(test_bed.combustion_is_lit());
+        assert!(test_bed.core_speed() < Ratio::new::<percent>(50.));
+        assert!(!test_bed.is_above_minimum_idle());
+    }
+
+    #[test]
+    fn corrected_n1_is_higher_on_cold_day() {
+        let mut test_bed = test_bed_at_idle();
+        let standard_day_n1 = test_bed.query(|a| a.engine.corrected_n1());
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-30.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(test_bed.query(|a| a.engine.corrected_n1()) > standard_day_n1);
+    }
+
+    #[test]
+    fn three_spool_engine_starts_to_idle() {
+        let mut test_bed = NativeEngineTestBed::new(
+            NativeEngineCharacteristics::trent_900,
+            EngineOilCharacteristics::trent_900,
+        );
+        test_bed.start_engine(Duration::from_secs(120));
+
+        assert!(test_bed.is_above_minimum_idle());
+        assert!(test_bed.fan_speed() < test_bed.query(|a| a.engine.uncorrected_n2()));
+        assert!(test_bed.query(|a| a.engine.uncorrected_n2()) < test_bed.core_speed());
+    }
+}
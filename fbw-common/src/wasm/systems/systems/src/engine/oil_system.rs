@@ -0,0 +1,579 @@
+use std::time::Duration;
+
+use uom::si::{
+    f64::*,
+    mass_rate::kilogram_per_second,
+    power::watt,
+    pressure::psi,
+    ratio::{percent, ratio},
+    thermodynamic_temperature::degree_celsius,
+    volume::quart_liquid,
+};
+
+use crate::{
+    failures::{Failure, FailureType},
+    shared::interpolation,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+pub struct EngineOilCharacteristics {
+    initial_quantity: Volume,
+    gulping_quantity: Volume,
+    unusable_quantity: Volume,
+    consumption_per_hour: Volume,
+
+    speed_breakpoints_percent: [f64; 8],
+    pressure_map_psi: [f64; 8],
+
+    rated_heat_generation: Power,
+    // Thermal conductance of the air cooled oil cooler at rated speed, in W/K
+    air_cooler_rated_conductance: f64,
+    // Thermal capacity of the oil and of the lubricated parts, in J/K
+    thermal_capacity: f64,
+    idg_rated_heat_rejection: Power,
+}
+impl EngineOilCharacteristics {
+    // Oil pressure reaches the low pressure threshold around 25% gearbox speed
+    const SPEED_BREAKPOINTS_PERCENT: [f64; 8] = [0., 10., 25., 40., 60., 80., 100., 110.];
+
+    pub fn leap_1a26() -> Self {
+        Self {
+            initial_quantity: Volume::new::<quart_liquid>(17.),
+            gulping_quantity: Volume::new::<quart_liquid>(5.),
+            unusable_quantity: Volume::new::<quart_liquid>(2.),
+            consumption_per_hour: Volume::new::<quart_liquid>(0.3),
+
+            speed_breakpoints_percent: Self::SPEED_BREAKPOINTS_PERCENT,
+            pressure_map_psi: [0., 4., 18., 30., 45., 60., 80., 88.],
+
+            rated_heat_generation: Power::new::<watt>(115000.),
+            air_cooler_rated_conductance: 200.,
+            thermal_capacity: 80000.,
+            idg_rated_heat_rejection: Power::new::<watt>(15000.),
+        }
+    }
+
+    pub fn trent_900() -> Self {
+        Self {
+            initial_quantity: Volume::new::<quart_liquid>(26.),
+            gulping_quantity: Volume::new::<quart_liquid>(8.),
+            unusable_quantity: Volume::new::<quart_liquid>(3.),
+            consumption_per_hour: Volume::new::<quart_liquid>(0.4),
+
+            speed_breakpoints_percent: Self::SPEED_BREAKPOINTS_PERCENT,
+            pressure_map_psi: [0., 4., 18., 35., 60., 80., 100., 110.],
+
+            rated_heat_generation: Power::new::<watt>(250000.),
+            air_cooler_rated_conductance: 450.,
+            thermal_capacity: 180000.,
+            idg_rated_heat_rejection: Power::new::<watt>(20000.),
+        }
+    }
+}
+
+/// Lubrication system of an engine: oil tank, gearbox driven pressure pump and
+/// fuel/oil and air/oil coolers.
+///
+/// Oil held in the sumps and gearboxes while running is "gulped" out of the tank, so the tank
+/// quantity drops after start and only recovers minutes after shutdown.
+pub struct EngineOilSystem {
+    pressure_id: VariableIdentifier,
+    temperature_id: VariableIdentifier,
+    tank_quantity_id: VariableIdentifier,
+    total_quantity_id: VariableIdentifier,
+    filter_clog_id: VariableIdentifier,
+    idg_load_id: VariableIdentifier,
+
+    characteristics: EngineOilCharacteristics,
+
+    total_quantity: Volume,
+    gulped_quantity: Volume,
+    pressure: Pressure,
+    temperature: ThermodynamicTemperature,
+    idg_load: Ratio,
+    is_initialised: bool,
+
+    low_quantity_failure: Failure,
+    filter_clog_failure: Failure,
+    pressure_transmitter_failure: Failure,
+}
+impl EngineOilSystem {
+    const GULPING_TIME_CONSTANT: Duration = Duration::from_secs(20);
+    const DRAIN_BACK_TIME_CONSTANT: Duration = Duration::from_secs(600);
+
+    const LEAK_PER_HOUR_QUART: f64 = 20.;
+
+    // Pressure rises with viscosity when oil is colder than its nominal temperature
+    const NOMINAL_TEMPERATURE_DEGREE_CELSIUS: f64 = 90.;
+    const VISCOSITY_PRESSURE_FACTOR_PER_DEGREE: f64 = 0.004;
+    const MIN_VISCOSITY_PRESSURE_FACTOR: f64 = 0.85;
+    const MAX_VISCOSITY_PRESSURE_FACTOR: f64 = 1.4;
+
+    // With a clogged filter the bypass valve opens, lowering the delivered pressure
+    const CLOGGED_FILTER_PRESSURE_FACTOR: f64 = 0.85;
+    const FILTER_CLOG_INDICATION_MIN_PRESSURE_PSI: f64 = 10.;
+
+    const HEAT_GENERATION_SPEED_EXPONENT: f64 = 4.5;
+    const FUEL_SPECIFIC_HEAT_J_PER_KG_K: f64 = 2000.;
+    const FUEL_OIL_HEAT_EXCHANGER_EFFECTIVENESS: f64 = 0.4;
+    const MAX_IDG_FUEL_TEMPERATURE_RISE_DEGREE_CELSIUS: f64 = 60.;
+    const IDG_NO_LOAD_HEAT_REJECTION_RATIO: f64 = 0.2;
+
+    const LOW_PRESSURE_THRESHOLD_PSI: f64 = 18.;
+
+    const RUNNING_SPEED_RATIO: f64 = 0.5;
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        characteristics: EngineOilCharacteristics,
+    ) -> Self {
+        Self {
+            pressure_id: context.get_identifier(format!("ENGINE_OIL_PRESSURE:{}", number)),
+            temperature_id: context.get_identifier(format!("ENGINE_OIL_TEMPERATURE:{}", number)),
+            tank_quantity_id: context.get_identifier(format!("ENGINE_OIL_QTY:{}", number)),
+            total_quantity_id: context.get_identifier(format!("ENGINE_OIL_TOTAL:{}", number)),
+            filter_clog_id: context.get_identifier(format!("ENGINE_OIL_FILTER_CLOG:{}", number)),
+            idg_load_id: context.get_identifier(format!("ELEC_ENG_GEN_{}_LOAD", number)),
+
+            total_quantity: characteristics.initial_quantity,
+            characteristics,
+
+            gulped_quantity: Volume::default(),
+            pressure: Pressure::default(),
+            temperature: ThermodynamicTemperature::default(),
+            idg_load: Ratio::default(),
+            is_initialised: false,
+
+            low_quantity_failure: Failure::new(FailureType::EngineOilLowQuantity(number)),
+            filter_clog_failure: Failure::new(FailureType::EngineOilFilterClog(number)),
+            pressure_transmitter_failure: Failure::new(FailureType::EngineOilPressureTransmitter(
+                number,
+            )),
+        }
+    }
+
+    /// `gearbox_speed` is the speed of the spool driving the accessory gearbox, relative to its
+    /// rated speed.
+    pub fn update(&mut self, context: &UpdateContext, gearbox_speed: Ratio, fuel_flow: MassRate) {
+        let speed = gearbox_speed.get::<ratio>().max(0.);
+
+        // Oil starts soaked at the ambient temperature, or warm when spawning with engines running
+        if !self.is_initialised {
+            self.temperature = if speed > Self::RUNNING_SPEED_RATIO {
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::NOMINAL_TEMPERATURE_DEGREE_CELSIUS,
+                )
+            } else {
+                context.ambient_temperature()
+            };
+            self.is_initialised = true;
+        }
+
+        self.update_quantity(context.delta(), speed);
+        self.update_temperature(context, speed, fuel_flow);
+        self.update_pressure(gearbox_speed);
+    }
+
+    fn update_quantity(&mut self, delta: Duration, speed: f64) {
+        let hours = delta.as_secs_f64() / 3600.;
+
+        let mut lost_quantity = self.characteristics.consumption_per_hour * speed.min(1.) * hours;
+        if self.low_quantity_failure.is_active() {
+            lost_quantity += Volume::new::<quart_liquid>(Self::LEAK_PER_HOUR_QUART * hours);
+        }
+        self.total_quantity = (self.total_quantity - lost_quantity).max(Volume::default());
+
+        let gulping_target = self.characteristics.gulping_quantity * speed.min(1.);
+        let time_constant = if gulping_target > self.gulped_quantity {
+            Self::GULPING_TIME_CONSTANT
+        } else {
+            Self::DRAIN_BACK_TIME_CONSTANT
+        };
+        self.gulped_quantity += (gulping_target - self.gulped_quantity)
+            * (1. - (-delta.as_secs_f64() / time_constant.as_secs_f64()).exp());
+        self.gulped_quantity = self.gulped_quantity.min(self.total_quantity);
+    }
+
+    fn update_temperature(&mut self, context: &UpdateContext, speed: f64, fuel_flow: MassRate) {
+        let oil_temperature = self.temperature.get::<degree_celsius>();
+        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
+
+        let heat_generation = self.characteristics.rated_heat_generation.get::<watt>()
+            * speed.powf(Self::HEAT_GENERATION_SPEED_EXPONENT);
+
+        // Fuel first cools the IDG oil before reaching the engine fuel/oil heat exchanger
+        let fuel_heat_capacity_rate =
+            fuel_flow.get::<kilogram_per_second>().max(0.) * Self::FUEL_SPECIFIC_HEAT_J_PER_KG_K;
+        let fuel_cooling = if fuel_heat_capacity_rate > 0. {
+            let idg_fuel_temperature_rise = (self.idg_heat_rejection(speed).get::<watt>()
+                / fuel_heat_capacity_rate)
+                .min(Self::MAX_IDG_FUEL_TEMPERATURE_RISE_DEGREE_CELSIUS);
+
+            Self::FUEL_OIL_HEAT_EXCHANGER_EFFECTIVENESS
+                * fuel_heat_capacity_rate
+                * (oil_temperature - ambient_temperature - idg_fuel_temperature_rise)
+        } else {
+            0.
+        };
+
+        let air_cooling = self.characteristics.air_cooler_rated_conductance
+            * speed
+            * (oil_temperature - ambient_temperature);
+
+        // Residual losses to the nacelle keep a stopped engine at ambient temperature
+        let nacelle_losses = 0.05
+            * self.characteristics.air_cooler_rated_conductance
+            * (oil_temperature - ambient_temperature);
+
+        let temperature_rate = (heat_generation - fuel_cooling - air_cooling - nacelle_losses)
+            / self.characteristics.thermal_capacity;
+
+        self.temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            oil_temperature + temperature_rate * context.delta_as_secs_f64(),
+        );
+    }
+
+    fn idg_heat_rejection(&self, speed: f64) -> Power {
+        if speed > 0. {
+            self.characteristics.idg_rated_heat_rejection
+                * (Self::IDG_NO_LOAD_HEAT_REJECTION_RATIO
+                    + (1. - Self::IDG_NO_LOAD_HEAT_REJECTION_RATIO)
+                        * self.idg_load.get::<ratio>().clamp(0., 1.))
+        } else {
+            Power::default()
+        }
+    }
+
+    fn update_pressure(&mut self, gearbox_speed: Ratio) {
+        let pump_pressure = interpolation(
+            &self.characteristics.speed_breakpoints_percent,
+            &self.characteristics.pressure_map_psi,
+            gearbox_speed.get::<percent>().max(0.),
+        );
+
+        let viscosity_factor = (1.
+            + Self::VISCOSITY_PRESSURE_FACTOR_PER_DEGREE
+                * (Self::NOMINAL_TEMPERATURE_DEGREE_CELSIUS
+                    - self.temperature.get::<degree_celsius>()))
+        .clamp(
+            Self::MIN_VISCOSITY_PRESSURE_FACTOR,
+            Self::MAX_VISCOSITY_PRESSURE_FACTOR,
+        );
+
+        // Pump cavitates when the tank runs out of usable oil
+        let supply_factor = (self.tank_quantity().get::<quart_liquid>()
+            / self.characteristics.unusable_quantity.get::<quart_liquid>())
+        .clamp(0., 1.);
+
+        let filter_factor = if self.filter_clog_failure.is_active() {
+            Self::CLOGGED_FILTER_PRESSURE_FACTOR
+        } else {
+            1.
+        };
+
+        self.pressure =
+            Pressure::new::<psi>(pump_pressure * viscosity_factor * supply_factor * filter_factor);
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    pub fn pressure_is_low(&self) -> bool {
+        self.pressure.get::<psi>() < Self::LOW_PRESSURE_THRESHOLD_PSI
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    pub fn tank_quantity(&self) -> Volume {
+        self.total_quantity - self.gulped_quantity
+    }
+
+    pub fn total_quantity(&self) -> Volume {
+        self.total_quantity
+    }
+
+    pub fn filter_is_clogged(&self) -> bool {
+        self.filter_clog_failure.is_active()
+            && self.pressure.get::<psi>() > Self::FILTER_CLOG_INDICATION_MIN_PRESSURE_PSI
+    }
+}
+impl SimulationElement for EngineOilSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.low_quantity_failure.accept(visitor);
+        self.filter_clog_failure.accept(visitor);
+        self.pressure_transmitter_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.idg_load = reader.read(&self.idg_load_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        // A failed transmitter no longer provides an indication, the low pressure switch being
+        // independent from it
+        writer.write(
+            &self.pressure_id,
+            if self.pressure_transmitter_failure.is_active() {
+                Pressure::default()
+            } else {
+                self.pressure
+            },
+        );
+        writer.write(&self.temperature_id, self.temperature);
+        writer.write(
+            &self.tank_quantity_id,
+            self.tank_quantity().get::<quart_liquid>(),
+        );
+        writer.write(
+            &self.total_quantity_id,
+            self.total_quantity.get::<quart_liquid>(),
+        );
+        writer.write(&self.filter_clog_id, self.filter_is_clogged());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft,
+    };
+    use uom::si::mass_rate::kilogram_per_hour;
+
+    struct TestAircraft {
+        oil_system: EngineOilSystem,
+        gearbox_speed: Ratio,
+        fuel_flow: MassRate,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                oil_system: EngineOilSystem::new(context, 1, EngineOilCharacteristics::leap_1a26()),
+                gearbox_speed: Ratio::default(),
+                fuel_flow: MassRate::default(),
+            }
+        }
+
+        fn set_engine(&mut self, gearbox_speed: Ratio, fuel_flow: MassRate) {
+            self.gearbox_speed = gearbox_speed;
+            self.fuel_flow = fuel_flow;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.oil_system
+                .update(context, self.gearbox_speed, self.fuel_flow);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.oil_system.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    fn run_at_idle(test_bed: &mut SimulationTestBed<TestAircraft>, duration: Duration) {
+        run_engine(test_bed, 60., 375., duration);
+    }
+
+    fn run_engine(
+        test_bed: &mut SimulationTestBed<TestAircraft>,
+        gearbox_speed_percent: f64,
+        fuel_flow_kg_per_hour: f64,
+        duration: Duration,
+    ) {
+        test_bed.command(|a| {
+            a.set_engine(
+                Ratio::new::<percent>(gearbox_speed_percent),
+                MassRate::new::<kilogram_per_hour>(fuel_flow_kg_per_hour),
+            )
+        });
+        for _ in 0..duration.as_secs() {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+    }
+
+    fn pressure(test_bed: &SimulationTestBed<TestAircraft>) -> Pressure {
+        test_bed.query(|a| a.oil_system.pressure())
+    }
+
+    fn temperature(test_bed: &SimulationTestBed<TestAircraft>) -> ThermodynamicTemperature {
+        test_bed.query(|a| a.oil_system.temperature())
+    }
+
+    fn tank_quantity(test_bed: &SimulationTestBed<TestAircraft>) -> Volume {
+        test_bed.query(|a| a.oil_system.tank_quantity())
+    }
+
+    #[test]
+    fn oil_starts_at_ambient_temperature() {
+        let mut test_bed = test_bed();
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+        test_bed.run();
+
+        assert!(
+            (temperature(&test_bed).get::<degree_celsius>() + 20.).abs() < 0.5,
+            "Oil should be soaked at ambient temperature"
+        );
+    }
+
+    #[test]
+    fn oil_starts_warm_when_engine_is_already_running() {
+        let mut test_bed = test_bed();
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-50.));
+        run_at_idle(&mut test_bed, Duration::from_secs(1));
+
+        assert!(temperature(&test_bed) > ThermodynamicTemperature::new::<degree_celsius>(80.));
+    }
+
+    #[test]
+    fn oil_quantities_are_written_in_quarts() {
+        let mut test_bed = test_bed();
+        run_at_idle(&mut test_bed, Duration::from_secs(120));
+
+        let tank_quantity: f64 = test_bed.read_by_name("ENGINE_OIL_QTY:1");
+        let total_quantity: f64 = test_bed.read_by_name("ENGINE_OIL_TOTAL:1");
+        assert!(tank_quantity > 0.);
+        assert!(total_quantity > tank_quantity);
+        assert!(total_quantity <= 17.);
+    }
+
+    #[test]
+    fn stopped_engine_has_low_oil_pressure() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.oil_system.pressure_is_low()));
+        assert!(pressure(&test_bed).get::<psi>() < 1.);
+    }
+
+    #[test]
+    fn running_engine_has_normal_oil_pressure() {
+        let mut test_bed = test_bed();
+        run_at_idle(&mut test_bed, Duration::from_secs(10));
+
+        assert!(!test_bed.query(|a| a.oil_system.pressure_is_low()));
+    }
+
+    #[test]
+    fn cold_oil_gives_higher_pressure() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+        run_at_idle(&mut test_bed, Duration::from_secs(5));
+        let cold_pressure = pressure(&test_bed);
+
+        run_at_idle(&mut test_bed, Duration::from_secs(30 * 60));
+
+        assert!(temperature(&test_bed) > ThermodynamicTemperature::new::<degree_celsius>(50.));
+        assert!(pressure(&test_bed) < cold_pressure);
+    }
+
+    #[test]
+    fn tank_quantity_drops_after_start_and_recovers_after_shutdown() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+        let initial_quantity = tank_quantity(&test_bed);
+
+        run_at_idle(&mut test_bed, Duration::from_secs(120));
+        let running_quantity = tank_quantity(&test_bed);
+        assert!(
+            running_quantity < initial_quantity - Volume::new::<quart_liquid>(2.),
+            "Oil should be gulped into the engine"
+        );
+
+        run_engine(&mut test_bed, 0., 0., Duration::from_secs(60 * 60));
+        assert!(tank_quantity(&test_bed) > initial_quantity - Volume::new::<quart_liquid>(0.5));
+    }
+
+    #[test]
+    fn oil_is_consumed_while_running() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+        let initial_quantity = test_bed.query(|a| a.oil_system.total_quantity());
+
+        run_engine(&mut test_bed, 95., 2500., Duration::from_secs(5 * 3600));
+
+        let consumed = initial_quantity - test_bed.query(|a| a.oil_system.total_quantity());
+        assert!(consumed > Volume::new::<quart_liquid>(1.));
+        assert!(consumed < Volume::new::<quart_liquid>(2.));
+    }
+
+    #[test]
+    fn oil_warms_up_at_idle_and_stabilises_below_limit_at_takeoff() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+        run_at_idle(&mut test_bed, Duration::from_secs(15 * 60));
+        let idle_temperature = temperature(&test_bed);
+        assert!(idle_temperature > ThermodynamicTemperature::new::<degree_celsius>(50.));
+        assert!(idle_temperature < ThermodynamicTemperature::new::<degree_celsius>(100.));
+
+        run_engine(&mut test_bed, 100., 4300., Duration::from_secs(30 * 60));
+        assert!(temperature(&test_bed) > idle_temperature);
+        assert!(temperature(&test_bed) < ThermodynamicTemperature::new::<degree_celsius>(140.));
+    }
+
+    #[test]
+    fn loaded_idg_warms_engine_oil() {
+        let mut unloaded = test_bed();
+        run_at_idle(&mut unloaded, Duration::from_secs(30 * 60));
+
+        let mut loaded = test_bed();
+        loaded.write_by_name("ELEC_ENG_GEN_1_LOAD", 100.);
+        run_at_idle(&mut loaded, Duration::from_secs(30 * 60));
+
+        assert!(temperature(&loaded) > temperature(&unloaded));
+    }
+
+    #[test]
+    fn low_quantity_failure_leads_to_loss_of_oil_pressure() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::EngineOilLowQuantity(1));
+        run_engine(&mut test_bed, 90., 2500., Duration::from_secs(10 * 60));
+        assert!(!test_bed.query(|a| a.oil_system.pressure_is_low()));
+
+        run_engine(&mut test_bed, 90., 2500., Duration::from_secs(60 * 60));
+        assert!(test_bed.query(|a| a.oil_system.pressure_is_low()));
+    }
+
+    #[test]
+    fn filter_clog_is_indicated_and_lowers_pressure() {
+        let mut test_bed = test_bed();
+        run_at_idle(&mut test_bed, Duration::from_secs(10));
+        let nominal_pressure = pressure(&test_bed);
+        assert!(!test_bed.query(|a| a.oil_system.filter_is_clogged()));
+
+        test_bed.fail(FailureType::EngineOilFilterClog(1));
+        run_at_idle(&mut test_bed, Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.oil_system.filter_is_clogged()));
+        let clog_is_indicated: bool = test_bed.read_by_name("ENGINE_OIL_FILTER_CLOG:1");
+        assert!(clog_is_indicated);
+        assert!(pressure(&test_bed) < nominal_pressure);
+    }
+
+    #[test]
+    fn pressure_transmitter_failure_loses_indication_only() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::EngineOilPressureTransmitter(1));
+        run_at_idle(&mut test_bed, Duration::from_secs(10));
+
+        let indicated_pressure: Pressure = test_bed.read_by_name("ENGINE_OIL_PRESSURE:1");
+        assert!(indicated_pressure.get::<psi>() < 1.);
+        assert!(!test_bed.query(|a| a.oil_system.pressure_is_low()));
+    }
+}
@@ -0,0 +1,823 @@
+use std::time::Duration;
+
+use uom::si::{f64::*, ratio::percent, thermodynamic_temperature::degree_celsius};
+
+use crate::{
+    pneumatic::{EngineModeSelector, EngineState},
+    simulation::UpdateContext,
+};
+
+use super::{native_engine::NativeEngine, Engine};
+
+pub struct EngineStartCharacteristics {
+    ignition_core_speed: Ratio,
+    fuel_on_core_speed: Ratio,
+    starter_cutout_core_speed: Ratio,
+    start_egt_limit: ThermodynamicTemperature,
+    max_crank_time: Duration,
+    light_off_time_limit: Duration,
+}
+impl EngineStartCharacteristics {
+    pub fn leap_1a26() -> Self {
+        Self {
+            ignition_core_speed: Ratio::new::<percent>(16.),
+            fuel_on_core_speed: Ratio::new::<percent>(22.),
+            starter_cutout_core_speed: Ratio::new::<percent>(50.),
+            start_egt_limit: ThermodynamicTemperature::new::<degree_celsius>(725.),
+            max_crank_time: Duration::from_secs(60),
+            light_off_time_limit: Duration::from_secs(15),
+        }
+    }
+
+    pub fn trent_900() -> Self {
+        Self {
+            ignition_core_speed: Ratio::new::<percent>(15.),
+            fuel_on_core_speed: Ratio::new::<percent>(22.),
+            starter_cutout_core_speed: Ratio::new::<percent>(45.),
+            start_egt_limit: ThermodynamicTemperature::new::<degree_celsius>(700.),
+            max_crank_time: Duration::from_secs(60),
+            light_off_time_limit: Duration::from_secs(20),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EngineStartFault {
+    HotStart,
+    HungStart,
+    NoLightOff,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StartPhase {
+    Off,
+    DryCrank,
+    Cranking,
+    LightOff,
+    Acceleration,
+    Ventilation,
+    Running,
+}
+
+/// Start logic of the FADEC, commanding the starter valve, the igniters and the HP fuel valve of a
+/// [`NativeEngine`].
+///
+/// In automatic mode the start begins when the master lever is set on with the mode selector in
+/// IGN/START. The starter motors the core, ignition comes on and fuel is introduced at their core
+/// speed thresholds, and the starter is cut out once the engine is self sustaining. Hot, hung and
+/// no light-off starts are aborted: fuel and ignition are cut and the engine is dry cranked to
+/// ventilate it before the next attempt. The start fault stays latched once all attempts are used,
+/// until the master lever is set off.
+///
+/// In manual mode the starter valve is opened by the MAN START pushbutton, and fuel and ignition
+/// follow the master lever. Start faults are only monitored, but releasing MAN START before
+/// light-off aborts the start. With the mode selector on CRANK, the MAN START pushbutton dry cranks
+/// the engine.
+pub struct EngineStartSequence {
+    characteristics: EngineStartCharacteristics,
+
+    phase: StartPhase,
+    phase_duration: Duration,
+    is_manual: bool,
+    attempt: usize,
+    fault: Option<EngineStartFault>,
+
+    previous_core_speed: Ratio,
+    core_speed_stagnation_duration: Duration,
+
+    starter_valve_is_open: bool,
+    ignition_is_on: bool,
+    fuel_valve_is_open: bool,
+}
+impl EngineStartSequence {
+    const MAX_START_ATTEMPTS: usize = 3;
+    const VENTILATION_TIME: Duration = Duration::from_secs(30);
+
+    // The start is considered hung when the core stops accelerating below idle for this long
+    const HUNG_START_MIN_ACCELERATION_PERCENT_PER_SECOND: f64 = 0.2;
+    const HUNG_START_DETECTION_TIME: Duration = Duration::from_secs(10);
+
+    pub fn new(characteristics: EngineStartCharacteristics) -> Self {
+        Self {
+            characteristics,
+
+            phase: StartPhase::Off,
+            phase_duration: Duration::default(),
+            is_manual: false,
+            attempt: 0,
+            fault: None,
+
+            previous_core_speed: Ratio::default(),
+            core_speed_stagnation_duration: Duration::default(),
+
+            starter_valve_is_open: false,
+            ignition_is_on: false,
+            fuel_valve_is_open: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        mode_selector: EngineModeSelector,
+        master_lever_is_on: bool,
+        manual_start_is_on: bool,
+        engine: &NativeEngine,
+    ) {
+        self.update_core_speed_stagnation(context, engine);
+
+        let next_phase = match self.phase {
+            StartPhase::Off => self.next_phase_when_off(
+                mode_selector,
+                master_lever_is_on,
+                manual_start_is_on,
+                engine,
+            ),
+            StartPhase::DryCrank => {
+                if mode_selector == EngineModeSelector::Crank && manual_start_is_on {
+                    StartPhase::DryCrank
+                } else {
+                    StartPhase::Off
+                }
+            }
+            _ if self.is_manual => {
+                self.next_phase_in_manual_start(master_lever_is_on, manual_start_is_on, engine)
+            }
+            _ if !master_lever_is_on => StartPhase::Off,
+            _ => self.next_phase_in_automatic_start(engine),
+        };
+
+        if next_phase != self.phase {
+            self.phase = next_phase;
+            self.phase_duration = Duration::default();
+            self.core_speed_stagnation_duration = Duration::default();
+        } else {
+            self.phase_duration += context.delta();
+        }
+
+        self.update_commands(mode_selector, master_lever_is_on, engine);
+    }
+
+    fn update_core_speed_stagnation(&mut self, context: &UpdateContext, engine: &NativeEngine) {
+        let acceleration_percent_per_second = (engine.core_speed() - self.previous_core_speed)
+            .get::<percent>()
+            / context.delta_as_secs_f64().max(f64::EPSILON);
+
+        if acceleration_percent_per_second < Self::HUNG_START_MIN_ACCELERATION_PERCENT_PER_SECOND {
+            self.core_speed_stagnation_duration += context.delta();
+        } else {
+            self.core_speed_stagnation_duration = Duration::default();
+        }
+
+        self.previous_core_speed = engine.core_speed();
+    }
+
+    fn next_phase_when_off(
+        &mut self,
+        mode_selector: EngineModeSelector,
+        master_lever_is_on: bool,
+        manual_start_is_on: bool,
+        engine: &NativeEngine,
+    ) -> StartPhase {
+        if !master_lever_is_on {
+            self.fault = None;
+            self.attempt = 0;
+            self.is_manual = false;
+        }
+
+        if master_lever_is_on && engine.is_above_minimum_idle() {
+            StartPhase::Running
+        } else if mode_selector == EngineModeSelector::Crank && manual_start_is_on {
+            StartPhase::DryCrank
+        } else if mode_selector == EngineModeSelector::Ignition
+            && self.fault.is_none()
+            // An aborted manual start doesn't turn into an automatic one until the master lever
+            // is cycled
+            && ((master_lever_is_on && !self.is_manual) || manual_start_is_on)
+        {
+            self.is_manual = manual_start_is_on;
+            self.attempt = 1;
+            StartPhase::Cranking
+        } else {
+            StartPhase::Off
+        }
+    }
+
+    fn next_phase_in_manual_start(
+        &mut self,
+        master_lever_is_on: bool,
+        manual_start_is_on: bool,
+        engine: &NativeEngine,
+    ) -> StartPhase {
+        match self.phase {
+            // Releasing MAN START before light-off closes the starter valve and aborts the start
+            StartPhase::Cranking if !manual_start_is_on => StartPhase::Off,
+            StartPhase::Cranking if engine.combustion_is_lit() => StartPhase::Acceleration,
+            StartPhase::Acceleration | StartPhase::Running if !master_lever_is_on => {
+                StartPhase::Off
+            }
+            StartPhase::Acceleration => {
+                // The FADEC has no authority to abort a manual start, it only reports the fault
+                if let Some(fault) = self.detected_acceleration_fault(engine) {
+                    self.fault = Some(fault);
+                }
+
+                if engine.is_above_minimum_idle() {
+                    StartPhase::Running
+                } else {
+                    StartPhase::Acceleration
+                }
+            }
+            phase => phase,
+        }
+    }
+
+    fn next_phase_in_automatic_start(&mut self, engine: &NativeEngine) -> StartPhase {
+        match self.phase {
+            StartPhase::Cranking => {
+                if engine.core_speed() >= self.characteristics.fuel_on_core_speed {
+                    StartPhase::LightOff
+                } else if self.phase_duration > self.characteristics.max_crank_time {
+                    self.abort(EngineStartFault::HungStart)
+                } else {
+                    StartPhase::Cranking
+                }
+            }
+            StartPhase::LightOff => {
+                if engine.combustion_is_lit() {
+                    StartPhase::Acceleration
+                } else if self.phase_duration > self.characteristics.light_off_time_limit {
+                    self.abort(EngineStartFault::NoLightOff)
+                } else {
+                    StartPhase::LightOff
+                }
+            }
+            StartPhase::Acceleration => {
+                if let Some(fault) = self.detected_acceleration_fault(engine) {
+                    self.abort(fault)
+                } else if engine.is_above_minimum_idle() {
+                    StartPhase::Running
+                } else {
+                    StartPhase::Acceleration
+                }
+            }
+            StartPhase::Ventilation => {
+                if self.phase_duration < Self::VENTILATION_TIME {
+                    StartPhase::Ventilation
+                } else if self.attempt < Self::MAX_START_ATTEMPTS {
+                    self.attempt += 1;
+                    self.fault = None;
+                    StartPhase::Cranking
+                } else {
+                    StartPhase::Off
+                }
+            }
+            phase => phase,
+        }
+    }
+
+    fn detected_acceleration_fault(&self, engine: &NativeEngine) -> Option<EngineStartFault> {
+        if engine.egt() > self.characteristics.start_egt_limit {
+            Some(EngineStartFault::HotStart)
+        } else if !engine.is_above_minimum_idle()
+            && self.core_speed_stagnation_duration > Self::HUNG_START_DETECTION_TIME
+        {
+            Some(EngineStartFault::HungStart)
+        } else {
+            None
+        }
+    }
+
+    fn abort(&mut self, fault: EngineStartFault) -> StartPhase {
+        self.fault = Some(fault);
+        StartPhase::Ventilation
+    }
+
+    fn update_commands(
+        &mut self,
+        mode_selector: EngineModeSelector,
+        master_lever_is_on: bool,
+        engine: &NativeEngine,
+    ) {
+        let below_starter_cutout =
+            engine.core_speed() < self.characteristics.starter_cutout_core_speed;
+
+        self.starter_valve_is_open = match self.phase {
+            StartPhase::DryCrank | StartPhase::Cranking | StartPhase::LightOff => true,
+            StartPhase::Ventilation => true,
+            StartPhase::Acceleration => below_starter_cutout,
+            StartPhase::Off | StartPhase::Running => false,
+        };
+
+        self.ignition_is_on = match self.phase {
+            StartPhase::Cranking if self.is_manual => master_lever_is_on,
+            StartPhase::Cranking => engine.core_speed() >= self.characteristics.ignition_core_speed,
+            StartPhase::LightOff => true,
+            StartPhase::Acceleration => below_starter_cutout,
+            // Automatic relight when a flame out is detected
+            StartPhase::Running => {
+                mode_selector == EngineModeSelector::Ignition && !engine.combustion_is_lit()
+            }
+            StartPhase::Off | StartPhase::DryCrank | StartPhase::Ventilation => false,
+        };
+
+        self.fuel_valve_is_open = match self.phase {
+            StartPhase::Cranking if self.is_manual => master_lever_is_on,
+            StartPhase::LightOff | StartPhase::Acceleration | StartPhase::Running => true,
+            // Wet crank
+            StartPhase::DryCrank => master_lever_is_on,
+            StartPhase::Off | StartPhase::Cranking | StartPhase::Ventilation => false,
+        };
+    }
+
+    pub fn starter_valve_is_open(&self) -> bool {
+        self.starter_valve_is_open
+    }
+
+    pub fn ignition_is_on(&self) -> bool {
+        self.ignition_is_on
+    }
+
+    pub fn fuel_valve_is_open(&self) -> bool {
+        self.fuel_valve_is_open
+    }
+
+    pub fn fault(&self) -> Option<EngineStartFault> {
+        self.fault
+    }
+
+    pub fn attempt(&self) -> usize {
+        self.attempt
+    }
+
+    pub fn engine_state(&self) -> EngineState {
+        match self.phase {
+            StartPhase::Off | StartPhase::DryCrank => EngineState::Off,
+            StartPhase::Cranking
+            | StartPhase::LightOff
+            | StartPhase::Acceleration
+            | StartPhase::Ventilation => EngineState::Starting,
+            StartPhase::Running => EngineState::On,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::{
+            native_engine::NativeEngineCharacteristics, oil_system::EngineOilCharacteristics,
+        },
+        failures::FailureType,
+        simulation::{
+            test::{SimulationTestBed, TestBed},
+            Aircraft, InitContext, SimulationElement, SimulationElementVisitor,
+        },
+    };
+
+    use uom::si::{pressure::psi, velocity::knot};
+
+    struct TestAircraft {
+        engine: NativeEngine,
+        start_sequence: EngineStartSequence,
+
+        mode_selector: EngineModeSelector,
+        master_lever_is_on: bool,
+        manual_start_is_on: bool,
+        starter_supply_pressure: Pressure,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                engine: NativeEngine::new(
+                    context,
+                    1,
+                    NativeEngineCharacteristics::leap_1a26(),
+                    EngineOilCharacteristics::leap_1a26(),
+                ),
+                start_sequence: EngineStartSequence::new(EngineStartCharacteristics::leap_1a26()),
+
+                mode_selector: EngineModeSelector::Norm,
+                master_lever_is_on: false,
+                manual_start_is_on: false,
+                starter_supply_pressure: Pressure::new::<psi>(35.),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.start_sequence.update(
+                context,
+                self.mode_selector,
+                self.master_lever_is_on,
+                self.manual_start_is_on,
+                &self.engine,
+            );
+
+            self.engine.update(
+                context,
+                self.start_sequence.fuel_valve_is_open(),
+                self.start_sequence.ignition_is_on(),
+                Ratio::default(),
+                if self.start_sequence.starter_valve_is_open() {
+                    self.starter_supply_pressure
+                } else {
+                    Pressure::default()
+                },
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.engine.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct StartSequenceTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl StartSequenceTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_true_airspeed(Velocity::new::<knot>(0.));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed
+        }
+
+        fn mode_selector(mut self, mode: EngineModeSelector) -> Self {
+            self.command(|a| a.mode_selector = mode);
+            self
+        }
+
+        fn master_lever(mut self, is_on: bool) -> Self {
+            self.command(|a| a.master_lever_is_on = is_on);
+            self
+        }
+
+        fn manual_start(mut self, is_on: bool) -> Self {
+            self.command(|a| a.manual_start_is_on = is_on);
+            self
+        }
+
+        fn starter_supply_pressure(mut self, pressure: Pressure) -> Self {
+            self.command(|a| a.starter_supply_pressure = pressure);
+            self
+        }
+
+        fn with_failure(mut self, failure: FailureType) -> Self {
+            self.fail(failure);
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            for _ in 0..(duration.as_millis() / 100) {
+                self.run_with_delta(Duration::from_millis(100));
+            }
+            self
+        }
+
+        /// Runs until the given condition is met, returning whether it was met within the duration
+        fn run_until(&mut self, duration: Duration, condition: fn(&TestAircraft) -> bool) -> bool {
+            for _ in 0..(duration.as_millis() / 100) {
+                self.run_with_delta(Duration::from_millis(100));
+                if self.query(condition) {
+                    return true;
+                }
+            }
+            false
+        }
+
+        fn engine_state(&self) -> EngineState {
+            self.query(|a| a.start_sequence.engine_state())
+        }
+
+        fn fault(&self) -> Option<EngineStartFault> {
+            self.query(|a| a.start_sequence.fault())
+        }
+
+        fn attempt(&self) -> usize {
+            self.query(|a| a.start_sequence.attempt())
+        }
+
+        fn starter_valve_is_open(&self) -> bool {
+            self.query(|a| a.start_sequence.starter_valve_is_open())
+        }
+
+        fn ignition_is_on(&self) -> bool {
+            self.query(|a| a.start_sequence.ignition_is_on())
+        }
+
+        fn fuel_valve_is_open(&self) -> bool {
+            self.query(|a| a.start_sequence.fuel_valve_is_open())
+        }
+
+        fn core_speed(&self) -> Ratio {
+            self.query(|a| a.engine.core_speed())
+        }
+
+        fn is_above_minimum_idle(&self) -> bool {
+            self.query(|a| a.engine.is_above_minimum_idle())
+        }
+    }
+    impl TestBed for StartSequenceTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> StartSequenceTestBed {
+        StartSequenceTestBed::new()
+    }
+
+    fn test_bed_in_automatic_start() -> StartSequenceTestBed {
+        test_bed()
+            .mode_selector(EngineModeSelector::Ignition)
+            .master_lever(true)
+    }
+
+    #[test]
+    fn nothing_happens_with_mode_selector_in_norm() {
+        let test_bed = test_bed()
+            .master_lever(true)
+            .run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.engine_state(), EngineState::Off);
+        assert!(!test_bed.starter_valve_is_open());
+        assert!(!test_bed.fuel_valve_is_open());
+    }
+
+    #[test]
+    fn automatic_start_opens_starter_valve_without_fuel_nor_ignition() {
+        let test_bed = test_bed_in_automatic_start().run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.engine_state(), EngineState::Starting);
+        assert!(test_bed.starter_valve_is_open());
+        assert!(!test_bed.ignition_is_on());
+        assert!(!test_bed.fuel_valve_is_open());
+    }
+
+    #[test]
+    fn automatic_start_introduces_ignition_then_fuel() {
+        let mut test_bed = test_bed_in_automatic_start();
+
+        assert!(test_bed.run_until(Duration::from_secs(60), |a| a
+            .start_sequence
+            .ignition_is_on()));
+        assert!(test_bed.core_speed() >= Ratio::new::<percent>(16.));
+        assert!(!test_bed.fuel_valve_is_open());
+
+        assert!(test_bed.run_until(Duration::from_secs(60), |a| a
+            .start_sequence
+            .fuel_valve_is_open()));
+        assert!(test_bed.core_speed() >= Ratio::new::<percent>(22.));
+    }
+
+    #[test]
+    fn automatic_start_reaches_idle_and_cuts_starter_and_ignition() {
+        let test_bed = test_bed_in_automatic_start().run_for(Duration::from_secs(120));
+
+        assert_eq!(test_bed.engine_state(), EngineState::On);
+        assert!(test_bed.is_above_minimum_idle());
+        assert!(!test_bed.starter_valve_is_open());
+        assert!(!test_bed.ignition_is_on());
+        assert!(test_bed.fuel_valve_is_open());
+        assert_eq!(test_bed.fault(), None);
+        assert_eq!(test_bed.attempt(), 1);
+    }
+
+    #[test]
+    fn starter_is_cut_out_above_cutout_speed() {
+        let mut test_bed = test_bed_in_automatic_start();
+
+        assert!(
+            test_bed.run_until(Duration::from_secs(120), |a| a.engine.core_speed()
+                > Ratio::new::<percent>(51.))
+        );
+        test_bed = test_bed.run_for(Duration::from_millis(200));
+
+        assert!(!test_bed.starter_valve_is_open());
+        assert_eq!(test_bed.engine_state(), EngineState::Starting);
+    }
+
+    #[test]
+    fn master_lever_off_shuts_down_running_engine() {
+        let test_bed = test_bed_in_automatic_start()
+            .run_for(Duration::from_secs(120))
+            .master_lever(false)
+            .run_for(Duration::from_secs(60));
+
+        assert_eq!(test_bed.engine_state(), EngineState::Off);
+        assert!(!test_bed.fuel_valve_is_open());
+        assert!(test_bed.core_speed() < Ratio::new::<percent>(20.));
+    }
+
+    #[test]
+    fn hot_start_is_aborted_and_engine_ventilated() {
+        let mut test_bed =
+            test_bed_in_automatic_start().with_failure(FailureType::EngineHotStart(1));
+
+        assert!(test_bed.run_until(Duration::from_secs(120), |a| a
+            .start_sequence
+            .fault()
+            .is_some()));
+
+        assert_eq!(test_bed.fault(), Some(EngineStartFault::HotStart));
+        assert!(test_bed.starter_valve_is_open());
+        assert!(!test_bed.fuel_valve_is_open());
+        assert!(!test_bed.ignition_is_on());
+        assert!(
+            test_bed.query(|a| a.engine.egt())
+                < ThermodynamicTemperature::new::<degree_celsius>(800.)
+        );
+    }
+
+    #[test]
+    fn hung_start_is_aborted() {
+        let mut test_bed =
+            test_bed_in_automatic_start().with_failure(FailureType::EngineHungStart(1));
+
+        assert!(test_bed.run_until(Duration::from_secs(180), |a| a
+            .start_sequence
+            .fault()
+            .is_some()));
+
+        assert_eq!(test_bed.fault(), Some(EngineStartFault::HungStart));
+        assert!(!test_bed.fuel_valve_is_open());
+        assert!(!test_bed.is_above_minimum_idle());
+    }
+
+    #[test]
+    fn insufficient_starter_air_does_not_reach_light_off() {
+        let mut test_bed =
+            test_bed_in_automatic_start().starter_supply_pressure(Pressure::new::<psi>(5.));
+
+        assert!(test_bed.run_until(Duration::from_secs(120), |a| a
+            .start_sequence
+            .fault()
+            .is_some()));
+
+        assert_eq!(test_bed.fault(), Some(EngineStartFault::HungStart));
+        assert!(!test_bed.fuel_valve_is_open());
+    }
+
+    #[test]
+    fn aborted_start_is_retried_after_ventilation() {
+        let test_bed = test_bed_in_automatic_start()
+            .with_failure(FailureType::EngineHotStart(1))
+            .run_for(Duration::from_secs(120));
+
+        assert_eq!(test_bed.attempt(), 2);
+    }
+
+    #[test]
+    fn start_fault_is_latched_after_last_attempt() {
+        let test_bed = test_bed_in_automatic_start()
+            .with_failure(FailureType::EngineHungStart(1))
+            .run_for(Duration::from_secs(600));
+
+        assert_eq!(test_bed.attempt(), 3);
+        assert_eq!(test_bed.fault(), Some(EngineStartFault::HungStart));
+        assert_eq!(test_bed.engine_state(), EngineState::Off);
+        assert!(!test_bed.starter_valve_is_open());
+        assert!(!test_bed.fuel_valve_is_open());
+    }
+
+    #[test]
+    fn start_fault_is_reset_by_master_lever_off() {
+        let test_bed = test_bed_in_automatic_start()
+            .with_failure(FailureType::EngineHungStart(1))
+            .run_for(Duration::from_secs(600))
+            .master_lever(false)
+            .run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.fault(), None);
+    }
+
+    #[test]
+    fn manual_start_opens_starter_valve_with_man_start_pushbutton() {
+        let test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Ignition)
+            .manual_start(true)
+            .run_for(Duration::from_secs(30));
+
+        assert_eq!(test_bed.engine_state(), EngineState::Starting);
+        assert!(test_bed.starter_valve_is_open());
+        assert!(!test_bed.ignition_is_on());
+        assert!(!test_bed.fuel_valve_is_open());
+    }
+
+    #[test]
+    fn manual_start_introduces_fuel_and_ignition_with_master_lever() {
+        let test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Ignition)
+            .manual_start(true)
+            .run_for(Duration::from_secs(30))
+            .master_lever(true)
+            .run_for(Duration::from_millis(100));
+
+        assert!(test_bed.ignition_is_on());
+        assert!(test_bed.fuel_valve_is_open());
+    }
+
+    #[test]
+    fn manual_start_reaches_idle() {
+        let test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Ignition)
+            .manual_start(true)
+            .run_for(Duration::from_secs(30))
+            .master_lever(true)
+            .run_for(Duration::from_secs(90));
+
+        assert_eq!(test_bed.engine_state(), EngineState::On);
+        assert!(!test_bed.starter_valve_is_open());
+    }
+
+    #[test]
+    fn manual_start_is_aborted_when_man_start_released_during_cranking() {
+        let test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Ignition)
+            .manual_start(true)
+            .master_lever(true)
+            .run_for(Duration::from_millis(500))
+            .manual_start(false)
+            .run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.engine_state(), EngineState::Off);
+        assert!(!test_bed.starter_valve_is_open());
+        assert!(!test_bed.ignition_is_on());
+        assert!(!test_bed.fuel_valve_is_open());
+        assert!(!test_bed.query(|a| a.engine.combustion_is_lit()));
+    }
+
+    #[test]
+    fn aborted_manual_start_restarts_automatically_only_after_master_lever_cycle() {
+        let test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Ignition)
+            .manual_start(true)
+            .master_lever(true)
+            .run_for(Duration::from_millis(500))
+            .manual_start(false)
+            .run_for(Duration::from_secs(1))
+            .master_lever(false)
+            .run_for(Duration::from_millis(100))
+            .master_lever(true)
+            .run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.engine_state(), EngineState::Starting);
+        assert!(test_bed.starter_valve_is_open());
+    }
+
+    #[test]
+    fn manual_hot_start_is_not_aborted() {
+        let test_bed = test_bed()
+            .with_failure(FailureType::EngineHotStart(1))
+            .mode_selector(EngineModeSelector::Ignition)
+            .manual_start(true)
+            .run_for(Duration::from_secs(30))
+            .master_lever(true)
+            .run_for(Duration::from_secs(90));
+
+        assert_eq!(test_bed.fault(), Some(EngineStartFault::HotStart));
+        assert!(test_bed.fuel_valve_is_open());
+        assert!(test_bed.is_above_minimum_idle());
+    }
+
+    #[test]
+    fn dry_crank_motors_engine_without_fuel_nor_ignition() {
+        let test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Crank)
+            .manual_start(true)
+            .run_for(Duration::from_secs(60));
+
+        assert_eq!(test_bed.engine_state(), EngineState::Off);
+        assert!(test_bed.starter_valve_is_open());
+        assert!(!test_bed.ignition_is_on());
+        assert!(!test_bed.fuel_valve_is_open());
+        assert!(test_bed.core_speed() > Ratio::new::<percent>(15.));
+        assert!(!test_bed.query(|a| a.engine.combustion_is_lit()));
+    }
+
+    #[test]
+    fn dry_crank_stops_when_man_start_pushbutton_released() {
+        let test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Crank)
+            .manual_start(true)
+            .run_for(Duration::from_secs(30))
+            .manual_start(false)
+            .run_for(Duration::from_millis(100));
+
+        assert!(!test_bed.starter_valve_is_open());
+    }
+}
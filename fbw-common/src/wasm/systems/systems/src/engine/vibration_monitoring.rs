@@ -0,0 +1,547 @@
+use std::time::Duration;
+
+use uom::si::{f64::*, ratio::ratio};
+
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{low_pass_filter::LowPassFilter, random_from_range},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use super::Engine;
+
+pub struct EngineVibrationCharacteristics {
+    // Vibration levels are expressed in the cockpit indication units, from 0 to 10
+    n1_advisory_threshold: f64,
+    n2_advisory_threshold: f64,
+
+    // Imbalance left on a healthy engine after balancing, giving the vibration level at 100% speed
+    max_residual_n1_imbalance: f64,
+    max_residual_n2_imbalance: f64,
+
+    fan_blade_damage_n1_imbalance: f64,
+    bird_strike_n1_imbalance: f64,
+    bird_strike_n2_imbalance: f64,
+}
+impl EngineVibrationCharacteristics {
+    pub fn leap_1a26() -> Self {
+        Self {
+            n1_advisory_threshold: 6.,
+            n2_advisory_threshold: 4.3,
+
+            max_residual_n1_imbalance: 1.,
+            max_residual_n2_imbalance: 0.8,
+
+            fan_blade_damage_n1_imbalance: 9.,
+            bird_strike_n1_imbalance: 6.5,
+            bird_strike_n2_imbalance: 2.5,
+        }
+    }
+
+    pub fn trent_900() -> Self {
+        Self {
+            n1_advisory_threshold: 5.,
+            n2_advisory_threshold: 4.,
+
+            max_residual_n1_imbalance: 0.9,
+            max_residual_n2_imbalance: 0.7,
+
+            fan_blade_damage_n1_imbalance: 8.,
+            bird_strike_n1_imbalance: 6.,
+            bird_strike_n2_imbalance: 2.,
+        }
+    }
+}
+
+/// Vibration measured on one spool by the engine accelerometers.
+///
+/// The unbalance force of a rotor grows with the square of its speed, so the level
+/// is the spool imbalance scaled by the squared speed ratio, plus what is transmitted
+/// from the other spool through the bearings and the engine case movement on its pylon.
+struct SpoolVibration {
+    residual_imbalance: f64,
+    advisory_threshold: f64,
+    filter: LowPassFilter<f64>,
+    advisory: bool,
+}
+impl SpoolVibration {
+    const FILTER_TIME_CONSTANT: Duration = Duration::from_millis(1500);
+    const ADVISORY_HYSTERESIS: f64 = 0.3;
+
+    fn new(max_residual_imbalance: f64, advisory_threshold: f64) -> Self {
+        Self {
+            residual_imbalance: random_from_range(
+                0.2 * max_residual_imbalance,
+                max_residual_imbalance,
+            ),
+            advisory_threshold,
+            filter: LowPassFilter::new(Self::FILTER_TIME_CONSTANT),
+            advisory: false,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, raw_vibration: f64) {
+        let level = self.filter.update(
+            context.delta(),
+            raw_vibration.clamp(0., EngineVibrationMonitoring::MAX_VIBRATION),
+        );
+
+        if level > self.advisory_threshold {
+            self.advisory = true;
+        } else if level < self.advisory_threshold - Self::ADVISORY_HYSTERESIS {
+            self.advisory = false;
+        }
+    }
+
+    fn level(&self) -> f64 {
+        self.filter.output()
+    }
+}
+
+/// Vibration monitoring of a single engine, computing N1 and N2 vibration levels
+/// with their advisory (pulsing) thresholds.
+pub struct EngineVibrationMonitoring {
+    n1_vibration_id: VariableIdentifier,
+    n2_vibration_id: VariableIdentifier,
+    n1_advisory_id: VariableIdentifier,
+    n2_advisory_id: VariableIdentifier,
+
+    fan_blade_damage_imbalance: f64,
+    bird_strike_n1_imbalance: f64,
+    bird_strike_n2_imbalance: f64,
+
+    n1: SpoolVibration,
+    n2: SpoolVibration,
+
+    fan_blade_damage: Failure,
+    bird_strike: Failure,
+}
+impl EngineVibrationMonitoring {
+    const MAX_VIBRATION: f64 = 10.;
+
+    // Share of a spool vibration felt by the sensor of the other spool
+    const SPOOL_CROSS_COUPLING: f64 = 0.15;
+
+    // Vibration level added when the engine reaches its wobble animation limit
+    const WOBBLE_VIBRATION_GAIN: f64 = 1.5;
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        characteristics: &EngineVibrationCharacteristics,
+    ) -> Self {
+        Self {
+            n1_vibration_id: context.get_identifier(format!("ENGINE_N1_VIBRATION:{}", number)),
+            n2_vibration_id: context.get_identifier(format!("ENGINE_N2_VIBRATION:{}", number)),
+            n1_advisory_id: context
+                .get_identifier(format!("ENGINE_N1_VIBRATION_ADVISORY:{}", number)),
+            n2_advisory_id: context
+                .get_identifier(format!("ENGINE_N2_VIBRATION_ADVISORY:{}", number)),
+
+            fan_blade_damage_imbalance: characteristics.fan_blade_damage_n1_imbalance,
+            bird_strike_n1_imbalance: characteristics.bird_strike_n1_imbalance,
+            bird_strike_n2_imbalance: characteristics.bird_strike_n2_imbalance,
+
+            n1: SpoolVibration::new(
+                characteristics.max_residual_n1_imbalance,
+                characteristics.n1_advisory_threshold,
+            ),
+            n2: SpoolVibration::new(
+                characteristics.max_residual_n2_imbalance,
+                characteristics.n2_advisory_threshold,
+            ),
+
+            fan_blade_damage: Failure::new(FailureType::EngineFanBladeDamage(number)),
+            bird_strike: Failure::new(FailureType::EngineBirdStrike(number)),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, engine: &impl Engine, wobble: Ratio) {
+        let mut n1_imbalance = self.n1.residual_imbalance;
+        let mut n2_imbalance = self.n2.residual_imbalance;
+
+        if self.fan_blade_damage.is_active() {
+            n1_imbalance += self.fan_blade_damage_imbalance;
+        }
+
+        if self.bird_strike.is_active() {
+            n1_imbalance += self.bird_strike_n1_imbalance;
+            n2_imbalance += self.bird_strike_n2_imbalance;
+        }
+
+        let n1_unbalance = n1_imbalance * engine.corrected_n1().get::<ratio>().powi(2);
+        let n2_unbalance = n2_imbalance * engine.uncorrected_n2().get::<ratio>().powi(2);
+        let case_vibration = Self::WOBBLE_VIBRATION_GAIN * wobble.get::<ratio>();
+
+        self.n1.update(
+            context,
+            n1_unbalance + Self::SPOOL_CROSS_COUPLING * n2_unbalance + case_vibration,
+        );
+        self.n2.update(
+            context,
+            n2_unbalance + Self::SPOOL_CROSS_COUPLING * n1_unbalance + case_vibration,
+        );
+    }
+
+    pub fn n1_vibration(&self) -> f64 {
+        self.n1.level()
+    }
+
+    pub fn n2_vibration(&self) -> f64 {
+        self.n2.level()
+    }
+
+    pub fn n1_vibration_is_high(&self) -> bool {
+        self.n1.advisory
+    }
+
+    pub fn n2_vibration_is_high(&self) -> bool {
+        self.n2.advisory
+    }
+}
+impl SimulationElement for EngineVibrationMonitoring {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.fan_blade_damage.accept(visitor);
+        self.bird_strike.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.n1_vibration_id, self.n1_vibration());
+        writer.write(&self.n2_vibration_id, self.n2_vibration());
+        writer.write(&self.n1_advisory_id, self.n1_vibration_is_high());
+        writer.write(&self.n2_advisory_id, self.n2_vibration_is_high());
+    }
+}
+
+/// Engine Vibration Monitoring Unit, processing the vibration of all engines.
+///
+/// Engine wobble ratios come from the structural flex model when the aircraft has one,
+/// so pylon movements in turbulence or hard landings are seen on the vibration indications.
+pub struct EngineVibrationMonitoringUnit<const N: usize> {
+    engines: Vec<EngineVibrationMonitoring>,
+}
+impl<const N: usize> EngineVibrationMonitoringUnit<N> {
+    pub fn new(context: &mut InitContext, characteristics: EngineVibrationCharacteristics) -> Self {
+        Self {
+            engines: (1..=N)
+                .map(|number| EngineVibrationMonitoring::new(context, number, &characteristics))
+                .collect(),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        engines: [&impl Engine; N],
+        engines_wobble: [Ratio; N],
+    ) {
+        for ((monitoring, engine), wobble) in
+            self.engines.iter_mut().zip(engines).zip(engines_wobble)
+        {
+            monitoring.update(context, engine, wobble);
+        }
+    }
+
+    pub fn engine(&self, number: usize) -> &EngineVibrationMonitoring {
+        &self.engines[number - 1]
+    }
+}
+impl<const N: usize> SimulationElement for EngineVibrationMonitoringUnit<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.engines, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft,
+        },
+    };
+    use uom::si::{angular_velocity::revolution_per_minute, mass::kilogram, ratio::percent};
+
+    struct TestEngine {
+        n1: Ratio,
+        n2: Ratio,
+    }
+    impl TestEngine {
+        fn new() -> Self {
+            Self {
+                n1: Ratio::default(),
+                n2: Ratio::default(),
+            }
+        }
+
+        fn set_speeds(&mut self, n1_percent: f64, n2_percent: f64) {
+            self.n1 = Ratio::new::<percent>(n1_percent);
+            self.n2 = Ratio::new::<percent>(n2_percent);
+        }
+    }
+    impl Engine for TestEngine {
+        fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
+            AngularVelocity::new::<revolution_per_minute>(0.)
+        }
+
+        fn oil_pressure_is_low(&self) -> bool {
+            false
+        }
+
+        fn is_above_minimum_idle(&self) -> bool {
+            self.n2.get::<percent>() > 55.
+        }
+
+        fn net_thrust(&self) -> Mass {
+            Mass::new::<kilogram>(0.)
+        }
+
+        fn gearbox_speed(&self) -> AngularVelocity {
+            AngularVelocity::new::<revolution_per_minute>(0.)
+        }
+    }
+    impl EngineCorrectedN1 for TestEngine {
+        fn corrected_n1(&self) -> Ratio {
+            self.n1
+        }
+    }
+    impl EngineCorrectedN2 for TestEngine {
+        fn corrected_n2(&self) -> Ratio {
+            self.n2
+        }
+    }
+    impl EngineUncorrectedN2 for TestEngine {
+        fn uncorrected_n2(&self) -> Ratio {
+            self.n2
+        }
+    }
+
+    struct TestAircraft {
+        evmu: EngineVibrationMonitoringUnit<2>,
+        engines: [TestEngine; 2],
+        engines_wobble: [Ratio; 2],
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                evmu: EngineVibrationMonitoringUnit::new(
+                    context,
+                    EngineVibrationCharacteristics::leap_1a26(),
+                ),
+                engines: [TestEngine::new(), TestEngine::new()],
+                engines_wobble: [Ratio::default(); 2],
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.evmu.update(
+                context,
+                [&self.engines[0], &self.engines[1]],
+                self.engines_wobble,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.evmu.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct EvmuTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl EvmuTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        fn engines_at(mut self, n1_percent: f64, n2_percent: f64) -> Self {
+            self.command(|a| {
+                for engine in a.engines.iter_mut() {
+                    engine.set_speeds(n1_percent, n2_percent);
+                }
+            });
+            self
+        }
+
+        fn engine_wobble(mut self, engine_number: usize, wobble: f64) -> Self {
+            self.command(|a| a.engines_wobble[engine_number - 1] = Ratio::new::<ratio>(wobble));
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn n1_vibration(&self, engine_number: usize) -> f64 {
+            self.query(|a| a.evmu.engine(engine_number).n1_vibration())
+        }
+
+        fn n2_vibration(&self, engine_number: usize) -> f64 {
+            self.query(|a| a.evmu.engine(engine_number).n2_vibration())
+        }
+
+        fn n1_advisory(&mut self, engine_number: usize) -> bool {
+            self.read_by_name(&format!("ENGINE_N1_VIBRATION_ADVISORY:{}", engine_number))
+        }
+
+        fn n2_advisory(&mut self, engine_number: usize) -> bool {
+            self.read_by_name(&format!("ENGINE_N2_VIBRATION_ADVISORY:{}", engine_number))
+        }
+    }
+    impl TestBed for EvmuTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> EvmuTestBed {
+        EvmuTestBed::new()
+    }
+
+    #[test]
+    fn no_vibration_when_engines_are_stopped() {
+        let test_bed = test_bed().run_for(Duration::from_secs(10));
+
+        assert!(test_bed.n1_vibration(1) < 0.01);
+        assert!(test_bed.n2_vibration(1) < 0.01);
+    }
+
+    #[test]
+    fn healthy_engine_at_takeoff_power_stays_below_advisory() {
+        let mut test_bed = test_bed()
+            .engines_at(95., 98.)
+            .run_for(Duration::from_secs(20));
+
+        assert!(test_bed.n1_vibration(1) > 0.1);
+        assert!(test_bed.n1_vibration(1) < 2.);
+        assert!(test_bed.n2_vibration(1) < 2.);
+        assert!(!test_bed.n1_advisory(1));
+        assert!(!test_bed.n2_advisory(1));
+    }
+
+    #[test]
+    fn vibration_increases_with_engine_speed() {
+        let test_bed = test_bed()
+            .engines_at(20., 60.)
+            .run_for(Duration::from_secs(20));
+
+        let idle_vibration = test_bed.n1_vibration(1);
+
+        let test_bed = test_bed
+            .engines_at(90., 95.)
+            .run_for(Duration::from_secs(20));
+
+        assert!(test_bed.n1_vibration(1) > 3. * idle_vibration);
+    }
+
+    #[test]
+    fn fan_blade_damage_triggers_n1_advisory_on_failed_engine_only() {
+        let mut test_bed = test_bed().engines_at(85., 92.);
+        test_bed.fail(FailureType::EngineFanBladeDamage(1));
+        test_bed = test_bed.run_for(Duration::from_secs(20));
+
+        assert!(test_bed.n1_vibration(1) > 6.);
+        assert!(test_bed.n1_advisory(1));
+        assert!(!test_bed.n1_advisory(2));
+    }
+
+    #[test]
+    fn fan_blade_damage_vibration_decreases_when_thrust_is_reduced() {
+        let mut test_bed = test_bed().engines_at(85., 92.);
+        test_bed.fail(FailureType::EngineFanBladeDamage(1));
+        test_bed = test_bed
+            .run_for(Duration::from_secs(20))
+            .engines_at(20., 60.)
+            .run_for(Duration::from_secs(20));
+
+        assert!(test_bed.n1_vibration(1) < 1.);
+        assert!(!test_bed.n1_advisory(1));
+    }
+
+    #[test]
+    fn bird_strike_raises_both_spools_vibration() {
+        let mut test_bed = test_bed()
+            .engines_at(95., 98.)
+            .run_for(Duration::from_secs(20));
+
+        let n2_vibration_before = test_bed.n2_vibration(2);
+
+        test_bed.fail(FailureType::EngineBirdStrike(2));
+        test_bed = test_bed.run_for(Duration::from_secs(20));
+
+        assert!(test_bed.n1_advisory(2));
+        assert!(test_bed.n2_vibration(2) > n2_vibration_before + 2.);
+    }
+
+    #[test]
+    fn vibration_indication_is_limited() {
+        let mut test_bed = test_bed().engines_at(100., 100.);
+        test_bed.fail(FailureType::EngineFanBladeDamage(1));
+        test_bed.fail(FailureType::EngineBirdStrike(1));
+        test_bed = test_bed.run_for(Duration::from_secs(30));
+
+        assert!(test_bed.n1_vibration(1) <= 10.);
+    }
+
+    #[test]
+    fn engine_wobble_is_seen_on_vibration_indication() {
+        let test_bed = test_bed()
+            .engines_at(30., 65.)
+            .engine_wobble(1, 1.)
+            .run_for(Duration::from_secs(20));
+
+        assert!(test_bed.n1_vibration(1) > test_bed.n1_vibration(2) + 1.);
+        assert!(test_bed.n2_vibration(1) > test_bed.n2_vibration(2) + 1.);
+    }
+
+    #[test]
+    fn advisory_has_hysteresis() {
+        let mut test_bed = test_bed().engines_at(85., 92.);
+        test_bed.fail(FailureType::EngineFanBladeDamage(1));
+        test_bed = test_bed.run_for(Duration::from_secs(20));
+        assert!(test_bed.n1_advisory(1));
+
+        // Brings the level slightly below the threshold, within the hysteresis band
+        let n1_percent = 85. * (5.9 / test_bed.n1_vibration(1)).sqrt();
+        test_bed = test_bed
+            .engines_at(n1_percent, 92.)
+            .run_for(Duration::from_secs(20));
+
+        assert!(test_bed.n1_vibration(1) < 6.);
+        assert!(test_bed.n1_advisory(1));
+    }
+
+    #[test]
+    fn vibration_variables_exist() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert!(test_bed.contains_variable_with_name("ENGINE_N1_VIBRATION:1"));
+        assert!(test_bed.contains_variable_with_name("ENGINE_N2_VIBRATION:2"));
+        assert!(test_bed.contains_variable_with_name("ENGINE_N1_VIBRATION_ADVISORY:1"));
+        assert!(test_bed.contains_variable_with_name("ENGINE_N2_VIBRATION_ADVISORY:2"));
+        assert!(!test_bed.contains_variable_with_name("ENGINE_N1_VIBRATION:3"));
+    }
+}
@@ -4,8 +4,9 @@ use crate::air_conditioning::{
 use crate::air_conditioning::{FdacId, OcsmId};
 use crate::integrated_modular_avionics::core_processing_input_output_module::CpiomId;
 use crate::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FireDetectionLoopID,
-    FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedAirDuctZone, ElectricalBusType,
+    FireDetectionLoopID, FireDetectionZone, FlightControlSurface, GearActuatorId, HydraulicColor,
+    HydraulicSectionId, LgciuId, ProximityDetectorId,
 };
 use crate::simulation::SimulationElement;
 use fxhash::FxHashSet;
@@ -24,6 +25,8 @@ pub enum FailureType {
     OutflowValveFault,
     SafetyValveFault,
     RapidDecompression,
+    DoorSealLeak(usize),
+    ProgressiveHullBreach(ZoneType),
     Fdac(FdacId, Channel),
     Tadd(Channel),
     Vcm(VcmId, Channel),
@@ -47,23 +50,55 @@ pub enum FailureType {
     // ATA26
     SetOnFire(FireDetectionZone),
     FireDetectionLoop(FireDetectionLoopID, FireDetectionZone),
+    // ATA28
+    FuelAutoCgControlFault,
+    FuelTrimTankTransferFault,
+    FuelTankLeak(usize),
+    FuelEngineFeedLeak(usize),
     // ATA29
     ReservoirLeak(HydraulicColor),
     ReservoirAirLeak(HydraulicColor),
     ReservoirReturnLeak(HydraulicColor),
     EnginePumpOverheat(AirbusEngineDrivenPumpId),
     ElecPumpOverheat(AirbusElectricPumpId),
+    HydraulicSectionLeak(HydraulicColor, HydraulicSectionId),
+    FlightControlActuatorLeak(FlightControlSurface, HydraulicColor),
     // ATA32
     LgciuPowerSupply(LgciuId),
     LgciuInternalError(LgciuId),
     GearProxSensorDamage(ProximityDetectorId),
     GearActuatorJammed(GearActuatorId),
+    GearActuatorLeak(GearActuatorId),
     BrakeHydraulicLeak(HydraulicColor),
     BrakeAccumulatorGasLeak,
     // ATA34
     RadioAltimeter(usize),
     RadioAntennaInterrupted(usize),
     RadioAntennaDirectCoupling(usize),
+    AirDataReference(usize),
+    AirDataReferenceFault(usize),
+    InertialReference(usize),
+    InertialReferenceAttitudeOnly(usize),
+    InertialReferenceAlignmentFault(usize),
+    // ATA36
+    BleedAirDuctLeak(BleedAirDuctZone),
+    FanAirValveStuckClosed(usize),
+    FanAirValveStuckOpen(usize),
+    PrecoolerFouling(usize),
+    // ATA49
+    ApuOilLeak,
+    ApuIgnitionFault,
+    ApuEgtMarginDegradation,
+    // ATA72
+    EngineFanBladeDamage(usize),
+    EngineBirdStrike(usize),
+    // ATA79
+    EngineOilLowQuantity(usize),
+    EngineOilFilterClog(usize),
+    EngineOilPressureTransmitter(usize),
+    // ATA80
+    EngineHungStart(usize),
+    EngineHotStart(usize),
 }
 
 pub struct Failure {
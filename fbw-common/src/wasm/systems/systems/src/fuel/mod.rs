@@ -1,5 +1,6 @@
 use crate::{
-    shared::{ConsumePower, ElectricalBusType, ElectricalBuses},
+    engine::Engine,
+    shared::{ConsumePower, ElectricalBusType, ElectricalBuses, EngineFuelFlow},
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
         SimulatorWriter, UpdateContext, VariableIdentifier, Write, Writer,
@@ -7,7 +8,10 @@ use crate::{
 };
 use nalgebra::Vector3;
 use num_traits::Zero;
-use uom::si::{electric_current::ampere, f64::*, mass::kilogram};
+use uom::si::{
+    electric_current::ampere, f64::*, length::foot, mass::kilogram, power::watt,
+    temperature_interval, thermodynamic_temperature::degree_celsius,
+};
 
 pub const FUEL_GALLONS_TO_KG: f64 = 3.039075693483925;
 
@@ -29,9 +33,43 @@ impl From<f64> for RefuelRate {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FuelType {
+    JetA1,
+    JetA,
+    JetB,
+}
+read_write_enum!(FuelType);
+impl FuelType {
+    pub fn freezing_point(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(match self {
+            FuelType::JetA1 => -47.,
+            FuelType::JetA => -40.,
+            FuelType::JetB => -51.,
+        })
+    }
+}
+impl From<f64> for FuelType {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => FuelType::JetA1,
+            1 => FuelType::JetA,
+            2 => FuelType::JetB,
+            _ => FuelType::JetA1,
+        }
+    }
+}
+
 pub trait FuelPayload {
     fn total_load(&self) -> Mass;
     fn fore_aft_center_of_gravity(&self) -> f64;
+    // The fuel load once the requested refuel or defuel is done.
+    fn total_target_load(&self) -> Mass {
+        self.total_load()
+    }
+    fn target_fore_aft_center_of_gravity(&self) -> f64 {
+        self.fore_aft_center_of_gravity()
+    }
     fn tank_mass(&self, _t: usize) -> Mass {
         Mass::default()
     }
@@ -59,6 +97,7 @@ impl FuelInfo<'_> {
             context,
             self.fuel_tank_id,
             Vector3::new(self.position.0, self.position.1, self.position.2),
+            Mass::new::<kilogram>(self.total_capacity_gallons * FUEL_GALLONS_TO_KG),
             write,
         )
     }
@@ -68,19 +107,73 @@ impl FuelInfo<'_> {
 pub struct FuelTank {
     fuel_id: VariableIdentifier,
     location: Vector3<f64>,
+    capacity: Mass,
     quantity: Mass,
+    temperature: ThermodynamicTemperature,
     write: bool,
 }
 impl FuelTank {
-    pub fn new(context: &mut InitContext, id: &str, location: Vector3<f64>, write: bool) -> Self {
+    const SPECIFIC_HEAT_CAPACITY_J_PER_KG_K: f64 = 2000.;
+    // The time it takes a full tank to cover ~63% of the difference to the skin temperature.
+    const FULL_TANK_TIME_CONSTANT_SECONDS: f64 = 4. * 3600.;
+    // The tank structure and unusable fuel keep absorbing heat when the tank is nearly empty.
+    const MINIMUM_THERMAL_MASS_KG: f64 = 500.;
+
+    pub fn new(
+        context: &mut InitContext,
+        id: &str,
+        location: Vector3<f64>,
+        capacity: Mass,
+        write: bool,
+    ) -> Self {
         FuelTank {
             fuel_id: context.get_identifier(id.to_owned()),
             location,
+            capacity,
             quantity: Mass::default(),
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
             write,
         }
     }
 
+    /// Moves the fuel temperature towards the skin temperature and adds the heat returned to the
+    /// tank. The skin conducts heat to the fuel over the whole tank, so the less fuel there is,
+    /// the faster its temperature follows the skin.
+    fn update_temperature(
+        &mut self,
+        context: &UpdateContext,
+        skin_temperature: ThermodynamicTemperature,
+        heat_input: Power,
+    ) {
+        let quantity = self.quantity.get::<kilogram>();
+        let skin_temperature_celsius = skin_temperature.get::<degree_celsius>();
+        if quantity <= 0. {
+            self.temperature = skin_temperature;
+            return;
+        }
+
+        let thermal_mass = quantity.max(Self::MINIMUM_THERMAL_MASS_KG);
+        let capacity = self.capacity.get::<kilogram>().max(thermal_mass);
+        let time_constant = Self::FULL_TANK_TIME_CONSTANT_SECONDS * thermal_mass / capacity;
+        let delta = context.delta_as_secs_f64();
+
+        let conducted = skin_temperature_celsius
+            + (self.temperature.get::<degree_celsius>() - skin_temperature_celsius)
+                * (-delta / time_constant).exp();
+        let heated = heat_input.get::<watt>() * delta
+            / (thermal_mass * Self::SPECIFIC_HEAT_CAPACITY_J_PER_KG_K);
+
+        self.temperature = ThermodynamicTemperature::new::<degree_celsius>(conducted + heated);
+    }
+
+    fn set_temperature(&mut self, temperature: ThermodynamicTemperature) {
+        self.temperature = temperature;
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
     pub fn location(&self) -> Vector3<f64> {
         self.location
     }
@@ -92,6 +185,11 @@ impl FuelTank {
     pub fn set_quantity(&mut self, quantity: Mass) {
         self.quantity = quantity;
     }
+
+    /// Removes fuel from the tank, e.g. through a leak.
+    pub fn leak(&mut self, quantity: Mass) {
+        self.quantity = (self.quantity - quantity).max(Mass::default());
+    }
 }
 impl SimulationElement for FuelTank {
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -120,10 +218,19 @@ pub struct FuelSystem<const N: usize, const PUMP_COUNT: usize> {
     fuel_total_weight_id: VariableIdentifier,
     fuel_total_weight: Mass,
 
+    fuel_type_id: VariableIdentifier,
+    fuel_type: FuelType,
+
+    tank_temperature_ids: [VariableIdentifier; N],
+    tank_temperatures_are_initialised: bool,
+
     fuel_tanks: [FuelTank; N],
     fuel_pumps: [FuelPump; PUMP_COUNT],
 }
 impl<const N: usize, const PUMP_COUNT: usize> FuelSystem<N, PUMP_COUNT> {
+    const LAPSE_RATE_DEGREE_CELSIUS_PER_FOOT: f64 = 0.0019812;
+    const TROPOPAUSE_ALTITUDE_FEET: f64 = 36089.;
+
     pub fn new(
         context: &mut InitContext,
         fuel_tanks: [FuelTank; N],
@@ -134,6 +241,12 @@ impl<const N: usize, const PUMP_COUNT: usize> FuelSystem<N, PUMP_COUNT> {
             unlimited_fuel: false,
             fuel_total_weight_id: context.get_identifier("FUEL TOTAL QUANTITY WEIGHT".to_owned()),
             fuel_total_weight: Mass::default(),
+            fuel_type_id: context.get_identifier("FUEL_TYPE".to_owned()),
+            fuel_type: FuelType::JetA1,
+            tank_temperature_ids: std::array::from_fn(|t| {
+                context.get_identifier(format!("FUEL_TANK_TEMPERATURE_{}", t + 1))
+            }),
+            tank_temperatures_are_initialised: false,
             fuel_tanks,
             fuel_pumps,
         }
@@ -168,9 +281,94 @@ impl<const N: usize, const PUMP_COUNT: usize> FuelSystem<N, PUMP_COUNT> {
         }
     }
 
+    /// The center of gravity the fuel would have with the given tank quantities, e.g. once the
+    /// tanks are refuelled to their target.
+    pub fn center_of_gravity_with(&self, quantities: &[Mass; N]) -> Vector3<f64> {
+        let total_mass_kg: f64 = quantities.iter().map(|m| m.get::<kilogram>()).sum();
+        if total_mass_kg > 0. {
+            self.fuel_tanks
+                .iter()
+                .zip(quantities)
+                .map(|(t, m)| t.location() * m.get::<kilogram>())
+                .fold(Vector3::zeros(), |acc, x| acc + x)
+                / total_mass_kg
+        } else {
+            Vector3::zeros()
+        }
+    }
+
     pub fn tank_mass(&self, t: usize) -> Mass {
         self.fuel_tanks[t].quantity()
     }
+
+    /// The sum of the tank quantities. Unlike the total load, which the simulator only updates on
+    /// the next frame, it includes the changes made to the tanks during this frame.
+    pub fn fuel_on_board(&self) -> Mass {
+        self.fuel_tanks
+            .iter()
+            .fold(Mass::default(), |total, tank| total + tank.quantity())
+    }
+
+    pub fn leak_from_tank(&mut self, t: usize, quantity: Mass) {
+        self.fuel_tanks[t].leak(quantity);
+    }
+
+    pub fn fuel_pump_is_powered(&self, p: usize) -> bool {
+        self.fuel_pumps[p].is_powered()
+    }
+
+    /// Updates the fuel temperature of every tank from the skin temperature and the heat
+    /// returned into each tank, e.g. by fuel recirculated through the engine oil and IDG coolers.
+    /// On the first update the fuel starts at its preflight temperature.
+    pub fn update_temperatures(
+        &mut self,
+        context: &UpdateContext,
+        skin_temperature: ThermodynamicTemperature,
+        heat_input: [Power; N],
+    ) {
+        if !self.tank_temperatures_are_initialised {
+            let initial_temperature = Self::preflight_temperature(context);
+            self.fuel_tanks
+                .iter_mut()
+                .for_each(|tank| tank.set_temperature(initial_temperature));
+            self.tank_temperatures_are_initialised = true;
+        }
+
+        self.fuel_tanks
+            .iter_mut()
+            .zip(heat_input)
+            .for_each(|(tank, heat)| tank.update_temperature(context, skin_temperature, heat));
+    }
+
+    /// On ground the fuel is cold soaked to the ambient temperature. When starting in flight, the
+    /// fuel is still at the temperature of the departure airport, which is estimated by bringing
+    /// the ambient temperature down to sea level along the standard atmosphere lapse rate.
+    fn preflight_temperature(context: &UpdateContext) -> ThermodynamicTemperature {
+        if context.is_on_ground() {
+            return context.ambient_temperature();
+        }
+
+        let altitude_feet = context
+            .pressure_altitude()
+            .get::<foot>()
+            .clamp(0., Self::TROPOPAUSE_ALTITUDE_FEET);
+        context.ambient_temperature()
+            + TemperatureInterval::new::<temperature_interval::degree_celsius>(
+                altitude_feet * Self::LAPSE_RATE_DEGREE_CELSIUS_PER_FOOT,
+            )
+    }
+
+    pub fn tank_temperature(&self, t: usize) -> ThermodynamicTemperature {
+        self.fuel_tanks[t].temperature()
+    }
+
+    pub fn fuel_type(&self) -> FuelType {
+        self.fuel_type
+    }
+
+    pub fn freezing_point(&self) -> ThermodynamicTemperature {
+        self.fuel_type.freezing_point()
+    }
 }
 impl<const N: usize, const PUMP_COUNT: usize> SimulationElement for FuelSystem<N, PUMP_COUNT> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -182,6 +380,86 @@ impl<const N: usize, const PUMP_COUNT: usize> SimulationElement for FuelSystem<N
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.unlimited_fuel = reader.read(&self.unlimited_fuel_id);
         self.fuel_total_weight = reader.read(&self.fuel_total_weight_id);
+        self.fuel_type = reader.read(&self.fuel_type_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        if self.tank_temperatures_are_initialised {
+            for (id, tank) in self.tank_temperature_ids.iter().zip(&self.fuel_tanks) {
+                writer.write(id, tank.temperature());
+            }
+        }
+    }
+}
+
+/// Compares the fuel on board with the fuel used by the engines since engine start, the same
+/// way the crew does with the FOB + FU check. A leak is suspected when their sum has dropped
+/// below the fuel on board at engine start by more than the threshold.
+pub struct FuelLeakMonitor {
+    leak_suspected_id: VariableIdentifier,
+    discrepancy_id: VariableIdentifier,
+
+    threshold: Mass,
+    fuel_on_board_at_engine_start: Option<Mass>,
+    fuel_used: Mass,
+    discrepancy: Mass,
+}
+impl FuelLeakMonitor {
+    pub fn new(context: &mut InitContext, threshold: Mass) -> Self {
+        Self {
+            leak_suspected_id: context.get_identifier("FUEL_LEAK_SUSPECTED".to_owned()),
+            discrepancy_id: context.get_identifier("FUEL_FOB_FU_DISCREPANCY".to_owned()),
+
+            threshold,
+            fuel_on_board_at_engine_start: None,
+            fuel_used: Mass::default(),
+            discrepancy: Mass::default(),
+        }
+    }
+
+    pub fn update<const N: usize>(
+        &mut self,
+        context: &UpdateContext,
+        fuel_on_board: Mass,
+        engines: [&(impl Engine + EngineFuelFlow); N],
+    ) {
+        let any_engine_is_running = engines.iter().any(|engine| engine.is_above_minimum_idle());
+
+        // The fuel used is only reset on the ground, an engine shut down in flight keeps its count.
+        if !any_engine_is_running && context.is_on_ground() {
+            self.fuel_on_board_at_engine_start = None;
+            self.fuel_used = Mass::default();
+            self.discrepancy = Mass::default();
+            return;
+        }
+
+        let fuel_on_board_at_engine_start = *self
+            .fuel_on_board_at_engine_start
+            .get_or_insert(fuel_on_board);
+
+        self.fuel_used += engines
+            .iter()
+            .map(|engine| engine.fuel_flow() * context.delta_as_time())
+            .fold(Mass::default(), |total, used| total + used);
+        self.discrepancy = fuel_on_board_at_engine_start - (fuel_on_board + self.fuel_used);
+    }
+
+    pub fn fuel_used(&self) -> Mass {
+        self.fuel_used
+    }
+
+    pub fn discrepancy(&self) -> Mass {
+        self.discrepancy
+    }
+
+    pub fn leak_is_suspected(&self) -> bool {
+        self.discrepancy > self.threshold
+    }
+}
+impl SimulationElement for FuelLeakMonitor {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.leak_suspected_id, self.leak_is_suspected());
+        writer.write(&self.discrepancy_id, self.discrepancy.get::<kilogram>());
     }
 }
 
@@ -190,6 +468,7 @@ pub struct FuelPump {
     pump_id: VariableIdentifier,
     properties: FuelPumpProperties,
     available_potential: ElectricPotential,
+    is_powered: bool,
     running: bool,
 }
 impl FuelPump {
@@ -198,9 +477,14 @@ impl FuelPump {
             pump_id: context.get_identifier(format!("FUELSYSTEM PUMP ACTIVE:{id}")),
             properties,
             available_potential: ElectricPotential::default(),
+            is_powered: false,
             running: false,
         }
     }
+
+    pub fn is_powered(&self) -> bool {
+        self.is_powered
+    }
 }
 impl SimulationElement for FuelPump {
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -209,6 +493,7 @@ impl SimulationElement for FuelPump {
 
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.available_potential = buses.potential_of(self.properties.powered_by).raw();
+        self.is_powered = buses.is_powered(self.properties.powered_by);
     }
 
     fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, power: &mut T) {
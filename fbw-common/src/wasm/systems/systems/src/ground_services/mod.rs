@@ -0,0 +1,597 @@
+//! Ground services performed on the aircraft during a turnaround.
+//!
+//! Systems which take part in a turnaround consume the [`GroundServices`] trait instead of
+//! reading the variables of a specific add-on. GSX is one provider of these services, the
+//! built-in [`TurnaroundTimeline`] is another for users without GSX and for headless tests.
+
+use std::time::Duration;
+
+use crate::simulation::{
+    InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
+    SimulatorWriter, UpdateContext, VariableIdentifier, Write, Writer,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroundService {
+    Boarding,
+    Deboarding,
+    Catering,
+    Cargo,
+    Refuel,
+    Gpu,
+    AirStart,
+    Pushback,
+}
+impl GroundService {
+    const ALL: [GroundService; 8] = [
+        GroundService::Boarding,
+        GroundService::Deboarding,
+        GroundService::Catering,
+        GroundService::Cargo,
+        GroundService::Refuel,
+        GroundService::Gpu,
+        GroundService::AirStart,
+        GroundService::Pushback,
+    ];
+
+    fn id(&self) -> &'static str {
+        match self {
+            GroundService::Boarding => "BOARDING",
+            GroundService::Deboarding => "DEBOARDING",
+            GroundService::Catering => "CATERING",
+            GroundService::Cargo => "CARGO",
+            GroundService::Refuel => "REFUEL",
+            GroundService::Gpu => "GPU",
+            GroundService::AirStart => "ASU",
+            GroundService::Pushback => "PUSHBACK",
+        }
+    }
+
+    /// The time the built-in timeline takes to set up the service, and to perform it.
+    /// Services without a duration are performed until they are no longer requested,
+    /// or until their consumer completes them.
+    fn timeline(&self) -> (Duration, Option<Duration>) {
+        match self {
+            GroundService::Boarding => (Duration::from_secs(60), Some(Duration::from_secs(900))),
+            GroundService::Deboarding => (Duration::from_secs(60), Some(Duration::from_secs(600))),
+            GroundService::Catering => (Duration::from_secs(120), Some(Duration::from_secs(600))),
+            GroundService::Cargo => (Duration::from_secs(120), Some(Duration::from_secs(900))),
+            GroundService::Refuel => (Duration::from_secs(180), None),
+            GroundService::Gpu => (Duration::from_secs(30), None),
+            GroundService::AirStart => (Duration::from_secs(60), None),
+            GroundService::Pushback => (Duration::from_secs(60), None),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroundServiceState {
+    None,
+    Available,
+    NotAvailable,
+    Bypassed,
+    Requested,
+    Performing,
+    Completed,
+}
+impl From<f64> for GroundServiceState {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => GroundServiceState::None,
+            1 => GroundServiceState::Available,
+            2 => GroundServiceState::NotAvailable,
+            3 => GroundServiceState::Bypassed,
+            4 => GroundServiceState::Requested,
+            5 => GroundServiceState::Performing,
+            6 => GroundServiceState::Completed,
+            _ => GroundServiceState::None,
+        }
+    }
+}
+
+read_write_enum!(GroundServiceState);
+
+pub trait GroundServices {
+    /// Whether the provider drives the turnaround, instead of the aircraft's own interfaces.
+    fn is_enabled(&self) -> bool;
+
+    fn state(&self, service: GroundService) -> GroundServiceState;
+
+    /// The progress of the service, in percent.
+    fn progress_percent(&self, service: GroundService) -> f64;
+
+    /// The number of passengers moved so far by the boarding or deboarding service,
+    /// out of the `total` number of passengers it moves.
+    fn passengers_moved(&self, service: GroundService, total: i32) -> i32 {
+        (self.progress_percent(service) / 100. * total as f64).round() as i32
+    }
+
+    /// Completes a service which ends when its consumer is done, such as refuelling.
+    fn complete(&mut self, _service: GroundService) {}
+
+    fn is_performing(&self, service: GroundService) -> bool {
+        self.is_enabled() && self.state(service) == GroundServiceState::Performing
+    }
+}
+
+pub struct GsxGroundServices {
+    is_enabled_id: VariableIdentifier,
+    boarding_state_id: VariableIdentifier,
+    deboarding_state_id: VariableIdentifier,
+    catering_state_id: VariableIdentifier,
+    refuel_state_id: VariableIdentifier,
+    gpu_state_id: VariableIdentifier,
+    pushback_state_id: VariableIdentifier,
+    pax_boarding_id: VariableIdentifier,
+    pax_deboarding_id: VariableIdentifier,
+    cargo_boarding_percent_id: VariableIdentifier,
+    cargo_deboarding_percent_id: VariableIdentifier,
+
+    is_enabled: bool,
+    boarding_state: GroundServiceState,
+    deboarding_state: GroundServiceState,
+    catering_state: GroundServiceState,
+    refuel_state: GroundServiceState,
+    gpu_state: GroundServiceState,
+    pushback_state: GroundServiceState,
+    pax_boarding: i32,
+    pax_deboarding: i32,
+    cargo_boarding_percent: f64,
+    cargo_deboarding_percent: f64,
+}
+impl GsxGroundServices {
+    pub fn new(context: &mut InitContext) -> Self {
+        GsxGroundServices {
+            is_enabled_id: context.get_identifier("GSX_PAYLOAD_SYNC_ENABLED".to_owned()),
+            boarding_state_id: context.get_identifier("FSDT_GSX_BOARDING_STATE".to_owned()),
+            deboarding_state_id: context.get_identifier("FSDT_GSX_DEBOARDING_STATE".to_owned()),
+            catering_state_id: context.get_identifier("FSDT_GSX_CATERING_STATE".to_owned()),
+            refuel_state_id: context.get_identifier("FSDT_GSX_REFUELING_STATE".to_owned()),
+            gpu_state_id: context.get_identifier("FSDT_GSX_GPU_STATE".to_owned()),
+            pushback_state_id: context.get_identifier("FSDT_GSX_DEPARTURE_STATE".to_owned()),
+            pax_boarding_id: context
+                .get_identifier("FSDT_GSX_NUMPASSENGERS_BOARDING_TOTAL".to_owned()),
+            pax_deboarding_id: context
+                .get_identifier("FSDT_GSX_NUMPASSENGERS_DEBOARDING_TOTAL".to_owned()),
+            cargo_boarding_percent_id: context
+                .get_identifier("FSDT_GSX_BOARDING_CARGO_PERCENT".to_owned()),
+            cargo_deboarding_percent_id: context
+                .get_identifier("FSDT_GSX_DEBOARDING_CARGO_PERCENT".to_owned()),
+            is_enabled: false,
+            boarding_state: GroundServiceState::None,
+            deboarding_state: GroundServiceState::None,
+            catering_state: GroundServiceState::None,
+            refuel_state: GroundServiceState::None,
+            gpu_state: GroundServiceState::None,
+            pushback_state: GroundServiceState::None,
+            pax_boarding: 0,
+            pax_deboarding: 0,
+            cargo_boarding_percent: 0.0,
+            cargo_deboarding_percent: 0.0,
+        }
+    }
+}
+impl GroundServices for GsxGroundServices {
+    fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    fn state(&self, service: GroundService) -> GroundServiceState {
+        match service {
+            // GSX loads the cargo together with the passengers.
+            GroundService::Boarding | GroundService::Cargo => self.boarding_state,
+            GroundService::Deboarding => self.deboarding_state,
+            GroundService::Catering => self.catering_state,
+            GroundService::Refuel => self.refuel_state,
+            GroundService::Gpu => self.gpu_state,
+            GroundService::Pushback => self.pushback_state,
+            GroundService::AirStart => GroundServiceState::NotAvailable,
+        }
+    }
+
+    /// GSX counts the passengers separately, its progress only tracks the cargo.
+    fn progress_percent(&self, service: GroundService) -> f64 {
+        match service {
+            GroundService::Boarding | GroundService::Cargo => self.cargo_boarding_percent,
+            GroundService::Deboarding => self.cargo_deboarding_percent,
+            _ => match self.state(service) {
+                GroundServiceState::Completed => 100.,
+                _ => 0.,
+            },
+        }
+    }
+
+    fn passengers_moved(&self, service: GroundService, _total: i32) -> i32 {
+        match service {
+            GroundService::Boarding => self.pax_boarding,
+            GroundService::Deboarding => self.pax_deboarding,
+            _ => 0,
+        }
+    }
+}
+impl SimulationElement for GsxGroundServices {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_enabled = reader.read(&self.is_enabled_id);
+        self.pax_boarding = reader.read(&self.pax_boarding_id);
+        self.pax_deboarding = reader.read(&self.pax_deboarding_id);
+        self.cargo_boarding_percent = reader.read(&self.cargo_boarding_percent_id);
+        self.cargo_deboarding_percent = reader.read(&self.cargo_deboarding_percent_id);
+        self.boarding_state = reader.read(&self.boarding_state_id);
+        self.deboarding_state = reader.read(&self.deboarding_state_id);
+        self.catering_state = reader.read(&self.catering_state_id);
+        self.refuel_state = reader.read(&self.refuel_state_id);
+        self.gpu_state = reader.read(&self.gpu_state_id);
+        self.pushback_state = reader.read(&self.pushback_state_id);
+    }
+}
+
+struct TimelineService {
+    service: GroundService,
+    requested_id: VariableIdentifier,
+    state_id: VariableIdentifier,
+    progress_id: VariableIdentifier,
+
+    requested: bool,
+    state: GroundServiceState,
+    elapsed: Duration,
+}
+impl TimelineService {
+    fn new(context: &mut InitContext, service: GroundService) -> Self {
+        Self {
+            service,
+            requested_id: context
+                .get_identifier(format!("GROUND_SERVICES_{}_REQUESTED", service.id())),
+            state_id: context.get_identifier(format!("GROUND_SERVICES_{}_STATE", service.id())),
+            progress_id: context
+                .get_identifier(format!("GROUND_SERVICES_{}_PROGRESS", service.id())),
+            requested: false,
+            state: GroundServiceState::Available,
+            elapsed: Duration::default(),
+        }
+    }
+
+    fn update(&mut self, delta: Duration) {
+        let (setup_time, duration) = self.service.timeline();
+
+        match self.state {
+            GroundServiceState::Requested | GroundServiceState::Performing if !self.requested => {
+                // A service without a duration is ended by withdrawing its request.
+                self.state = if self.state == GroundServiceState::Performing && duration.is_none() {
+                    GroundServiceState::Completed
+                } else {
+                    GroundServiceState::Available
+                };
+            }
+            GroundServiceState::Requested => {
+                self.elapsed += delta;
+                if self.elapsed >= setup_time {
+                    self.state = GroundServiceState::Performing;
+                    self.elapsed = Duration::default();
+                }
+            }
+            GroundServiceState::Performing => {
+                self.elapsed += delta;
+                if duration.is_some_and(|duration| self.elapsed >= duration) {
+                    self.state = GroundServiceState::Completed;
+                }
+            }
+            _ => {
+                if self.requested && self.state != GroundServiceState::Completed {
+                    self.state = GroundServiceState::Requested;
+                    self.elapsed = Duration::default();
+                }
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.state = GroundServiceState::Available;
+        self.elapsed = Duration::default();
+    }
+
+    fn complete(&mut self) {
+        if self.state == GroundServiceState::Performing {
+            self.state = GroundServiceState::Completed;
+        }
+    }
+
+    fn progress_percent(&self) -> f64 {
+        match (self.state, self.service.timeline().1) {
+            (GroundServiceState::Completed, _) => 100.,
+            (GroundServiceState::Performing, Some(duration)) => {
+                (self.elapsed.as_secs_f64() / duration.as_secs_f64() * 100.).min(100.)
+            }
+            _ => 0.,
+        }
+    }
+}
+impl SimulationElement for TimelineService {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let requested: bool = reader.read(&self.requested_id);
+
+        // Withdrawing the request of a completed service makes it available again.
+        if !requested && self.state == GroundServiceState::Completed {
+            self.reset();
+        }
+        self.requested = requested;
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.state_id, self.state);
+        writer.write(&self.progress_id, self.progress_percent());
+    }
+}
+
+/// Performs the requested ground services on a fixed timeline.
+pub struct TurnaroundTimeline {
+    is_enabled_id: VariableIdentifier,
+    is_enabled: bool,
+    services: [TimelineService; 8],
+}
+impl TurnaroundTimeline {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            is_enabled_id: context.get_identifier("GROUND_SERVICES_TIMELINE_ENABLED".to_owned()),
+            is_enabled: false,
+            services: GroundService::ALL.map(|service| TimelineService::new(context, service)),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        for service in &mut self.services {
+            if self.is_enabled {
+                service.update(context.delta());
+            } else {
+                service.reset();
+            }
+        }
+    }
+
+    fn service(&self, service: GroundService) -> &TimelineService {
+        &self.services[service as usize]
+    }
+}
+impl GroundServices for TurnaroundTimeline {
+    fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    fn state(&self, service: GroundService) -> GroundServiceState {
+        self.service(service).state
+    }
+
+    fn progress_percent(&self, service: GroundService) -> f64 {
+        self.service(service).progress_percent()
+    }
+
+    fn complete(&mut self, service: GroundService) {
+        self.services[service as usize].complete();
+    }
+}
+impl SimulationElement for TurnaroundTimeline {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.services, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_enabled = reader.read(&self.is_enabled_id);
+    }
+}
+
+/// Provides the ground services of GSX when its payload sync is enabled,
+/// and those of the built-in timeline otherwise.
+pub struct GroundServicesProvider {
+    gsx: GsxGroundServices,
+    timeline: TurnaroundTimeline,
+}
+impl GroundServicesProvider {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            gsx: GsxGroundServices::new(context),
+            timeline: TurnaroundTimeline::new(context),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.timeline.update(context);
+    }
+
+    fn active(&self) -> &dyn GroundServices {
+        if self.gsx.is_enabled() {
+            &self.gsx
+        } else {
+            &self.timeline
+        }
+    }
+}
+impl GroundServices for GroundServicesProvider {
+    fn is_enabled(&self) -> bool {
+        self.active().is_enabled()
+    }
+
+    fn state(&self, service: GroundService) -> GroundServiceState {
+        self.active().state(service)
+    }
+
+    fn progress_percent(&self, service: GroundService) -> f64 {
+        self.active().progress_percent(service)
+    }
+
+    fn passengers_moved(&self, service: GroundService, total: i32) -> i32 {
+        self.active().passengers_moved(service, total)
+    }
+
+    fn complete(&mut self, service: GroundService) {
+        if self.gsx.is_enabled() {
+            self.gsx.complete(service);
+        } else {
+            self.timeline.complete(service);
+        }
+    }
+}
+impl SimulationElement for GroundServicesProvider {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.gsx.accept(visitor);
+        self.timeline.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::test::{
+        ElementCtorFn, SimulationTestBed, TestAircraft, TestBed, WriteByName,
+    };
+
+    type GroundServicesTestBed = SimulationTestBed<TestAircraft<GroundServicesProvider>>;
+
+    fn test_bed() -> GroundServicesTestBed {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(GroundServicesProvider::new))
+            .with_update_before_power_distribution(|element, context, _| element.update(context));
+        test_bed.write_by_name("GROUND_SERVICES_TIMELINE_ENABLED", true);
+
+        test_bed
+    }
+
+    fn request(test_bed: &mut GroundServicesTestBed, service: GroundService, requested: bool) {
+        test_bed.write_by_name(
+            &format!("GROUND_SERVICES_{}_REQUESTED", service.id()),
+            requested,
+        );
+    }
+
+    #[test]
+    fn timeline_services_are_available_when_not_requested() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert!(test_bed.query_element(|e| e.is_enabled()));
+        for service in GroundService::ALL {
+            assert_eq!(
+                test_bed.query_element(|e| e.state(service)),
+                GroundServiceState::Available
+            );
+        }
+    }
+
+    #[test]
+    fn timeline_service_is_performed_after_its_setup_time() {
+        let mut test_bed = test_bed();
+        request(&mut test_bed, GroundService::Boarding, true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_eq!(
+            test_bed.query_element(|e| e.state(GroundService::Boarding)),
+            GroundServiceState::Requested
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(60));
+        test_bed.run_with_delta(Duration::from_secs(450));
+
+        assert!(test_bed.query_element(|e| e.is_performing(GroundService::Boarding)));
+        assert!(
+            (test_bed.query_element(|e| e.progress_percent(GroundService::Boarding)) - 50.).abs()
+                < 0.1
+        );
+        assert_eq!(
+            test_bed.query_element(|e| e.passengers_moved(GroundService::Boarding, 100)),
+            50
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(450));
+
+        assert_eq!(
+            test_bed.query_element(|e| e.state(GroundService::Boarding)),
+            GroundServiceState::Completed
+        );
+    }
+
+    #[test]
+    fn timeline_service_without_duration_completes_when_no_longer_requested() {
+        let mut test_bed = test_bed();
+        request(&mut test_bed, GroundService::Gpu, true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(30));
+        test_bed.run_with_delta(Duration::from_secs(3600));
+
+        assert!(test_bed.query_element(|e| e.is_performing(GroundService::Gpu)));
+
+        request(&mut test_bed, GroundService::Gpu, false);
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query_element(|e| e.state(GroundService::Gpu)),
+            GroundServiceState::Completed
+        );
+    }
+
+    #[test]
+    fn timeline_service_can_be_completed_by_its_consumer() {
+        let mut test_bed = test_bed();
+        request(&mut test_bed, GroundService::Refuel, true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(180));
+
+        assert!(test_bed.query_element(|e| e.is_performing(GroundService::Refuel)));
+
+        test_bed.command_element(|e| e.complete(GroundService::Refuel));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query_element(|e| e.state(GroundService::Refuel)),
+            GroundServiceState::Completed
+        );
+    }
+
+    #[test]
+    fn withdrawn_request_aborts_a_timed_service() {
+        let mut test_bed = test_bed();
+        request(&mut test_bed, GroundService::Catering, true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(200));
+
+        request(&mut test_bed, GroundService::Catering, false);
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query_element(|e| e.state(GroundService::Catering)),
+            GroundServiceState::Available
+        );
+    }
+
+    #[test]
+    fn gsx_takes_precedence_over_the_timeline() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("GSX_PAYLOAD_SYNC_ENABLED", true);
+        test_bed.write_by_name("FSDT_GSX_BOARDING_STATE", GroundServiceState::Performing);
+        test_bed.write_by_name("FSDT_GSX_NUMPASSENGERS_BOARDING_TOTAL", 42);
+        test_bed.run();
+
+        assert!(test_bed.query_element(|e| e.is_performing(GroundService::Boarding)));
+        assert_eq!(
+            test_bed.query_element(|e| e.passengers_moved(GroundService::Boarding, 100)),
+            42
+        );
+        assert_eq!(
+            test_bed.query_element(|e| e.state(GroundService::AirStart)),
+            GroundServiceState::NotAvailable
+        );
+    }
+
+    #[test]
+    fn unknown_gsx_state_is_read_as_none() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("GSX_PAYLOAD_SYNC_ENABLED", true);
+        test_bed.write_by_name("FSDT_GSX_CATERING_STATE", 42);
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query_element(|e| e.state(GroundService::Catering)),
+            GroundServiceState::None
+        );
+    }
+}
@@ -5,8 +5,8 @@ use crate::{
         pid::PidController, random_from_normal_distribution, random_from_range, HydraulicColor,
     },
     simulation::{
-        SimulationElement, SimulationElementVisitor, SimulatorWriter, StartState, UpdateContext,
-        Write,
+        Read, SimulationElement, SimulationElementVisitor, SimulatorReader, SimulatorWriter,
+        StartState, UpdateContext, Write,
     },
 };
 
@@ -136,6 +136,8 @@ pub struct BrakeCircuit {
     left_press_id: VariableIdentifier,
     right_press_id: VariableIdentifier,
     acc_press_id: VariableIdentifier,
+    acc_precharge_id: VariableIdentifier,
+    acc_gas_servicing_id: VariableIdentifier,
 
     left_brake_actuator: BrakeActuator,
     right_brake_actuator: BrakeActuator,
@@ -160,6 +162,8 @@ pub struct BrakeCircuit {
     leak_failure: Failure,
     accu_gas_precharge_failure: Option<Failure>,
     accu_gas_precharge_failure_active_previous_state: bool,
+
+    accu_gas_servicing_requested: bool,
 }
 impl BrakeCircuit {
     // Filtered using time constant low pass: new_val = old_val + (new_val - old_val)* (1 - e^(-dt/TCONST))
@@ -184,6 +188,9 @@ impl BrakeCircuit {
             left_press_id: context.get_identifier(format!("HYD_BRAKE_{}_LEFT_PRESS", id)),
             right_press_id: context.get_identifier(format!("HYD_BRAKE_{}_RIGHT_PRESS", id)),
             acc_press_id: context.get_identifier(format!("HYD_BRAKE_{}_ACC_PRESS", id)),
+            acc_precharge_id: context.get_identifier(format!("HYD_BRAKE_{}_ACC_GAS_PRECHARGE", id)),
+            acc_gas_servicing_id: context
+                .get_identifier(format!("HYD_BRAKE_{}_ACC_GAS_SERVICING", id)),
 
             // We assume displacement is just split on left and right
             left_brake_actuator: BrakeActuator::new(context, total_displacement / 2.),
@@ -209,6 +216,8 @@ impl BrakeCircuit {
                 None
             },
             accu_gas_precharge_failure_active_previous_state: false,
+
+            accu_gas_servicing_requested: false,
         }
     }
 
@@ -271,6 +280,18 @@ impl BrakeCircuit {
             .update(context.delta(), actual_pressure_available);
 
         self.update_failures(context, section);
+        self.update_gas_servicing();
+    }
+
+    fn update_gas_servicing(&mut self) {
+        if let Some(accumulator) = &mut self.accumulator {
+            // Servicing is triggered once on request: nitrogen is refilled to its nominal value
+            // and the request is cleared when writing
+            if self.accu_gas_servicing_requested {
+                accumulator.reset_gas_precharge_pressure_to_nominal();
+                self.accu_gas_servicing_requested = false;
+            }
+        }
     }
 
     fn update_failures(&mut self, context: &UpdateContext, section: &impl SectionPressure) {
@@ -371,6 +392,13 @@ impl BrakeCircuit {
         self.accumulator_fluid_pressure_sensor_filter.output()
     }
 
+    pub fn accumulator_gas_precharge(&self) -> Pressure {
+        self.accumulator.as_ref().map_or(
+            Pressure::default(),
+            Accumulator::measured_gas_precharge_pressure,
+        )
+    }
+
     #[cfg(test)]
     fn accumulator_total_volume(&self) -> Volume {
         self.accumulator
@@ -410,11 +438,22 @@ impl SimulationElement for BrakeCircuit {
         visitor.visit(self);
     }
 
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        if self.accumulator.is_some() {
+            self.accu_gas_servicing_requested = reader.read(&self.acc_gas_servicing_id);
+        }
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.left_press_id, self.left_brake_pressure());
         writer.write(&self.right_press_id, self.right_brake_pressure());
         if self.accumulator.is_some() {
             writer.write(&self.acc_press_id, self.accumulator_pressure());
+            writer.write(&self.acc_precharge_id, self.accumulator_gas_precharge());
+            writer.write(
+                &self.acc_gas_servicing_id,
+                self.accu_gas_servicing_requested,
+            );
         }
     }
 }
@@ -582,6 +621,7 @@ impl Default for AutobrakeDecelerationGovernor {
 pub struct BrakeAccumulatorCharacteristics {
     total_volume: Volume,
     gas_precharge: Pressure,
+    gas_precharge_after_servicing: Pressure,
     target_pressure: Pressure,
     volume_at_init: Volume,
 }
@@ -593,6 +633,11 @@ impl BrakeAccumulatorCharacteristics {
     // 16 psi std dev would give 99.7% of values inside those 50 expected psi according to normal distribution
     const STANDARD_DEVIATION_FOR_GAS_PRE_CHARGE_DISTRIBUTION_PSI: f64 = 16.;
 
+    // Nitrogen slowly escapes through accumulator seals. Precharge is checked and refilled at
+    // regular maintenance intervals, so plane can be found anywhere between two servicing actions
+    const GAS_PRECHARGE_LOSS_PSI_PER_DAY: f64 = 0.5;
+    const MAX_DAYS_SINCE_LAST_GAS_SERVICING: f64 = 90.;
+
     pub fn new(
         total_volume: Volume,
         gas_precharge: Pressure,
@@ -608,8 +653,15 @@ impl BrakeAccumulatorCharacteristics {
                 Self::STANDARD_DEVIATION_FOR_GAS_PRE_CHARGE_DISTRIBUTION_PSI,
             ));
 
+        let days_since_last_servicing =
+            random_from_range(0., Self::MAX_DAYS_SINCE_LAST_GAS_SERVICING);
+        let actual_gas_precharge_after_drift = actual_gas_precharge_randomized
+            - Pressure::new::<psi>(
+                Self::GAS_PRECHARGE_LOSS_PSI_PER_DAY * days_since_last_servicing,
+            );
+
         let init_volume_for_target_pressure =
-            total_volume - (actual_gas_precharge_randomized / target_pressure) * total_volume;
+            total_volume - (actual_gas_precharge_after_drift / target_pressure) * total_volume;
 
         // We take a normal distribution with mean as the full volume, and standard deviation a fraction of full volume
         let volume_at_init_randomized = if !is_empty {
@@ -625,7 +677,8 @@ impl BrakeAccumulatorCharacteristics {
 
         Self {
             total_volume,
-            gas_precharge: actual_gas_precharge_randomized,
+            gas_precharge: actual_gas_precharge_after_drift,
+            gas_precharge_after_servicing: actual_gas_precharge_randomized,
             target_pressure,
             volume_at_init: volume_at_init_randomized,
         }
@@ -643,6 +696,14 @@ impl BrakeAccumulatorCharacteristics {
         self.gas_precharge
     }
 
+    pub fn gas_precharge_after_servicing(&self) -> Pressure {
+        self.gas_precharge_after_servicing
+    }
+
+    pub fn gas_precharge_loss_per_day(&self) -> Pressure {
+        Pressure::new::<psi>(Self::GAS_PRECHARGE_LOSS_PSI_PER_DAY)
+    }
+
     pub fn target_pressure(&self) -> Pressure {
         self.target_pressure
     }
@@ -663,10 +724,12 @@ impl Debug for BrakeAccumulatorCharacteristics {
 mod tests {
     use super::*;
 
-    use crate::simulation::test::{ElementCtorFn, SimulationTestBed, TestBed};
+    use crate::simulation::test::{
+        ElementCtorFn, ReadByName, SimulationTestBed, TestBed, WriteByName,
+    };
     use crate::simulation::{Aircraft, UpdateContext};
     use std::time::Duration;
-    use uom::si::{pressure::psi, volume::gallon};
+    use uom::si::{pressure::psi, thermodynamic_temperature::degree_celsius, volume::gallon};
 
     #[derive(Default)]
     struct TestHydraulicSection {
@@ -778,6 +841,14 @@ mod tests {
         fn brake_accumulator_volume(&self) -> Volume {
             self.brake_circuit.accumulator_fluid_volume()
         }
+
+        fn brake_accumulator_gas_precharge(&self) -> Pressure {
+            self.brake_circuit.accumulator_gas_precharge()
+        }
+
+        fn brake_accumulator_fluid_volume(&self) -> Volume {
+            self.brake_circuit.accumulator_fluid_volume()
+        }
     }
     impl Aircraft for TestAircraft {
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
@@ -1034,6 +1105,114 @@ mod tests {
         assert!(test_bed.query(|a| a.right_brake_pressure()) <= pressure_limit);
     }
 
+    #[test]
+    fn accumulator_gas_precharge_follows_ambient_temperature() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            TestAircraft::new(brake_circuit_with_brake_accumulator(context))
+        });
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.));
+        test_bed.run_with_delta(Duration::from_secs_f64(0.1));
+
+        let precharge_at_reference_temperature =
+            test_bed.query(|a| a.brake_accumulator_gas_precharge());
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-40.));
+        test_bed.run_with_delta(Duration::from_secs_f64(0.1));
+
+        // Gas temperature takes time to follow ambient
+        assert!(
+            test_bed.query(|a| a.brake_accumulator_gas_precharge())
+                > precharge_at_reference_temperature * 0.99
+        );
+
+        // Three hours parked in cold weather
+        for _ in 0..(3 * 360) {
+            test_bed.run_with_delta(Duration::from_secs(10));
+        }
+
+        // Ideal gas at constant volume: 233.15K / 293.15K
+        let cold_precharge = test_bed.query(|a| a.brake_accumulator_gas_precharge());
+        assert!(cold_precharge < precharge_at_reference_temperature * 0.81);
+        assert!(cold_precharge > precharge_at_reference_temperature * 0.78);
+    }
+
+    #[test]
+    fn system_accumulator_gas_precharge_does_not_follow_ambient_temperature() {
+        let init_max_vol = Volume::new::<gallon>(1.5);
+
+        let mut test_bed = SimulationTestBed::new(|context| {
+            TestAircraft::new(brake_circuit(context, init_max_vol))
+        });
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-40.));
+        for _ in 0..(3 * 360) {
+            test_bed.run_with_delta(Duration::from_secs(10));
+        }
+
+        assert!(
+            (test_bed.query(|a| a.brake_accumulator_gas_precharge()) - Pressure::new::<psi>(1000.))
+                .abs()
+                < Pressure::new::<psi>(1.)
+        );
+    }
+
+    #[test]
+    fn brake_accumulator_gas_precharge_drifts_down_and_is_restored_by_servicing() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            TestAircraft::new(brake_circuit_with_brake_accumulator(context))
+        });
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.));
+        test_bed.run_with_delta(Duration::from_secs_f64(0.1));
+
+        let initial_precharge = test_bed.query(|a| a.brake_accumulator_gas_precharge());
+
+        // A month of calendar time
+        for _ in 0..(30 * 24) {
+            test_bed.run_with_delta(Duration::from_secs(3600));
+        }
+
+        let precharge_after_a_month = test_bed.query(|a| a.brake_accumulator_gas_precharge());
+        assert!(precharge_after_a_month < initial_precharge - Pressure::new::<psi>(14.));
+        assert!(precharge_after_a_month > initial_precharge - Pressure::new::<psi>(16.));
+
+        test_bed.write_by_name("HYD_BRAKE_TestBrakes_ACC_GAS_SERVICING", true);
+        test_bed.run_with_delta(Duration::from_secs_f64(0.1));
+
+        let precharge_after_servicing = test_bed.query(|a| a.brake_accumulator_gas_precharge());
+        assert!(precharge_after_servicing > initial_precharge);
+        assert!(
+            (precharge_after_servicing - Pressure::new::<psi>(1000.)).abs()
+                < Pressure::new::<psi>(100.)
+        );
+
+        let servicing_request: bool =
+            test_bed.read_by_name("HYD_BRAKE_TestBrakes_ACC_GAS_SERVICING");
+        assert!(!servicing_request);
+    }
+
+    #[test]
+    fn brake_accumulator_gas_servicing_keeps_accumulator_fluid() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            TestAircraft::new(brake_circuit_with_brake_accumulator(context))
+        });
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.));
+        test_bed.run_with_delta(Duration::from_secs_f64(0.1));
+
+        let fluid_before_servicing = test_bed.query(|a| a.brake_accumulator_fluid_volume());
+        assert!(fluid_before_servicing > Volume::default());
+
+        test_bed.write_by_name("HYD_BRAKE_TestBrakes_ACC_GAS_SERVICING", true);
+        test_bed.run_with_delta(Duration::from_secs_f64(0.1));
+
+        assert!(
+            (test_bed.query(|a| a.brake_accumulator_fluid_volume()) - fluid_before_servicing).abs()
+                < Volume::new::<gallon>(0.001)
+        );
+    }
+
     fn brake_circuit(context: &mut InitContext, init_max_vol: Volume) -> BrakeCircuit {
         BrakeCircuit::new(
             context,
@@ -1050,6 +1229,23 @@ mod tests {
         )
     }
 
+    fn brake_circuit_with_brake_accumulator(context: &mut InitContext) -> BrakeCircuit {
+        BrakeCircuit::new(
+            context,
+            "TestBrakes",
+            HydraulicColor::Yellow,
+            Some(Accumulator::new_brake_accumulator(
+                BrakeAccumulatorCharacteristics::new(
+                    Volume::new::<gallon>(1.),
+                    Pressure::new::<psi>(1000.),
+                    Pressure::new::<psi>(3000.),
+                    Ratio::new::<ratio>(0.),
+                ),
+            )),
+            Volume::new::<gallon>(0.1),
+        )
+    }
+
     fn brake_actuator(context: &mut InitContext) -> BrakeActuator {
         BrakeActuator::new(context, Volume::new::<gallon>(0.04))
     }
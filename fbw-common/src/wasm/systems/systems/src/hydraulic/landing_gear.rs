@@ -454,12 +454,14 @@ impl GearSystemComponentAssembly {
     fn new(
         id: GearActuatorId,
         is_inverted_control: bool,
-        hydraulic_assembly: HydraulicLinearActuatorAssembly<1>,
+        mut hydraulic_assembly: HydraulicLinearActuatorAssembly<1>,
         has_hydraulic_downlock: bool,
         uplock_id: [ProximityDetectorId; 2],
         downlock_id: [ProximityDetectorId; 2],
         aerodynamic_model: AerodynamicModel,
     ) -> Self {
+        hydraulic_assembly.set_actuator_external_leak_failure(0, FailureType::GearActuatorLeak(id));
+
         let mut obj = Self {
             component_id: id.into(),
             is_inverted_control,
@@ -614,6 +616,7 @@ impl SimulationElement for GearSystemComponentAssembly {
         accept_iterable!(self.uplock_proximity_detectors, visitor);
         accept_iterable!(self.fully_opened_proximity_detectors, visitor);
         self.hydraulic_controller.accept(visitor);
+        self.hydraulic_assembly.accept(visitor);
 
         visitor.visit(self);
     }
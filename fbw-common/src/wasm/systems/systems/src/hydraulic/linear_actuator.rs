@@ -19,6 +19,7 @@ use uom::si::{
 };
 
 use crate::{
+    failures::FailureType,
     shared::{
         interpolation, low_pass_filter::LowPassFilter, pid::PidController,
         random_from_normal_distribution, random_from_range, ConsumePower, ElectricalBusType,
@@ -32,6 +33,8 @@ use crate::{
 
 use super::aerodynamic_model::AerodynamicBody;
 
+use fxhash::FxHashSet;
+
 use std::fmt::Debug;
 use std::time::Duration;
 
@@ -968,8 +971,14 @@ pub struct LinearActuator {
     core_hydraulics: CoreHydraulicForce,
 
     electro_hydrostatic_backup: Option<ElectroHydrostaticBackup>,
+
+    external_leak_failure: Option<FailureType>,
+    is_leaking: bool,
 }
 impl LinearActuator {
+    // External leak flow when actuator is supplied at its max working pressure. This fluid is lost overboard.
+    const EXTERNAL_LEAK_FAILURE_FLOW_GAL_P_S: f64 = 0.05;
+
     pub fn new(
         context: &mut InitContext,
         bounded_linear_length: &impl BoundedLinearLength,
@@ -1086,6 +1095,26 @@ impl LinearActuator {
                 max_working_pressure,
             ),
             electro_hydrostatic_backup,
+            external_leak_failure: None,
+            is_leaking: false,
+        }
+    }
+
+    /// Allows the actuator to leak fluid overboard when the given failure is active
+    pub fn set_external_leak_failure(&mut self, failure_type: FailureType) {
+        self.external_leak_failure = Some(failure_type);
+    }
+
+    /// Leak is taken from the supply line, so it only happens when actuator is supplied with pressure
+    fn update_external_leak(&mut self, context: &UpdateContext, supply_pressure: Pressure) {
+        if self.is_leaking {
+            let pressure_ratio = (supply_pressure / self.core_hydraulics.max_working_pressure)
+                .max(Ratio::new::<ratio>(0.));
+
+            self.total_volume_to_actuator +=
+                VolumeRate::new::<gallon_per_second>(Self::EXTERNAL_LEAK_FAILURE_FLOW_GAL_P_S)
+                    * context.delta_as_time()
+                    * pressure_ratio;
         }
     }
 
@@ -1269,6 +1298,12 @@ impl SimulationElement for LinearActuator {
 
         visitor.visit(self);
     }
+
+    fn receive_failure(&mut self, active_failures: &FxHashSet<FailureType>) {
+        self.is_leaking = self
+            .external_leak_failure
+            .is_some_and(|failure_type| active_failures.contains(&failure_type));
+    }
 }
 impl Debug for LinearActuator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -1370,6 +1405,8 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
                         assembly_controllers[index].requested_position(),
                     ),
             );
+
+            actuator.update_external_leak(context, current_pressure[index]);
         }
 
         self.update_hard_lock_mechanism(assembly_controllers);
@@ -1469,6 +1506,11 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
         self.linear_actuators[index].position_normalized()
     }
 
+    pub fn set_actuator_external_leak_failure(&mut self, index: usize, failure_type: FailureType) {
+        assert!(index < N);
+        self.linear_actuators[index].set_external_leak_failure(failure_type);
+    }
+
     pub fn set_trim_offset(&mut self, trim_angle: Angle) {
         self.rigid_body.apply_global_angle_offset(trim_angle);
     }
@@ -1992,7 +2034,9 @@ mod tests {
     use crate::electrical::Electricity;
 
     use crate::shared::PowerConsumptionReport;
-    use crate::shared::{update_iterator::MaxStepLoop, PotentialOrigin};
+    use crate::shared::{
+        update_iterator::MaxStepLoop, FlightControlSurface, HydraulicColor, PotentialOrigin,
+    };
     use crate::simulation::test::{ElementCtorFn, SimulationTestBed, TestBed, WriteByName};
     use crate::simulation::{Aircraft, InitContext, SimulationElement};
     use std::time::Duration;
@@ -2626,6 +2670,69 @@ mod tests {
         assert!(test_bed.query(|a| a.body_position()) > actuator_position_init);
     }
 
+    #[test]
+    fn linear_actuator_external_leak_takes_fluid_from_pressurised_supply() {
+        let mut nominal_test_bed = SimulationTestBed::new(|context| {
+            let tested_object = cargo_door_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+        let mut leaking_test_bed = SimulationTestBed::new(|context| {
+            let mut tested_object = cargo_door_assembly(context, true);
+            tested_object.set_actuator_external_leak_failure(
+                0,
+                FailureType::FlightControlActuatorLeak(
+                    FlightControlSurface::Rudder,
+                    HydraulicColor::Green,
+                ),
+            );
+            TestAircraft::new(context, tested_object)
+        });
+        leaking_test_bed.fail(FailureType::FlightControlActuatorLeak(
+            FlightControlSurface::Rudder,
+            HydraulicColor::Green,
+        ));
+
+        nominal_test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(3000.)]));
+        leaking_test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(3000.)]));
+
+        nominal_test_bed.run_with_delta(Duration::from_secs(2));
+        leaking_test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert!(
+            leaking_test_bed.query(|a| a.actuator_used_volume(0))
+                > nominal_test_bed.query(|a| a.actuator_used_volume(0))
+                    + Volume::new::<gallon>(0.05)
+        );
+    }
+
+    #[test]
+    fn linear_actuator_external_leak_stops_without_supply_pressure() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let mut tested_object = cargo_door_assembly(context, true);
+            tested_object.set_actuator_external_leak_failure(
+                0,
+                FailureType::FlightControlActuatorLeak(
+                    FlightControlSurface::Rudder,
+                    HydraulicColor::Green,
+                ),
+            );
+            TestAircraft::new(context, tested_object)
+        });
+        test_bed.fail(FailureType::FlightControlActuatorLeak(
+            FlightControlSurface::Rudder,
+            HydraulicColor::Green,
+        ));
+
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert_about_eq!(
+            test_bed
+                .query(|a| a.actuator_used_volume(0))
+                .get::<gallon>(),
+            0.
+        );
+    }
+
     #[test]
     fn linear_actuator_can_move_rigid_body_up() {
         let mut test_bed = SimulationTestBed::new(|context| {
@@ -11,7 +11,8 @@ use crate::physics::{GravityEffect, WobblePhysics};
 use crate::shared::{
     interpolation, low_pass_filter::LowPassFilter, random_from_normal_distribution,
     random_from_range, AirbusElectricPumpId, AirbusEngineDrivenPumpId, DelayedTrueLogicGate,
-    ElectricalBusType, ElectricalBuses, HydraulicColor, RamAirTurbineController, SectionPressure,
+    ElectricalBusType, ElectricalBuses, HydraulicColor, HydraulicSectionId,
+    RamAirTurbineController, SectionPressure,
 };
 use crate::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -27,6 +28,7 @@ use uom::si::{
     length::meter,
     pressure::{pascal, psi},
     ratio::ratio,
+    thermodynamic_temperature::kelvin,
     torque::{newton_meter, pound_force_inch},
     volume::{cubic_inch, cubic_meter, gallon},
     volume_rate::{gallon_per_minute, gallon_per_second},
@@ -775,8 +777,12 @@ pub trait HydraulicCircuitController {
 
 pub struct Accumulator {
     total_volume: Volume,
-    current_gas_init_precharge: Pressure, // Current precharge as it can be changed by leaks for example
-    gas_nominal_init_precharge: Pressure, // Original precharge used at plane init
+    current_gas_init_precharge: Pressure, // Current precharge at reference temperature as it can be changed by leaks for example
+    gas_nominal_init_precharge: Pressure, // Precharge at reference temperature right after servicing
+    gas_precharge_loss_per_day: Pressure, // Natural nitrogen loss through seals over calendar time
+    gas_temperature: LowPassFilter<f64>,  // Gas temperature in kelvin
+    is_gas_temperature_initialised: bool,
+    has_gas_temperature_effect: bool, // Only modelled for brake accumulators
     gas_pressure: Pressure,
     gas_volume: Volume,
     fluid_volume: Volume,
@@ -793,6 +799,14 @@ impl Accumulator {
     // Higher gain enables faster flow transient but brings instability.
     const DELTA_PRESSURE_CHARACTERISTICS: f64 = 0.009;
 
+    // Precharge values are given by manufacturer at 20°C
+    const GAS_PRECHARGE_REFERENCE_TEMPERATURE_KELVIN: f64 = 293.15;
+
+    // Time constant for the gas to reach ambient temperature through accumulator body
+    const GAS_TEMPERATURE_TIME_CONSTANT: Duration = Duration::from_secs(60 * 20);
+
+    const SECONDS_PER_DAY: f64 = 86400.;
+
     fn new(
         gas_precharge: Pressure,
         total_volume: Volume,
@@ -811,6 +825,13 @@ impl Accumulator {
             total_volume,
             current_gas_init_precharge: gas_precharge,
             gas_nominal_init_precharge: gas_precharge,
+            gas_precharge_loss_per_day: Pressure::default(),
+            gas_temperature: LowPassFilter::new_with_init_value(
+                Self::GAS_TEMPERATURE_TIME_CONSTANT,
+                Self::GAS_PRECHARGE_REFERENCE_TEMPERATURE_KELVIN,
+            ),
+            is_gas_temperature_initialised: false,
+            has_gas_temperature_effect: false,
             gas_pressure: gas_press_at_init,
             gas_volume: (total_volume - limited_volume),
             fluid_volume: limited_volume,
@@ -828,6 +849,8 @@ impl Accumulator {
         circuit_pressure: Pressure,
         max_volume_to_target: Volume,
     ) {
+        self.update_gas_precharge(context);
+
         let accumulator_delta_press = self.gas_pressure - circuit_pressure;
 
         let mut flow_variation = VolumeRate::new::<gallon_per_second>(
@@ -850,7 +873,7 @@ impl Accumulator {
             *delta_vol += volume_from_acc;
         } else if accumulator_delta_press.get::<psi>() < 0.0 {
             let fluid_volume_to_reach_equilibrium = self.total_volume
-                - ((self.current_gas_init_precharge / self.circuit_target_pressure)
+                - ((self.gas_precharge_at_current_temperature() / self.circuit_target_pressure)
                     * self.total_volume);
 
             let max_delta_vol = fluid_volume_to_reach_equilibrium - self.fluid_volume;
@@ -866,10 +889,37 @@ impl Accumulator {
         }
 
         self.current_flow = self.current_delta_vol / context.delta_as_time();
-        self.gas_pressure = (self.current_gas_init_precharge * self.total_volume)
+        self.gas_pressure = (self.gas_precharge_at_current_temperature() * self.total_volume)
             / (self.total_volume - self.fluid_volume);
     }
 
+    /// Updates gas temperature and natural precharge loss. Gas pressure follows
+    /// ideal gas law at constant volume with respect to the reference temperature precharge.
+    fn update_gas_precharge(&mut self, context: &UpdateContext) {
+        if self.has_gas_temperature_effect {
+            let ambient_temperature_kelvin = context.ambient_temperature().get::<kelvin>();
+
+            // At first update gas is considered to be at ambient temperature as plane was parked
+            if !self.is_gas_temperature_initialised {
+                self.gas_temperature.reset(ambient_temperature_kelvin);
+                self.is_gas_temperature_initialised = true;
+            } else {
+                self.gas_temperature
+                    .update(context.delta(), ambient_temperature_kelvin);
+            }
+        }
+
+        self.current_gas_init_precharge = (self.current_gas_init_precharge
+            - self.gas_precharge_loss_per_day * context.delta_as_secs_f64()
+                / Self::SECONDS_PER_DAY)
+            .max(Pressure::default());
+    }
+
+    fn gas_precharge_at_current_temperature(&self) -> Pressure {
+        self.current_gas_init_precharge * self.gas_temperature.output()
+            / Self::GAS_PRECHARGE_REFERENCE_TEMPERATURE_KELVIN
+    }
+
     fn new_system_accumulator(
         gas_precharge: Pressure,
         total_volume: Volume,
@@ -886,13 +936,18 @@ impl Accumulator {
     }
 
     pub fn new_brake_accumulator(characteristics: BrakeAccumulatorCharacteristics) -> Self {
-        Accumulator::new(
+        let mut accumulator = Accumulator::new(
             characteristics.gas_precharge(),
             characteristics.total_volume(),
             characteristics.volume_at_init(),
             true,
             characteristics.target_pressure(),
-        )
+        );
+        accumulator.gas_nominal_init_precharge = characteristics.gas_precharge_after_servicing();
+        accumulator.gas_precharge_loss_per_day = characteristics.gas_precharge_loss_per_day();
+        accumulator.has_gas_temperature_effect = true;
+
+        accumulator
     }
 
     fn get_delta_vol(&mut self, required_delta_vol: Volume) -> Volume {
@@ -903,7 +958,7 @@ impl Accumulator {
                 self.fluid_volume -= volume_from_acc;
                 self.gas_volume += volume_from_acc;
 
-                self.gas_pressure = self.current_gas_init_precharge * self.total_volume
+                self.gas_pressure = self.gas_precharge_at_current_temperature() * self.total_volume
                     / (self.total_volume - self.fluid_volume);
             }
         }
@@ -922,7 +977,7 @@ impl Accumulator {
     fn set_gas_precharge_pressure(&mut self, new_pressure: Pressure) {
         self.current_gas_init_precharge = new_pressure;
         self.gas_pressure = Self::gas_pressure_from_gas_precharge(
-            self.current_gas_init_precharge,
+            self.gas_precharge_at_current_temperature(),
             self.total_volume,
             self.fluid_volume,
         );
@@ -932,8 +987,13 @@ impl Accumulator {
         self.current_gas_init_precharge
     }
 
+    /// Precharge a maintenance gauge would read with fluid side depressurised
+    fn measured_gas_precharge_pressure(&self) -> Pressure {
+        self.gas_precharge_at_current_temperature()
+    }
+
+    /// Refills nitrogen to its nominal precharge. Fluid side is left untouched
     fn reset_gas_precharge_pressure_to_nominal(&mut self) {
-        self.fluid_volume = Volume::default();
         self.set_gas_precharge_pressure(self.gas_nominal_init_precharge);
     }
 
@@ -1037,6 +1097,7 @@ impl HydraulicCircuit {
                 id,
                 "PUMP",
                 pump_id,
+                HydraulicSectionId::Pump(pump_id),
                 VolumeRate::new::<gallon_per_second>(Self::PUMP_SECTION_STATIC_LEAK_GAL_P_S),
                 Volume::new::<gallon>(
                     Self::PUMP_SECTION_MAX_VOLUME_GAL * priming_volume.get::<ratio>(),
@@ -1068,6 +1129,7 @@ impl HydraulicCircuit {
                 id,
                 "SYSTEM",
                 1,
+                HydraulicSectionId::System,
                 VolumeRate::new::<gallon_per_second>(Self::SYSTEM_SECTION_STATIC_LEAK_GAL_P_S),
                 system_section_volume * priming_volume,
                 system_section_volume,
@@ -1093,6 +1155,7 @@ impl HydraulicCircuit {
                     id,
                     "AUXILIARY",
                     1,
+                    HydraulicSectionId::Auxiliary,
                     VolumeRate::new::<gallon_per_second>(Self::AUX_SECTION_STATIC_LEAK_GAL_P_S),
                     system_section_volume
                         * Self::AUXILIARY_TO_SYSTEM_SECTION_SIZE_RATIO
@@ -1460,6 +1523,10 @@ impl HydraulicCircuit {
     pub fn pump_section(&self, pump_index: usize) -> &impl SectionPressure {
         &self.pump_sections[pump_index]
     }
+
+    pub fn pump_sections_count(&self) -> usize {
+        self.pump_sections.len()
+    }
 }
 impl SimulationElement for HydraulicCircuit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -1502,6 +1569,7 @@ pub struct Section {
     section_id_number: usize,
 
     static_leak_at_max_press: VolumeRate,
+    external_leak_failure: Failure,
     current_volume: Volume,
     max_high_press_volume: Volume,
     current_pressure: Pressure,
@@ -1529,11 +1597,15 @@ pub struct Section {
     total_actuator_returned_volume: Volume,
 }
 impl Section {
+    // External leak flow when section is at circuit target pressure. This fluid is lost overboard.
+    const EXTERNAL_LEAK_FAILURE_FLOW_GAL_P_S: f64 = 0.1;
+
     pub fn new(
         context: &mut InitContext,
         loop_id: HydraulicColor,
         section_id: &str,
         pump_id: usize,
+        section_leak_id: HydraulicSectionId,
         static_leak_at_max_press: VolumeRate,
         current_volume: Volume,
         max_high_press_volume: Volume,
@@ -1557,6 +1629,10 @@ impl Section {
                 .to_owned(),
             section_id_number: pump_id,
             static_leak_at_max_press,
+            external_leak_failure: Failure::new(FailureType::HydraulicSectionLeak(
+                loop_id,
+                section_leak_id,
+            )),
             current_volume,
             max_high_press_volume,
             current_pressure: Pressure::new::<psi>(14.7),
@@ -1640,6 +1716,21 @@ impl Section {
             / target_pressure
     }
 
+    /// External leak is proportional to section pressure: isolating the section
+    /// (pumps off, fire valve closed...) stops the fluid loss
+    fn external_leak(&self, context: &UpdateContext, target_pressure: Pressure) -> Volume {
+        if self.external_leak_failure.is_active() {
+            (VolumeRate::new::<gallon_per_second>(Self::EXTERNAL_LEAK_FAILURE_FLOW_GAL_P_S)
+                * context.delta_as_time()
+                * (self.current_pressure - Pressure::new::<psi>(14.7))
+                / target_pressure)
+                .max(Volume::default())
+                .min(self.current_volume.max(Volume::default()))
+        } else {
+            Volume::default()
+        }
+    }
+
     /// Updates hydraulic flow from consumers like accumulator / ptu / any actuator
     pub fn update_flow(
         &mut self,
@@ -1653,6 +1744,9 @@ impl Section {
 
         reservoir.add_return_volume(static_leak);
 
+        // External leak never returns to reservoir
+        delta_volume_flow_pass -= self.external_leak(context, target_pressure);
+
         if let Some(accumulator) = &mut self.accumulator {
             accumulator.update(
                 context,
@@ -1807,6 +1901,8 @@ impl Section {
 }
 impl SimulationElement for Section {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.external_leak_failure.accept(visitor);
+
         if let Some(fire_valve) = &mut self.fire_valve {
             fire_valve.accept(visitor);
         }
@@ -3228,7 +3324,7 @@ impl SimulationElement for HydraulicValve {
 #[cfg(test)]
 mod tests {
     use crate::simulation::test::{
-        ElementCtorFn, ReadByName, SimulationTestBed, TestBed, WriteByName,
+        ElementCtorFn, ReadByName, SimulationTestBed, TestAircraft, TestBed, WriteByName,
     };
     use crate::simulation::InitContext;
     use ntest::assert_about_eq;
@@ -3269,6 +3365,112 @@ mod tests {
         assert!(test_bed.contains_variable_with_name("HYD_GREEN_PUMP_2_FIRE_VALVE_OPENED"));
     }
 
+    struct LeakingSection {
+        section: Section,
+        leaked_volume: Volume,
+    }
+    impl LeakingSection {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                section: section(context, HydraulicColor::Green, "PUMP", 2),
+                leaked_volume: Volume::default(),
+            }
+        }
+
+        fn set_state(&mut self, pressure: Pressure, volume: Volume) {
+            self.section.current_pressure = pressure;
+            self.section.current_volume = volume;
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            self.leaked_volume += self
+                .section
+                .external_leak(context, Pressure::new::<psi>(3000.));
+        }
+    }
+    impl SimulationElement for LeakingSection {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.section.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn leaking_section_test_bed(
+        pressure: Pressure,
+        volume: Volume,
+    ) -> SimulationTestBed<TestAircraft<LeakingSection>> {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(LeakingSection::new));
+        test_bed.set_update_after_power_distribution(|element, context| element.update(context));
+        test_bed.command_element(|e| e.set_state(pressure, volume));
+
+        test_bed
+    }
+
+    #[test]
+    fn section_without_leak_failure_does_not_leak() {
+        let mut test_bed = leaking_section_test_bed(
+            Pressure::new::<psi>(3000.),
+            Volume::new::<gallon>(HydraulicCircuit::PUMP_SECTION_MAX_VOLUME_GAL),
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_about_eq!(
+            test_bed.query_element(|e| e.leaked_volume.get::<gallon>()),
+            0.
+        );
+    }
+
+    #[test]
+    fn pressurised_section_with_leak_failure_loses_fluid() {
+        let mut test_bed = leaking_section_test_bed(
+            Pressure::new::<psi>(3000.),
+            Volume::new::<gallon>(HydraulicCircuit::PUMP_SECTION_MAX_VOLUME_GAL),
+        );
+        test_bed.fail(FailureType::HydraulicSectionLeak(
+            HydraulicColor::Green,
+            HydraulicSectionId::Pump(2),
+        ));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query_element(|e| e.leaked_volume.get::<gallon>()) > 0.09);
+    }
+
+    #[test]
+    fn section_leak_never_adds_fluid_below_ambient_pressure() {
+        let mut test_bed = leaking_section_test_bed(
+            Pressure::new::<psi>(0.),
+            Volume::new::<gallon>(HydraulicCircuit::PUMP_SECTION_MAX_VOLUME_GAL),
+        );
+        test_bed.fail(FailureType::HydraulicSectionLeak(
+            HydraulicColor::Green,
+            HydraulicSectionId::Pump(2),
+        ));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_about_eq!(
+            test_bed.query_element(|e| e.leaked_volume.get::<gallon>()),
+            0.
+        );
+    }
+
+    #[test]
+    fn section_leak_cannot_take_more_than_section_volume() {
+        let mut test_bed =
+            leaking_section_test_bed(Pressure::new::<psi>(3000.), Volume::new::<gallon>(0.01));
+        test_bed.fail(FailureType::HydraulicSectionLeak(
+            HydraulicColor::Green,
+            HydraulicSectionId::Pump(2),
+        ));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query_element(|e| e.leaked_volume.get::<gallon>()) <= 0.01);
+    }
+
     #[test]
     fn hyd_circuit_writes_its_state() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
@@ -3650,6 +3852,7 @@ mod tests {
             loop_id,
             section_id,
             pump_id,
+            HydraulicSectionId::Pump(pump_id),
             VolumeRate::new::<gallon_per_second>(
                 HydraulicCircuit::PUMP_SECTION_STATIC_LEAK_GAL_P_S,
             ),
@@ -16,6 +16,7 @@ pub mod engine;
 pub mod enhanced_gpwc;
 pub mod failures;
 pub mod fuel;
+pub mod ground_services;
 pub mod hydraulic;
 pub mod icing_state;
 pub mod indicating_recording;
@@ -1,4 +1,5 @@
 use crate::air_conditioning::AdirsToAirCondInterface;
+use crate::failures::{Failure, FailureType};
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
     overhead::{IndicationLight, OnOffFaultPushButton},
@@ -81,7 +82,12 @@ impl AirDataInertialReferenceSystemOverheadPanel {
         self.ir
             .iter_mut()
             .enumerate()
-            .for_each(|(index, ir)| ir.set_fault(adirs.ir_has_fault(index + 1)))
+            .for_each(|(index, ir)| ir.set_fault(adirs.ir_has_fault(index + 1)));
+
+        self.adr
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, adr)| adr.set_fault(adirs.adr_has_fault(index + 1)));
     }
 
     fn mode_of(&self, number: usize) -> InertialReferenceMode {
@@ -477,6 +483,10 @@ impl AirDataInertialReferenceSystem {
     fn ir_has_fault(&self, number: usize) -> bool {
         self.adirus[number - 1].ir_has_fault()
     }
+
+    fn adr_has_fault(&self, number: usize) -> bool {
+        self.adirus[number - 1].adr_has_fault()
+    }
 }
 impl SimulationElement for AirDataInertialReferenceSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -565,6 +575,10 @@ impl AdirsMeasurementOutputs for AirDataInertialReferenceSystem {
     fn angle_of_attack(&self, adiru_number: usize) -> Arinc429Word<Angle> {
         self.adirus[adiru_number - 1].angle_of_attack()
     }
+
+    fn total_air_temperature(&self, adiru_number: usize) -> Arinc429Word<ThermodynamicTemperature> {
+        self.adirus[adiru_number - 1].total_air_temperature()
+    }
 }
 
 struct AirDataInertialReferenceUnit {
@@ -650,14 +664,20 @@ impl AirDataInertialReferenceUnit {
         self.ir.has_fault()
     }
 
+    fn adr_has_fault(&self) -> bool {
+        self.adr.has_fault()
+    }
+
     // When the ADR is unpowered (i.e. knob is set to OFF), all discretes go to open circuit (false).
     // Discrete #4 is inverted, so ground when below 260kts, OC otherwise.
-    // When CAS is invalid but the ADR is otherwise powered (currently only possible when ADR p/b is off),
+    // When CAS is invalid but the ADR is otherwise powered (ADR p/b off or ADR fault),
     // 1-3 remain in their previous state, and 4 goes OC.
+    // A total loss of the ADR is treated like an unpowered ADR.
     fn update_discrete_outputs(&mut self) {
         let speed = self.adr.computed_airspeed_raw();
+        let adr_is_operating = self.adr.is_operating();
 
-        if self.adr.is_on {
+        if adr_is_operating {
             for (low_speed_warning, threshold) in self
                 .low_speed_warnings
                 .iter_mut()
@@ -672,9 +692,9 @@ impl AirDataInertialReferenceUnit {
             }
         }
 
-        if self.low_speed_warning_thresholds[3].speed_is_below(speed) && self.adr.is_on {
+        if self.low_speed_warning_thresholds[3].speed_is_below(speed) && adr_is_operating {
             self.low_speed_warnings[3] = true;
-        } else if self.low_speed_warning_thresholds[3].speed_is_above(speed) || !self.adr.is_on {
+        } else if self.low_speed_warning_thresholds[3].speed_is_above(speed) || !adr_is_operating {
             self.low_speed_warnings[3] = false;
         }
 
@@ -742,6 +762,10 @@ impl AirDataInertialReferenceUnit {
     fn angle_of_attack(&self) -> Arinc429Word<Angle> {
         self.adr.angle_of_attack()
     }
+
+    fn total_air_temperature(&self) -> Arinc429Word<ThermodynamicTemperature> {
+        self.adr.total_air_temperature()
+    }
 }
 impl SimulationElement for AirDataInertialReferenceUnit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -924,6 +948,9 @@ struct AirDataReference {
     discrete_word_1: AdirsData<u32>,
 
     remaining_initialisation_duration: Option<Duration>,
+
+    total_loss_failure: Failure,
+    fault_failure: Failure,
 }
 impl AirDataReference {
     const INITIALISATION_DURATION: Duration = Duration::from_secs(18);
@@ -1007,6 +1034,9 @@ impl AirDataReference {
 
             // Start fully initialised.
             remaining_initialisation_duration: Some(Duration::from_secs(0)),
+
+            total_loss_failure: Failure::new(FailureType::AirDataReference(number)),
+            fault_failure: Failure::new(FailureType::AirDataReferenceFault(number)),
         }
     }
 
@@ -1050,7 +1080,7 @@ impl AirDataReference {
         // For now some of the data will be read from the context. Later the context will no longer
         // contain this information (and instead all usages will be replaced by requests to the ADIRUs).
 
-        // If the ADR is off or not initialized, output all labels as FW with value 0.
+        // If the ADR is off, failed or not initialized, output all labels as FW with value 0.
         if !self.is_valid() {
             self.baro_correction_1_hpa.set_failure_warning();
             self.baro_correction_1_inhg.set_failure_warning();
@@ -1148,7 +1178,9 @@ impl AirDataReference {
     fn update_discrete_word_1(&mut self) {
         let mut discrete_word = AdrDiscrete1Flags::default();
 
-        if self.is_on {
+        // A faulty ADR keeps transmitting its discrete word, so that the fault can be annunciated.
+        // A total loss of the ADR stops all transmission, similar to when it is switched off.
+        if self.is_operating() {
             // FIXME implement icing detector heat
             // FIXME implement pitot heat
 
@@ -1175,7 +1207,7 @@ impl AirDataReference {
 
         self.discrete_word_1.set_value(
             discrete_word.bits(),
-            if self.is_on {
+            if self.is_operating() {
                 SignStatus::NormalOperation
             } else {
                 SignStatus::FailureWarning
@@ -1187,8 +1219,16 @@ impl AirDataReference {
         self.remaining_initialisation_duration == Some(Duration::from_secs(0))
     }
 
+    fn is_operating(&self) -> bool {
+        self.is_on && !self.total_loss_failure.is_active()
+    }
+
     fn is_valid(&self) -> bool {
-        self.is_on && self.is_initialised()
+        self.is_operating() && self.is_initialised() && !self.fault_failure.is_active()
+    }
+
+    fn has_fault(&self) -> bool {
+        self.total_loss_failure.is_active() || self.fault_failure.is_active()
     }
 
     fn computed_airspeed_raw(&self) -> Velocity {
@@ -1224,6 +1264,13 @@ impl AirDataReference {
     fn angle_of_attack(&self) -> Arinc429Word<Angle> {
         Arinc429Word::new(self.angle_of_attack.value(), self.angle_of_attack.ssm())
     }
+
+    fn total_air_temperature(&self) -> Arinc429Word<ThermodynamicTemperature> {
+        Arinc429Word::new(
+            self.total_air_temperature.value(),
+            self.total_air_temperature.ssm(),
+        )
+    }
 }
 impl TrueAirspeedSource for AirDataReference {
     fn true_airspeed(&self) -> Arinc429Word<Velocity> {
@@ -1231,6 +1278,13 @@ impl TrueAirspeedSource for AirDataReference {
     }
 }
 impl SimulationElement for AirDataReference {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.total_loss_failure.accept(visitor);
+        self.fault_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.baro_correction_1_hpa
             .write_to_converted(writer, |value| value.get::<hectopascal>());
@@ -1347,10 +1401,18 @@ struct InertialReference {
     longitude: AdirsData<Angle>,
     /// label 270
     maint_word: AdirsData<u32>,
+
+    total_loss_failure: Failure,
+    attitude_only_failure: Failure,
+    alignment_fault_failure: Failure,
+    /// Time since the fault light started flashing, when a fault is present
+    /// which requires crew action (e.g. selecting ATT mode).
+    fault_flashing_duration: Option<Duration>,
 }
 impl InertialReference {
     const FAST_ALIGNMENT_TIME_IN_SECS: f64 = 90.;
     const IR_FAULT_FLASH_DURATION: Duration = Duration::from_millis(50);
+    const FAULT_FLASHING_PERIOD: Duration = Duration::from_secs(1);
     const ATTITUDE_INITIALISATION_DURATION: Duration = Duration::from_secs(28);
     const ATTITUDE_INITIALISATION_QUICK_DURATION: Duration = Duration::from_secs(2);
     const PITCH: &'static str = "PITCH";
@@ -1423,6 +1485,13 @@ impl InertialReference {
             latitude: AdirsData::new_ir(context, number, Self::LATITUDE),
             longitude: AdirsData::new_ir(context, number, Self::LONGITUDE),
             maint_word: AdirsData::new_ir(context, number, Self::MAINT_WORD),
+
+            total_loss_failure: Failure::new(FailureType::InertialReference(number)),
+            attitude_only_failure: Failure::new(FailureType::InertialReferenceAttitudeOnly(number)),
+            alignment_fault_failure: Failure::new(FailureType::InertialReferenceAlignmentFault(
+                number,
+            )),
+            fault_flashing_duration: None,
         }
     }
 
@@ -1450,6 +1519,8 @@ impl InertialReference {
             simulator_data,
         );
 
+        self.update_fault_flashing_duration(context, overhead);
+
         self.update_latitude(simulator_data);
         self.update_attitude_values(context, simulator_data);
         self.update_heading_values(overhead, simulator_data);
@@ -1457,12 +1528,53 @@ impl InertialReference {
         self.update_maint_word(overhead);
     }
 
+    /// The mode the IR is operating in, which differs from the selected mode when failed.
+    /// An IR which is totally lost no longer operates, while an IR which can only provide
+    /// attitude loses its alignment in NAV mode and must be reinitialised in ATT mode.
+    fn operating_mode(
+        &self,
+        overhead: &AirDataInertialReferenceSystemOverheadPanel,
+    ) -> InertialReferenceMode {
+        let selected_mode = overhead.mode_of(self.number);
+        if self.total_loss_failure.is_active()
+            || (self.attitude_only_failure.is_active()
+                && selected_mode == InertialReferenceMode::Navigation)
+        {
+            InertialReferenceMode::Off
+        } else {
+            selected_mode
+        }
+    }
+
+    fn has_navigation_failure(&self) -> bool {
+        self.total_loss_failure.is_active() || self.attitude_only_failure.is_active()
+    }
+
+    fn update_fault_flashing_duration(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &AirDataInertialReferenceSystemOverheadPanel,
+    ) {
+        // The fault light flashes when attitude and heading can be recovered in ATT mode,
+        // or when the alignment cannot be completed.
+        let attitude_recoverable_in_att_mode = self.attitude_only_failure.is_active()
+            && !self.total_loss_failure.is_active()
+            && overhead.mode_of(self.number) == InertialReferenceMode::Navigation;
+        let alignment_faulty = self.alignment_fault_failure.is_active() && self.is_aligning();
+
+        self.fault_flashing_duration = if attitude_recoverable_in_att_mode || alignment_faulty {
+            Some(self.fault_flashing_duration.unwrap_or_default() + context.delta())
+        } else {
+            None
+        };
+    }
+
     fn update_fault_flash_duration(
         &mut self,
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
     ) {
-        if self.alignment_starting(overhead.mode_of(self.number)) {
+        if self.alignment_starting(self.operating_mode(overhead)) {
             self.ir_fault_flash_duration = Some(Self::IR_FAULT_FLASH_DURATION);
         } else if let Some(flash_duration) = self.ir_fault_flash_duration {
             let remaining = subtract_delta_from_duration(context, flash_duration);
@@ -1488,7 +1600,7 @@ impl InertialReference {
         self.remaining_attitude_initialisation_duration = remaining_initialisation_duration(
             context,
             initialisation_duration,
-            overhead.mode_of(self.number),
+            self.operating_mode(overhead),
             self.remaining_attitude_initialisation_duration,
         );
     }
@@ -1504,12 +1616,17 @@ impl InertialReference {
         // then set remaining time to 0. This allows to implement a "Instant Align" button in the EFB
         // for users who want to align the ADIRS instantly but do not want to change the default
         // setting and restart the flight.
-        if let AlignTime::Instant = configured_align_time {
+        let alignment_faulty = self.alignment_fault_failure.is_active();
+        if matches!(configured_align_time, AlignTime::Instant) && !alignment_faulty {
             self.remaining_align_duration = Some(Duration::from_secs_f64(0.));
         }
 
-        self.remaining_align_duration = match overhead.mode_of(self.number) {
+        self.remaining_align_duration = match self.operating_mode(overhead) {
             InertialReferenceMode::Navigation => match self.remaining_align_duration {
+                // An alignment fault prevents an alignment in progress from completing.
+                Some(remaining) if alignment_faulty && remaining > Duration::from_secs(0) => {
+                    Some(remaining)
+                }
                 Some(remaining) => Some(subtract_delta_from_duration(context, remaining)),
                 None => Some(Self::total_alignment_duration(
                     configured_align_time,
@@ -1538,7 +1655,9 @@ impl InertialReference {
         context: &UpdateContext,
         simulator_data: AdirsSimulatorData,
     ) {
-        let ssm = if self.is_on && self.is_attitude_aligned() {
+        let ssm = if self.total_loss_failure.is_active() {
+            SignStatus::FailureWarning
+        } else if self.is_on && self.is_attitude_aligned() {
             SignStatus::NormalOperation
         } else {
             SignStatus::NoComputedData
@@ -1592,12 +1711,14 @@ impl InertialReference {
 
         let heading_available = self.is_on
             && (self.is_fully_aligned()
-                || (overhead.mode_of(self.number) == InertialReferenceMode::Navigation
+                || (self.operating_mode(overhead) == InertialReferenceMode::Navigation
                     && self
                         .remaining_align_duration
                         .is_some_and(|duration| duration.as_secs() < 120)));
 
-        let true_heading_ssm = if heading_available {
+        let true_heading_ssm = if self.has_navigation_failure() {
+            SignStatus::FailureWarning
+        } else if heading_available {
             SignStatus::NormalOperation
         } else {
             SignStatus::NoComputedData
@@ -1606,9 +1727,11 @@ impl InertialReference {
             .set_value(simulator_data.true_heading, true_heading_ssm);
 
         // TODO in ATT mode NCD until heading initialised on MCDU
-        let magnetic_heading_ssm = if self.is_on
+        let magnetic_heading_ssm = if self.total_loss_failure.is_active() {
+            SignStatus::FailureWarning
+        } else if self.is_on
             && (heading_available
-                || (overhead.mode_of(self.number) == InertialReferenceMode::Attitude
+                || (self.operating_mode(overhead) == InertialReferenceMode::Attitude
                     && self.is_attitude_aligned()))
         {
             SignStatus::NormalOperation
@@ -1635,7 +1758,7 @@ impl InertialReference {
         // In ATT mode these labels are not even transmitted
         // In Align, NCD prior to NAV
 
-        let no_transmission = match overhead.mode_of(self.number) {
+        let no_transmission = match self.operating_mode(overhead) {
             InertialReferenceMode::Navigation => false,
             InertialReferenceMode::Off | InertialReferenceMode::Attitude => true,
         } || !self.is_on;
@@ -1731,7 +1854,9 @@ impl InertialReference {
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
         simulator_data: AdirsSimulatorData,
     ) {
-        let ssm = if self.is_on && self.is_fully_aligned() {
+        let ssm = if self.has_navigation_failure() {
+            SignStatus::FailureWarning
+        } else if self.is_on && self.is_fully_aligned() {
             SignStatus::NormalOperation
         } else {
             SignStatus::NoComputedData
@@ -1819,7 +1944,7 @@ impl InertialReference {
             maint_word |= IrMaintFlags::ALIGNMENT_NOT_READY;
         }
 
-        if overhead.mode_of(self.number) == InertialReferenceMode::Attitude {
+        if self.operating_mode(overhead) == InertialReferenceMode::Attitude {
             maint_word |= IrMaintFlags::REV_ATT_MODE;
         }
 
@@ -1829,7 +1954,13 @@ impl InertialReference {
 
         // TODO request heading setting in att mode if not set
 
-        // TODO attitude invalid fault
+        if self.attitude_only_failure.is_active() && !self.is_attitude_aligned() {
+            maint_word |= IrMaintFlags::ATTITUDE_INVALID;
+        }
+
+        if self.has_navigation_failure() {
+            maint_word |= IrMaintFlags::IR_FAULT;
+        }
 
         // TODO dc < 18 V
 
@@ -1841,7 +1972,9 @@ impl InertialReference {
 
         // TODO DC fault during DC operation last power up
 
-        // TODO align fault
+        if self.alignment_fault_failure.is_active() && self.is_aligning() {
+            maint_word |= IrMaintFlags::ALIGN_FAULT;
+        }
 
         // TODO No IRS initial pos
 
@@ -1912,7 +2045,14 @@ impl InertialReference {
     }
 
     fn has_fault(&self) -> bool {
+        let flashing_fault_illuminated = self.fault_flashing_duration.is_some_and(|duration| {
+            duration.as_millis() % Self::FAULT_FLASHING_PERIOD.as_millis()
+                < Self::FAULT_FLASHING_PERIOD.as_millis() / 2
+        });
+
         self.ir_fault_flash_duration.is_some()
+            || self.total_loss_failure.is_active()
+            || flashing_fault_illuminated
     }
 
     fn has_magnetic_data(&self) -> bool {
@@ -1947,6 +2087,14 @@ impl InertialReference {
     }
 }
 impl SimulationElement for InertialReference {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.total_loss_failure.accept(visitor);
+        self.attitude_only_failure.accept(visitor);
+        self.alignment_fault_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.pitch.write_to(writer);
         self.roll.write_to(writer);
@@ -2322,6 +2470,13 @@ mod tests {
             )))
         }
 
+        fn adr_fault_light_illuminated(&mut self, number: usize) -> bool {
+            self.read_by_name(&OnOffFaultPushButton::has_fault_id(&format!(
+                "ADIRS_ADR_{}",
+                number
+            )))
+        }
+
         fn is_aligned(&mut self, adiru_number: usize) -> bool {
             self.align_state(adiru_number) == AlignState::Aligned
         }
@@ -3293,6 +3448,58 @@ mod tests {
             test_bed.assert_adr_data_valid(false, adiru_number);
         }
 
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn when_adr_failed_all_labels_are_failure_warning(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::AirDataReference(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_adr_data_valid(false, adiru_number);
+            assert!(test_bed
+                .adr_discrete_word_1(adiru_number)
+                .is_failure_warning());
+            assert!(test_bed.adr_fault_light_illuminated(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn when_adr_faulty_data_is_failure_warning_and_status_fail_is_transmitted(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::AirDataReferenceFault(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_adr_data_valid(false, adiru_number);
+            let discrete_word = test_bed.adr_discrete_word_1(adiru_number);
+            assert!(discrete_word.is_normal_operation());
+            assert!(AdrDiscrete1Flags::from_bits(discrete_word.value())
+                .unwrap()
+                .contains(AdrDiscrete1Flags::ADR_STATUS_FAIL));
+            assert!(test_bed.adr_fault_light_illuminated(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn adr_data_is_valid_again_when_fault_clears(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::AirDataReferenceFault(adiru_number));
+            test_bed.run();
+
+            test_bed.unfail(FailureType::AirDataReferenceFault(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_adr_data_valid(true, adiru_number);
+            assert!(!test_bed.adr_fault_light_illuminated(adiru_number));
+        }
+
         #[rstest]
         #[case(1)]
         #[case(2)]
@@ -3858,6 +4065,180 @@ mod tests {
             test_bed.assert_all_ir_data_available(false, adiru_number);
         }
 
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn when_ir_failed_all_data_is_failure_warning(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::InertialReference(adiru_number));
+            test_bed.run();
+
+            assert!(test_bed.pitch(adiru_number).is_failure_warning());
+            assert!(test_bed.roll(adiru_number).is_failure_warning());
+            assert!(test_bed.heading(adiru_number).is_failure_warning());
+            assert!(test_bed.true_heading(adiru_number).is_failure_warning());
+            assert!(test_bed.ground_speed(adiru_number).is_failure_warning());
+            assert!(test_bed.latitude(adiru_number).is_failure_warning());
+            assert!(test_bed.wind_speed(adiru_number).is_failure_warning());
+            assert!(!test_bed.is_aligned(adiru_number));
+
+            let maint_word_flags =
+                IrMaintFlags::from_bits(test_bed.maint_word(adiru_number).value());
+            assert!(maint_word_flags.unwrap().contains(IrMaintFlags::IR_FAULT));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn when_ir_failed_fault_light_is_steady(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::InertialReference(adiru_number));
+
+            for _ in 0..4 {
+                test_bed.run_with_delta(InertialReference::FAULT_FLASHING_PERIOD / 4);
+                assert!(test_bed.ir_fault_light_illuminated(adiru_number));
+            }
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn with_attitude_only_failure_in_nav_mode_attitude_is_lost_and_navigation_data_is_failure_warning(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::InertialReferenceAttitudeOnly(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_ir_attitude_data_available(false, adiru_number);
+            test_bed.assert_ir_heading_data_available(false, adiru_number);
+            assert!(test_bed.true_heading(adiru_number).is_failure_warning());
+            assert!(test_bed.track(adiru_number).is_failure_warning());
+            assert!(test_bed.ground_speed(adiru_number).is_failure_warning());
+            assert!(test_bed.latitude(adiru_number).is_failure_warning());
+            assert!(test_bed.longitude(adiru_number).is_failure_warning());
+
+            let maint_word_flags =
+                IrMaintFlags::from_bits(test_bed.maint_word(adiru_number).value()).unwrap();
+            assert!(maint_word_flags.contains(IrMaintFlags::IR_FAULT));
+            assert!(maint_word_flags.contains(IrMaintFlags::ATTITUDE_INVALID));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn with_attitude_only_failure_in_nav_mode_fault_light_flashes(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::InertialReferenceAttitudeOnly(adiru_number));
+
+            test_bed.run_without_delta();
+            assert!(test_bed.ir_fault_light_illuminated(adiru_number));
+
+            test_bed.run_with_delta(InertialReference::FAULT_FLASHING_PERIOD / 2);
+            assert!(!test_bed.ir_fault_light_illuminated(adiru_number));
+
+            test_bed.run_with_delta(InertialReference::FAULT_FLASHING_PERIOD / 2);
+            assert!(test_bed.ir_fault_light_illuminated(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn with_attitude_only_failure_attitude_and_heading_are_recovered_after_att_mode_realignment(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::InertialReferenceAttitudeOnly(adiru_number));
+            test_bed.run();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
+            test_bed.run_without_delta();
+
+            test_bed.run_with_delta(
+                InertialReference::ATTITUDE_INITIALISATION_DURATION - Duration::from_millis(1),
+            );
+            test_bed.assert_ir_attitude_data_available(false, adiru_number);
+            test_bed.assert_ir_heading_data_available(false, adiru_number);
+
+            test_bed.run_with_delta(Duration::from_millis(1));
+            test_bed.assert_ir_attitude_data_available(true, adiru_number);
+            test_bed.assert_ir_heading_data_available(true, adiru_number);
+            assert!(test_bed.ground_speed(adiru_number).is_failure_warning());
+            assert!(!test_bed.ir_fault_light_illuminated(adiru_number));
+
+            let maint_word_flags =
+                IrMaintFlags::from_bits(test_bed.maint_word(adiru_number).value()).unwrap();
+            assert!(maint_word_flags.contains(IrMaintFlags::REV_ATT_MODE));
+            assert!(!maint_word_flags.contains(IrMaintFlags::ATTITUDE_INVALID));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn alignment_fault_prevents_alignment_from_completing(#[case] adiru_number: usize) {
+            let mut test_bed = test_bed_with()
+                .align_time_configured_as(AlignTime::Fast)
+                .and()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation);
+            test_bed.fail(FailureType::InertialReferenceAlignmentFault(adiru_number));
+            test_bed.run_without_delta();
+
+            test_bed.run_with_delta(Duration::from_secs_f64(
+                InertialReference::FAST_ALIGNMENT_TIME_IN_SECS * 2.,
+            ));
+            assert!(test_bed.is_aligning(adiru_number));
+            test_bed.assert_ir_non_attitude_data_available(false, adiru_number);
+
+            let maint_word_flags =
+                IrMaintFlags::from_bits(test_bed.maint_word(adiru_number).value()).unwrap();
+            assert!(maint_word_flags.contains(IrMaintFlags::ALIGN_FAULT));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn alignment_completes_once_alignment_fault_clears(#[case] adiru_number: usize) {
+            let mut test_bed = test_bed_with()
+                .align_time_configured_as(AlignTime::Fast)
+                .and()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation);
+            test_bed.fail(FailureType::InertialReferenceAlignmentFault(adiru_number));
+            test_bed.run_without_delta();
+            test_bed.run_with_delta(Duration::from_secs_f64(
+                InertialReference::FAST_ALIGNMENT_TIME_IN_SECS * 2.,
+            ));
+
+            test_bed.unfail(FailureType::InertialReferenceAlignmentFault(adiru_number));
+            test_bed.run_with_delta(Duration::from_secs_f64(
+                InertialReference::FAST_ALIGNMENT_TIME_IN_SECS,
+            ));
+
+            assert!(test_bed.is_aligned(adiru_number));
+            assert!(!test_bed.ir_fault_light_illuminated(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn alignment_fault_does_not_affect_an_aligned_ir(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::InertialReferenceAlignmentFault(adiru_number));
+            test_bed.run();
+
+            assert!(test_bed.is_aligned(adiru_number));
+            assert!(!test_bed.ir_fault_light_illuminated(adiru_number));
+        }
+
         #[rstest]
         #[case(1)]
         #[case(2)]
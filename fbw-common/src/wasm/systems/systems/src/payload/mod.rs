@@ -2,6 +2,7 @@ use std::{cell::Cell, rc::Rc, time::Duration};
 use uom::si::{f64::Ratio, ratio::percent};
 
 use crate::{
+    ground_services::{GroundService, GroundServiceState, GroundServices},
     shared::random_from_range,
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -17,6 +18,30 @@ pub struct LoadsheetInfo {
     pub per_pax_weight_kg: f64,
     pub mean_aerodynamic_chord_size: f64,
     pub leading_edge_mean_aerodynamic_chord: f64,
+    pub index_constant: f64,
+    pub index_offset: f64,
+}
+impl LoadsheetInfo {
+    /// The balance chart index of a mass at the given fore/aft position. Moments are taken about
+    /// the reference station at 25% MAC, so that a positive index moves the CG aft.
+    pub fn index(&self, mass: Mass, position: f64) -> f64 {
+        let reference_position =
+            self.leading_edge_mean_aerodynamic_chord - 0.25 * self.mean_aerodynamic_chord_size;
+        mass.get::<kilogram>() * (reference_position - position) / self.index_constant
+            + self.index_offset
+    }
+
+    /// The CG in % MAC of a fore/aft position.
+    pub fn percent_mac(&self, position: f64) -> f64 {
+        -100. * (position - self.leading_edge_mean_aerodynamic_chord)
+            / self.mean_aerodynamic_chord_size
+    }
+
+    /// The fore/aft position of a CG in % MAC.
+    pub fn position(&self, percent_mac: f64) -> f64 {
+        self.leading_edge_mean_aerodynamic_chord
+            - percent_mac / 100. * self.mean_aerodynamic_chord_size
+    }
 }
 
 pub struct PaxInfo<'a> {
@@ -44,6 +69,8 @@ pub trait PassengerPayload {
     fn fore_aft_center_of_gravity(&self) -> f64;
     fn target_center_of_gravity(&self) -> Vector3<f64>;
     fn target_fore_aft_center_of_gravity(&self) -> f64;
+    fn station_passengers(&self, ps: usize) -> i8;
+    fn station_passenger_load(&self, ps: usize) -> Mass;
 }
 
 pub trait CargoPayload {
@@ -54,6 +81,7 @@ pub trait CargoPayload {
     fn fore_aft_center_of_gravity(&self) -> f64;
     fn target_center_of_gravity(&self) -> Vector3<f64>;
     fn target_fore_aft_center_of_gravity(&self) -> f64;
+    fn station_cargo_load(&self, cs: usize) -> Mass;
 }
 
 #[derive(Debug)]
@@ -71,31 +99,82 @@ impl<const P: usize> BoardingAgent<P> {
         }
     }
 
-    pub fn handle_one_pax(&self, pax: &mut [Pax; P]) {
-        for ps in self.order {
-            if self.is_door_open() {
-                if pax[ps].pax_is_target() {
-                    continue;
-                }
-                pax[ps].move_one_pax();
-                break;
-            }
+    pub fn handle_one_pax(
+        &self,
+        pax: &mut [Pax; P],
+        strategy: BoardingStrategy,
+    ) -> Option<(usize, usize)> {
+        if self.is_door_open() {
+            self.force_one_pax(pax, strategy)
+        } else {
+            None
         }
     }
 
-    pub fn force_one_pax(&self, pax: &mut [Pax; P]) {
+    /// Moves one passenger and returns the station and the seat taken, if the passenger boarded.
+    pub fn force_one_pax(
+        &self,
+        pax: &mut [Pax; P],
+        strategy: BoardingStrategy,
+    ) -> Option<(usize, usize)> {
+        if let Some((ps, seat)) = self.next_seat(pax, strategy) {
+            pax[ps].board_seat(seat);
+            return Some((ps, seat));
+        }
+
         for ps in self.order {
             if pax[ps].pax_is_target() {
                 continue;
             }
-            pax[ps].move_one_pax();
-            break;
+            return pax[ps].move_one_pax().map(|seat| (ps, seat));
         }
+        None
     }
 
-    pub fn force_num_pax(&self, num_to_move: i32, pax: &mut [Pax; P]) {
+    pub fn force_num_pax(&self, num_to_move: i32, pax: &mut [Pax; P], strategy: BoardingStrategy) {
         for _ in 0..num_to_move {
-            self.force_one_pax(pax);
+            self.force_one_pax(pax, strategy);
+        }
+    }
+
+    /// The seat the next boarding passenger takes, among the stations served by this agent which
+    /// are below their target number of passengers. When boarding by zone, the stations are
+    /// filled in order and the seat is left to the station.
+    fn next_seat(&self, pax: &[Pax; P], strategy: BoardingStrategy) -> Option<(usize, usize)> {
+        let seats = self
+            .order
+            .iter()
+            .filter(|&&ps| pax[ps].pax_target_num() > pax[ps].pax_num())
+            .flat_map(|&ps| pax[ps].seats_to_board().map(move |seat| (ps, seat)));
+
+        match strategy {
+            BoardingStrategy::ByZone => None,
+            BoardingStrategy::BackToFront => seats.min_by(|&(ps_a, seat_a), &(ps_b, seat_b)| {
+                pax[ps_a]
+                    .seat(seat_a)
+                    .position
+                    .total_cmp(&pax[ps_b].seat(seat_b).position)
+            }),
+            BoardingStrategy::WindowMiddleAisle => {
+                seats.min_by(|&(ps_a, seat_a), &(ps_b, seat_b)| {
+                    let seat_a = pax[ps_a].seat(seat_a);
+                    let seat_b = pax[ps_b].seat(seat_b);
+                    seat_a
+                        .seat_type
+                        .cmp(&seat_b.seat_type)
+                        .then(seat_a.position.total_cmp(&seat_b.position))
+                })
+            }
+            BoardingStrategy::Random => {
+                let seats: Vec<(usize, usize)> = seats.collect();
+                if seats.is_empty() {
+                    None
+                } else {
+                    let index =
+                        (random_from_range(0., seats.len() as f64) as usize).min(seats.len() - 1);
+                    Some(seats[index])
+                }
+            }
         }
     }
 
@@ -117,8 +196,14 @@ pub struct PassengerDeck<const N: usize, const G: usize> {
     pax: [Pax; N],
     default_boarding_agent: BoardingAgent<N>,
     boarding_agents: [BoardingAgent<N>; G],
+    boarding_strategy: BoardingStrategy,
+    default_boarding_agent_row: Option<u8>,
+    boarding_agent_rows: [Option<u8>; G],
 }
 impl<const N: usize, const G: usize> PassengerDeck<N, G> {
+    const SEAT_INTERFERENCE_TIME_FACTOR: f64 = 0.5;
+    const AISLE_BLOCKING_TIME_FACTOR: f64 = 0.5;
+
     pub fn new(
         pax: [Pax; N],
         default_boarding_agent: BoardingAgent<N>,
@@ -128,9 +213,16 @@ impl<const N: usize, const G: usize> PassengerDeck<N, G> {
             pax,
             default_boarding_agent,
             boarding_agents,
+            boarding_strategy: BoardingStrategy::ByZone,
+            default_boarding_agent_row: None,
+            boarding_agent_rows: [None; G],
         }
     }
 
+    fn set_boarding_strategy(&mut self, boarding_strategy: BoardingStrategy) {
+        self.boarding_strategy = boarding_strategy;
+    }
+
     fn pax_num(&self, ps: usize) -> i8 {
         self.pax[ps].pax_num()
     }
@@ -139,6 +231,10 @@ impl<const N: usize, const G: usize> PassengerDeck<N, G> {
         self.pax.iter().map(|ps| ps.pax_num() as i32).sum()
     }
 
+    fn total_target_pax_num(&self) -> i32 {
+        self.pax.iter().map(|ps| ps.pax_target_num() as i32).sum()
+    }
+
     fn pax_payload(&self, ps: usize) -> Mass {
         self.pax[ps].payload()
     }
@@ -193,17 +289,55 @@ impl<const N: usize, const G: usize> PassengerDeck<N, G> {
         }
     }
 
-    fn update_one_tick(&mut self) {
+    /// Moves one passenger through each open door, or through the default boarding agent when
+    /// all the doors are closed, and returns how long the slowest of them took relative to the
+    /// real boarding rate.
+    fn update_one_tick(&mut self) -> f64 {
         let doors_open = self.boarding_agents.iter().any(|ba| ba.is_door_open());
         if doors_open {
-            for boarding_agent in &mut self.boarding_agents {
-                boarding_agent.handle_one_pax(&mut self.pax);
+            let mut time_factor: f64 = 1.;
+            for (boarding_agent, previous_row) in self
+                .boarding_agents
+                .iter()
+                .zip(self.boarding_agent_rows.iter_mut())
+            {
+                let boarded = boarding_agent.handle_one_pax(&mut self.pax, self.boarding_strategy);
+                time_factor =
+                    time_factor.max(Self::boarding_time_factor(&self.pax, boarded, previous_row));
             }
+            time_factor
         } else {
-            self.default_boarding_agent.force_one_pax(&mut self.pax);
+            let boarded = self
+                .default_boarding_agent
+                .force_one_pax(&mut self.pax, self.boarding_strategy);
+            Self::boarding_time_factor(&self.pax, boarded, &mut self.default_boarding_agent_row)
         }
     }
 
+    /// How long a passenger taking the given seat keeps the aisle busy, relative to the real
+    /// boarding rate. Each passenger already sitting between the seat and the aisle has to get
+    /// up, and a passenger stowing their bags in the same row as the previous passenger of the
+    /// same door has to wait for them. The row of the seat becomes the previous row.
+    fn boarding_time_factor(
+        pax: &[Pax; N],
+        boarded: Option<(usize, usize)>,
+        previous_row: &mut Option<u8>,
+    ) -> f64 {
+        let Some((ps, seat)) = boarded else {
+            return 1.;
+        };
+        let row = pax[ps].seat_row(seat);
+        let aisle_blocked = row.is_some() && row == *previous_row;
+        *previous_row = row;
+
+        1. + Self::SEAT_INTERFERENCE_TIME_FACTOR * pax[ps].seat_interference(seat) as f64
+            + if aisle_blocked {
+                Self::AISLE_BLOCKING_TIME_FACTOR
+            } else {
+                0.
+            }
+    }
+
     fn spawn_all_pax(&mut self) {
         for ps in &mut self.pax {
             if ps.pax_is_target() {
@@ -224,11 +358,14 @@ impl<const N: usize, const G: usize> PassengerDeck<N, G> {
 
             if available_agents.peek().is_some() {
                 for boarding_agent in available_agents.cycle().take(pax_diff as usize) {
-                    boarding_agent.handle_one_pax(&mut self.pax);
+                    boarding_agent.handle_one_pax(&mut self.pax, self.boarding_strategy);
                 }
             } else {
-                self.default_boarding_agent
-                    .force_num_pax(pax_diff, &mut self.pax);
+                self.default_boarding_agent.force_num_pax(
+                    pax_diff,
+                    &mut self.pax,
+                    self.boarding_strategy,
+                );
             }
         }
     }
@@ -236,8 +373,11 @@ impl<const N: usize, const G: usize> PassengerDeck<N, G> {
     fn deboard_pax_until_target(&mut self, pax_target: i32) {
         let pax_diff = self.total_pax_num() - pax_target;
         if pax_diff > 0 {
-            self.default_boarding_agent
-                .force_num_pax(pax_diff, &mut self.pax);
+            self.default_boarding_agent.force_num_pax(
+                pax_diff,
+                &mut self.pax,
+                self.boarding_strategy,
+            );
         }
     }
 }
@@ -361,6 +501,7 @@ pub struct Pax {
 
     position: Vector3<f64>,
     max: i8,
+    seats: Vec<SeatInfo>,
 }
 impl Pax {
     const JS_MAX_SAFE_INTEGER: i8 = 53;
@@ -385,9 +526,68 @@ impl Pax {
             payload: Mass::default(),
             position,
             max,
+            seats: Vec::new(),
         }
     }
 
+    /// Places the passengers of the station at the given seats, one seat per passenger bit. The
+    /// seats must be centred on the station position, so that a full station keeps its CG.
+    pub fn with_seats(mut self, seats: &[SeatInfo]) -> Self {
+        debug_assert!(seats.len() == self.max as usize);
+        debug_assert!(
+            (seats.iter().map(|seat| seat.position).sum::<f64>() / seats.len() as f64
+                - self.position.x)
+                .abs()
+                < 1e-6
+        );
+        self.seats = seats.to_vec();
+        self
+    }
+
+    /// Without a seat map, all the seats of the station are at the station position.
+    pub fn seat(&self, seat: usize) -> SeatInfo {
+        self.seats.get(seat).copied().unwrap_or(SeatInfo {
+            row: 0,
+            block: 0,
+            seat_type: SeatType::Aisle,
+            position: self.position.x,
+        })
+    }
+
+    fn seat_row(&self, seat: usize) -> Option<u8> {
+        self.seats.get(seat).map(|seat| seat.row)
+    }
+
+    /// The number of seated passengers between the seat and the aisle.
+    fn seat_interference(&self, seat: usize) -> u32 {
+        let Some(seat) = self.seats.get(seat) else {
+            return 0;
+        };
+        self.seats
+            .iter()
+            .enumerate()
+            .filter(|&(other, other_seat)| {
+                self.pax & (1 << other) > 0
+                    && other_seat.row == seat.row
+                    && other_seat.block == seat.block
+                    && other_seat.seat_type > seat.seat_type
+            })
+            .count() as u32
+    }
+
+    fn seats_to_board(&self) -> impl Iterator<Item = usize> {
+        let seats = self.pax_target & !self.pax;
+        (0..Self::JS_MAX_SAFE_INTEGER as usize).filter(move |seat| seats & (1 << seat) > 0)
+    }
+
+    fn seats_moment(&self, seats: u64) -> Vector3<f64> {
+        (0..Self::JS_MAX_SAFE_INTEGER as usize)
+            .filter(|seat| seats & (1 << seat) > 0)
+            .map(|seat| Vector3::new(self.seat(seat).position, self.position.y, self.position.z))
+            .sum::<Vector3<f64>>()
+            * self.per_pax_weight().get::<kilogram>()
+    }
+
     fn is_developer_state_active(&self) -> bool {
         self.developer_state.get() > 0
     }
@@ -425,11 +625,19 @@ impl Pax {
     }
 
     pub fn pax_moment(&self) -> Vector3<f64> {
-        self.pax_num() as f64 * self.per_pax_weight().get::<kilogram>() * self.position
+        if self.seats.is_empty() {
+            self.pax_num() as f64 * self.per_pax_weight().get::<kilogram>() * self.position
+        } else {
+            self.seats_moment(self.pax)
+        }
     }
 
     pub fn pax_target_moment(&self) -> Vector3<f64> {
-        self.pax_target_num() as f64 * self.per_pax_weight().get::<kilogram>() * self.position
+        if self.seats.is_empty() {
+            self.pax_target_num() as f64 * self.per_pax_weight().get::<kilogram>() * self.position
+        } else {
+            self.seats_moment(self.pax_target)
+        }
     }
 
     pub fn payload_is_sync(&self) -> bool {
@@ -457,7 +665,15 @@ impl Pax {
         }
     }
 
-    pub fn move_one_pax(&mut self) {
+    pub fn board_seat(&mut self, seat: usize) {
+        self.pax |= 1 << seat;
+        self.load_payload();
+    }
+
+    /// Boards or deboards one passenger towards the target, and returns the seat taken if the
+    /// passenger boarded.
+    pub fn move_one_pax(&mut self) -> Option<usize> {
+        let mut boarded_seat = None;
         let pax_diff = self.pax_target_num() - self.pax_num();
 
         let n = if pax_diff > 0 {
@@ -474,6 +690,9 @@ impl Pax {
                 if (n & bit) > 0 {
                     if skip <= 0 {
                         self.pax ^= bit;
+                        if pax_diff > 0 {
+                            boarded_seat = Some(i as usize);
+                        }
                         break;
                     }
                     skip -= 1;
@@ -481,6 +700,7 @@ impl Pax {
             }
         }
         self.load_payload();
+        boarded_seat
     }
 
     pub fn reset_pax_target(&mut self) {
@@ -641,6 +861,89 @@ impl SimulationElement for Cargo {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SeatType {
+    Window,
+    Middle,
+    Aisle,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SeatInfo {
+    pub row: u8,
+    pub block: u8, // the seats between two aisles, or an aisle and the cabin wall
+    pub seat_type: SeatType,
+    pub position: f64, // fore/aft, same axis as the station positions
+}
+
+/// Reads a seat map with one row per line: the row number, the fore/aft position of the row and
+/// its layout, e.g. `12 5.4 WMA-AMW`. The layout lists the seats in passenger bit order, with `W`
+/// for window, `M` for middle and `A` for aisle seats, while `-` marks an aisle between two blocks
+/// of seats. Empty lines and lines starting with `#` are ignored.
+pub fn parse_seat_map(seat_map: &str) -> Result<Vec<SeatInfo>, String> {
+    let mut seats = Vec::new();
+
+    for line in seat_map
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [row, position, layout] = fields[..] else {
+            return Err(format!("Expected row, position and layout in \"{}\"", line));
+        };
+
+        let row: u8 = row
+            .parse()
+            .map_err(|_| format!("Invalid row number in \"{}\"", line))?;
+        let position: f64 = position
+            .parse()
+            .map_err(|_| format!("Invalid position in \"{}\"", line))?;
+
+        for (block, block_seats) in layout.split('-').enumerate() {
+            for seat in block_seats.chars() {
+                let seat_type = match seat {
+                    'W' => SeatType::Window,
+                    'M' => SeatType::Middle,
+                    'A' => SeatType::Aisle,
+                    _ => return Err(format!("Invalid seat \"{}\" in \"{}\"", seat, line)),
+                };
+                seats.push(SeatInfo {
+                    row,
+                    block: block as u8,
+                    seat_type,
+                    position,
+                });
+            }
+        }
+    }
+
+    Ok(seats)
+}
+
+/// The order in which the passengers take their seats. Boarding by zone fills the stations in the
+/// order of the boarding agent, the other strategies pick the next seat over all the stations the
+/// agent serves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoardingStrategy {
+    ByZone,
+    BackToFront,
+    WindowMiddleAisle,
+    Random,
+}
+read_write_enum!(BoardingStrategy);
+impl From<f64> for BoardingStrategy {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => BoardingStrategy::ByZone,
+            1 => BoardingStrategy::BackToFront,
+            2 => BoardingStrategy::WindowMiddleAisle,
+            3 => BoardingStrategy::Random,
+            _ => BoardingStrategy::ByZone,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BoardingRate {
     Instant,
@@ -733,13 +1036,16 @@ impl SimulationElement for BoardingSounds {
 
 pub struct PayloadManager<const P: usize, const G: usize, const C: usize> {
     time: Duration,
+    cargo_time: Duration,
     fast_rate: u16,
     real_rate: u16,
+    pax_time_factor: f64,
     boarding_inputs: BoardingInputs,
     boarding_sounds: BoardingSounds,
     passenger_deck: PassengerDeck<P, G>,
     cargo_deck: CargoDeck<C>,
-    gsx_driver: GsxDriver,
+    ground_services_driver: GroundServicesDriver,
+    target_load_within_limits: bool,
 }
 impl<const P: usize, const G: usize, const C: usize> PayloadManager<P, G, C> {
     pub fn new(
@@ -754,13 +1060,16 @@ impl<const P: usize, const G: usize, const C: usize> PayloadManager<P, G, C> {
     ) -> Self {
         PayloadManager {
             time: Duration::default(),
+            cargo_time: Duration::default(),
             boarding_inputs: BoardingInputs::new(context, per_pax_weight, developer_state),
             boarding_sounds,
-            gsx_driver: GsxDriver::new(context),
+            ground_services_driver: GroundServicesDriver::new(),
             passenger_deck,
             cargo_deck,
             fast_rate,
             real_rate,
+            pax_time_factor: 1.,
+            target_load_within_limits: true,
         }
     }
 
@@ -768,6 +1077,10 @@ impl<const P: usize, const G: usize, const C: usize> PayloadManager<P, G, C> {
         self.time
     }
 
+    fn cargo_time(&self) -> Duration {
+        self.cargo_time
+    }
+
     pub fn is_boarding_allowed(&self) -> bool {
         self.boarding_inputs.is_boarding()
     }
@@ -776,6 +1089,15 @@ impl<const P: usize, const G: usize, const C: usize> PayloadManager<P, G, C> {
         self.boarding_inputs.board_rate()
     }
 
+    pub fn boarding_strategy(&self) -> BoardingStrategy {
+        self.boarding_inputs.boarding_strategy()
+    }
+
+    /// Boarding is refused while the target load is outside the weight and balance envelopes.
+    pub fn set_target_load_within_limits(&mut self, target_load_within_limits: bool) {
+        self.target_load_within_limits = target_load_within_limits;
+    }
+
     // ======================================
 
     pub fn pax_num(&self, ps: usize) -> i8 {
@@ -888,10 +1210,12 @@ impl<const P: usize, const G: usize, const C: usize> PayloadManager<P, G, C> {
 
     fn update_time(&mut self, delta_time: Duration) {
         self.time += delta_time;
+        self.cargo_time += delta_time;
     }
 
     fn reset_time(&mut self) {
-        self.time = Duration::default()
+        self.time = Duration::default();
+        self.cargo_time = Duration::default();
     }
 
     fn emit_stop_boarding(&mut self) {
@@ -905,7 +1229,7 @@ impl<const P: usize, const G: usize, const C: usize> PayloadManager<P, G, C> {
     }
 
     fn update_one_tick(&mut self) {
-        self.passenger_deck.update_one_tick();
+        self.pax_time_factor = self.passenger_deck.update_one_tick();
     }
 
     pub fn override_pax_payload(&mut self, ps: usize, payload: Mass) {
@@ -955,27 +1279,41 @@ impl<const P: usize, const G: usize, const C: usize> PayloadManager<P, G, C> {
     }
 
     // ======================================
-    pub fn update(&mut self, delta_time: Duration) {
+    pub fn update(&mut self, delta_time: Duration, ground_services: &impl GroundServices) {
         self.update_pax_ambience();
+        self.passenger_deck
+            .set_boarding_strategy(self.boarding_strategy());
 
-        if !self.gsx_driver.is_enabled() {
+        if !ground_services.is_enabled() {
             if !self.is_boarding_allowed() {
                 self.reset_time();
                 self.stop_boarding_sounds();
                 return;
             }
-            let ms_delay = if self.board_rate() == BoardingRate::Instant {
-                0
-            } else if self.board_rate() == BoardingRate::Fast {
-                self.fast_rate.into()
-            } else {
-                self.real_rate.into()
+            if !self.target_load_within_limits {
+                self.emit_stop_boarding();
+                self.reset_time();
+                self.stop_boarding_sounds();
+                return;
+            }
+            // In real time, each passenger takes as long as their seat requires, while the cargo
+            // is loaded at a steady rate.
+            let (pax_ms_delay, cargo_ms_delay): (u128, u128) = match self.board_rate() {
+                BoardingRate::Instant => (0, 0),
+                BoardingRate::Fast => (self.fast_rate.into(), self.fast_rate.into()),
+                BoardingRate::Real => (
+                    (self.real_rate as f64 * self.pax_time_factor) as u128,
+                    self.real_rate.into(),
+                ),
             };
             self.update_time(delta_time);
 
-            if self.time().as_millis() > ms_delay {
-                self.reset_time();
+            if self.time().as_millis() > pax_ms_delay {
+                self.time = Duration::default();
                 self.update_pax_tick();
+            }
+            if self.cargo_time().as_millis() > cargo_ms_delay {
+                self.cargo_time = Duration::default();
                 self.update_cargo_tick();
             }
             self.update_boarding_sounds();
@@ -985,7 +1323,8 @@ impl<const P: usize, const G: usize, const C: usize> PayloadManager<P, G, C> {
         } else {
             self.emit_stop_boarding();
             self.stop_boarding_sounds();
-            self.gsx_driver.update(
+            self.ground_services_driver.update(
+                ground_services,
                 &mut self.passenger_deck,
                 &mut self.cargo_deck,
                 &mut self.boarding_sounds,
@@ -999,7 +1338,6 @@ impl<const P: usize, const G: usize, const C: usize> SimulationElement for Paylo
         self.passenger_deck.accept(visitor);
         self.cargo_deck.accept(visitor);
         self.boarding_sounds.accept(visitor);
-        self.gsx_driver.accept(visitor);
 
         visitor.visit(self);
     }
@@ -1009,11 +1347,13 @@ pub struct BoardingInputs {
     developer_state_id: VariableIdentifier,
     is_boarding_id: VariableIdentifier,
     board_rate_id: VariableIdentifier,
+    boarding_strategy_id: VariableIdentifier,
     per_pax_weight_id: VariableIdentifier,
 
     developer_state: Rc<Cell<i8>>,
     is_boarding: bool,
     board_rate: BoardingRate,
+    boarding_strategy: BoardingStrategy,
     per_pax_weight: Rc<Cell<Mass>>,
 }
 impl BoardingInputs {
@@ -1026,11 +1366,13 @@ impl BoardingInputs {
             developer_state_id: context.get_identifier("DEVELOPER_STATE".to_owned()),
             is_boarding_id: context.get_identifier("BOARDING_STARTED_BY_USR".to_owned()),
             board_rate_id: context.get_identifier("BOARDING_RATE".to_owned()),
+            boarding_strategy_id: context.get_identifier("BOARDING_STRATEGY".to_owned()),
             per_pax_weight_id: context.get_identifier("WB_PER_PAX_WEIGHT".to_owned()),
 
             developer_state,
             is_boarding: false,
             board_rate: BoardingRate::Instant,
+            boarding_strategy: BoardingStrategy::ByZone,
             per_pax_weight,
         }
     }
@@ -1047,6 +1389,10 @@ impl BoardingInputs {
         self.board_rate
     }
 
+    pub fn boarding_strategy(&self) -> BoardingStrategy {
+        self.boarding_strategy
+    }
+
     pub fn stop_boarding(&mut self) {
         self.is_boarding = false;
     }
@@ -1061,6 +1407,7 @@ impl SimulationElement for BoardingInputs {
             .set(reader.read(&self.developer_state_id));
         self.is_boarding = reader.read(&self.is_boarding_id);
         self.board_rate = reader.read(&self.board_rate_id);
+        self.boarding_strategy = reader.read(&self.boarding_strategy_id);
         self.per_pax_weight
             .set(Mass::new::<kilogram>(reader.read(&self.per_pax_weight_id)));
     }
@@ -1075,228 +1422,135 @@ impl SimulationElement for BoardingInputs {
 }
 
 // ========================================
-// GSX Integration
+// Ground Services Integration
 // ========================================
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum GsxState {
-    None,
-    Available,
-    NotAvailable,
-    Bypassed,
-    Requested,
-    Performing,
-    Completed,
-}
-impl From<f64> for GsxState {
-    fn from(value: f64) -> Self {
-        match value as u8 {
-            0 => GsxState::None,
-            1 => GsxState::Available,
-            2 => GsxState::NotAvailable,
-            3 => GsxState::Bypassed,
-            4 => GsxState::Requested,
-            5 => GsxState::Performing,
-            6 => GsxState::Completed,
-            i => panic!("Cannot convert from {} to GsxState.", i),
-        }
-    }
-}
-
-read_write_enum!(GsxState);
-
-pub struct GsxInput {
-    is_enabled_id: VariableIdentifier,
-    boarding_state_id: VariableIdentifier,
-    deboarding_state_id: VariableIdentifier,
-    pax_boarding_id: VariableIdentifier,
-    pax_deboarding_id: VariableIdentifier,
-    cargo_boarding_percent_id: VariableIdentifier,
-    cargo_deboarding_percent_id: VariableIdentifier,
-
-    is_enabled: bool,
-    boarding_state: GsxState,
-    deboarding_state: GsxState,
-    pax_boarding: i32,
-    pax_deboarding: i32,
-    cargo_boarding_percent: f64,
-    cargo_deboarding_percent: f64,
-}
-impl GsxInput {
-    pub fn new(context: &mut InitContext) -> Self {
-        GsxInput {
-            is_enabled_id: context.get_identifier("GSX_PAYLOAD_SYNC_ENABLED".to_owned()),
-            boarding_state_id: context.get_identifier("FSDT_GSX_BOARDING_STATE".to_owned()),
-            deboarding_state_id: context.get_identifier("FSDT_GSX_DEBOARDING_STATE".to_owned()),
-            pax_boarding_id: context
-                .get_identifier("FSDT_GSX_NUMPASSENGERS_BOARDING_TOTAL".to_owned()),
-            pax_deboarding_id: context
-                .get_identifier("FSDT_GSX_NUMPASSENGERS_DEBOARDING_TOTAL".to_owned()),
-            cargo_boarding_percent_id: context
-                .get_identifier("FSDT_GSX_BOARDING_CARGO_PERCENT".to_owned()),
-            cargo_deboarding_percent_id: context
-                .get_identifier("FSDT_GSX_DEBOARDING_CARGO_PERCENT".to_owned()),
-            is_enabled: false,
-            boarding_state: GsxState::None,
-            deboarding_state: GsxState::None,
-            pax_boarding: 0,
-            pax_deboarding: 0,
-            cargo_boarding_percent: 0.0,
-            cargo_deboarding_percent: 0.0,
-        }
-    }
-
-    fn is_enabled(&self) -> bool {
-        self.is_enabled
-    }
-
-    fn boarding_state(&self) -> GsxState {
-        self.boarding_state
-    }
-
-    fn deboarding_state(&self) -> GsxState {
-        self.deboarding_state
-    }
-
-    fn pax_boarding(&self) -> i32 {
-        self.pax_boarding
-    }
-
-    fn pax_deboarding(&self) -> i32 {
-        self.pax_deboarding
-    }
-}
-impl SimulationElement for GsxInput {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.is_enabled = reader.read(&self.is_enabled_id);
-        self.pax_boarding = reader.read(&self.pax_boarding_id);
-        self.pax_deboarding = reader.read(&self.pax_deboarding_id);
-        self.cargo_boarding_percent = reader.read(&self.cargo_boarding_percent_id);
-        self.cargo_deboarding_percent = reader.read(&self.cargo_deboarding_percent_id);
-        self.boarding_state = reader.read(&self.boarding_state_id);
-        self.deboarding_state = reader.read(&self.deboarding_state_id);
-    }
-}
-
-pub struct GsxDriver {
-    gsx_input: GsxInput,
+struct GroundServicesDriver {
     performing_board: bool,
     performing_deboard: bool,
+    performing_cargo: bool,
     deboarding_total: i32,
 }
-impl GsxDriver {
-    pub fn new(context: &mut InitContext) -> Self {
-        GsxDriver {
-            gsx_input: GsxInput::new(context),
+impl GroundServicesDriver {
+    fn new() -> Self {
+        GroundServicesDriver {
             performing_board: false,
             performing_deboard: false,
+            performing_cargo: false,
             deboarding_total: 0,
         }
     }
 
-    fn is_enabled(&self) -> bool {
-        self.gsx_input.is_enabled()
-    }
-
-    fn deboarding_state(&self) -> GsxState {
-        self.gsx_input.deboarding_state()
-    }
-
-    fn boarding_state(&self) -> GsxState {
-        self.gsx_input.boarding_state()
-    }
-
-    fn has_pax<const P: usize, const G: usize>(
-        &self,
-        passenger_deck: &PassengerDeck<P, G>,
-    ) -> bool {
-        passenger_deck.has_pax()
-    }
-
-    fn pax_boarding(&self) -> i32 {
-        self.gsx_input.pax_boarding()
-    }
-
-    fn pax_deboarding(&self) -> i32 {
-        self.gsx_input.pax_deboarding()
-    }
-
-    fn cargo_boarding_percent(&self) -> f64 {
-        self.gsx_input.cargo_boarding_percent
-    }
-
-    fn cargo_deboarding_percent(&self) -> f64 {
-        self.gsx_input.cargo_deboarding_percent
-    }
-
-    pub fn update<const P: usize, const G: usize, const C: usize>(
+    fn update<const P: usize, const G: usize, const C: usize>(
         &mut self,
+        ground_services: &impl GroundServices,
         passenger_deck: &mut PassengerDeck<P, G>,
         cargo_deck: &mut CargoDeck<C>,
         boarding_sounds: &mut BoardingSounds,
     ) {
-        self.update_boarding_sounds(passenger_deck, boarding_sounds);
-        self.update_boarding(passenger_deck, cargo_deck);
-        self.update_deboarding(passenger_deck, cargo_deck);
+        self.update_boarding_sounds(ground_services, passenger_deck, boarding_sounds);
+        self.update_boarding(ground_services, passenger_deck);
+        self.update_cargo(ground_services, cargo_deck);
+        self.update_deboarding(ground_services, passenger_deck, cargo_deck);
     }
 
     fn update_boarding_sounds<const P: usize, const G: usize>(
         &mut self,
+        ground_services: &impl GroundServices,
         passenger_deck: &PassengerDeck<P, G>,
         boarding_sounds: &mut BoardingSounds,
     ) {
-        boarding_sounds.play_sound_pax_boarding(self.boarding_state() == GsxState::Performing);
-        boarding_sounds.play_sound_pax_deboarding(self.deboarding_state() == GsxState::Performing);
-        boarding_sounds.play_sound_pax_ambience(self.has_pax(passenger_deck));
-        boarding_sounds.play_sound_pax_complete(self.boarding_state() == GsxState::Completed)
+        boarding_sounds.play_sound_pax_boarding(
+            ground_services.state(GroundService::Boarding) == GroundServiceState::Performing,
+        );
+        boarding_sounds.play_sound_pax_deboarding(
+            ground_services.state(GroundService::Deboarding) == GroundServiceState::Performing,
+        );
+        boarding_sounds.play_sound_pax_ambience(passenger_deck.has_pax());
+        boarding_sounds.play_sound_pax_complete(
+            ground_services.state(GroundService::Boarding) == GroundServiceState::Completed,
+        )
     }
 
-    fn update_boarding<const P: usize, const G: usize, const C: usize>(
+    fn update_boarding<const P: usize, const G: usize>(
         &mut self,
+        ground_services: &impl GroundServices,
         passenger_deck: &mut PassengerDeck<P, G>,
-        cargo_deck: &mut CargoDeck<C>,
     ) {
-        match self.boarding_state() {
-            GsxState::None
-            | GsxState::Available
-            | GsxState::NotAvailable
-            | GsxState::Bypassed
-            | GsxState::Requested => {
+        match ground_services.state(GroundService::Boarding) {
+            GroundServiceState::None
+            | GroundServiceState::Available
+            | GroundServiceState::NotAvailable
+            | GroundServiceState::Bypassed
+            | GroundServiceState::Requested => {
                 self.performing_board = false;
             }
-            GsxState::Completed => {
+            GroundServiceState::Completed => {
                 if self.performing_board {
                     passenger_deck.spawn_all_pax();
-                    cargo_deck.spawn_all_cargo();
                 }
                 self.performing_board = false;
             }
-            GsxState::Performing => {
-                passenger_deck.board_pax_until_target(self.pax_boarding());
-                cargo_deck.load_cargo_deck_percent(self.cargo_boarding_percent());
+            GroundServiceState::Performing => {
+                passenger_deck.board_pax_until_target(ground_services.passengers_moved(
+                    GroundService::Boarding,
+                    passenger_deck.total_target_pax_num(),
+                ));
                 self.performing_board = true;
             }
         }
     }
 
+    fn update_cargo<const C: usize>(
+        &mut self,
+        ground_services: &impl GroundServices,
+        cargo_deck: &mut CargoDeck<C>,
+    ) {
+        match ground_services.state(GroundService::Cargo) {
+            GroundServiceState::None
+            | GroundServiceState::Available
+            | GroundServiceState::NotAvailable
+            | GroundServiceState::Bypassed
+            | GroundServiceState::Requested => {
+                self.performing_cargo = false;
+            }
+            GroundServiceState::Completed => {
+                if self.performing_cargo {
+                    cargo_deck.spawn_all_cargo();
+                }
+                self.performing_cargo = false;
+            }
+            GroundServiceState::Performing => {
+                cargo_deck.load_cargo_deck_percent(
+                    ground_services.progress_percent(GroundService::Cargo),
+                );
+                self.performing_cargo = true;
+            }
+        }
+    }
+
     fn update_deboarding<const P: usize, const G: usize, const C: usize>(
         &mut self,
+        ground_services: &impl GroundServices,
         passenger_deck: &mut PassengerDeck<P, G>,
         cargo_deck: &mut CargoDeck<C>,
     ) {
-        match self.deboarding_state() {
-            GsxState::None | GsxState::Available | GsxState::NotAvailable | GsxState::Bypassed => {
+        match ground_services.state(GroundService::Deboarding) {
+            GroundServiceState::None
+            | GroundServiceState::Available
+            | GroundServiceState::NotAvailable
+            | GroundServiceState::Bypassed => {
                 self.deboarding_total = 0;
                 self.performing_deboard = false;
             }
-            GsxState::Requested => {
+            GroundServiceState::Requested => {
                 cargo_deck.update_cargo_loaded();
                 passenger_deck.target_none();
                 cargo_deck.target_none();
                 self.deboarding_total = passenger_deck.total_pax_num();
                 self.performing_deboard = false;
             }
-            GsxState::Completed => {
+            GroundServiceState::Completed => {
                 if self.performing_deboard {
                     passenger_deck.spawn_all_pax();
                     cargo_deck.spawn_all_cargo();
@@ -1306,18 +1560,172 @@ impl GsxDriver {
                 self.deboarding_total = 0;
                 self.performing_deboard = false;
             }
-            GsxState::Performing => {
-                passenger_deck
-                    .deboard_pax_until_target(self.deboarding_total - self.pax_deboarding());
-                cargo_deck.load_cargo_deck_percent(100. - self.cargo_deboarding_percent());
+            GroundServiceState::Performing => {
+                passenger_deck.deboard_pax_until_target(
+                    self.deboarding_total
+                        - ground_services
+                            .passengers_moved(GroundService::Deboarding, self.deboarding_total),
+                );
+                cargo_deck.load_cargo_deck_percent(
+                    100. - ground_services.progress_percent(GroundService::Deboarding),
+                );
                 self.performing_deboard = true;
             }
         }
     }
 }
-impl SimulationElement for GsxDriver {
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.gsx_input.accept(visitor);
-        visitor.visit(self);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::Electricity,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft, UpdateContext,
+        },
+    };
+
+    struct NoGroundServices;
+    impl GroundServices for NoGroundServices {
+        fn is_enabled(&self) -> bool {
+            false
+        }
+
+        fn state(&self, _service: GroundService) -> GroundServiceState {
+            GroundServiceState::None
+        }
+
+        fn progress_percent(&self, _service: GroundService) -> f64 {
+            0.
+        }
+    }
+
+    struct PayloadTestAircraft {
+        payload_manager: PayloadManager<1, 0, 1>,
+    }
+    impl PayloadTestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let per_pax_weight = Rc::new(Cell::new(Mass::new::<kilogram>(84.)));
+            let developer_state = Rc::new(Cell::new(0));
+            let boarding_sounds = BoardingSounds::new(context);
+            let pax = Pax::new(
+                context.get_identifier("PAX_A".to_owned()),
+                context.get_identifier("PAX_A_DESIRED".to_owned()),
+                context.get_identifier("PAYLOAD_STATION_1_REQ".to_owned()),
+                developer_state.clone(),
+                per_pax_weight.clone(),
+                Vector3::new(0., 0., 0.),
+                36,
+            );
+            let cargo = Cargo::new(
+                context.get_identifier("CARGO_FWD".to_owned()),
+                context.get_identifier("CARGO_FWD_DESIRED".to_owned()),
+                context.get_identifier("PAYLOAD_STATION_2_REQ".to_owned()),
+                developer_state.clone(),
+                Vector3::new(0., 0., 0.),
+                Mass::new::<kilogram>(3000.),
+            );
+
+            Self {
+                payload_manager: PayloadManager::new(
+                    context,
+                    per_pax_weight,
+                    developer_state,
+                    boarding_sounds,
+                    PassengerDeck::new([pax], BoardingAgent::new(None, [0]), []),
+                    CargoDeck::new([cargo]),
+                    5,
+                    10,
+                ),
+            }
+        }
+    }
+    impl Aircraft for PayloadTestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            _electricity: &mut Electricity,
+        ) {
+            self.payload_manager
+                .update(context.delta(), &NoGroundServices);
+        }
+    }
+    impl SimulationElement for PayloadTestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.payload_manager.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_with_pax(pax: u64, pax_target: u64) -> SimulationTestBed<PayloadTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(PayloadTestAircraft::new);
+        test_bed.write_by_name("BOARDING_RATE", BoardingRate::Fast);
+        test_bed.write_by_name("WB_PER_PAX_WEIGHT", 84.);
+        test_bed.write_by_name("PAX_A", pax);
+        test_bed.write_by_name("PAX_A_DESIRED", pax_target);
+        test_bed.write_by_name("BOARDING_STARTED_BY_USR", true);
+
+        test_bed
+    }
+
+    fn board_ten_pax(target_load_within_limits: bool) -> SimulationTestBed<PayloadTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(PayloadTestAircraft::new);
+        test_bed.write_by_name("BOARDING_RATE", BoardingRate::Instant);
+        test_bed.write_by_name("WB_PER_PAX_WEIGHT", 84.);
+        test_bed.write_by_name("PAX_A_DESIRED", 0b11_1111_1111_u64);
+        test_bed.write_by_name("BOARDING_STARTED_BY_USR", true);
+        test_bed.command(|a| {
+            a.payload_manager
+                .set_target_load_within_limits(target_load_within_limits)
+        });
+        for _ in 0..20 {
+            test_bed.run();
+        }
+
+        test_bed
+    }
+
+    #[test]
+    fn target_load_within_limits_is_boarded() {
+        let mut test_bed = board_ten_pax(true);
+
+        let is_boarding: bool = test_bed.read_by_name("BOARDING_STARTED_BY_USR");
+        assert_eq!(test_bed.query(|a| a.payload_manager.pax_num(0)), 10);
+        assert!(!is_boarding);
+    }
+
+    #[test]
+    fn target_load_out_of_limits_refuses_boarding() {
+        let mut test_bed = board_ten_pax(false);
+
+        let is_boarding: bool = test_bed.read_by_name("BOARDING_STARTED_BY_USR");
+        let pax_boarding_sound: bool = test_bed.read_by_name("SOUND_PAX_BOARDING");
+        assert_eq!(test_bed.query(|a| a.payload_manager.pax_num(0)), 0);
+        assert!(!is_boarding);
+        assert!(!pax_boarding_sound);
+    }
+
+    #[test]
+    fn station_above_its_target_only_deboards_with_a_boarding_strategy() {
+        let mut test_bed = test_bed_with_pax(0b1111, 0b11_0000);
+        test_bed.write_by_name("BOARDING_STRATEGY", BoardingStrategy::BackToFront);
+
+        test_bed.run_with_delta(Duration::from_millis(100));
+        let pax: u64 = test_bed.read_by_name("PAX_A");
+        assert_eq!(pax.count_ones(), 3);
+        assert_eq!(pax & !0b1111, 0);
+
+        for _ in 0..20 {
+            test_bed.run_with_delta(Duration::from_millis(100));
+        }
+        let pax: u64 = test_bed.read_by_name("PAX_A");
+        assert_eq!(pax, 0b11_0000);
+    }
+
+    #[test]
+    fn unknown_boarding_strategy_boards_by_zone() {
+        assert_eq!(BoardingStrategy::from(7.), BoardingStrategy::ByZone);
     }
 }
@@ -14,6 +14,7 @@ use crate::{
 use uom::si::{
     f64::*,
     mass::kilogram,
+    mass_rate::kilogram_per_second,
     pressure::{pascal, psi},
     ratio::{percent, ratio},
     temperature_interval,
@@ -48,6 +49,8 @@ pub trait ControllablePneumaticValve: PneumaticValve {
         &mut self,
         controller: &U,
     );
+    fn open_amount(&self) -> Ratio;
+    fn fluid_flow(&self) -> MassRate;
 }
 
 pub trait PneumaticContainer {
@@ -527,24 +530,40 @@ impl From<f64> for EngineState {
     }
 }
 
+/// Air to air heat exchanger cooling the bleed air with fan air.
+///
+/// The heat exchange follows the effectiveness-NTU method for a counterflow exchanger: the
+/// effectiveness depends on the heat capacity rates of the bleed air flowing through the
+/// precooler and of the cooling air flowing out of the supply. The heat taken from the flowing
+/// bleed air is carried overboard by the cooling air. The remaining exchange capacity lets the air
+/// held on both sides of the core settle towards a common temperature, even without flow.
 pub struct Precooler {
     heat_transfer_coefficient: f64,
+    fouling: Ratio,
     internal_connector: PneumaticContainerConnector,
     exhaust: PneumaticExhaust,
+    effectiveness: Ratio,
 }
 impl Precooler {
     const HEAT_CAPACITY_CONSTANT_PRESSURE: f64 = 1.005e3;
 
-    /// The `heat_transfer_coefficient` contains both the heat transfer coefficient and the area of exchange.
+    /// The `heat_transfer_coefficient` contains both the heat transfer coefficient and the area of exchange (W/K).
     /// Typical values of the heat transfer coefficient for air to air coolers are 60-180 W/(m^2*K).
     pub fn new(heat_transfer_coefficient: f64) -> Self {
         Self {
             heat_transfer_coefficient,
+            fouling: Ratio::default(),
             internal_connector: PneumaticContainerConnector::new(),
             exhaust: PneumaticExhaust::new(3., 3., Pressure::new::<psi>(0.)),
+            effectiveness: Ratio::default(),
         }
     }
 
+    /// Fouling of the exchange surfaces reduces the heat transfer coefficient by the given ratio
+    pub fn set_fouling(&mut self, fouling: Ratio) {
+        self.fouling = fouling.max(Ratio::default()).min(Ratio::new::<ratio>(1.));
+    }
+
     pub fn update(
         &mut self,
         context: &UpdateContext,
@@ -552,21 +571,100 @@ impl Precooler {
         supply: &mut impl PneumaticContainer,
         container_two: &mut impl PneumaticContainer,
     ) {
-        let temperature_gradient = TemperatureInterval::new::<temperature_interval::degree_celsius>(
-            supply.temperature().get::<degree_celsius>()
-                - container_one.temperature().get::<degree_celsius>(),
-        );
-
-        let mass_energy_change = temperature_gradient / Self::HEAT_CAPACITY_CONSTANT_PRESSURE
-            * (self.heat_transfer_coefficient * context.delta_as_secs_f64());
-
-        supply.update_temperature(-mass_energy_change / supply.mass().get::<kilogram>());
-        container_one
-            .update_temperature(mass_energy_change / container_one.mass().get::<kilogram>());
+        let hot_air_inlet_temperature = container_one.temperature();
 
         self.exhaust.update_move_fluid(context, supply);
         self.internal_connector
             .update_move_fluid(context, container_one, container_two);
+
+        let hot_air_capacity_rate = self
+            .internal_connector
+            .fluid_flow()
+            .get::<kilogram_per_second>()
+            .max(0.)
+            * Self::HEAT_CAPACITY_CONSTANT_PRESSURE;
+        let cooling_air_capacity_rate = self
+            .exhaust
+            .fluid_flow()
+            .get::<kilogram_per_second>()
+            .max(0.)
+            * Self::HEAT_CAPACITY_CONSTANT_PRESSURE;
+
+        self.effectiveness = Ratio::new::<ratio>(Self::counterflow_effectiveness(
+            self.heat_transfer_coefficient * (1. - self.fouling.get::<ratio>()),
+            hot_air_capacity_rate,
+            cooling_air_capacity_rate,
+        ));
+
+        // Heat flow in W from the bleed air to the cooling air
+        let heat_flow = self.effectiveness.get::<ratio>()
+            * hot_air_capacity_rate.min(cooling_air_capacity_rate)
+            * (hot_air_inlet_temperature.get::<kelvin>() - supply.temperature().get::<kelvin>());
+
+        if container_two.mass() > Mass::default() {
+            container_two.update_temperature(TemperatureInterval::new::<
+                temperature_interval::kelvin,
+            >(
+                -heat_flow * context.delta_as_secs_f64()
+                    / (Self::HEAT_CAPACITY_CONSTANT_PRESSURE
+                        * container_two.mass().get::<kilogram>()),
+            ));
+        }
+
+        // Air held in the core passages exchanges heat through the core for the part of the
+        // exchange capacity which isn't used by the flowing air.
+        let core_heat_flow = (1. - self.effectiveness.get::<ratio>())
+            * self.heat_transfer_coefficient
+            * (1. - self.fouling.get::<ratio>())
+            * (container_one.temperature().get::<kelvin>() - supply.temperature().get::<kelvin>());
+        let core_heat_exchanged = core_heat_flow * context.delta_as_secs_f64();
+
+        if container_one.mass() > Mass::default() && supply.mass() > Mass::default() {
+            container_one.update_temperature(TemperatureInterval::new::<
+                temperature_interval::kelvin,
+            >(
+                -core_heat_exchanged
+                    / (Self::HEAT_CAPACITY_CONSTANT_PRESSURE
+                        * container_one.mass().get::<kilogram>()),
+            ));
+            supply.update_temperature(TemperatureInterval::new::<temperature_interval::kelvin>(
+                core_heat_exchanged
+                    / (Self::HEAT_CAPACITY_CONSTANT_PRESSURE * supply.mass().get::<kilogram>()),
+            ));
+        }
+    }
+
+    fn counterflow_effectiveness(
+        heat_transfer_coefficient: f64,
+        hot_air_capacity_rate: f64,
+        cooling_air_capacity_rate: f64,
+    ) -> f64 {
+        let min_capacity_rate = hot_air_capacity_rate.min(cooling_air_capacity_rate);
+        let max_capacity_rate = hot_air_capacity_rate.max(cooling_air_capacity_rate);
+
+        if min_capacity_rate <= 0. {
+            return 0.;
+        }
+
+        let number_of_transfer_units = heat_transfer_coefficient / min_capacity_rate;
+        let capacity_ratio = min_capacity_rate / max_capacity_rate;
+
+        if (1. - capacity_ratio).abs() < 1e-6 {
+            number_of_transfer_units / (1. + number_of_transfer_units)
+        } else {
+            let exponential = (-number_of_transfer_units * (1. - capacity_ratio)).exp();
+
+            (1. - exponential) / (1. - capacity_ratio * exponential)
+        }
+    }
+
+    /// Ratio of the heat exchanged to the maximum heat that could be exchanged with the current flows
+    pub fn effectiveness(&self) -> Ratio {
+        self.effectiveness
+    }
+
+    pub fn cooling_air_flow(&self) -> MassRate {
+        self.exhaust.fluid_flow()
     }
 }
 
@@ -1214,9 +1312,11 @@ mod tests {
         );
     }
 
-    #[test]
-    fn precooler_cools() {
-        let context = context(Duration::from_secs(1), Length::new::<foot>(0.));
+    fn precooler_outlet_temperature(
+        precooler: &mut Precooler,
+        supply_pressure: Pressure,
+    ) -> ThermodynamicTemperature {
+        let context = context(Duration::from_millis(100), Length::new::<foot>(0.));
 
         let mut from = PneumaticPipe::new(
             Volume::new::<cubic_meter>(1.),
@@ -1225,7 +1325,7 @@ mod tests {
         );
         let mut supply = PneumaticPipe::new(
             Volume::new::<cubic_meter>(1.),
-            Pressure::new::<psi>(14.7),
+            supply_pressure,
             ThermodynamicTemperature::new::<degree_celsius>(15.),
         );
         let mut to = PneumaticPipe::new(
@@ -1234,11 +1334,61 @@ mod tests {
             ThermodynamicTemperature::new::<degree_celsius>(200.),
         );
 
-        let mut precooler = Precooler::new(1.);
         precooler.update(&context, &mut from, &mut supply, &mut to);
 
-        // We only check whether this temperature stayed the same because the other temperatures are expected to change due to compression
-        assert!(supply.temperature() > ThermodynamicTemperature::new::<degree_celsius>(15.));
+        to.temperature()
+    }
+
+    #[test]
+    fn precooler_cools() {
+        let uncooled_outlet_temperature =
+            precooler_outlet_temperature(&mut Precooler::new(0.), Pressure::new::<psi>(20.));
+
+        let mut precooler = Precooler::new(1000.);
+        let outlet_temperature =
+            precooler_outlet_temperature(&mut precooler, Pressure::new::<psi>(20.));
+
+        assert!(precooler.effectiveness() > Ratio::default());
+        assert!(precooler.cooling_air_flow() > MassRate::default());
+        assert!(outlet_temperature < uncooled_outlet_temperature);
+    }
+
+    #[test]
+    fn precooler_does_not_cool_without_cooling_air_flow() {
+        let uncooled_outlet_temperature =
+            precooler_outlet_temperature(&mut Precooler::new(0.), Pressure::new::<psi>(10.));
+
+        let mut precooler = Precooler::new(1000.);
+        let outlet_temperature =
+            precooler_outlet_temperature(&mut precooler, Pressure::new::<psi>(10.));
+
+        assert_eq!(precooler.effectiveness(), Ratio::default());
+        assert_eq!(outlet_temperature, uncooled_outlet_temperature);
+    }
+
+    #[test]
+    fn precooler_cools_more_with_more_cooling_air_flow() {
+        let low_flow_outlet_temperature =
+            precooler_outlet_temperature(&mut Precooler::new(1000.), Pressure::new::<psi>(16.));
+        let high_flow_outlet_temperature =
+            precooler_outlet_temperature(&mut Precooler::new(1000.), Pressure::new::<psi>(30.));
+
+        assert!(high_flow_outlet_temperature < low_flow_outlet_temperature);
+    }
+
+    #[test]
+    fn fouled_precooler_cools_less() {
+        let mut clean_precooler = Precooler::new(1000.);
+        let mut fouled_precooler = Precooler::new(1000.);
+        fouled_precooler.set_fouling(Ratio::new::<percent>(60.));
+
+        let clean_outlet_temperature =
+            precooler_outlet_temperature(&mut clean_precooler, Pressure::new::<psi>(20.));
+        let fouled_outlet_temperature =
+            precooler_outlet_temperature(&mut fouled_precooler, Pressure::new::<psi>(20.));
+
+        assert!(fouled_precooler.effectiveness() < clean_precooler.effectiveness());
+        assert!(fouled_outlet_temperature > clean_outlet_temperature);
     }
 
     #[test]
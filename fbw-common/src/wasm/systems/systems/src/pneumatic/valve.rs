@@ -186,6 +186,7 @@ impl<const N: usize> SimulationElement for SolenoidValve<N> {
 
 pub struct ElectroPneumaticValve {
     open_amount: Ratio,
+    stuck_open_amount: Option<Ratio>,
     connector: PneumaticContainerConnector,
     is_powered: bool,
     powered_by: ElectricalBusType,
@@ -196,19 +197,30 @@ impl ElectroPneumaticValve {
     pub fn new(powered_by: ElectricalBusType) -> Self {
         Self {
             open_amount: Ratio::default(),
+            stuck_open_amount: None,
             connector: PneumaticContainerConnector::new(),
             is_powered: false,
             powered_by,
         }
     }
 
+    /// A stuck valve keeps the given open amount whatever the control signal or pressure.
+    /// Passing `None` frees the valve.
+    pub fn set_stuck(&mut self, stuck_open_amount: Option<Ratio>) {
+        self.stuck_open_amount = stuck_open_amount;
+
+        if let Some(open_amount) = stuck_open_amount {
+            self.open_amount = open_amount;
+        }
+    }
+
     pub fn update_move_fluid(
         &mut self,
         context: &UpdateContext,
         container_one: &mut impl PneumaticContainer,
         container_two: &mut impl PneumaticContainer,
     ) {
-        if !self.is_powered {
+        if !self.is_powered && self.stuck_open_amount.is_none() {
             self.set_open_amount_from_pressure_difference(
                 container_one.pressure() - container_two.pressure(),
             )
@@ -250,12 +262,20 @@ impl ControllablePneumaticValve for ElectroPneumaticValve {
         &mut self,
         controller: &U,
     ) {
-        if self.is_powered {
+        if self.is_powered && self.stuck_open_amount.is_none() {
             if let Some(signal) = controller.signal() {
                 self.open_amount = signal.target_open_amount();
             }
         }
     }
+
+    fn open_amount(&self) -> Ratio {
+        self.open_amount
+    }
+
+    fn fluid_flow(&self) -> MassRate {
+        self.connector.fluid_flow()
+    }
 }
 impl SimulationElement for ElectroPneumaticValve {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -412,6 +432,14 @@ impl ControllablePneumaticValve for DefaultValve {
             self.open_amount = signal.target_open_amount();
         }
     }
+
+    fn open_amount(&self) -> Ratio {
+        self.open_amount
+    }
+
+    fn fluid_flow(&self) -> MassRate {
+        self.connector.fluid_flow()
+    }
 }
 impl SimulationElement for DefaultValve {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -906,6 +934,29 @@ mod tests {
         assert_eq!(valve.open_amount(), Ratio::new::<ratio>(0.));
     }
 
+    #[test]
+    fn stuck_electropneumatic_valve_ignores_signal_and_pressure() {
+        let mut container_one = quick_container(1., 14., 15.);
+        let mut container_two = quick_container(1., 14., 15.);
+
+        let controller = TestValveController::new(Ratio::new::<percent>(0.));
+
+        let mut valve = ElectroPneumaticValve::new(ElectricalBusType::DirectCurrent(2));
+        valve.set_stuck(Some(Ratio::new::<percent>(100.)));
+
+        valve.update_open_amount(&controller);
+
+        let context = context(Duration::from_secs(1), Length::new::<foot>(0.));
+        valve.update_move_fluid(&context, &mut container_one, &mut container_two);
+
+        assert_eq!(valve.open_amount(), Ratio::new::<percent>(100.));
+
+        valve.set_stuck(None);
+        valve.update_move_fluid(&context, &mut container_one, &mut container_two);
+
+        assert_eq!(valve.open_amount(), Ratio::new::<percent>(0.));
+    }
+
     #[test]
     fn exhaust_makes_pressure_go_to_ambient_pressure() {
         let mut container = quick_container(1., 20., 15.);
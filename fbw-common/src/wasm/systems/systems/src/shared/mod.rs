@@ -208,6 +208,10 @@ pub trait EngineUncorrectedN2 {
     fn uncorrected_n2(&self) -> Ratio;
 }
 
+pub trait EngineFuelFlow {
+    fn fuel_flow(&self) -> MassRate;
+}
+
 pub trait CabinAltitude {
     fn altitude(&self) -> Length;
 }
@@ -220,6 +224,13 @@ pub trait CabinSimulation {
     fn cabin_pressure(&self) -> Pressure {
         Pressure::new::<hectopascal>(1013.25)
     }
+    /// Volumetric CO2 concentration of each cabin zone
+    fn cabin_co2_concentration(&self) -> Vec<Ratio> {
+        Vec::new()
+    }
+    fn cabin_relative_humidity(&self) -> Vec<Ratio> {
+        Vec::new()
+    }
 }
 
 pub trait PneumaticBleed {
@@ -252,6 +263,7 @@ pub trait AdirsMeasurementOutputs {
     fn vertical_speed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
     fn altitude(&self, adiru_number: usize) -> Arinc429Word<Length>;
     fn angle_of_attack(&self, adiru_number: usize) -> Arinc429Word<Angle>;
+    fn total_air_temperature(&self, adiru_number: usize) -> Arinc429Word<ThermodynamicTemperature>;
 }
 
 pub trait AdirsDiscreteOutputs {
@@ -292,6 +304,23 @@ impl Display for HydraulicColor {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HydraulicSectionId {
+    /// Pump section identified by its pump number (starting at 1)
+    Pump(usize),
+    System,
+    Auxiliary,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FlightControlSurface {
+    LeftAileron,
+    RightAileron,
+    LeftElevator,
+    RightElevator,
+    Rudder,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AirbusEngineDrivenPumpId {
     Edp1a,
@@ -1091,6 +1120,16 @@ pub enum FireDetectionLoopID {
     B,
 }
 
+/// Zones crossed by hot bleed air ducts and monitored by the leak detection loops.
+/// Pylon and wing zones are numbered by engine (or wing side) from left to right.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BleedAirDuctZone {
+    Pylon(usize),
+    Wing(usize),
+    ApuDuct,
+    PackBay,
+}
+
 pub trait Clamp {
     /// Restrict a value to a certain interval unless it is NaN.
     ///
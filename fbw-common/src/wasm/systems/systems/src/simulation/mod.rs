@@ -32,6 +32,9 @@ pub trait SimulatorReaderWriter {
     fn read(&mut self, identifier: &VariableIdentifier) -> f64;
     /// Writes a variable with the given identifier to the simulator.
     fn write(&mut self, identifier: &VariableIdentifier, value: f64);
+    /// Writes a string with the given identifier to the simulator. Simulator variables only
+    /// hold numbers, so implementors publish strings through other means.
+    fn write_string(&mut self, identifier: &VariableIdentifier, value: &str);
 }
 
 pub trait VariableRegistry {
@@ -409,6 +412,7 @@ impl<T: Aircraft> Simulation<T> {
     /// # impl SimulatorReaderWriter for MySimulatorReaderWriter {
     /// #     fn read(&mut self, identifier: &VariableIdentifier) -> f64 { 0.0 }
     /// #     fn write(&mut self, identifier: &VariableIdentifier, value: f64) { }
+    /// #     fn write_string(&mut self, identifier: &VariableIdentifier, value: &str) { }
     /// # }
     /// # struct MyVariableRegistry {}
     /// # impl MyVariableRegistry {
@@ -567,6 +571,10 @@ impl<'a> SimulatorWriter<'a> {
             simulator_read_writer,
         }
     }
+
+    pub fn write_string(&mut self, identifier: &VariableIdentifier, value: &str) {
+        self.simulator_read_writer.write_string(identifier, value);
+    }
 }
 impl Writer for SimulatorWriter<'_> {
     fn write_f64(&mut self, identifier: &VariableIdentifier, value: f64) {
@@ -145,6 +145,10 @@ pub trait TestBed {
         self.test_bed().contains_variable_with_name(name)
     }
 
+    fn read_string_by_name(&mut self, name: &str) -> String {
+        self.test_bed_mut().read_string_by_name(name)
+    }
+
     fn get_variable_identifier(&mut self, name: &str) -> Option<&VariableIdentifier> {
         self.test_bed_mut().get_variable_identifier(name)
     }
@@ -459,6 +463,13 @@ impl<T: Aircraft> SimulationTestBed<T> {
         self.reader_writer.read_f64(identifier)
     }
 
+    fn read_string_by_name(&mut self, name: &str) -> String {
+        match self.variable_registry.find(name) {
+            Some(identifier) => self.reader_writer.read_string(identifier),
+            None => String::new(),
+        }
+    }
+
     fn contains_variable_with_name(&self, name: &str) -> bool {
         match self.variable_registry.find(name) {
             Some(identifier) => self.reader_writer.contains(identifier),
@@ -616,14 +627,20 @@ impl<T: SimulationElement> SimulationElement for TestAircraft<T> {
 
 struct TestReaderWriter {
     variables: FxHashMap<VariableIdentifier, f64>,
+    strings: FxHashMap<VariableIdentifier, String>,
 }
 impl TestReaderWriter {
     fn new() -> Self {
         Self {
             variables: FxHashMap::default(),
+            strings: FxHashMap::default(),
         }
     }
 
+    fn read_string(&self, identifier: &VariableIdentifier) -> String {
+        self.strings.get(identifier).cloned().unwrap_or_default()
+    }
+
     fn contains(&self, identifier: &VariableIdentifier) -> bool {
         self.variables.contains_key(identifier)
     }
@@ -644,6 +661,10 @@ impl SimulatorReaderWriter for TestReaderWriter {
     fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
         self.variables.insert(*identifier, value);
     }
+
+    fn write_string(&mut self, identifier: &VariableIdentifier, value: &str) {
+        self.strings.insert(*identifier, value.to_owned());
+    }
 }
 
 impl Default for TestReaderWriter {
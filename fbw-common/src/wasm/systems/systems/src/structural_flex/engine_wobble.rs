@@ -11,7 +11,7 @@ use crate::{
 
 use nalgebra::Vector3;
 use std::fmt::Debug;
-use uom::si::{acceleration::meter_per_second_squared, f64::*};
+use uom::si::{acceleration::meter_per_second_squared, f64::*, ratio::ratio};
 
 /// Solves a basic mass connected to a static point through a spring damper system
 /// Mass center of gravity position reacting to external accelerations is then used to model engine wobbling movement
@@ -86,6 +86,11 @@ impl EngineFlexPhysics {
 
         self.animation_position = (limited_pos + 1.) / 2.;
     }
+
+    /// Engine displacement on its pylon, 0 when centered, 1 when at the animation limit
+    pub fn wobble_amplitude(&self) -> Ratio {
+        Ratio::new::<ratio>((2. * self.animation_position - 1.).abs())
+    }
 }
 impl SimulationElement for EngineFlexPhysics {
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -151,6 +156,10 @@ impl<const N: usize> EnginesFlexiblePhysics<N> {
             }
         }
     }
+
+    pub fn wobble_amplitudes(&self) -> [Ratio; N] {
+        core::array::from_fn(|engine_index| self.engines_flex[engine_index].wobble_amplitude())
+    }
 }
 impl SimulationElement for EnginesFlexiblePhysics<4> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
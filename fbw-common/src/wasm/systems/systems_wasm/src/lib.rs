@@ -24,7 +24,7 @@ use ::msfs::{
 };
 use failures::Failures;
 use fxhash::FxHashMap;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::fmt::{Display, Formatter};
 use std::rc::Rc;
 use std::{error::Error, time::Duration};
@@ -180,6 +180,8 @@ pub struct MsfsHandler {
     aspects: Vec<Box<dyn Aspect>>,
     failures: Rc<RefCell<Failures>>,
     _commbus: CommBus<'static>,
+    published_strings: FxHashMap<VariableIdentifier, String>,
+    string_request: Rc<Cell<bool>>,
     time: Time,
 }
 impl MsfsHandler {
@@ -198,11 +200,20 @@ impl MsfsHandler {
             });
         }
         CommBus::call("FBW_FAILURE_REQUEST", "", CommBusBroadcastFlags::JS);
+        let string_request = Rc::new(Cell::new(false));
+        {
+            let string_request = string_request.clone();
+            commbus.register("FBW_SYSTEMS_STRING_REQUEST", move |_| {
+                string_request.set(true);
+            });
+        }
         Ok(Self {
             variables: Some(variables),
             aspects,
             failures,
             _commbus: commbus,
+            published_strings: FxHashMap::default(),
+            string_request,
             time: Time::new(sim_connect)?,
         })
     }
@@ -217,6 +228,9 @@ impl MsfsHandler {
             MSFSEvent::PreDraw(_) => {
                 if !self.time.is_pausing() {
                     let delta_time = self.time.take();
+                    if self.string_request.take() {
+                        self.published_strings.clear();
+                    }
                     self.pre_tick(sim_connect, delta_time)?;
                     self.read_failures_into_simulation(simulation);
 
@@ -312,6 +326,24 @@ impl SimulatorReaderWriter for MsfsHandler {
             variable_registry.write(identifier, value);
         }
     }
+
+    /// Strings are broadcast to JS over the CommBus, on an event named after the variable.
+    /// They are only broadcast when they change, or on the next tick after JS sends
+    /// `FBW_SYSTEMS_STRING_REQUEST`, so that late subscribers receive the current values.
+    fn write_string(&mut self, identifier: &VariableIdentifier, value: &str) {
+        if self.published_strings.get(identifier).map(String::as_str) == Some(value) {
+            return;
+        }
+
+        if let Some(name) = self
+            .variables
+            .as_ref()
+            .and_then(|registry| registry.prefixed_name(identifier))
+        {
+            CommBus::call(name, value, CommBusBroadcastFlags::JS);
+            self.published_strings.insert(*identifier, value.to_owned());
+        }
+    }
 }
 
 /// Declares a variable of a given type with a given name.
@@ -474,6 +506,7 @@ impl VariableValue {
 pub struct MsfsVariableRegistry {
     named_variable_prefix: String,
     name_to_identifier: FxHashMap<String, VariableIdentifier>,
+    named_variable_names: FxHashMap<VariableIdentifier, String>,
     next_variable_identifier: FxHashMap<VariableType, VariableIdentifier>,
     variables: [Vec<VariableValue>; 3],
 }
@@ -483,6 +516,7 @@ impl MsfsVariableRegistry {
         Self {
             named_variable_prefix,
             name_to_identifier: FxHashMap::default(),
+            named_variable_names: FxHashMap::default(),
             next_variable_identifier: FxHashMap::default(),
             variables: [vec![], vec![], vec![]],
         }
@@ -528,6 +562,8 @@ impl MsfsVariableRegistry {
                 let mut variable = variable.clone();
                 if matches!(variable, Variable::Named(..)) {
                     variable.add_prefix(&self.named_variable_prefix);
+                    self.named_variable_names
+                        .insert(identifier, variable.lookup_name());
                 }
 
                 let value: VariableValue = (&variable).into();
@@ -553,6 +589,12 @@ impl MsfsVariableRegistry {
     fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
         self.variables[identifier.identifier_type()][identifier.identifier_index()].write(value);
     }
+
+    fn prefixed_name(&self, identifier: &VariableIdentifier) -> Option<&str> {
+        self.named_variable_names
+            .get(identifier)
+            .map(String::as_str)
+    }
 }
 
 impl VariableRegistry for MsfsVariableRegistry {